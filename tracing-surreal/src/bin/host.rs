@@ -1,21 +1,27 @@
 use est::AnyRes;
-use std::time::Duration;
+use std::{env, time::Duration};
 use surrealdb::{
-    engine::remote::ws::{Client, Ws},
+    engine::any::{self, Any},
     opt::auth::Root,
     Surreal,
 };
 use tokio::time::timeout;
 use tracing_surreal::{stop::Stop, tmp::server::BuildServerDefault};
 
-async fn db() -> AnyRes<Surreal<Client>> {
-    let db = Surreal::new::<Ws>("localhost:8000").await?;
+// `SURREAL_ENDPOINT` may also be `mem://` or `rocksdb://path` (features `kv-mem` / `kv-rocksdb`).
+async fn db() -> AnyRes<Surreal<Any>> {
+    let endpoint = env::var("SURREAL_ENDPOINT").unwrap_or("ws://localhost:8000".into());
+    let db = any::connect(endpoint.as_str()).await?;
+
+    // There is no root user to sign in as with the embedded engines.
+    if endpoint.starts_with("ws") || endpoint.starts_with("http") {
+        db.signin(Root {
+            username: "root",
+            password: "root",
+        })
+        .await?;
+    }
 
-    db.signin(Root {
-        username: "root",
-        password: "root",
-    })
-    .await?;
     db.use_ns("root").await?;
     Ok(db)
 }
@@ -1,21 +1,20 @@
 use est::AnyRes;
 use std::time::Duration;
 use tokio::time::sleep;
-use tokio_tungstenite::{connect_async, tungstenite::error};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_surreal::{tracing_msg::TracingLayerDefault, ws_pusher::WsPusher};
 
 #[tokio::main]
 async fn main() -> AnyRes {
-    let res =
-        connect_async("ws://127.0.0.1:8192/pusher?111=222&token=fucker&333=444&format=bincode")
-            .await;
-    if let Err(error::Error::Http(resp)) = &res {
-        println!(
-            "{}",
-            String::from_utf8(resp.body().clone().unwrap()).unwrap()
-        );
-    }
-    let (_stream, _resp) = res?;
+    let pusher = WsPusher::builder("ws://127.0.0.1:8192/pusher", "pusher")
+        .token("fucker")
+        .connect()
+        .await?;
+    let (layer, routine) = pusher.tracing_layer_default().build();
+    tracing::subscriber::set_global_default(tracing_subscriber::registry().with(layer))?;
+
+    tracing::info!("Hello, world!");
     sleep(Duration::from_secs(10)).await;
-    println!("Hello, world!");
+    println!("{:?}", routine.graceful_shutdown().await?);
     Ok(())
 }
@@ -1,7 +1,7 @@
 use est::AnyRes;
-use std::time::Duration;
+use std::{env, time::Duration};
 use surrealdb::{
-    engine::remote::ws::{Client, Ws},
+    engine::any::{self, Any},
     opt::auth::Root,
     Surreal,
 };
@@ -10,14 +10,20 @@ use tokio_util::sync::CancellationToken;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 use tracing_surreal::{stop::Stop, tracing_msg::TracingLayerDefault};
 
-async fn db() -> AnyRes<Surreal<Client>> {
-    let db = Surreal::new::<Ws>("localhost:8000").await?;
+// `SURREAL_ENDPOINT` may also be `mem://` or `rocksdb://path` (features `kv-mem` / `kv-rocksdb`).
+async fn db() -> AnyRes<Surreal<Any>> {
+    let endpoint = env::var("SURREAL_ENDPOINT").unwrap_or("ws://localhost:8000".into());
+    let db = any::connect(endpoint.as_str()).await?;
+
+    // There is no root user to sign in as with the embedded engines.
+    if endpoint.starts_with("ws") || endpoint.starts_with("http") {
+        db.signin(Root {
+            username: "root",
+            password: "root",
+        })
+        .await?;
+    }
 
-    db.signin(Root {
-        username: "root",
-        password: "root",
-    })
-    .await?;
     db.use_ns("root").await?;
     Ok(db)
 }
@@ -58,7 +64,7 @@ async fn main() -> AnyRes {
         }
         _ = &mut routine_trace => {
             println!("routine_trace exited");
-            println!("{:?}", stop.query_last_n(3).await);
+            println!("{:?}", stop.query().fetch().await);
             routine_msg.graceful_shutdown().await??;
             routine_obs.graceful_shutdown().await??
         }
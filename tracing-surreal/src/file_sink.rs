@@ -0,0 +1,736 @@
+use crate::tracing_msg::{
+    observe::{ClientId, CloseInfo, MsgInfo},
+    Callsite, CallsiteId, ClientInfo, CloseMsg, CloseTransport, HelloMsg, MsgBody, MsgFormat,
+    ObserveMsg, PushMsg, Role, TracingMsg,
+};
+use chrono::{DateTime, Local};
+use either::Either;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fs::{self, File},
+    io::{self, BufWriter, Read, Write},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+use surrealdb::RecordId;
+use thiserror::Error;
+use tokio::task::spawn_blocking;
+use ulid::Ulid;
+
+pub use crate::tracing_msg;
+
+const JSON_EXTENSION: &str = "jsonl";
+const MSGPACK_EXTENSION: &str = "msgpack";
+const GZIP_EXTENSION: &str = "gz";
+const CLIENT_TABLE: &str = "file";
+
+#[derive(Error, Debug)]
+pub enum FileSinkError {
+    #[error("io error: `{0}`")]
+    Io(#[from] io::Error),
+    #[error("serde_json error: `{0}`")]
+    Json(#[from] serde_json::Error),
+    #[error("rmp_serde encode error: `{0}`")]
+    MsgpackEncode(#[from] rmp_serde::encode::Error),
+    #[error("rmp_serde decode error: `{0}`")]
+    MsgpackDecode(#[from] rmp_serde::decode::Error),
+    // Bincode cannot encode the flattened & internally tagged `MsgBody` of `TracingMsg`.
+    #[error("unsupported msg format: `{0:?}`")]
+    UnsupportedFormat(MsgFormat),
+    #[error("not a segment file: `{0}`")]
+    NotSegment(PathBuf),
+    #[error("segment without hello header: `{0}`")]
+    MissingHello(PathBuf),
+    #[error("file sink closed")]
+    Closed,
+}
+
+// Every segment starts with a `Hello` header, so that it can be read on its own,
+// the last one of a session ends with a `Close` trailer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum FileRecord {
+    Hello {
+        client_key: String,
+        client_info: Box<ClientInfo>,
+    },
+    Msg(TracingMsg),
+    Close {
+        close_timestamp: DateTime<Local>,
+        close_msg: CloseMsg,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct FileSinkBuilder {
+    dir: PathBuf,
+    hello_msg: HelloMsg,
+    format: MsgFormat,
+    max_segment_bytes: Option<u64>,
+    rotate_every: Option<Duration>,
+    gzip: bool,
+    max_segments: Option<NonZeroUsize>,
+}
+
+impl FileSinkBuilder {
+    // `Json` writes JSON lines, `Msgpack` writes `u32` (little endian) length prefixed frames.
+    pub fn msg_format(self, format: MsgFormat) -> Self {
+        Self { format, ..self }
+    }
+
+    pub fn max_segment_bytes(self, max_bytes: u64) -> Self {
+        Self {
+            max_segment_bytes: Some(max_bytes),
+            ..self
+        }
+    }
+
+    // Checked on every push, so an idle sink rotates on its next message.
+    pub fn rotate_every(self, interval: Duration) -> Self {
+        Self {
+            rotate_every: Some(interval),
+            ..self
+        }
+    }
+
+    pub fn disable_rotation(self) -> Self {
+        Self {
+            max_segment_bytes: None,
+            rotate_every: None,
+            ..self
+        }
+    }
+
+    // Rotated (and closed) segments get compressed into `.gz` files.
+    pub fn gzip_rotated(self) -> Self {
+        Self { gzip: true, ..self }
+    }
+
+    // Only the newest segments of the sink are kept, the older ones are deleted on rotation.
+    pub fn max_segments(self, max_segments: NonZeroUsize) -> Self {
+        Self {
+            max_segments: Some(max_segments),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> Result<FileSink, FileSinkError> {
+        if self.format == MsgFormat::Bincode {
+            return Err(FileSinkError::UnsupportedFormat(self.format));
+        }
+
+        fs::create_dir_all(&self.dir)?;
+
+        let client_key = Ulid::new().to_string();
+        let client_info = ClientInfo {
+            hello_timestamp: Local::now(),
+            hello_msg: self.hello_msg.clone(),
+            client_role: Role::pusher(),
+            client_addr: None,
+        };
+        let mut inner = Inner {
+            config: self,
+            client_key,
+            client_info,
+            seq: 0,
+            segment: None,
+            closed: Default::default(),
+            callsites: Default::default(),
+        };
+        inner.open_segment()?;
+
+        Ok(FileSink(Arc::new(Mutex::new(inner))))
+    }
+}
+
+#[derive(Debug)]
+struct Segment {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    bytes: u64,
+    opened: Instant,
+}
+
+#[derive(Debug)]
+struct Inner {
+    config: FileSinkBuilder,
+    client_key: String,
+    client_info: ClientInfo,
+    seq: u64,
+    segment: Option<Segment>,
+    // Oldest first, for `max_segments`
+    closed: VecDeque<PathBuf>,
+    // Written again after the hello of every segment, so that each one can be read on its own.
+    callsites: IndexMap<CallsiteId, TracingMsg>,
+}
+
+impl Inner {
+    fn extension(&self) -> &'static str {
+        match self.config.format {
+            MsgFormat::Msgpack => MSGPACK_EXTENSION,
+            _ => JSON_EXTENSION,
+        }
+    }
+
+    fn open_segment(&mut self) -> Result<(), FileSinkError> {
+        let name = format!("{}.{:06}.{}", self.client_key, self.seq, self.extension());
+        let path = self.config.dir.join(name);
+        let writer = BufWriter::new(File::create_new(&path)?);
+        let hello = FileRecord::Hello {
+            client_key: self.client_key.clone(),
+            client_info: Box::new(self.client_info.clone()),
+        };
+
+        self.seq += 1;
+        self.segment = Some(Segment {
+            path,
+            writer,
+            bytes: 0,
+            opened: Instant::now(),
+        });
+        self.prune()?;
+        self.append(&hello)?;
+
+        for msg in self.callsites.values().cloned().collect::<Vec<_>>() {
+            self.append(&FileRecord::Msg(msg))?;
+        }
+
+        Ok(())
+    }
+
+    fn prune(&mut self) -> Result<(), FileSinkError> {
+        let Some(max_segments) = self.config.max_segments else {
+            return Ok(());
+        };
+
+        while self.closed.len() + usize::from(self.segment.is_some()) > max_segments.get() {
+            let Some(path) = self.closed.pop_front() else {
+                break;
+            };
+
+            match fs::remove_file(path) {
+                Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err.into()),
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn close_segment(&mut self) -> Result<(), FileSinkError> {
+        let Some(mut segment) = self.segment.take() else {
+            return Ok(());
+        };
+
+        segment.writer.flush()?;
+        drop(segment.writer);
+
+        let path = match self.config.gzip {
+            true => gzip(&segment.path)?,
+            false => segment.path,
+        };
+
+        self.closed.push_back(path);
+        Ok(())
+    }
+
+    fn should_rotate(&self, segment: &Segment, len: u64) -> bool {
+        let full = self
+            .config
+            .max_segment_bytes
+            .is_some_and(|max_bytes| segment.bytes > 0 && segment.bytes + len > max_bytes);
+        let expired = self
+            .config
+            .rotate_every
+            .is_some_and(|interval| segment.opened.elapsed() >= interval);
+
+        full || expired
+    }
+
+    fn write(&mut self, record: &FileRecord) -> Result<(), FileSinkError> {
+        let buf = encode(self.config.format, record)?;
+        let segment = self.segment.as_ref().ok_or(FileSinkError::Closed)?;
+
+        if matches!(record, FileRecord::Msg(_)) && self.should_rotate(segment, buf.len() as u64) {
+            self.close_segment()?;
+            self.open_segment()?;
+        }
+
+        if let FileRecord::Msg(msg) = record {
+            if let MsgBody::OnRegisterCallsite { callsite_id, .. } = &msg.body {
+                self.callsites.insert(*callsite_id, msg.clone());
+            }
+        }
+
+        self.write_buf(&buf)
+    }
+
+    // Without rotating
+    fn append(&mut self, record: &FileRecord) -> Result<(), FileSinkError> {
+        let buf = encode(self.config.format, record)?;
+        self.write_buf(&buf)
+    }
+
+    fn write_buf(&mut self, buf: &[u8]) -> Result<(), FileSinkError> {
+        let segment = self.segment.as_mut().ok_or(FileSinkError::Closed)?;
+        segment.writer.write_all(buf)?;
+        segment.bytes += buf.len() as u64;
+
+        Ok(())
+    }
+}
+
+// Cheap to clone, all the clones write to the same segments.
+#[derive(Debug, Clone)]
+pub struct FileSink(Arc<Mutex<Inner>>);
+
+impl FileSink {
+    pub fn builder(dir: impl Into<PathBuf>, hello_msg: HelloMsg) -> FileSinkBuilder {
+        FileSinkBuilder {
+            dir: dir.into(),
+            hello_msg,
+            format: MsgFormat::Json,
+            max_segment_bytes: Some(64 * 1024 * 1024),
+            rotate_every: None,
+            gzip: false,
+            max_segments: None,
+        }
+    }
+
+    // The file IO (including the rotation & the gzip compression) runs on the blocking threads.
+    async fn blocking<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut Inner) -> R + Send + 'static,
+    ) -> Result<R, FileSinkError> {
+        let inner = self.0.clone();
+
+        spawn_blocking(move || f(&mut inner.lock().unwrap_or_else(PoisonError::into_inner)))
+            .await
+            .map_err(|err| io::Error::other(err).into())
+    }
+}
+
+impl PushMsg for FileSink {
+    type Error = FileSinkError;
+
+    async fn bulk_push(&mut self, msgs: Vec<TracingMsg>) -> Result<(), Self::Error> {
+        self.blocking(|inner| {
+            for msg in msgs {
+                inner.write(&FileRecord::Msg(msg))?;
+            }
+
+            let segment = inner.segment.as_mut().ok_or(FileSinkError::Closed)?;
+            segment.writer.flush()?;
+
+            Ok(())
+        })
+        .await?
+    }
+}
+
+impl CloseTransport for FileSink {
+    async fn close_transport(&mut self, msg: Option<CloseMsg>) {
+        let close = msg.map(|close_msg| FileRecord::Close {
+            close_timestamp: Local::now(),
+            close_msg,
+        });
+
+        let _ = self
+            .blocking(|inner| {
+                if let Some(close) = close {
+                    let _ = inner.write(&close);
+                }
+
+                let _ = inner.close_segment();
+            })
+            .await;
+    }
+}
+
+fn encode(format: MsgFormat, record: &FileRecord) -> Result<Vec<u8>, FileSinkError> {
+    match format {
+        MsgFormat::Json => {
+            let mut buf = serde_json::to_vec(record)?;
+            buf.push(b'\n');
+            Ok(buf)
+        }
+        MsgFormat::Msgpack => {
+            let frame = rmp_serde::to_vec_named(record)?;
+            let len = u32::try_from(frame.len()).map_err(io::Error::other)?;
+            let mut buf = Vec::with_capacity(frame.len() + 4);
+            buf.extend_from_slice(&len.to_le_bytes());
+            buf.extend_from_slice(&frame);
+            Ok(buf)
+        }
+        MsgFormat::Bincode => Err(FileSinkError::UnsupportedFormat(format)),
+    }
+}
+
+fn gzip(path: &Path) -> io::Result<PathBuf> {
+    let mut gz_path = path.as_os_str().to_owned();
+    gz_path.push(".");
+    gz_path.push(GZIP_EXTENSION);
+
+    let mut encoder = GzEncoder::new(File::create(&gz_path)?, Compression::default());
+    io::copy(&mut File::open(path)?, &mut encoder)?;
+    encoder.finish()?.sync_all()?;
+    fs::remove_file(path)?;
+
+    Ok(gz_path.into())
+}
+
+// A truncated last record (e.g. after a crash) is ignored.
+fn decode(path: &Path) -> Result<Vec<FileRecord>, FileSinkError> {
+    let not_segment = || FileSinkError::NotSegment(path.into());
+    let mut name = path.file_name().ok_or_else(not_segment)?.to_string_lossy();
+    let mut data = Vec::new();
+
+    if let Some(stripped) = name.strip_suffix(&format!(".{}", GZIP_EXTENSION)) {
+        name = stripped.to_owned().into();
+        GzDecoder::new(File::open(path)?).read_to_end(&mut data)?;
+    } else {
+        File::open(path)?.read_to_end(&mut data)?;
+    }
+
+    if name.ends_with(&format!(".{}", JSON_EXTENSION)) {
+        let mut lines: Vec<_> = data.split(|byte| *byte == b'\n').collect();
+        lines.pop();
+
+        Ok(lines
+            .into_iter()
+            .map(serde_json::from_slice)
+            .collect::<Result<_, _>>()?)
+    } else if name.ends_with(&format!(".{}", MSGPACK_EXTENSION)) {
+        let mut records = Vec::new();
+        let mut rest = data.as_slice();
+
+        while let Some(len) = rest.get(..4) {
+            let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+            let Some(frame) = rest.get(4..4 + len) else {
+                break;
+            };
+
+            records.push(rmp_serde::from_slice(frame)?);
+            rest = &rest[4 + len..];
+        }
+
+        Ok(records)
+    } else {
+        Err(not_segment())
+    }
+}
+
+fn is_segment(path: &Path) -> bool {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let name = name
+        .strip_suffix(&format!(".{}", GZIP_EXTENSION))
+        .unwrap_or(&name);
+
+    [JSON_EXTENSION, MSGPACK_EXTENSION]
+        .iter()
+        .any(|ext| name.ends_with(&format!(".{}", ext)))
+}
+
+// Carried over the segments of a session: `clients` keeps its `OnClientHello` from being repeated
+// by the later segments, `callsites` resolves the callsites registered in the earlier ones.
+#[derive(Default)]
+struct Seen {
+    clients: HashSet<String>,
+    callsites: HashMap<CallsiteId, Callsite>,
+}
+
+fn to_observe_msgs(path: &Path, seen: &mut Seen) -> Result<Vec<ObserveMsg>, FileSinkError> {
+    let records = decode(path)?;
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let mut client_id = None;
+    let mut msgs = Vec::with_capacity(records.len());
+
+    for (index, record) in records.into_iter().enumerate() {
+        let msg_key = format!("{}:{}", name, index);
+
+        match record {
+            FileRecord::Hello {
+                client_key,
+                client_info,
+            } => {
+                let id: ClientId =
+                    RecordId::from_table_key(CLIENT_TABLE, client_key.clone()).into();
+
+                if seen.clients.insert(client_key) {
+                    msgs.push(ObserveMsg::OnClientHello(id.clone(), *client_info));
+                }
+
+                client_id = Some(id);
+            }
+            FileRecord::Msg(tracing_msg) => {
+                let client_id = client_id
+                    .clone()
+                    .ok_or_else(|| FileSinkError::MissingHello(path.into()))?;
+
+                if let MsgBody::OnRegisterCallsite {
+                    callsite_id,
+                    callsite,
+                } = &tracing_msg.body
+                {
+                    // Repeated by every segment
+                    if seen.callsites.get(callsite_id) == Some(callsite) {
+                        continue;
+                    }
+
+                    seen.callsites.insert(*callsite_id, callsite.clone());
+                }
+
+                let callsite = tracing_msg
+                    .body
+                    .callsite_id()
+                    .and_then(|callsite_id| seen.callsites.get(&callsite_id).cloned());
+                let msg_info = MsgInfo {
+                    client_info: Either::Left(client_id),
+                    callsite,
+                    tracing_msg,
+                };
+
+                msgs.push(ObserveMsg::OnMsg(msg_key, Box::new(msg_info)));
+            }
+            FileRecord::Close {
+                close_timestamp,
+                close_msg,
+            } => {
+                let client_id = client_id
+                    .clone()
+                    .ok_or_else(|| FileSinkError::MissingHello(path.into()))?;
+                let close_info = CloseInfo {
+                    close_timestamp,
+                    client_info: Either::Left(client_id),
+                    close_msg,
+                };
+
+                msgs.push(ObserveMsg::OnDisconnect(msg_key, close_info));
+            }
+        }
+    }
+
+    Ok(msgs)
+}
+
+// Reads a single (possibly gzipped) segment back for offline analysis.
+pub fn read_segment(path: impl AsRef<Path>) -> Result<Vec<ObserveMsg>, FileSinkError> {
+    to_observe_msgs(path.as_ref(), &mut Default::default())
+}
+
+// Reads all the segments of a directory, in the order they were written.
+pub fn read_dir(dir: impl AsRef<Path>) -> Result<Vec<ObserveMsg>, FileSinkError> {
+    let mut paths = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if is_segment(&path) {
+            paths.push(path);
+        }
+    }
+
+    paths.sort_unstable();
+
+    let mut seen = Seen::default();
+    let mut msgs = Vec::new();
+
+    for path in paths {
+        msgs.extend(to_observe_msgs(&path, &mut seen)?);
+    }
+
+    Ok(msgs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing_msg::{in_memory::run_layer, GraceType, SpanId};
+    use std::{env, num::NonZeroU64};
+
+    // A fresh directory, removed on drop.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            Self(env::temp_dir().join(format!("file_sink_{}", Ulid::new())))
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn hello_msg() -> HelloMsg {
+        HelloMsg {
+            client_name: "file_sink".into(),
+            proc_env: None,
+        }
+    }
+
+    fn enter(span: u64) -> TracingMsg {
+        let span_id = SpanId(NonZeroU64::new(span).unwrap());
+        MsgBody::OnEnter { span_id }.into()
+    }
+
+    // Each msg in its own push, then the sink is closed.
+    async fn push_all(mut sink: FileSink, spans: impl IntoIterator<Item = u64>) -> CloseMsg {
+        for span in spans {
+            sink.bulk_push(vec![enter(span)]).await.unwrap();
+        }
+
+        let close_msg = CloseMsg::ok(GraceType::Explicit.into());
+        sink.close_transport(Some(close_msg.clone())).await;
+        assert!(matches!(
+            sink.bulk_push(vec![enter(1)]).await,
+            Err(FileSinkError::Closed)
+        ));
+
+        close_msg
+    }
+
+    fn files(dir: &Path) -> Vec<String> {
+        let mut files: Vec<_> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        files.sort_unstable();
+        files
+    }
+
+    fn entered(msgs: &[ObserveMsg]) -> Vec<u64> {
+        msgs.iter()
+            .filter_map(|msg| match msg {
+                ObserveMsg::OnMsg(_, info) => match info.tracing_msg.body {
+                    MsgBody::OnEnter { span_id } => Some(span_id.0.get()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn rotates_by_size_and_gzips() {
+        let dir = TempDir::new();
+        let sink = FileSink::builder(&dir.0, hello_msg())
+            .max_segment_bytes(1)
+            .gzip_rotated()
+            .build()
+            .unwrap();
+        let close_msg = push_all(sink, 1..=3).await;
+
+        // The first segment only holds the hello header, every msg then fills one segment.
+        let files = files(&dir.0);
+        assert_eq!(files.len(), 4);
+        assert!(files.iter().all(|file| file.ends_with(".jsonl.gz")));
+
+        let msgs = read_dir(&dir.0).unwrap();
+        assert_eq!(entered(&msgs), [1, 2, 3]);
+        assert!(matches!(msgs[0], ObserveMsg::OnClientHello(..)));
+        assert!(matches!(
+            msgs.last(),
+            Some(ObserveMsg::OnDisconnect(_, info)) if info.close_msg == close_msg
+        ));
+
+        let last = read_segment(dir.0.join(&files[3])).unwrap();
+        assert_eq!(entered(&last), [3]);
+        assert!(matches!(last[0], ObserveMsg::OnClientHello(..)));
+    }
+
+    #[tokio::test]
+    async fn rotates_by_time() {
+        let dir = TempDir::new();
+        let sink = FileSink::builder(&dir.0, hello_msg())
+            .disable_rotation()
+            .rotate_every(Duration::ZERO)
+            .msg_format(MsgFormat::Msgpack)
+            .build()
+            .unwrap();
+        push_all(sink, 1..=2).await;
+
+        assert_eq!(files(&dir.0).len(), 3);
+        assert_eq!(entered(&read_dir(&dir.0).unwrap()), [1, 2]);
+    }
+
+    #[tokio::test]
+    async fn keeps_the_newest_segments() {
+        let dir = TempDir::new();
+        let sink = FileSink::builder(&dir.0, hello_msg())
+            .max_segment_bytes(1)
+            .max_segments(NonZeroUsize::new(2).unwrap())
+            .gzip_rotated()
+            .build()
+            .unwrap();
+        let client_key = sink.0.lock().unwrap().client_key.clone();
+        push_all(sink, 1..=5).await;
+
+        let expected = [4, 5].map(|seq| format!("{}.{:06}.jsonl.gz", client_key, seq));
+        assert_eq!(files(&dir.0), expected);
+
+        // Every segment starts with the hello header, so the rest can still be read on its own.
+        let msgs = read_dir(&dir.0).unwrap();
+        assert!(matches!(msgs[0], ObserveMsg::OnClientHello(..)));
+        assert_eq!(entered(&msgs), [4, 5]);
+    }
+
+    fn events(msgs: &[ObserveMsg]) -> Vec<&MsgInfo> {
+        msgs.iter()
+            .filter_map(|msg| match msg {
+                ObserveMsg::OnMsg(_, info) => Some(&**info),
+                _ => None,
+            })
+            .filter(|info| matches!(info.tracing_msg.body, MsgBody::OnEvent { .. }))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn every_segment_registers_the_callsites_again() {
+        let dir = TempDir::new();
+        let sink = FileSink::builder(&dir.0, hello_msg())
+            .max_segment_bytes(1)
+            .max_segments(NonZeroUsize::new(2).unwrap())
+            .build()
+            .unwrap();
+        run_layer(
+            &sink,
+            |builder| builder.close_transport_on_shutdown(),
+            |_| async {
+                for ping in 0..3 {
+                    tracing::info!(ping, "ping");
+                }
+            },
+        )
+        .await;
+
+        // The segment of the registration itself is pruned, the last one is read alone.
+        let files = files(&dir.0);
+        let last = read_segment(dir.0.join(files.last().unwrap())).unwrap();
+        let [event] = events(&last)[..] else {
+            panic!("one event expected");
+        };
+        let callsite = event.callsite.as_ref().unwrap();
+        assert_eq!(callsite.fields, ["message", "ping"]);
+
+        // Read together, the repeated registrations are skipped.
+        let msgs = read_dir(&dir.0).unwrap();
+        let registered = msgs
+            .iter()
+            .filter(|msg| match msg {
+                ObserveMsg::OnMsg(_, info) => {
+                    matches!(info.tracing_msg.body, MsgBody::OnRegisterCallsite { .. })
+                }
+                _ => false,
+            })
+            .count();
+        assert_eq!(registered, 1);
+        assert!(events(&msgs).iter().all(|info| info.callsite.is_some()));
+    }
+}
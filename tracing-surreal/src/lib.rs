@@ -1,9 +1,13 @@
 #![cfg_attr(nightly, feature(doc_auto_cfg))]
 
 pub mod async_req_res;
+pub mod file_sink;
+#[cfg(feature = "otlp")]
+pub mod otlp;
 pub mod stop;
 pub mod tmp;
 pub mod tracing_msg;
+pub mod ws_pusher;
 
 pub fn add(left: usize, right: usize) -> usize {
     left + right
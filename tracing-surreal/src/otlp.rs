@@ -0,0 +1,720 @@
+use crate::tracing_msg::{
+    proc_env::current_exe_name, BatchId, Callsite, CallsiteId, CloseMsg, CloseTransport, Level,
+    MsgBody, Parent, Payload, PushMsg, SpanId, Structured, TraceContext, TracingMsg, Value,
+};
+use chrono::{DateTime, Local};
+use opentelemetry_proto::tonic::{
+    collector::{
+        logs::v1::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
+        trace::v1::{trace_service_client::TraceServiceClient, ExportTraceServiceRequest},
+    },
+    common::v1::{any_value, AnyValue, ArrayValue, InstrumentationScope, KeyValue, KeyValueList},
+    logs::v1::{LogRecord, ResourceLogs, ScopeLogs, SeverityNumber},
+    resource::v1::Resource,
+    trace::v1::{
+        span::{Event, Link, SpanKind},
+        status::StatusCode,
+        ResourceSpans, ScopeSpans, Span, Status,
+    },
+};
+use prost::Message;
+use reqwest::header::CONTENT_TYPE;
+use std::{collections::HashMap, process};
+use thiserror::Error;
+use tonic::{
+    codegen::http::uri::InvalidUri,
+    transport::{Channel, Endpoint},
+};
+
+pub use crate::tracing_msg;
+
+#[derive(Error, Debug)]
+pub enum OtlpError {
+    #[error("reqwest error: `{0}`")]
+    Http(#[from] reqwest::Error),
+    #[error("invalid uri: `{0}`")]
+    InvalidUri(#[from] InvalidUri),
+    #[error("tonic transport error: `{0}`")]
+    Transport(#[from] tonic::transport::Error),
+    #[error("tonic status: `{0}`")]
+    Grpc(#[from] tonic::Status),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum EventsAs {
+    // Events outside of any (exported) span always become log records.
+    #[default]
+    SpanEvents,
+    LogRecords,
+}
+
+#[derive(Clone, Debug)]
+enum Client {
+    Http {
+        client: reqwest::Client,
+        traces: String,
+        logs: String,
+    },
+    Grpc {
+        traces: Box<TraceServiceClient<Channel>>,
+        logs: Box<LogsServiceClient<Channel>>,
+    },
+}
+
+// A span between its `OnNewSpan` and `OnClose`.
+#[derive(Clone, Debug)]
+struct OpenSpan {
+    trace: TraceContext,
+    span: Span,
+}
+
+#[derive(Default)]
+struct Export {
+    spans: Vec<Span>,
+    logs: Vec<LogRecord>,
+}
+
+// Converts the `OnNewSpan`...`OnClose` sequences into OTLP spans, and `OnEvent` into span events
+// or log records, exported to an OpenTelemetry collector over OTLP/HTTP (protobuf) or OTLP/gRPC.
+#[derive(Clone, Debug)]
+pub struct OtlpExporter {
+    client: Client,
+    resource: Resource,
+    events_as: EventsAs,
+    callsites: HashMap<CallsiteId, Callsite>,
+    open_spans: HashMap<SpanId, OpenSpan>,
+    // The failed batch whose traces were exported, only its logs are exported again on retry.
+    traces_sent: Option<BatchId>,
+}
+
+impl OtlpExporter {
+    // `endpoint` is the base url of the collector, e.g. `http://localhost:4318`.
+    pub fn http(endpoint: &str) -> Self {
+        let endpoint = endpoint.trim_end_matches('/');
+        let client = Client::Http {
+            client: reqwest::Client::new(),
+            traces: format!("{}/v1/traces", endpoint),
+            logs: format!("{}/v1/logs", endpoint),
+        };
+
+        Self::new(client)
+    }
+
+    // `endpoint` is the url of the collector, e.g. `http://localhost:4317`.
+    pub async fn grpc(endpoint: impl Into<String>) -> Result<Self, OtlpError> {
+        let channel = Endpoint::from_shared(endpoint.into())?.connect().await?;
+        let client = Client::Grpc {
+            traces: Box::new(TraceServiceClient::new(channel.clone())),
+            logs: Box::new(LogsServiceClient::new(channel)),
+        };
+
+        Ok(Self::new(client))
+    }
+
+    fn new(client: Client) -> Self {
+        let service_name = current_exe_name().unwrap_or_else(|_| "unknown_service".into());
+        let resource = Resource {
+            attributes: vec![
+                key_value("service.name", string_value(service_name)),
+                key_value("process.pid", int_value(process::id().into())),
+            ],
+            dropped_attributes_count: 0,
+        };
+
+        Self {
+            client,
+            resource,
+            events_as: Default::default(),
+            callsites: Default::default(),
+            open_spans: Default::default(),
+            traces_sent: None,
+        }
+    }
+
+    pub fn service_name(mut self, service_name: impl Into<String>) -> Self {
+        let value = string_value(service_name.into());
+
+        match self
+            .resource
+            .attributes
+            .iter_mut()
+            .find(|attr| attr.key == "service.name")
+        {
+            Some(attr) => attr.value = Some(value),
+            None => self
+                .resource
+                .attributes
+                .push(key_value("service.name", value)),
+        }
+
+        self
+    }
+
+    pub fn resource_attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.resource
+            .attributes
+            .push(key_value(key, string_value(value.into())));
+        self
+    }
+
+    pub fn events_as(self, events_as: EventsAs) -> Self {
+        Self { events_as, ..self }
+    }
+
+    fn scope() -> InstrumentationScope {
+        InstrumentationScope {
+            name: env!("CARGO_PKG_NAME").into(),
+            version: env!("CARGO_PKG_VERSION").into(),
+            attributes: vec![],
+            dropped_attributes_count: 0,
+        }
+    }
+
+    fn callsite_attrs(&self, callsite_id: CallsiteId, attrs: &mut Vec<KeyValue>) {
+        if let Some(callsite) = self.callsites.get(&callsite_id) {
+            attrs.push(key_value("target", string_value(callsite.target.clone())));
+
+            if let Some(module_path) = &callsite.module_path {
+                attrs.push(key_value(
+                    "code.namespace",
+                    string_value(module_path.clone()),
+                ));
+            }
+
+            if let Some(file) = &callsite.file {
+                attrs.push(key_value("code.filepath", string_value(file.clone())));
+            }
+
+            if let Some(line) = callsite.line {
+                attrs.push(key_value("code.lineno", int_value(line.into())));
+            }
+        }
+    }
+
+    fn thread_attrs(msg: &TracingMsg, attrs: &mut Vec<KeyValue>) {
+        attrs.push(key_value(
+            "thread.id",
+            int_value(msg.thread_id.0.get().try_into().unwrap_or(i64::MAX)),
+        ));
+
+        if let Some(thread_name) = &msg.thread_name {
+            attrs.push(key_value("thread.name", string_value(thread_name.clone())));
+        }
+    }
+
+    // Works on a copy of the open spans, so that a failed export can be retried with the same msgs.
+    fn convert(
+        &mut self,
+        msg: TracingMsg,
+        open_spans: &mut HashMap<SpanId, OpenSpan>,
+        export: &mut Export,
+    ) {
+        let time = unix_nanos(msg.timestamp);
+
+        match &msg.body {
+            MsgBody::OnRegisterCallsite {
+                callsite_id,
+                callsite,
+            } => {
+                self.callsites.insert(*callsite_id, callsite.clone());
+            }
+            MsgBody::OnNewSpan {
+                span_id,
+                level,
+                callsite_id,
+                parent,
+                trace,
+                payload,
+                ..
+            } => {
+                let parent = match parent {
+                    Parent::Explicit(parent) => open_spans.get(parent).map(|open| open.trace),
+                    _ => None,
+                };
+                let trace = trace.unwrap_or_else(|| match parent {
+                    None => TraceContext::root(true),
+                    Some(parent) => parent.child(true),
+                });
+                let name = self
+                    .callsites
+                    .get(callsite_id)
+                    .map_or_else(|| "span".into(), |callsite| callsite.name.clone());
+                let mut attributes = payload_attrs(payload, false);
+                attributes.push(key_value("level", string_value(level_str(*level).into())));
+                self.callsite_attrs(*callsite_id, &mut attributes);
+                Self::thread_attrs(&msg, &mut attributes);
+
+                let span = Span {
+                    trace_id: trace.trace_id.0.get().to_be_bytes().into(),
+                    span_id: trace.span_id.0.get().to_be_bytes().into(),
+                    parent_span_id: trace
+                        .parent_id
+                        .map(|id| id.0.get().to_be_bytes().into())
+                        .unwrap_or_default(),
+                    flags: flags(&trace),
+                    name,
+                    kind: SpanKind::Internal.into(),
+                    start_time_unix_nano: time,
+                    attributes,
+                    ..Default::default()
+                };
+
+                open_spans.insert(*span_id, OpenSpan { trace, span });
+            }
+            MsgBody::OnRecord { span_id, payload } => {
+                if let Some(open) = open_spans.get_mut(span_id) {
+                    for attr in payload_attrs(payload, false) {
+                        match open.span.attributes.iter_mut().find(|a| a.key == attr.key) {
+                            Some(existing) => existing.value = attr.value,
+                            None => open.span.attributes.push(attr),
+                        }
+                    }
+                }
+            }
+            MsgBody::OnFollowsFrom { span_id, follows } => {
+                let follows = open_spans.get(follows).map(|open| open.trace);
+
+                if let (Some(open), Some(follows)) = (open_spans.get_mut(span_id), follows) {
+                    open.span.links.push(Link {
+                        trace_id: follows.trace_id.0.get().to_be_bytes().into(),
+                        span_id: follows.span_id.0.get().to_be_bytes().into(),
+                        flags: flags(&follows),
+                        ..Default::default()
+                    });
+                }
+            }
+            MsgBody::OnEvent {
+                message,
+                level,
+                callsite_id,
+                parent,
+                trace,
+                payload,
+                ..
+            } => {
+                let open = match (self.events_as, parent) {
+                    (EventsAs::SpanEvents, Parent::Explicit(parent)) => open_spans.get_mut(parent),
+                    _ => None,
+                };
+                let mut attributes = payload_attrs(payload, true);
+                attributes.push(key_value("level", string_value(level_str(*level).into())));
+                self.callsite_attrs(*callsite_id, &mut attributes);
+
+                match open {
+                    Some(open) => {
+                        let name = match message.is_empty() {
+                            true => self
+                                .callsites
+                                .get(callsite_id)
+                                .map_or_else(|| "event".into(), |callsite| callsite.name.clone()),
+                            false => message.clone(),
+                        };
+
+                        if *level == Level::Error {
+                            open.span.status = Some(Status {
+                                message: name.clone(),
+                                code: StatusCode::Error.into(),
+                            });
+                        }
+
+                        open.span.events.push(Event {
+                            time_unix_nano: time,
+                            name,
+                            attributes,
+                            dropped_attributes_count: 0,
+                        });
+                    }
+                    None => {
+                        Self::thread_attrs(&msg, &mut attributes);
+
+                        export.logs.push(LogRecord {
+                            time_unix_nano: time,
+                            observed_time_unix_nano: time,
+                            severity_number: severity(*level).into(),
+                            severity_text: level_str(*level).into(),
+                            body: Some(string_value(message.clone())),
+                            attributes,
+                            flags: trace.as_ref().map(flags).unwrap_or_default(),
+                            trace_id: trace
+                                .map(|trace| trace.trace_id.0.get().to_be_bytes().into())
+                                .unwrap_or_default(),
+                            span_id: trace
+                                .map(|trace| trace.span_id.0.get().to_be_bytes().into())
+                                .unwrap_or_default(),
+                            ..Default::default()
+                        });
+                    }
+                }
+            }
+            MsgBody::OnEnter { .. } | MsgBody::OnExit { .. } => (),
+            MsgBody::OnClose { span_id, timing } => {
+                if let Some(mut open) = open_spans.remove(span_id) {
+                    open.span.end_time_unix_nano = time;
+
+                    if let Some(timing) = timing {
+                        let busy = timing.busy_ns.try_into().unwrap_or(i64::MAX);
+                        let idle = timing.idle_ns.try_into().unwrap_or(i64::MAX);
+                        open.span
+                            .attributes
+                            .push(key_value("busy_ns", int_value(busy)));
+                        open.span
+                            .attributes
+                            .push(key_value("idle_ns", int_value(idle)));
+                    }
+
+                    export.spans.push(open.span);
+                }
+            }
+            MsgBody::OnIdChange { old_span, new_span } => {
+                if let Some(open) = open_spans.remove(old_span) {
+                    open_spans.insert(*new_span, open);
+                }
+            }
+        }
+    }
+
+    async fn export_traces(&mut self, spans: Vec<Span>) -> Result<(), OtlpError> {
+        if spans.is_empty() {
+            return Ok(());
+        }
+
+        let request = ExportTraceServiceRequest {
+            resource_spans: vec![ResourceSpans {
+                resource: Some(self.resource.clone()),
+                scope_spans: vec![ScopeSpans {
+                    scope: Some(Self::scope()),
+                    spans,
+                    schema_url: Default::default(),
+                }],
+                schema_url: Default::default(),
+            }],
+        };
+
+        match &mut self.client {
+            Client::Http { client, traces, .. } => post(client, traces, request).await,
+            Client::Grpc { traces, .. } => Ok(traces.export(request).await.map(drop)?),
+        }
+    }
+
+    async fn export_logs(&mut self, log_records: Vec<LogRecord>) -> Result<(), OtlpError> {
+        if log_records.is_empty() {
+            return Ok(());
+        }
+
+        let request = ExportLogsServiceRequest {
+            resource_logs: vec![ResourceLogs {
+                resource: Some(self.resource.clone()),
+                scope_logs: vec![ScopeLogs {
+                    scope: Some(Self::scope()),
+                    log_records,
+                    schema_url: Default::default(),
+                }],
+                schema_url: Default::default(),
+            }],
+        };
+
+        match &mut self.client {
+            Client::Http { client, logs, .. } => post(client, logs, request).await,
+            Client::Grpc { logs, .. } => Ok(logs.export(request).await.map(drop)?),
+        }
+    }
+}
+
+impl PushMsg for OtlpExporter {
+    type Error = OtlpError;
+
+    async fn bulk_push(&mut self, msgs: Vec<TracingMsg>) -> Result<(), Self::Error> {
+        let batch = BatchId::of(&msgs);
+        let traces_sent = self
+            .traces_sent
+            .take()
+            .is_some_and(|sent| Some(sent) == batch);
+        let mut open_spans = self.open_spans.clone();
+        let mut export = Export::default();
+
+        for msg in msgs {
+            self.convert(msg, &mut open_spans, &mut export);
+        }
+
+        if !traces_sent {
+            self.export_traces(export.spans).await?;
+        }
+
+        if let Err(err) = self.export_logs(export.logs).await {
+            self.traces_sent = batch;
+            return Err(err);
+        }
+
+        self.open_spans = open_spans;
+
+        Ok(())
+    }
+}
+
+impl CloseTransport for OtlpExporter {
+    // Spans still open on shutdown are ended now, so that they don't get lost.
+    async fn close_transport(&mut self, _msg: Option<CloseMsg>) {
+        let time = unix_nanos(Local::now());
+        let spans = self
+            .open_spans
+            .drain()
+            .map(|(_, mut open)| {
+                open.span.end_time_unix_nano = time;
+                open.span
+            })
+            .collect();
+
+        let _ = self.export_traces(spans).await;
+    }
+}
+
+async fn post(client: &reqwest::Client, url: &str, request: impl Message) -> Result<(), OtlpError> {
+    client
+        .post(url)
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .body(request.encode_to_vec())
+        .send()
+        .await?
+        .error_for_status()?;
+
+    Ok(())
+}
+
+fn unix_nanos(timestamp: DateTime<Local>) -> u64 {
+    timestamp
+        .timestamp_nanos_opt()
+        .and_then(|nanos| nanos.try_into().ok())
+        .unwrap_or_default()
+}
+
+fn flags(trace: &TraceContext) -> u32 {
+    trace.sampled.into()
+}
+
+fn level_str(level: Level) -> &'static str {
+    match level {
+        Level::Trace => "TRACE",
+        Level::Debug => "DEBUG",
+        Level::Info => "INFO",
+        Level::Warn => "WARN",
+        Level::Error => "ERROR",
+    }
+}
+
+fn severity(level: Level) -> SeverityNumber {
+    match level {
+        Level::Trace => SeverityNumber::Trace,
+        Level::Debug => SeverityNumber::Debug,
+        Level::Info => SeverityNumber::Info,
+        Level::Warn => SeverityNumber::Warn,
+        Level::Error => SeverityNumber::Error,
+    }
+}
+
+fn key_value(key: impl Into<String>, value: AnyValue) -> KeyValue {
+    KeyValue {
+        key: key.into(),
+        value: Some(value),
+    }
+}
+
+fn string_value(value: String) -> AnyValue {
+    AnyValue {
+        value: Some(any_value::Value::StringValue(value)),
+    }
+}
+
+fn int_value(value: i64) -> AnyValue {
+    AnyValue {
+        value: Some(any_value::Value::IntValue(value)),
+    }
+}
+
+// Empty fields are skipped, fields recorded more than once become arrays.
+fn payload_attrs(payload: &Payload, skip_message: bool) -> Vec<KeyValue> {
+    payload
+        .iter()
+        .filter(|(key, _)| !(skip_message && *key == "message"))
+        .filter_map(|(key, values)| {
+            let value = match values.as_slice() {
+                [] => return None,
+                [value] => any_value(value),
+                values => AnyValue {
+                    value: Some(any_value::Value::ArrayValue(ArrayValue {
+                        values: values.iter().map(any_value).collect(),
+                    })),
+                },
+            };
+
+            Some(key_value(key.clone(), value))
+        })
+        .collect()
+}
+
+fn any_value(value: &Value) -> AnyValue {
+    let value = match value {
+        Value::Debug(value) | Value::String(value) | Value::Error(value) => {
+            any_value::Value::StringValue(value.clone())
+        }
+        Value::F64(value) => any_value::Value::DoubleValue(*value),
+        Value::I64(value) => any_value::Value::IntValue(*value),
+        Value::U64(value) => int_or_string(*value),
+        Value::I128(value) => int_or_string(*value),
+        Value::U128(value) => int_or_string(*value),
+        Value::Bool(value) => any_value::Value::BoolValue(*value),
+        Value::Bytes(value) => any_value::Value::BytesValue(value.clone()),
+        Value::Truncated { partial, .. } => return any_value(partial),
+        Value::Map(map) => kvlist_value(map.iter()),
+        Value::List(list) => array_value(list),
+        Value::Null => return AnyValue { value: None },
+    };
+
+    AnyValue { value: Some(value) }
+}
+
+fn structured_value(value: &Structured) -> AnyValue {
+    let value = match value {
+        Structured::Null => return AnyValue { value: None },
+        Structured::Bool(value) => any_value::Value::BoolValue(*value),
+        Structured::I64(value) => any_value::Value::IntValue(*value),
+        Structured::U64(value) => int_or_string(*value),
+        Structured::F64(value) => any_value::Value::DoubleValue(*value),
+        Structured::String(value) => any_value::Value::StringValue(value.clone()),
+        Structured::List(list) => array_value(list),
+        Structured::Map(map) => kvlist_value(map.iter()),
+    };
+
+    AnyValue { value: Some(value) }
+}
+
+fn array_value(list: &[Structured]) -> any_value::Value {
+    any_value::Value::ArrayValue(ArrayValue {
+        values: list.iter().map(structured_value).collect(),
+    })
+}
+
+fn kvlist_value<'a>(map: impl Iterator<Item = (&'a String, &'a Structured)>) -> any_value::Value {
+    any_value::Value::KvlistValue(KeyValueList {
+        values: map
+            .map(|(key, value)| key_value(key.clone(), structured_value(value)))
+            .collect(),
+    })
+}
+
+// OTLP integers are `i64`, larger ones are kept as strings.
+fn int_or_string<T: TryInto<i64> + ToString + Copy>(value: T) -> any_value::Value {
+    value.try_into().map_or_else(
+        |_| any_value::Value::StringValue(value.to_string()),
+        any_value::Value::IntValue,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing_msg::in_memory::capture;
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+        net::{TcpListener, TcpStream},
+        sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
+    };
+
+    // A stand-in OTLP/HTTP collector, that fails the first `/v1/logs` request.
+    async fn collector() -> (String, UnboundedReceiver<(String, Vec<u8>)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = unbounded_channel();
+
+        tokio::spawn(async move {
+            let mut failed = false;
+
+            while let Ok((stream, _)) = listener.accept().await {
+                failed = serve(stream, &sender, failed).await;
+            }
+        });
+
+        (endpoint, receiver)
+    }
+
+    async fn serve(
+        stream: TcpStream,
+        sender: &UnboundedSender<(String, Vec<u8>)>,
+        mut failed: bool,
+    ) -> bool {
+        let mut stream = BufReader::new(stream);
+        let mut line = String::new();
+
+        while stream.read_line(&mut line).await.unwrap_or(0) > 0 {
+            let path = line.split(' ').nth(1).unwrap_or_default().to_owned();
+            let mut len = 0;
+
+            loop {
+                line.clear();
+                stream.read_line(&mut line).await.unwrap();
+                let header = line.trim_end().to_ascii_lowercase();
+
+                if header.is_empty() {
+                    break;
+                }
+
+                if let Some(value) = header.strip_prefix("content-length:") {
+                    len = value.trim().parse().unwrap();
+                }
+            }
+
+            let mut body = vec![0; len];
+            stream.read_exact(&mut body).await.unwrap();
+
+            let status = match (path.as_str(), failed) {
+                ("/v1/logs", false) => {
+                    failed = true;
+                    "503 Service Unavailable"
+                }
+                _ => "200 OK",
+            };
+            let response = format!("HTTP/1.1 {}\r\ncontent-length: 0\r\n\r\n", status);
+            stream.write_all(response.as_bytes()).await.unwrap();
+            sender.send((path, body)).unwrap();
+            line.clear();
+        }
+
+        failed
+    }
+
+    #[tokio::test]
+    async fn logs_failure_doesnt_resend_traces() {
+        let transport = capture(
+            |builder| builder,
+            |_| async {
+                tracing::info_span!("work", user = "alice").in_scope(|| tracing::info!("inside"));
+                tracing::warn!("outside");
+            },
+        )
+        .await;
+
+        let (endpoint, mut requests) = collector().await;
+        let mut exporter = OtlpExporter::http(&endpoint).service_name("test");
+        let msgs = transport.msgs();
+
+        assert!(exporter.bulk_push(msgs.clone()).await.is_err());
+        exporter.bulk_push(msgs).await.unwrap();
+
+        let (path, body) = requests.recv().await.unwrap();
+        assert_eq!(path, "/v1/traces");
+        let traces = ExportTraceServiceRequest::decode(body.as_slice()).unwrap();
+        let span = &traces.resource_spans[0].scope_spans[0].spans[0];
+        assert_eq!(span.name, "work");
+        assert_eq!(span.events[0].name, "inside");
+
+        // The traces went through on the first attempt, only the logs are exported again.
+        for _ in 0..2 {
+            let (path, body) = requests.recv().await.unwrap();
+            assert_eq!(path, "/v1/logs");
+            let logs = ExportLogsServiceRequest::decode(body.as_slice()).unwrap();
+            let record = &logs.resource_logs[0].scope_logs[0].log_records[0];
+            assert_eq!(record.body, Some(string_value("outside".into())));
+        }
+
+        assert!(requests.try_recv().is_err());
+    }
+}
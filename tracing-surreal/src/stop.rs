@@ -4,35 +4,52 @@ use crate::{
     async_req_res::{req_res, Requester},
     tracing_msg::{
         observe::{CloseInfo, MsgInfo},
-        ClientInfo, ClientRole, CloseErr, CloseErrKind, CloseMsg, CloseOk, CloseTransport,
-        GraceType, HelloMsg, MsgFormat, ObserveMsg, Observer, ProcEnv, PushMsg, QueryHistory, Role,
-        TracingMsg,
+        observer, Callsite, ClientInfo, ClientRole, CloseErr, CloseErrKind, CloseMsg, CloseOk,
+        CloseStats, CloseTransport, GraceType, HelloMsg, Level, MsgBody, MsgFormat, ObserveMsg,
+        Observer, ProcEnv, PushMsg, QueryHistory, Role, Sampling, SpanId, TraceId, TracingMsg,
     },
 };
 use chrono::{DateTime, Local};
 use either::Either;
+use est::{task::TaskId, thread::ThreadId};
 use futures::StreamExt;
 use indexmap::IndexMap;
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde::{
+    de::{DeserializeOwned, IgnoredAny},
+    Deserialize, Serialize,
+};
 use serde_json::Value;
+#[cfg(feature = "kv-rocksdb")]
+use std::path::Path;
 use std::{
+    collections::HashSet,
     fmt,
     future::Future,
     io,
     net::SocketAddr,
+    num::NonZeroU16,
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
+    time::Duration,
 };
+#[cfg(any(feature = "kv-mem", feature = "kv-rocksdb"))]
+use surrealdb::engine::local::Db;
+#[cfg(feature = "kv-mem")]
+use surrealdb::engine::local::Mem;
+#[cfg(feature = "kv-rocksdb")]
+use surrealdb::engine::local::RocksDb;
 use surrealdb::{
     method::{QueryStream, Stream},
     value::{Action, Notification},
-    Connection, RecordId, RecordIdKey, Surreal,
+    Connection, Datetime, RecordId, RecordIdKey, Surreal,
 };
 use thiserror::Error;
 use tokio::{
+    signal::ctrl_c,
     sync::{broadcast, oneshot, RwLock},
     task::{JoinError, JoinHandle},
+    time::sleep,
 };
 use tokio_util::sync::CancellationToken;
 use ulid::Generator;
@@ -40,6 +57,9 @@ use ulid::Generator;
 pub use crate::tracing_msg;
 pub use surrealdb;
 
+#[cfg(any(feature = "kv-mem", feature = "kv-rocksdb"))]
+pub const EMBEDDED_NS: &str = "root";
+
 #[derive(Error, Debug)]
 pub enum StopError {
     #[error("surrealdb error: `{0}`")]
@@ -52,6 +72,8 @@ pub enum StopError {
     ObserverCannotPush,
     #[error("client cannot explicitly observe")]
     ClientCannotObserve,
+    #[error("pusher cannot observe")]
+    PusherCannotObserve,
     #[error("observer/director must fill QueryHistory on hello")]
     MustFillQueryHistory,
     #[error("live query stream closed")]
@@ -60,6 +82,10 @@ pub enum StopError {
     CorruptedData,
     #[error("requester dropped")]
     RequesterDropped,
+    #[error("observe routine stopped")]
+    RoutineStopped,
+    #[error("schema version {0} is newer than the supported {SCHEMA_VERSION}")]
+    NewerSchema(u32),
 }
 
 #[derive(Clone, Default)]
@@ -97,6 +123,7 @@ pub struct Stop<C: Connection> {
     client_id: RecordId,
     can_push: bool,
     is_client: bool,
+    query_history: Option<QueryHistory>,
     link_client: bool,
     ob_requester: ObserverRequester,
 }
@@ -134,6 +161,225 @@ struct RID {
     id: RecordId,
 }
 
+trait ToObserveMsg {
+    fn get_id(&self) -> &RecordId;
+    fn to_observe_msg(self) -> Result<ObserveMsg, StopError>;
+}
+
+impl<L: ToObserveMsg, R: ToObserveMsg> ToObserveMsg for Either<L, R> {
+    fn get_id(&self) -> &RecordId {
+        either::for_both!(self, model => model.get_id())
+    }
+
+    fn to_observe_msg(self) -> Result<ObserveMsg, StopError> {
+        either::for_both!(self, model => model.to_observe_msg())
+    }
+}
+
+#[derive(Deserialize)]
+struct ClientModel {
+    id: RecordId,
+    a_timestamp: DateTime<Local>,
+    c_client_name: String,
+    d_client_role: Role,
+    g_client_addr: Option<String>,
+    i_proc_env: Option<Value>,
+}
+
+impl ClientModel {
+    fn to_client_info(self) -> Result<ClientInfo, StopError> {
+        let hello_timestamp = self.a_timestamp;
+        let client_name = self.c_client_name;
+        let proc_env = match self.i_proc_env {
+            None => None,
+            Some(v) => serde_json::from_value(v)?,
+        };
+        let hello_msg = HelloMsg {
+            client_name,
+            proc_env,
+        };
+        let client_role = self.d_client_role;
+        let client_addr = match self.g_client_addr {
+            None => None,
+            Some(addr) => Some(addr.parse().map_err(|_| StopError::CorruptedData)?),
+        };
+
+        Ok(ClientInfo {
+            hello_timestamp,
+            hello_msg,
+            client_role,
+            client_addr,
+        })
+    }
+}
+
+impl ToObserveMsg for ClientModel {
+    fn get_id(&self) -> &RecordId {
+        &self.id
+    }
+
+    fn to_observe_msg(self) -> Result<ObserveMsg, StopError> {
+        let client_id = self.id.clone().into();
+        let client_info = self.to_client_info()?;
+
+        Ok(ObserveMsg::OnClientHello(client_id, client_info))
+    }
+}
+
+#[derive(Deserialize)]
+struct DisconnectIdModel {
+    id: RecordId,
+    a_timestamp: DateTime<Local>,
+    c_client_id: RecordId,
+    d_normal: bool,
+    e_ok_kind: Option<CloseOk>,
+    f_err_kind: Option<CloseErrKind>,
+    g_err_msg: Option<String>,
+    #[serde(default)]
+    h_close_stats: CloseStats,
+}
+
+impl ToObserveMsg for DisconnectIdModel {
+    fn get_id(&self) -> &RecordId {
+        &self.id
+    }
+
+    fn to_observe_msg(self) -> Result<ObserveMsg, StopError> {
+        let msg_key = self.id.key().to_string();
+        let close_timestamp = self.a_timestamp;
+        let client_info = Either::Left(self.c_client_id.into());
+        let close_msg = if self.d_normal {
+            CloseMsg::ok(self.e_ok_kind.ok_or(StopError::CorruptedData)?)
+        } else {
+            CloseMsg::err(CloseErr::new(
+                self.f_err_kind.ok_or(StopError::CorruptedData)?,
+                self.g_err_msg.ok_or(StopError::CorruptedData)?,
+            ))
+        }
+        .with_stats(self.h_close_stats);
+        let close_info = CloseInfo {
+            close_timestamp,
+            client_info,
+            close_msg,
+        };
+
+        Ok(ObserveMsg::OnDisconnect(msg_key, close_info))
+    }
+}
+
+#[derive(Deserialize)]
+struct DisconnectClientModel {
+    id: RecordId,
+    a_timestamp: DateTime<Local>,
+    c_client_id: ClientModel,
+    d_normal: bool,
+    e_ok_kind: Option<CloseOk>,
+    f_err_kind: Option<CloseErrKind>,
+    g_err_msg: Option<String>,
+    #[serde(default)]
+    h_close_stats: CloseStats,
+}
+
+impl ToObserveMsg for DisconnectClientModel {
+    fn get_id(&self) -> &RecordId {
+        &self.id
+    }
+
+    fn to_observe_msg(self) -> Result<ObserveMsg, StopError> {
+        let msg_key = self.id.key().to_string();
+        let close_timestamp = self.a_timestamp;
+        let client_info = Either::Right(self.c_client_id.to_client_info()?);
+        let close_msg = if self.d_normal {
+            CloseMsg::ok(self.e_ok_kind.ok_or(StopError::CorruptedData)?)
+        } else {
+            CloseMsg::err(CloseErr::new(
+                self.f_err_kind.ok_or(StopError::CorruptedData)?,
+                self.g_err_msg.ok_or(StopError::CorruptedData)?,
+            ))
+        }
+        .with_stats(self.h_close_stats);
+        let close_info = CloseInfo {
+            close_timestamp,
+            client_info,
+            close_msg,
+        };
+
+        Ok(ObserveMsg::OnDisconnect(msg_key, close_info))
+    }
+}
+
+// Record links deserialize as enums, which `#[serde(flatten)]` can't buffer, so every link
+// of a msg record is a named field.
+#[derive(Deserialize)]
+struct MsgIdModel {
+    id: RecordId,
+    client_id: RecordId,
+    #[serde(rename = "session_id")]
+    _session_id: IgnoredAny,
+    // Fetched by the queries, since observers need more than a `CallsiteId`
+    #[serde(default)]
+    callsite: Option<Callsite>,
+    #[serde(flatten)]
+    msg: TracingMsg,
+}
+
+impl ToObserveMsg for MsgIdModel {
+    fn get_id(&self) -> &RecordId {
+        &self.id
+    }
+
+    fn to_observe_msg(self) -> Result<ObserveMsg, StopError> {
+        let msg_key = self.id.key().to_string();
+        let client_info = Either::Left(self.client_id.into());
+        let tracing_msg = self.msg;
+        let msg_info = MsgInfo {
+            client_info,
+            callsite: self.callsite,
+            tracing_msg,
+        };
+
+        Ok(ObserveMsg::OnMsg(msg_key, Box::new(msg_info)))
+    }
+}
+
+#[derive(Deserialize)]
+struct MsgClientModel {
+    id: RecordId,
+    client_id: ClientModel,
+    #[serde(rename = "session_id")]
+    _session_id: IgnoredAny,
+    // Fetched by the queries, since observers need more than a `CallsiteId`
+    #[serde(default)]
+    callsite: Option<Callsite>,
+    #[serde(flatten)]
+    msg: TracingMsg,
+}
+
+impl ToObserveMsg for MsgClientModel {
+    fn get_id(&self) -> &RecordId {
+        &self.id
+    }
+
+    fn to_observe_msg(self) -> Result<ObserveMsg, StopError> {
+        let msg_key = self.id.key().to_string();
+        let client_info = Either::Right(self.client_id.to_client_info()?);
+        let tracing_msg = self.msg;
+        let msg_info = MsgInfo {
+            client_info,
+            callsite: self.callsite,
+            tracing_msg,
+        };
+
+        Ok(ObserveMsg::OnMsg(msg_key, Box::new(msg_info)))
+    }
+}
+
+// Bumped along with `SCHEMA` whenever the records change, and kept in `.sessions`.
+// Older sessions keep their own tables, and `.sessions` only gained optional fields,
+// so there's nothing to migrate yet; `init` refuses a database used by a newer version.
+pub const SCHEMA_VERSION: u32 = 1;
+const SCHEMA: &str = include_str!("surql/schema_v1.surql");
+
 #[derive(Clone, Debug)]
 pub struct StopBuilder<C: Connection> {
     db: Surreal<C>,
@@ -141,6 +387,7 @@ pub struct StopBuilder<C: Connection> {
     host: String,
     link_client: bool,
     ctrlc_shutdown: bool,
+    reconnect: bool,
 }
 
 impl<C: Connection> StopBuilder<C> {
@@ -165,6 +412,15 @@ impl<C: Connection> StopBuilder<C> {
         }
     }
 
+    // End the observe routine with `StopError::StreamClosed` when a live query closes,
+    // instead of re-subscribing once reconnected.
+    pub fn disable_reconnect(self) -> Self {
+        Self {
+            reconnect: false,
+            ..self
+        }
+    }
+
     pub async fn init(self) -> Result<(Stop<C>, ObserveRoutine), StopError> {
         let db = self.db;
 
@@ -180,6 +436,7 @@ impl<C: Connection> StopBuilder<C> {
             f_session_id: Option<String>,
             g_session_token: Option<Value>,
             h_link_client: bool,
+            i_schema_version: u32,
         }
 
         let id_gen = IdGen::default();
@@ -192,6 +449,7 @@ impl<C: Connection> StopBuilder<C> {
         let f_session_id = db.run("session::id").await?;
         let g_session_token = db.run("session::token").await?;
         let h_link_client = link_client;
+        let i_schema_version = SCHEMA_VERSION;
         let record = SessionRecord {
             a_timestamp,
             b_access_method,
@@ -201,227 +459,101 @@ impl<C: Connection> StopBuilder<C> {
             f_session_id,
             g_session_token,
             h_link_client,
+            i_schema_version,
         };
-        let rid: Option<RID> = db
-            .create((".sessions", id_gen.next(a_timestamp).await))
-            .content(record)
-            .await?;
         let formatted_timestamp = a_timestamp.format("%y%m%d-%H%M%S").to_string();
 
-        db.query(if link_client {
-            include_str!("surql/fns_link.surql")
-        } else {
-            include_str!("surql/fns.surql")
-        })
-        .await?
-        .check()?;
-
-        trait ToObserveMsg {
-            fn get_id(&self) -> &RecordId;
-            fn to_observe_msg(self) -> Result<ObserveMsg, StopError>;
-        }
-
-        #[derive(Deserialize)]
-        struct ClientModel {
-            id: RecordId,
-            a_timestamp: DateTime<Local>,
-            c_client_name: String,
-            d_client_role: Role,
-            g_client_addr: Option<SocketAddr>,
-            i_proc_env: Option<Value>,
+        // Overwriting `.sessions` with an older definition would reject the newer sessions.
+        let newer: Option<u32> = db
+            .query(
+                "SELECT VALUE i_schema_version FROM ONLY `.sessions` \
+                 WHERE i_schema_version > $version LIMIT 1",
+            )
+            .bind(("version", SCHEMA_VERSION))
+            .await?
+            .take(0)?;
+
+        if let Some(version) = newer {
+            return Err(StopError::NewerSchema(version));
         }
 
-        impl ClientModel {
-            fn to_client_info(self) -> Result<ClientInfo, StopError> {
-                let hello_timestamp = self.a_timestamp;
-                let client_name = self.c_client_name;
-                let proc_env = match self.i_proc_env {
-                    None => None,
-                    Some(v) => serde_json::from_value(v)?,
-                };
-                let hello_msg = HelloMsg {
-                    client_name,
-                    proc_env,
-                };
-                let client_role = self.d_client_role;
-                let client_addr = self.g_client_addr;
-
-                Ok(ClientInfo {
-                    hello_timestamp,
-                    hello_msg,
-                    client_role,
-                    client_addr,
-                })
-            }
-        }
-
-        impl ToObserveMsg for ClientModel {
-            fn get_id(&self) -> &RecordId {
-                &self.id
-            }
-
-            fn to_observe_msg(self) -> Result<ObserveMsg, StopError> {
-                let client_id = self.id.clone().into();
-                let client_info = self.to_client_info()?;
-
-                Ok(ObserveMsg::OnClientHello(client_id, client_info))
-            }
-        }
-
-        #[derive(Deserialize)]
-        struct DisconnectIdModel {
-            id: RecordId,
-            a_timestamp: DateTime<Local>,
-            c_client_id: RecordId,
-            d_normal: bool,
-            e_ok_kind: Option<CloseOk>,
-            f_err_kind: Option<CloseErrKind>,
-            g_err_msg: Option<String>,
-        }
-
-        impl ToObserveMsg for DisconnectIdModel {
-            fn get_id(&self) -> &RecordId {
-                &self.id
-            }
-
-            fn to_observe_msg(self) -> Result<ObserveMsg, StopError> {
-                let msg_key = self.id.key().to_string();
-                let close_timestamp = self.a_timestamp;
-                let client_info = Either::Left(self.c_client_id.into());
-                let close_msg = if self.d_normal {
-                    CloseMsg::ok(self.e_ok_kind.ok_or(StopError::CorruptedData)?)
-                } else {
-                    CloseMsg::err(CloseErr::new(
-                        self.f_err_kind.ok_or(StopError::CorruptedData)?,
-                        self.g_err_msg.ok_or(StopError::CorruptedData)?,
-                    ))
-                };
-                let close_info = CloseInfo {
-                    close_timestamp,
-                    client_info,
-                    close_msg,
-                };
+        // Applied on every init, since the tables of each session are named after its start.
+        db.query(SCHEMA.replace("{ts}", &formatted_timestamp))
+            .await?
+            .check()?;
 
-                Ok(ObserveMsg::OnDisconnect(msg_key, close_info))
-            }
-        }
-
-        #[derive(Deserialize)]
-        struct DisconnectClientModel {
-            id: RecordId,
-            a_timestamp: DateTime<Local>,
-            c_client_id: ClientModel,
-            d_normal: bool,
-            e_ok_kind: Option<CloseOk>,
-            f_err_kind: Option<CloseErrKind>,
-            g_err_msg: Option<String>,
-        }
-
-        impl ToObserveMsg for DisconnectClientModel {
-            fn get_id(&self) -> &RecordId {
-                &self.id
-            }
+        let rid: Option<RID> = db
+            .create((".sessions", id_gen.next(a_timestamp).await))
+            .content(record)
+            .await?;
 
-            fn to_observe_msg(self) -> Result<ObserveMsg, StopError> {
-                let msg_key = self.id.key().to_string();
-                let close_timestamp = self.a_timestamp;
-                let client_info = Either::Right(self.c_client_id.to_client_info()?);
-                let close_msg = if self.d_normal {
-                    CloseMsg::ok(self.e_ok_kind.ok_or(StopError::CorruptedData)?)
-                } else {
-                    CloseMsg::err(CloseErr::new(
-                        self.f_err_kind.ok_or(StopError::CorruptedData)?,
-                        self.g_err_msg.ok_or(StopError::CorruptedData)?,
-                    ))
-                };
-                let close_info = CloseInfo {
-                    close_timestamp,
-                    client_info,
-                    close_msg,
-                };
+        db.query(include_str!("surql/fns.surql")).await?.check()?;
 
-                Ok(ObserveMsg::OnDisconnect(msg_key, close_info))
-            }
+        // The clients table has nothing to fetch, so it's queried with the plain functions either way.
+        if link_client {
+            db.query(include_str!("surql/fns_link.surql"))
+                .await?
+                .check()?;
         }
 
-        #[derive(Deserialize)]
-        struct MsgIdModel {
-            id: RecordId,
-            client_id: RecordId,
-            #[serde(flatten)]
-            msg: TracingMsg,
+        struct LiveState {
+            last_key: Option<RecordIdKey>,
+            // Keys broadcast by the last backfill, which the new live queries may notify again
+            backfilled: HashSet<RecordIdKey>,
+            br_send: broadcast::Sender<ObserveMsg>,
         }
 
-        impl ToObserveMsg for MsgIdModel {
-            fn get_id(&self) -> &RecordId {
-                &self.id
-            }
-
-            fn to_observe_msg(self) -> Result<ObserveMsg, StopError> {
-                let msg_key = self.id.key().to_string();
-                let client_info = Either::Left(self.client_id.into());
-                let tracing_msg = self.msg;
-                let msg_info = MsgInfo {
-                    client_info,
-                    tracing_msg,
-                };
+        impl LiveState {
+            fn broadcast(&mut self, key: RecordIdKey, msg: ObserveMsg) {
+                // Keys follow the msg timestamps, so they may be notified out of order.
+                if self.last_key.as_ref() < Some(&key) {
+                    self.last_key = Some(key);
+                }
 
-                Ok(ObserveMsg::OnMsg(msg_key, msg_info))
+                self.br_send.send(msg).ok();
             }
         }
 
-        #[derive(Deserialize)]
-        struct MsgClientModel {
-            id: RecordId,
-            client_id: ClientModel,
-            #[serde(flatten)]
-            msg: TracingMsg,
-        }
-
-        impl ToObserveMsg for MsgClientModel {
-            fn get_id(&self) -> &RecordId {
-                &self.id
-            }
-
-            fn to_observe_msg(self) -> Result<ObserveMsg, StopError> {
-                let msg_key = self.id.key().to_string();
-                let client_info = Either::Right(self.client_id.to_client_info()?);
-                let tracing_msg = self.msg;
-                let msg_info = MsgInfo {
-                    client_info,
-                    tracing_msg,
-                };
+        // Whether the live stream is closed
+        fn handle_next<T: ToObserveMsg>(
+            item: Option<surrealdb::Result<(Action, T)>>,
+            state: &mut LiveState,
+        ) -> Result<bool, StopError> {
+            let (action, model) = match item {
+                None => return Ok(true),
+                Some(res) => res?,
+            };
 
-                Ok(ObserveMsg::OnMsg(msg_key, msg_info))
-            }
-        }
+            if action == Action::Create {
+                let key = model.get_id().key().clone();
 
-        fn handle_item<T: ToObserveMsg>(
-            item: Notification<T>,
-            last_key: &mut Option<RecordIdKey>,
-            br_send: &broadcast::Sender<ObserveMsg>,
-        ) -> Result<(), StopError> {
-            if item.action == Action::Create {
-                let model = item.data;
-                *last_key = Some(model.get_id().key().clone());
-                br_send.send(model.to_observe_msg()?).ok();
+                if !state.backfilled.remove(&key) {
+                    state.broadcast(key, model.to_observe_msg()?);
+                }
             }
 
-            Ok(())
+            Ok(false)
         }
 
         enum UnifiedStream<I, C> {
             Select(Stream<Vec<I>>),
+            // For the fields to fetch, which the `Select` live queries can't
+            QueryId(QueryStream<Notification<I>>),
             Query(QueryStream<Notification<C>>),
         }
 
         impl<I: DeserializeOwned + Unpin, C: DeserializeOwned + Unpin> UnifiedStream<I, C> {
-            async fn next(
-                &mut self,
-            ) -> Option<surrealdb::Result<Either<Notification<I>, Notification<C>>>> {
+            async fn next(&mut self) -> Option<surrealdb::Result<(Action, Either<I, C>)>> {
                 match self {
-                    Self::Select(s) => Some(s.next().await?.map(Either::Left)),
-                    Self::Query(s) => Some(s.next().await?.map(Either::Right)),
+                    Self::Select(s) => {
+                        Some(s.next().await?.map(|n| (n.action, Either::Left(n.data))))
+                    }
+                    Self::QueryId(s) => {
+                        Some(s.next().await?.map(|n| (n.action, Either::Left(n.data))))
+                    }
+                    Self::Query(s) => {
+                        Some(s.next().await?.map(|n| (n.action, Either::Right(n.data))))
+                    }
                 }
             }
         }
@@ -449,32 +581,69 @@ impl<C: Connection> StopBuilder<C> {
             }
         }
 
-        let live_link_ql = include_str!("surql/live_link.surql");
-        let clients_name = format!("{}-clients", formatted_timestamp);
-        let disconnects_name = format!("{}-disconnects", formatted_timestamp);
-        let msg_name = format!("{}-msg", formatted_timestamp);
-        let mut client_stream: Stream<Vec<ClientModel>> = db.select(clients_name).live().await?;
-        let mut close_stream: UnifiedStream<DisconnectIdModel, DisconnectClientModel> =
-            if link_client {
-                db.query(live_link_ql)
-                    .bind(TableName::new(disconnects_name))
-                    .await?
-                    .stream(1)?
-                    .into()
-            } else {
-                db.select(disconnects_name).live().await?.into()
-            };
-        let mut msg_stream: UnifiedStream<MsgIdModel, MsgClientModel> = if link_client {
-            db.query(live_link_ql)
-                .bind(TableName::new(msg_name))
-                .await?
-                .stream(1)?
-                .into()
-        } else {
-            db.select(msg_name).live().await?.into()
-        };
+        struct SessionTables {
+            clients: String,
+            disconnects: String,
+            msg: String,
+        }
+
+        struct LiveStreams {
+            // Nothing to fetch, so always `Select`
+            clients: UnifiedStream<ClientModel, ClientModel>,
+            disconnects: UnifiedStream<DisconnectIdModel, DisconnectClientModel>,
+            msg: UnifiedStream<MsgIdModel, MsgClientModel>,
+        }
+
+        impl LiveStreams {
+            async fn subscribe<C: Connection>(
+                db: &Surreal<C>,
+                tables: &SessionTables,
+                link_client: bool,
+            ) -> Result<Self, StopError> {
+                let live_link_ql = include_str!("surql/live_link.surql");
+                let clients = db.select(tables.clients.as_str()).live().await?.into();
+                let disconnects = if link_client {
+                    db.query(live_link_ql)
+                        .bind(TableName::new(tables.disconnects.clone()))
+                        .await?
+                        .stream(1)?
+                        .into()
+                } else {
+                    db.select(tables.disconnects.as_str()).live().await?.into()
+                };
+                let msg = if link_client {
+                    db.query(live_link_ql)
+                        .bind(TableName::new(tables.msg.clone()))
+                        .await?
+                        .stream(1)?
+                        .into()
+                } else {
+                    let stream = db
+                        .query(include_str!("surql/live.surql"))
+                        .bind(TableName::new(tables.msg.clone()))
+                        .await?
+                        .stream(1)?;
+
+                    UnifiedStream::QueryId(stream)
+                };
 
+                Ok(Self {
+                    clients,
+                    disconnects,
+                    msg,
+                })
+            }
+        }
+
+        let tables = SessionTables {
+            clients: format!("{}-clients", formatted_timestamp),
+            disconnects: format!("{}-disconnects", formatted_timestamp),
+            msg: format!("{}-msg", formatted_timestamp),
+        };
+        let mut live = LiveStreams::subscribe(&db, &tables, link_client).await?;
         let db_routine = db.clone();
+        let ctrlc_shutdown = self.ctrlc_shutdown;
+        let reconnect = self.reconnect;
         let shutdown_trigger = CancellationToken::new();
         let shutdown_waiter = shutdown_trigger.clone();
         let (wait_send, wait_recv) = oneshot::channel();
@@ -482,56 +651,266 @@ impl<C: Connection> StopBuilder<C> {
             req_res::<QueryHistory, Result<Observer, StopError>>();
         let routine = tokio::spawn(async move {
             let (br_send, _) = broadcast::channel(65536);
-            let mut last_key = None;
+            let mut state = LiveState {
+                last_key: None,
+                backfilled: HashSet::new(),
+                br_send,
+            };
 
-            match client_stream.next().await {
-                None => return Err(StopError::StreamClosed),
-                Some(res) => handle_item(res?, &mut last_key, &br_send)?,
-            }
+            // The live queries are already running, so `init` can go on with the host's hello.
+            wait_send.send(()).ok();
 
-            match close_stream.next().await {
-                None => return Err(StopError::StreamClosed),
-                Some(res) => match res? {
-                    Either::Left(item) => handle_item(item, &mut last_key, &br_send)?,
-                    Either::Right(item) => handle_item(item, &mut last_key, &br_send)?,
-                },
-            }
+            // The observer subscribes before the history (up to `last_key`, the greatest key notified so far)
+            // is queried, so every record is either in the history, or yet to be notified (or both,
+            // if committed out of key order, which `Observer` dedups), no gaps.
+            async fn query_history<C: Connection, T: ToObserveMsg + DeserializeOwned>(
+                db: &Surreal<C>,
+                table_name: &str,
+                fetch_client: bool,
+                last_key: &surrealdb::Value,
+                limit: Option<NonZeroU16>,
+            ) -> Result<Vec<ObserveMsg>, StopError> {
+                let suffix = if fetch_client { "_client" } else { "" };
+                // Not `run`, since the embedded engines' `run` unwraps a one-record array,
+                // and turns longer ones into NONE.
+                let mut res = match limit {
+                    None => {
+                        db.query(format!(
+                            "fn::all_before_key_desc{suffix}($table_name, $last_key)"
+                        ))
+                        .bind(("table_name", table_name.to_owned()))
+                        .bind(("last_key", last_key.clone()))
+                        .await?
+                    }
+                    Some(n) => {
+                        db.query(format!(
+                            "fn::last_n_before_key_desc{suffix}($table_name, $last_key, $n)"
+                        ))
+                        .bind(("table_name", table_name.to_owned()))
+                        .bind(("last_key", last_key.clone()))
+                        .bind(("n", n.get()))
+                        .await?
+                    }
+                };
+                let models: Vec<T> = res.take(0)?;
 
-            match msg_stream.next().await {
-                None => return Err(StopError::StreamClosed),
-                Some(res) => match res? {
-                    Either::Left(item) => handle_item(item, &mut last_key, &br_send)?,
-                    Either::Right(item) => handle_item(item, &mut last_key, &br_send)?,
-                },
+                models.into_iter().map(T::to_observe_msg).collect()
             }
 
-            // todo
             async fn build_observer<C: Connection>(
-                db: Surreal<C>,
+                db: &Surreal<C>,
+                tables: &SessionTables,
                 history: QueryHistory,
                 link_client: bool,
+                last_key: &Option<RecordIdKey>,
                 br_send: &broadcast::Sender<ObserveMsg>,
             ) -> Result<Observer, StopError> {
-                match (history, link_client) {
-                    (QueryHistory::Full, false) => (),
-                    (QueryHistory::Full, true) => (),
-                    (QueryHistory::Limit(n), false) => (),
-                    (QueryHistory::Limit(n), true) => (),
-                    _ => (),
+                let live = br_send.subscribe();
+                let limit = match history {
+                    QueryHistory::None => None,
+                    QueryHistory::Full => None,
+                    QueryHistory::Limit(n) => Some(n),
+                };
+                let last_key = match (history, last_key) {
+                    (QueryHistory::None, _) | (_, None) => {
+                        return Ok(observer(Vec::new(), live, link_client))
+                    }
+                    (_, Some(last_key)) => surrealdb::Value::from(last_key.clone()),
+                };
+                let SessionTables {
+                    clients,
+                    disconnects,
+                    msg,
+                } = tables;
+                let mut history =
+                    query_history::<_, ClientModel>(db, clients, false, &last_key, limit).await?;
+
+                if link_client {
+                    history.extend(
+                        query_history::<_, DisconnectClientModel>(
+                            db,
+                            disconnects,
+                            true,
+                            &last_key,
+                            limit,
+                        )
+                        .await?,
+                    );
+                    history.extend(
+                        query_history::<_, MsgClientModel>(db, msg, true, &last_key, limit).await?,
+                    );
+                } else {
+                    history.extend(
+                        query_history::<_, DisconnectIdModel>(
+                            db,
+                            disconnects,
+                            false,
+                            &last_key,
+                            limit,
+                        )
+                        .await?,
+                    );
+                    history.extend(
+                        query_history::<_, MsgIdModel>(db, msg, false, &last_key, limit).await?,
+                    );
                 }
-                todo!()
+
+                history.sort_by_cached_key(ObserveMsg::get_msg_key);
+
+                if let Some(n) = limit {
+                    history = history.split_off(history.len().saturating_sub(n.get().into()));
+                }
+
+                Ok(observer(history, live, link_client))
             }
 
-            match ob_responder.next_requset().await {
-                None => return Err(StopError::RequesterDropped),
-                Some(req) => {
-                    let res = build_observer(db_routine, req.req(), link_client, &br_send).await;
-                    req.response(res).ok();
+            // Records after `last_key` (the greatest key notified so far) in ascending key order,
+            // with their keys.
+            async fn query_after<C: Connection, T: ToObserveMsg + DeserializeOwned>(
+                db: &Surreal<C>,
+                table_name: &str,
+                fetch_client: bool,
+                last_key: &surrealdb::Value,
+            ) -> Result<Vec<(RecordIdKey, ObserveMsg)>, StopError> {
+                let suffix = if fetch_client { "_client" } else { "" };
+                let models: Vec<T> = db
+                    .query(format!(
+                        "fn::all_after_key_asc{suffix}($table_name, $last_key)"
+                    ))
+                    .bind(("table_name", table_name.to_owned()))
+                    .bind(("last_key", last_key.clone()))
+                    .await?
+                    .take(0)?;
+
+                let mut missed = Vec::with_capacity(models.len());
+
+                for model in models {
+                    let key = model.get_id().key().clone();
+                    missed.push((key, model.to_observe_msg()?));
                 }
+
+                Ok(missed)
             }
 
-            wait_send.send(()).ok();
-            Ok(GraceType::CtrlC)
+            // The new live queries are already running, so every record created during the gap
+            // is either backfilled, or yet to be notified (or both, which `LiveState` dedups).
+            async fn backfill<C: Connection>(
+                db: &Surreal<C>,
+                tables: &SessionTables,
+                link_client: bool,
+                state: &mut LiveState,
+            ) -> Result<(), StopError> {
+                let last_key = match &state.last_key {
+                    None => surrealdb::Value::from(RecordIdKey::from(String::new())),
+                    Some(last_key) => surrealdb::Value::from(last_key.clone()),
+                };
+                let SessionTables {
+                    clients,
+                    disconnects,
+                    msg,
+                } = tables;
+                let mut missed =
+                    query_after::<_, ClientModel>(db, clients, false, &last_key).await?;
+
+                if link_client {
+                    missed.extend(
+                        query_after::<_, DisconnectClientModel>(db, disconnects, true, &last_key)
+                            .await?,
+                    );
+                    missed
+                        .extend(query_after::<_, MsgClientModel>(db, msg, true, &last_key).await?);
+                } else {
+                    missed.extend(
+                        query_after::<_, DisconnectIdModel>(db, disconnects, false, &last_key)
+                            .await?,
+                    );
+                    missed.extend(query_after::<_, MsgIdModel>(db, msg, false, &last_key).await?);
+                }
+
+                missed.sort_by(|(a, _), (b, _)| a.cmp(b));
+                state.backfilled.clear();
+
+                for (key, msg) in missed {
+                    state.backfilled.insert(key.clone());
+                    state.broadcast(key, msg);
+                }
+
+                Ok(())
+            }
+
+            // The ws engine reconnects by itself (signing in & `use`-ing again), but the live queries
+            // of the lost connection are gone, so subscribe again, and backfill what was missed.
+            async fn resubscribe<C: Connection>(
+                db: &Surreal<C>,
+                tables: &SessionTables,
+                link_client: bool,
+                state: &mut LiveState,
+            ) -> LiveStreams {
+                let mut backoff = Duration::from_millis(500);
+
+                loop {
+                    sleep(backoff).await;
+                    backoff = (backoff * 2).min(Duration::from_secs(30));
+
+                    if db.health().await.is_err() {
+                        continue;
+                    }
+
+                    if let Ok(live) = LiveStreams::subscribe(db, tables, link_client).await {
+                        if backfill(db, tables, link_client, state).await.is_ok() {
+                            return live;
+                        }
+                    }
+                }
+            }
+
+            loop {
+                let closed = tokio::select! {
+                    res = ctrl_c(), if ctrlc_shutdown => {
+                        return res.map(|_| GraceType::CtrlC).map_err(From::from);
+                    }
+                    _ = shutdown_waiter.cancelled() => {
+                        return Ok(GraceType::Explicit);
+                    }
+                    item = live.clients.next() => handle_next(item, &mut state)?,
+                    item = live.disconnects.next() => handle_next(item, &mut state)?,
+                    item = live.msg.next() => handle_next(item, &mut state)?,
+                    req = ob_responder.next_requset() => match req {
+                        None => return Err(StopError::RequesterDropped),
+                        Some(req) => {
+                            let res = build_observer(
+                                &db_routine,
+                                &tables,
+                                req.req(),
+                                link_client,
+                                &state.last_key,
+                                &state.br_send,
+                            )
+                            .await;
+                            req.response(res).ok();
+                            false
+                        }
+                    },
+                };
+
+                if closed {
+                    if !reconnect {
+                        return Err(StopError::StreamClosed);
+                    }
+
+                    tokio::select! {
+                        res = ctrl_c(), if ctrlc_shutdown => {
+                            return res.map(|_| GraceType::CtrlC).map_err(From::from);
+                        }
+                        _ = shutdown_waiter.cancelled() => {
+                            return Ok(GraceType::Explicit);
+                        }
+                        new_live = resubscribe(&db_routine, &tables, link_client, &mut state) => {
+                            live = new_live;
+                        }
+                    }
+                }
+            }
         });
 
         wait_recv.await.ok();
@@ -586,9 +965,27 @@ impl<C: Connection> Stop<C> {
             host: "host".into(),
             link_client: true,
             ctrlc_shutdown: true,
+            reconnect: true,
         }
     }
 
+    pub fn db(&self) -> &Surreal<C> {
+        &self.db
+    }
+
+    pub fn session_id(&self) -> &RecordId {
+        &self.session_id
+    }
+
+    pub fn client_id(&self) -> &RecordId {
+        &self.client_id
+    }
+
+    // The prefix of the session's tables, e.g. `{formatted_timestamp}-msg`
+    pub fn formatted_timestamp(&self) -> &str {
+        &self.formatted_timestamp
+    }
+
     pub async fn client_hello(
         &self,
         client_role: ClientRole,
@@ -643,7 +1040,8 @@ impl<C: Connection> Stop<C> {
             d_client_role: Role,
             e_msg_format: Option<MsgFormat>,
             f_query_history: Option<QueryHistory>,
-            g_client_addr: Option<SocketAddr>,
+            // As text, since `SocketAddr` serializes as an enum
+            g_client_addr: Option<String>,
             h_query_map: Option<IndexMap<String, String>>,
             i_proc_env: Option<Value>,
         }
@@ -654,7 +1052,7 @@ impl<C: Connection> Stop<C> {
         let d_client_role = client_role;
         let e_msg_format = msg_format;
         let f_query_history = query_history;
-        let g_client_addr = client_addr;
+        let g_client_addr = client_addr.map(|addr| addr.to_string());
         let h_query_map = query_map.clone();
         let i_proc_env = proc_env.as_ref().and_then(|v| serde_json::to_value(v).ok());
         let record = ClientRecord {
@@ -682,6 +1080,7 @@ impl<C: Connection> Stop<C> {
         let client_id = rid.unwrap().id;
         let can_push = client_role.can_push();
         let is_client = client_role.is_client();
+        let query_history = f_query_history;
         let ob_requester = ob_requester.clone();
 
         Ok(Self {
@@ -692,41 +1091,306 @@ impl<C: Connection> Stop<C> {
             client_id,
             can_push,
             is_client,
+            query_history,
             link_client,
             ob_requester,
         })
     }
 
-    pub async fn query_last_n(&self, n: u8) -> Result<Vec<(String, String)>, surrealdb::Error> {
-        #[derive(Deserialize)]
-        struct ClientInfo {
-            c_client_name: String,
+    // The host may explicitly pass a `QueryHistory` (`Full` by default),
+    // while observer / director clients observe with the one of their hello.
+    pub async fn observe(&self, history: Option<QueryHistory>) -> Result<Observer, StopError> {
+        let history = match (self.is_client, history, self.query_history) {
+            (false, history, _) => history.unwrap_or_default(),
+            (true, Some(_), _) => return Err(StopError::ClientCannotObserve),
+            (true, None, None) => return Err(StopError::PusherCannotObserve),
+            (true, None, Some(history)) => history,
+        };
+
+        self.ob_requester
+            .request(history)
+            .await
+            .map_err(|_| StopError::RoutineStopped)?
+    }
+
+    // Tracing messages of the session (newest first, a page at a time), see `MsgQuery`.
+    pub fn query(&self) -> MsgQuery<'_, C> {
+        MsgQuery {
+            stop: self,
+            filter: Default::default(),
+            limit: NonZeroU16::new(100).unwrap(),
+            before: None,
+        }
+    }
+
+    pub async fn print(&self) {
+        println!("{}", self.is_client);
+    }
+}
+
+// Missing filters match everything, the others must all match:
+// ```ignore
+// let page = stop.query().min_level(Level::Warn).target_prefix("app::db").fetch().await?;
+// let older = stop.query().min_level(Level::Warn).target_prefix("app::db").before(page.cursor?);
+// ```
+#[derive(Clone, Debug)]
+pub struct MsgQuery<'a, C: Connection> {
+    stop: &'a Stop<C>,
+    filter: MsgFilter,
+    limit: NonZeroU16,
+    before: Option<String>,
+}
+
+#[derive(Serialize, Default, Clone, Debug)]
+struct MsgFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    levels: Option<Vec<Level>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    field: Option<FieldFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_role: Option<Role>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since: Option<Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    until: Option<Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    span_id: Option<SpanId>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+struct FieldFilter {
+    name: String,
+    value: tracing_msg::Value,
+}
+
+impl MsgFilter {
+    // Only the set filters, each on its own bound param, so that the indexed fields can use
+    // their index (instead of a function call on every msg). The `LET` lookups come first,
+    // since a subquery in the `WHERE` clause can't use the index.
+    fn statements(&self) -> (Vec<&'static str>, Vec<&'static str>) {
+        let mut lookups = Vec::new();
+        let mut conditions = Vec::new();
+
+        if self.levels.is_some() {
+            conditions.push("level IN $levels");
         }
 
-        #[derive(Deserialize)]
-        struct Msg {
-            message: String,
-            client_id: ClientInfo,
+        if self.target.is_some() {
+            lookups.push(
+                "LET $target_callsites = (SELECT VALUE id FROM type::table($callsites) \
+                 WHERE string::starts_with(target, $target));",
+            );
+            conditions.push("callsite IN $target_callsites");
         }
 
-        let table_name = format!("{}-msg", self.formatted_timestamp);
-        let msgs: Vec<Msg> = self
+        if self.message.is_some() {
+            conditions.push("string::contains(message ?? '', $message)");
+        }
+
+        if self.field.is_some() {
+            conditions.push("$field.value IN array::flatten([payload[$field.name] ?? []])");
+        }
+
+        if self.client_name.is_some() {
+            lookups.push(
+                "LET $named_clients = (SELECT VALUE id FROM type::table($clients) \
+                 WHERE c_client_name = $client_name);",
+            );
+            conditions.push("client_id IN $named_clients");
+        }
+
+        if self.client_role.is_some() {
+            lookups.push(
+                "LET $role_clients = (SELECT VALUE id FROM type::table($clients) \
+                 WHERE d_client_role = $client_role);",
+            );
+            conditions.push("client_id IN $role_clients");
+        }
+
+        if self.since.is_some() {
+            conditions.push("timestamp >= $since");
+        }
+
+        if self.until.is_some() {
+            conditions.push("timestamp < $until");
+        }
+
+        if self.span_id.is_some() {
+            conditions.push("(span_id = $span_id OR parent = $span_id)");
+        }
+
+        (lookups, conditions)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MsgPage {
+    // In ascending key order
+    pub msgs: Vec<ObserveMsg>,
+    // For `MsgQuery::before`, `None` once there's no older msg
+    pub cursor: Option<String>,
+}
+
+impl<C: Connection> MsgQuery<'_, C> {
+    fn filter(self, f: impl FnOnce(&mut MsgFilter)) -> Self {
+        let mut filter = self.filter;
+        f(&mut filter);
+
+        Self { filter, ..self }
+    }
+
+    pub fn level(self, level: Level) -> Self {
+        self.filter(|filter| filter.levels = Some(vec![level]))
+    }
+
+    // The level and the more severe ones
+    pub fn min_level(self, level: Level) -> Self {
+        let levels = [
+            Level::Trace,
+            Level::Debug,
+            Level::Info,
+            Level::Warn,
+            Level::Error,
+        ];
+        let levels = levels.into_iter().skip_while(|l| *l != level).collect();
+
+        self.filter(|filter| filter.levels = Some(levels))
+    }
+
+    pub fn target_prefix(self, target: &str) -> Self {
+        self.filter(|filter| filter.target = Some(target.into()))
+    }
+
+    pub fn message_contains(self, message: &str) -> Self {
+        self.filter(|filter| filter.message = Some(message.into()))
+    }
+
+    // Matches if any of the values recorded for the field is equal.
+    pub fn field_eq(self, name: &str, value: tracing_msg::Value) -> Self {
+        let field = FieldFilter {
+            name: name.into(),
+            value,
+        };
+
+        self.filter(|filter| filter.field = Some(field))
+    }
+
+    pub fn client_name(self, client_name: &str) -> Self {
+        self.filter(|filter| filter.client_name = Some(client_name.into()))
+    }
+
+    pub fn client_role(self, client_role: Role) -> Self {
+        self.filter(|filter| filter.client_role = Some(client_role))
+    }
+
+    // Inclusive
+    pub fn since(self, since: DateTime<Local>) -> Self {
+        self.filter(|filter| filter.since = Some(since.to_utc().into()))
+    }
+
+    // Exclusive
+    pub fn until(self, until: DateTime<Local>) -> Self {
+        self.filter(|filter| filter.until = Some(until.to_utc().into()))
+    }
+
+    // Msgs of the span itself, and events directly in it.
+    pub fn span_id(self, span_id: SpanId) -> Self {
+        self.filter(|filter| filter.span_id = Some(span_id))
+    }
+
+    // Page size, 100 by default.
+    pub fn limit(self, limit: NonZeroU16) -> Self {
+        Self { limit, ..self }
+    }
+
+    // Only msgs older than the cursor (of a previous page).
+    pub fn before(self, cursor: String) -> Self {
+        Self {
+            before: Some(cursor),
+            ..self
+        }
+    }
+
+    pub async fn fetch(&self) -> Result<MsgPage, StopError> {
+        let mut msgs = if self.stop.link_client {
+            self.fetch_models::<MsgClientModel>("*, client_id[*], c_client_id[*], callsite[*]")
+                .await?
+        } else {
+            self.fetch_models::<MsgIdModel>("*, callsite[*]").await?
+        };
+        // One more than `limit` is fetched, to tell whether there's an older msg.
+        let cursor = if msgs.len() > usize::from(self.limit.get()) {
+            msgs.truncate(self.limit.get().into());
+            msgs.last().map(ObserveMsg::get_msg_key)
+        } else {
+            None
+        };
+
+        msgs.reverse();
+
+        Ok(MsgPage { msgs, cursor })
+    }
+
+    async fn fetch_models<T: ToObserveMsg + DeserializeOwned>(
+        &self,
+        projection: &str,
+    ) -> Result<Vec<ObserveMsg>, StopError> {
+        let ts = &self.stop.formatted_timestamp;
+        let from = match self.before {
+            None => "type::table($table_name)",
+            Some(_) => "type::thing($table_name, ..$last_key)",
+        };
+        let (lookups, conditions) = self.filter.statements();
+        let mut query = format!("{} SELECT {projection} FROM {from}", lookups.join(" "));
+
+        if !conditions.is_empty() {
+            query = format!("{query} WHERE {}", conditions.join(" AND "));
+        }
+
+        // Not `run`, like in `init`'s `query_history`
+        let mut res = self
+            .stop
             .db
-            .run("fn::last_n_desc_client")
-            .args((table_name, n))
+            .query(format!("{query} ORDER BY id DESC LIMIT $n + 1"))
+            .bind(("table_name", format!("{ts}-msg")))
+            .bind(("clients", format!("{ts}-clients")))
+            .bind(("callsites", format!("{ts}-callsites")))
+            .bind(("last_key", self.before.clone()))
+            .bind(("n", self.limit.get()))
+            .bind(self.filter.clone())
             .await?;
+        let models: Vec<T> = res.take(lookups.len())?;
+
+        models.into_iter().map(T::to_observe_msg).collect()
+    }
+}
 
-        // clients + disconnects & merge
+// The in-process engines, which need no SurrealDB server (nor signing in).
+#[cfg(any(feature = "kv-mem", feature = "kv-rocksdb"))]
+impl Stop<Db> {
+    #[cfg(feature = "kv-mem")]
+    pub async fn builder_mem(app: &str) -> Result<StopBuilder<Db>, StopError> {
+        let db = Surreal::new::<Mem>(()).await?;
 
-        Ok(msgs
-            .iter()
-            .rev()
-            .map(|m| (m.message.clone(), m.client_id.c_client_name.clone()))
-            .collect())
+        db.use_ns(EMBEDDED_NS).await?;
+        Ok(Self::builder_default(db, app))
     }
 
-    pub async fn print(&self) {
-        println!("{}", self.is_client);
+    #[cfg(feature = "kv-rocksdb")]
+    pub async fn builder_rocksdb(
+        path: impl AsRef<Path>,
+        app: &str,
+    ) -> Result<StopBuilder<Db>, StopError> {
+        let db = Surreal::new::<RocksDb>(path.as_ref()).await?;
+
+        db.use_ns(EMBEDDED_NS).await?;
+        Ok(Self::builder_default(db, app))
     }
 }
 
@@ -742,6 +1406,7 @@ impl<C: Connection> CloseTransport for Stop<C> {
                 e_ok_kind: Option<CloseOk>,
                 f_err_kind: Option<CloseErrKind>,
                 g_err_msg: Option<String>,
+                h_close_stats: CloseStats,
             }
 
             let a_timestamp = Local::now();
@@ -753,6 +1418,7 @@ impl<C: Connection> CloseTransport for Stop<C> {
                 None => (None, None),
                 Some(CloseErr { kind, display }) => (Some(*kind), Some(display.clone())),
             };
+            let h_close_stats = *msg.stats();
             let record = DisconnectRecord {
                 a_timestamp,
                 b_session_id,
@@ -761,6 +1427,7 @@ impl<C: Connection> CloseTransport for Stop<C> {
                 e_ok_kind,
                 f_err_kind,
                 g_err_msg,
+                h_close_stats,
             };
             let _rid: Option<Option<RID>> = self
                 .db
@@ -792,26 +1459,70 @@ impl<C: Connection> PushMsg for Stop<C> {
             id: RecordId,
             session_id: RecordId,
             client_id: RecordId,
+            callsite: Option<RecordId>,
+            // Shared by all the clients taking part in a distributed trace
+            trace_id: Option<TraceId>,
+            // The other fields of `TracingMsg`, with the timestamp as a datetime (not a string),
+            // so that it's compared by time, with the index
+            timestamp: Datetime,
+            thread_name: Option<String>,
+            thread_id: ThreadId,
+            task_id: Option<TaskId>,
+            sampling: Option<Sampling>,
             #[serde(flatten)]
-            msg: TracingMsg,
+            body: MsgBody,
         }
 
         let table_name = format!("{}-msg", self.formatted_timestamp);
+        let callsites_name = format!("{}-callsites", self.formatted_timestamp);
         let mut records = Vec::new();
 
         for msg in msgs {
+            // Callsites are keyed by their stable id, so every client shares the same records.
+            if let MsgBody::OnRegisterCallsite {
+                callsite_id,
+                callsite,
+            } = msg.body
+            {
+                let _rid: Option<RID> = self
+                    .db
+                    .upsert((callsites_name.clone(), callsite_id.to_string()))
+                    .content(callsite)
+                    .await?;
+
+                continue;
+            }
+
             let id = RecordId::from_table_key(&table_name, self.id_gen.next(msg.timestamp).await);
             let session_id = self.session_id.clone();
             let client_id = self.client_id.clone();
+            let callsite = msg
+                .body
+                .callsite_id()
+                .map(|id| RecordId::from_table_key(&callsites_name, id.to_string()));
+            let trace_id = msg.body.trace_context().map(|trace| trace.trace_id);
 
             records.push(MsgRecord {
                 id,
                 session_id,
                 client_id,
-                msg,
+                callsite,
+                trace_id,
+                timestamp: msg.timestamp.to_utc().into(),
+                thread_name: msg.thread_name,
+                thread_id: msg.thread_id,
+                task_id: msg.task_id,
+                sampling: msg.sampling,
+                body: msg.body,
             });
         }
 
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        // The insert is atomic, so a batch is retried whole, and never partly duplicated
+        // (the schema accepting every `MsgBody` is kept by `schema_keeps_every_msg_body`).
         let _rids: Vec<RID> = self.db.insert(table_name).content(records).await?;
 
         Ok(())
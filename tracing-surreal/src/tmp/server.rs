@@ -1,31 +1,42 @@
 use crate::{
     stop::Stop,
-    tracing_msg::{query_map::QueryMap, ClientRole, GraceType, MsgFormat, QueryHistory},
+    tracing_msg::{
+        query_map::QueryMap, ClientRole, CloseErr, CloseErrKind, CloseMsg, CloseTransport,
+        GraceType, MsgFormat, PushMsg, QueryHistory,
+    },
+    ws_pusher::{parse_close_reason, PushAck, PushFrame},
 };
 use est::task::CloseAndWait;
+use futures::{SinkExt, StreamExt};
 use indexmap::IndexMap;
 use std::{
+    collections::HashMap,
     future::Future,
-    io,
+    io, mem,
     net::{Ipv4Addr, SocketAddr, SocketAddrV4},
     pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
     task::{Context, Poll},
     time::Duration,
 };
 use surrealdb::Connection;
 use thiserror::Error;
 use tokio::{
-    net::{lookup_host, TcpListener, ToSocketAddrs},
+    net::{lookup_host, TcpListener, TcpStream, ToSocketAddrs},
     signal::ctrl_c,
     sync::oneshot,
     task::{JoinError, JoinHandle},
+    time::{interval, Instant, MissedTickBehavior},
 };
 use tokio_tungstenite::{
     accept_hdr_async,
     tungstenite::{
         handshake::server::{ErrorResponse, Request, Response},
         http::StatusCode,
+        protocol::{frame::coding::CloseCode, CloseFrame},
+        Message,
     },
+    WebSocketStream,
 };
 use tokio_util::{sync::CancellationToken, task::TaskTracker, time::FutureExt};
 
@@ -70,6 +81,7 @@ pub struct ServerBuilder<C: Connection> {
     ctrlc_shutdown: bool,
     ws_handshake_timeout: Duration,
     tmp_hello_timeout: Duration,
+    pusher_resume_timeout: Duration,
     bind_addrs: Vec<SocketAddr>,
 }
 
@@ -220,6 +232,14 @@ impl<C: Connection + Clone> ServerBuilder<C> {
         }
     }
 
+    // How long a dropped pusher is kept for resuming, before it's closed.
+    pub fn pusher_resume_timeout(self, timeout: Duration) -> Self {
+        Self {
+            pusher_resume_timeout: timeout,
+            ..self
+        }
+    }
+
     pub async fn bind_addrs<A: ToSocketAddrs>(self, host: A) -> io::Result<Self> {
         Ok(Self {
             bind_addrs: lookup_host(host).await?.collect(),
@@ -245,6 +265,11 @@ impl<C: Connection + Clone> ServerBuilder<C> {
             // log safe builder info + local_addr into db
 
             let tracker = TaskTracker::new();
+            let pushers = Pushers::default();
+            let resume_timeout = builder.pusher_resume_timeout;
+            let mut evict = interval(resume_timeout.max(Duration::from_millis(100)));
+
+            evict.set_missed_tick_behavior(MissedTickBehavior::Delay);
 
             loop {
                 let (stream, client_addr) = tokio::select! {
@@ -252,13 +277,19 @@ impl<C: Connection + Clone> ServerBuilder<C> {
                         println!("Bye from ctrl_c");
                         shutdown_waiter.cancel();
                         tracker.close_and_wait().await;
+                        evict_pushers(&pushers, Duration::ZERO).await;
                         return res.map(|_| GraceType::CtrlC);
                     }
                     _ = shutdown_waiter.cancelled() => {
                         println!("Bye from shutdown_waiter");
                         tracker.close_and_wait().await;
+                        evict_pushers(&pushers, Duration::ZERO).await;
                         return Ok(GraceType::Explicit);
                     }
+                    _ = evict.tick() => {
+                        evict_pushers(&pushers, resume_timeout).await;
+                        continue;
+                    }
                     res = listener.accept() => {
                         if let Err(err) = &res {
                             println!("accept err: {}", err);
@@ -272,6 +303,7 @@ impl<C: Connection + Clone> ServerBuilder<C> {
                 println!("{}", client_addr);
 
                 let builder = builder.clone();
+                let pushers = pushers.clone();
                 let auth_args = builder.auth_args.clone();
                 let format_args = builder.format_args;
                 let shutdown_waiter = shutdown_waiter.clone();
@@ -380,7 +412,23 @@ impl<C: Connection + Clone> ServerBuilder<C> {
                     println!("query_history: {:?}", query_history);
                     println!("query_map: {:?}", query_map);
 
-                    // todo: HelloMsg + Stop::client_hello + 传回Observer::link_client
+                    if let ClientRole::Pusher = client_role {
+                        let serve = serve_pusher(
+                            &builder,
+                            &pushers,
+                            stream,
+                            client_addr,
+                            msg_format,
+                            query_map,
+                        );
+
+                        tokio::select! {
+                            _ = shutdown_waiter.cancelled() => (),
+                            _ = serve => (),
+                        }
+                    }
+
+                    // todo: Observer & Director: HelloMsg + Stop::client_hello + 传回Observer::link_client
                 });
             }
         });
@@ -419,11 +467,160 @@ impl<C: Connection + Clone> BuildServerDefault<C> for Stop<C> {
             ctrlc_shutdown: true,
             ws_handshake_timeout: Duration::from_secs_f64(1.5),
             tmp_hello_timeout: Duration::from_secs_f64(3.0),
+            pusher_resume_timeout: Duration::from_secs(60),
             bind_addrs: vec![SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8192).into()],
         }
     }
 }
 
+// By `client_key`, kept after a dropped connection (without a close frame),
+// so that a reconnecting pusher resumes as the same client, until `pusher_resume_timeout`.
+type Pushers<C> = Arc<Mutex<HashMap<String, (Instant, Stop<C>)>>>;
+
+// Closes & forgets the pushers dropped for `timeout` or longer.
+async fn evict_pushers<C: Connection + Clone>(pushers: &Pushers<C>, timeout: Duration) {
+    let stale: HashMap<_, _> = {
+        let mut pushers = pushers.lock().unwrap_or_else(PoisonError::into_inner);
+        let (stale, kept) = mem::take(&mut *pushers)
+            .into_iter()
+            .partition(|(_, (dropped, _))| dropped.elapsed() >= timeout);
+        *pushers = kept;
+        stale
+    };
+
+    for (client_key, (_, mut pusher)) in stale {
+        println!("pusher not resumed: {}", client_key);
+        let err = CloseErr::new(CloseErrKind::Other, "connection dropped, not resumed");
+        pusher.close_transport(Some(CloseMsg::err(err))).await;
+    }
+}
+
+async fn send_ack(
+    stream: &mut WebSocketStream<TcpStream>,
+    ack: PushAck,
+    msg_format: MsgFormat,
+) -> bool {
+    match ack.encode(msg_format) {
+        Ok(msg) => stream.send(msg).await.is_ok(),
+        Err(_) => false,
+    }
+}
+
+// The server half of `WsPusher`: a `PushFrame::Hello` first, then acked `PushFrame::Msgs`,
+// until the close frame (with the `CloseMsg` as its reason).
+async fn serve_pusher<C: Connection + Clone>(
+    builder: &ServerBuilder<C>,
+    pushers: &Pushers<C>,
+    mut stream: WebSocketStream<TcpStream>,
+    client_addr: SocketAddr,
+    msg_format: MsgFormat,
+    query_map: Option<IndexMap<String, String>>,
+) {
+    let client_key = query_map.as_ref().and_then(|map| map.get_client_key());
+    let hello = match stream.next().timeout(builder.tmp_hello_timeout).await {
+        Ok(Some(Ok(msg))) => PushFrame::decode(&msg, msg_format),
+        _ => {
+            println!("no hello from: {}", client_addr);
+            return;
+        }
+    };
+    let (resumed, hello_msg) = match hello {
+        Ok(PushFrame::Hello { resumed, hello_msg }) => (resumed, hello_msg),
+        _ => {
+            send_ack(
+                &mut stream,
+                PushAck::Err("hello expected".into()),
+                msg_format,
+            )
+            .await;
+            return;
+        }
+    };
+    let resumed = match (&client_key, resumed) {
+        (Some(client_key), true) => pushers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(client_key)
+            .map(|(_, pusher)| pusher),
+        _ => None,
+    };
+    let mut pusher = match resumed {
+        Some(pusher) => pusher,
+        None => {
+            let res = builder
+                .stop
+                .client_hello(
+                    ClientRole::Pusher,
+                    *hello_msg,
+                    client_addr,
+                    msg_format,
+                    None,
+                    query_map,
+                )
+                .await;
+
+            match res {
+                Ok(pusher) => pusher,
+                Err(err) => {
+                    send_ack(&mut stream, PushAck::Err(err.to_string()), msg_format).await;
+                    return;
+                }
+            }
+        }
+    };
+    let mut acked = send_ack(&mut stream, PushAck::Ok, msg_format).await;
+
+    while acked {
+        let msg = match stream.next().await {
+            None | Some(Err(_)) => break,
+            Some(Ok(Message::Close(frame))) => {
+                let msg = frame.and_then(|frame| match parse_close_reason(&frame.reason) {
+                    Some(msg) => Some(msg),
+                    None if frame.reason.is_empty() => None,
+                    None if frame.code == CloseCode::Normal => None,
+                    None => Some(CloseMsg::err(CloseErr::new(
+                        CloseErrKind::Other,
+                        frame.reason,
+                    ))),
+                });
+
+                pusher.close_transport(msg).await;
+
+                // Flushes the close reply.
+                while let Some(Ok(_)) = stream.next().await {}
+                return;
+            }
+            Some(Ok(Message::Ping(_) | Message::Pong(_) | Message::Frame(_))) => continue,
+            Some(Ok(msg)) => msg,
+        };
+        let ack = match PushFrame::decode(&msg, msg_format) {
+            Ok(PushFrame::Msgs(msgs)) => match pusher.bulk_push(msgs).await {
+                Ok(()) => PushAck::Ok,
+                Err(err) => PushAck::Err(err.to_string()),
+            },
+            Ok(PushFrame::Hello { .. }) | Err(_) if builder.fuck_off_on_damage => {
+                let frame = CloseFrame {
+                    code: CloseCode::Policy,
+                    reason: "damaged frame".into(),
+                };
+                stream.close(Some(frame)).await.ok();
+                break;
+            }
+            Ok(PushFrame::Hello { .. }) => PushAck::Err("unexpected hello".into()),
+            Err(err) => PushAck::Err(err.to_string()),
+        };
+
+        acked = send_ack(&mut stream, ack, msg_format).await;
+    }
+
+    if let Some(client_key) = client_key {
+        pushers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(client_key, (Instant::now(), pusher));
+    }
+}
+
 fn query_auth(
     query: Option<Result<IndexMap<String, String>, serde_qs::Error>>,
     token_need: Option<String>,
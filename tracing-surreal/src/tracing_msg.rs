@@ -2,20 +2,42 @@ use chrono::{DateTime, Local};
 use derive_more::Display;
 use est::{task::TaskId, thread::ThreadId};
 use indexmap::{map::Entry, IndexMap};
-use serde::{Deserialize, Serialize};
-use std::{error, fmt, future::Future, num::NonZeroU64, ops::Deref, thread};
+use serde::{ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{serde_as, DeserializeAs, DisplayFromStr, PickFirst, Same, SerializeAs};
+use std::{
+    error, fmt,
+    future::Future,
+    num::{NonZeroU64, ParseIntError},
+    ops::Deref,
+    thread,
+};
 use tokio::task;
 use tracing_core::{field, span};
 
+pub mod combinator;
+pub mod filter;
+pub mod in_memory;
 pub mod layer;
+mod limit;
 pub mod observe;
 pub mod proc_env;
 pub mod query_map;
-
+pub mod queue;
+mod redact;
+mod sample;
+mod spool;
+#[cfg(all(tracing_unstable, feature = "valuable"))]
+mod structured;
+pub mod trace_context;
+
+pub use combinator::{Fallback, FallbackError, Tee, TeeError};
+pub use in_memory::InMemoryTransport;
 pub use layer::TracingLayerDefault;
 pub use observe::{observer, ClientInfo, ObserveMsg, Observer};
 pub use proc_env::ProcEnv;
 pub use query_map::{MsgFormat, QueryHistory};
+pub use queue::{DropCounters, OverflowPolicy};
+pub use trace_context::{TraceContext, TraceId, TraceSpanId};
 
 #[derive(Debug, Display, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash)]
 #[serde(transparent)]
@@ -55,6 +77,139 @@ impl From<tracing_core::Level> for Level {
     }
 }
 
+// Stable across processes & platforms (FNV-1a of the callsite metadata),
+// serialized as hex since SurrealDB integers are `i64`.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[serde(into = "String", try_from = "String")]
+pub struct CallsiteId(pub u64);
+
+impl CallsiteId {
+    fn hash<'a>(
+        kind: CallsiteKind,
+        level: Level,
+        strs: impl IntoIterator<Item = Option<&'a str>>,
+        line: Option<u32>,
+    ) -> Self {
+        let fnv1a = |hash: u64, bytes: &[u8]| {
+            bytes.iter().fold(hash, |hash, byte| {
+                (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+            })
+        };
+        let hash = fnv1a(0xcbf29ce484222325, &[kind as u8, level as u8]);
+        let hash = strs.into_iter().fold(hash, |hash, str| match str {
+            None => fnv1a(hash, &[0]),
+            Some(str) => fnv1a(fnv1a(fnv1a(hash, &[1]), str.as_bytes()), &[0xff]),
+        });
+        let hash = match line {
+            None => fnv1a(hash, &[0]),
+            Some(line) => fnv1a(fnv1a(hash, &[1]), &line.to_le_bytes()),
+        };
+
+        Self(hash)
+    }
+}
+
+impl fmt::Display for CallsiteId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl From<CallsiteId> for String {
+    fn from(value: CallsiteId) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<String> for CallsiteId {
+    type Error = ParseIntError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        u64::from_str_radix(&value, 16).map(Self)
+    }
+}
+
+impl From<&tracing_core::Metadata<'_>> for CallsiteId {
+    fn from(metadata: &tracing_core::Metadata<'_>) -> Self {
+        let strs = [
+            Some(metadata.name()),
+            Some(metadata.target()),
+            metadata.module_path(),
+            metadata.file(),
+        ];
+        let fields = metadata.fields().iter().map(|field| Some(field.name()));
+
+        Self::hash(
+            metadata.into(),
+            (*metadata.level()).into(),
+            strs.into_iter().chain(fields),
+            metadata.line(),
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum CallsiteKind {
+    Span,
+    Event,
+}
+
+impl From<&tracing_core::Metadata<'_>> for CallsiteKind {
+    fn from(metadata: &tracing_core::Metadata<'_>) -> Self {
+        match metadata.is_span() {
+            true => Self::Span,
+            false => Self::Event,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Callsite {
+    pub kind: CallsiteKind,
+    pub level: Level,
+    pub name: String,
+    pub target: String,
+    pub module_path: Option<String>,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub fields: Vec<String>,
+}
+
+impl Callsite {
+    pub fn id(&self) -> CallsiteId {
+        let strs = [
+            Some(self.name.as_str()),
+            Some(self.target.as_str()),
+            self.module_path.as_deref(),
+            self.file.as_deref(),
+        ];
+        let fields = self.fields.iter().map(|field| Some(field.as_str()));
+
+        CallsiteId::hash(
+            self.kind,
+            self.level,
+            strs.into_iter().chain(fields),
+            self.line,
+        )
+    }
+}
+
+impl From<&tracing_core::Metadata<'_>> for Callsite {
+    fn from(metadata: &tracing_core::Metadata<'_>) -> Self {
+        Self {
+            kind: metadata.into(),
+            level: (*metadata.level()).into(),
+            name: metadata.name().into(),
+            target: metadata.target().into(),
+            module_path: metadata.module_path().map(From::from),
+            file: metadata.file().map(From::from),
+            line: metadata.line(),
+            fields: metadata.fields().iter().map(|f| f.name().into()).collect(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum Parent {
@@ -88,19 +243,97 @@ impl From<&tracing_core::Event<'_>> for Parent {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SpanFrame {
+    pub span_id: SpanId,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Default, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SpanTiming {
+    pub busy_ns: u64,
+    pub idle_ns: u64,
+    pub total_ns: u64,
+    pub enters: u64,
+}
+
+// SurrealDB integers are `i64`, so the ones out of its range are written as text
+// (and read back from either).
+struct IntOrText;
+
+impl<T: Copy + fmt::Display + TryInto<i64>> SerializeAs<T> for IntOrText {
+    fn serialize_as<S: Serializer>(source: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        match (*source).try_into() {
+            Ok(int) => serializer.serialize_i64(int),
+            Err(_) => serializer.collect_str(source),
+        }
+    }
+}
+
+impl<'de, T> DeserializeAs<'de, T> for IntOrText
+where
+    PickFirst<(Same, DisplayFromStr)>: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        PickFirst::<(Same, DisplayFromStr)>::deserialize_as(deserializer)
+    }
+}
+
+#[serde_as]
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(tag = "type", content = "value", rename_all = "lowercase")]
 pub enum Value {
     Debug(String),
     F64(f64),
     I64(i64),
-    U64(u64),
-    I128(i128),
-    U128(u128),
+    U64(#[serde_as(as = "IntOrText")] u64),
+    I128(#[serde_as(as = "IntOrText")] i128),
+    U128(#[serde_as(as = "IntOrText")] u128),
     Bool(bool),
     String(String),
     Bytes(Vec<u8>),
     Error(String),
+    // Only text & bytes get truncated, `original_len` is in bytes.
+    Truncated {
+        partial: Box<Value>,
+        original_len: u64,
+    },
+    Null,
+    // Recorded through `valuable`, stored untagged as native objects & arrays.
+    #[serde(untagged)]
+    Map(IndexMap<String, Structured>),
+    #[serde(untagged)]
+    List(Vec<Structured>),
+}
+
+// Untagged (unlike `Value`), so that nested fields can be queried directly in SurrealDB,
+// e.g. `WHERE payload.request.user.id = 42`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum Structured {
+    Null,
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    String(String),
+    List(Vec<Structured>),
+    Map(IndexMap<String, Structured>),
+}
+
+impl From<Structured> for Value {
+    fn from(value: Structured) -> Self {
+        match value {
+            Structured::Null => Self::Null,
+            Structured::Bool(value) => Self::Bool(value),
+            Structured::I64(value) => Self::I64(value),
+            Structured::U64(value) => Self::U64(value),
+            Structured::F64(value) => Self::F64(value),
+            Structured::String(value) => Self::String(value),
+            Structured::List(value) => Self::List(value),
+            Structured::Map(value) => Self::Map(value),
+        }
+    }
 }
 
 impl Value {
@@ -109,6 +342,7 @@ impl Value {
             Self::Debug(value) => Some(value.clone()),
             Self::String(value) => Some(value.clone()),
             Self::Error(value) => Some(value.clone()),
+            Self::Truncated { partial, .. } => partial.get_text(),
             _ => None,
         }
     }
@@ -123,10 +357,48 @@ impl Value {
     }
 }
 
-#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
-#[serde(transparent)]
+// Stored as the array of the values recorded for each field, except for a field recorded once
+// with a `Map`, which is stored as the map itself, so that its nested fields can be queried.
+#[derive(Default, Clone, Debug, PartialEq)]
 pub struct Payload(IndexMap<String, Vec<Value>>);
 
+impl Serialize for Payload {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+
+        for (key, values) in &self.0 {
+            match values.as_slice() {
+                [value @ Value::Map(_)] => map.serialize_entry(key, value)?,
+                values => map.serialize_entry(key, values)?,
+            }
+        }
+
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Payload {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Recorded {
+            Values(Vec<Value>),
+            Value(Value),
+        }
+
+        let map = IndexMap::<String, Recorded>::deserialize(deserializer)?;
+        let map = map
+            .into_iter()
+            .map(|(key, recorded)| match recorded {
+                Recorded::Values(values) => (key, values),
+                Recorded::Value(value) => (key, vec![value]),
+            })
+            .collect();
+
+        Ok(Self(map))
+    }
+}
+
 impl Payload {
     pub fn insert_empty(&mut self, key: &str) {
         self.0.entry(key.into()).or_default();
@@ -143,6 +415,14 @@ impl Payload {
         }
     }
 
+    fn message(&self) -> String {
+        self.get("message")
+            .map(Deref::deref)
+            .and_then(<[_]>::first)
+            .and_then(Value::get_text)
+            .unwrap_or_default()
+    }
+
     fn record_field(&mut self, field: &tracing_core::Field, value: Value) {
         self.record(field.name(), value.nulls_removed());
     }
@@ -196,6 +476,11 @@ impl field::Visit for Payload {
     fn record_error(&mut self, field: &tracing_core::Field, value: &(dyn error::Error + 'static)) {
         self.record_field(field, Value::Error(value.to_string()));
     }
+
+    #[cfg(all(tracing_unstable, feature = "valuable"))]
+    fn record_value(&mut self, field: &tracing_core::Field, value: valuable::Value<'_>) {
+        self.record_field(field, Structured::from(value).into());
+    }
 }
 
 impl From<field::Iter> for Payload {
@@ -237,15 +522,21 @@ impl From<&span::Record<'_>> for Payload {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum MsgBody {
+    // Sent once per callsite, before any `OnNewSpan` / `OnEvent` referencing it.
+    OnRegisterCallsite {
+        callsite_id: CallsiteId,
+        callsite: Callsite,
+    },
     OnNewSpan {
         span_id: SpanId,
         level: Level,
-        name: String,
-        target: String,
-        module_path: Option<String>,
-        file: Option<String>,
-        line: Option<u32>,
+        callsite_id: CallsiteId,
         parent: Parent,
+        // From the root down to the direct parent
+        #[serde(default)]
+        stack: Option<Vec<SpanFrame>>,
+        #[serde(default)]
+        trace: Option<TraceContext>,
         payload: Payload,
     },
     OnRecord {
@@ -259,12 +550,14 @@ pub enum MsgBody {
     OnEvent {
         message: String,
         level: Level,
-        name: String,
-        target: String,
-        module_path: Option<String>,
-        file: Option<String>,
-        line: Option<u32>,
+        callsite_id: CallsiteId,
         parent: Parent,
+        // From the root down to the span in which the event occurred
+        #[serde(default)]
+        stack: Option<Vec<SpanFrame>>,
+        // Of the span in which the event occurred
+        #[serde(default)]
+        trace: Option<TraceContext>,
         payload: Payload,
     },
     OnEnter {
@@ -275,6 +568,8 @@ pub enum MsgBody {
     },
     OnClose {
         span_id: SpanId,
+        #[serde(default)]
+        timing: Option<SpanTiming>,
     },
     OnIdChange {
         old_span: SpanId,
@@ -283,6 +578,22 @@ pub enum MsgBody {
 }
 
 impl MsgBody {
+    pub fn callsite_id(&self) -> Option<CallsiteId> {
+        match self {
+            Self::OnRegisterCallsite { callsite_id, .. }
+            | Self::OnNewSpan { callsite_id, .. }
+            | Self::OnEvent { callsite_id, .. } => Some(*callsite_id),
+            _ => None,
+        }
+    }
+
+    pub fn trace_context(&self) -> Option<&TraceContext> {
+        match self {
+            Self::OnNewSpan { trace, .. } | Self::OnEvent { trace, .. } => trace.as_ref(),
+            _ => None,
+        }
+    }
+
     fn on_new_span(attrs: &span::Attributes<'_>, id: &span::Id) -> Self {
         let metadata = attrs.metadata();
         let parent = Parent::from(attrs);
@@ -310,12 +621,7 @@ impl MsgBody {
         let metadata = event.metadata();
         let parent = Parent::from(event);
         let payload = Payload::from(event);
-        let message = payload
-            .get("message")
-            .map(Deref::deref)
-            .and_then(<[_]>::first)
-            .and_then(Value::get_text)
-            .unwrap_or_default();
+        let message = payload.message();
 
         (metadata, parent, payload, message).into()
     }
@@ -330,9 +636,9 @@ impl MsgBody {
         Self::OnExit { span_id }
     }
 
-    fn on_close(id: span::Id) -> Self {
+    fn on_close(id: span::Id, timing: Option<SpanTiming>) -> Self {
         let span_id = id.into();
-        Self::OnClose { span_id }
+        Self::OnClose { span_id, timing }
     }
 
     fn on_id_change(old: &span::Id, new: &span::Id) -> Self {
@@ -341,6 +647,80 @@ impl MsgBody {
 
         Self::OnIdChange { old_span, new_span }
     }
+
+    // Keeps the `message` of `OnEvent` in sync with its payload.
+    fn map_payload(&mut self, f: impl FnOnce(&mut Payload)) {
+        match self {
+            Self::OnNewSpan { payload, .. } | Self::OnRecord { payload, .. } => f(payload),
+            Self::OnEvent {
+                message, payload, ..
+            } => {
+                f(payload);
+                *message = payload.message();
+            }
+            _ => (),
+        }
+    }
+
+    // Only `Parent::Current` gets replaced, explicit parents are kept as is.
+    fn with_span_context(mut self, resolved: Parent, span_stack: Option<Vec<SpanFrame>>) -> Self {
+        if let Self::OnNewSpan { parent, stack, .. } | Self::OnEvent { parent, stack, .. } =
+            &mut self
+        {
+            if *parent == Parent::Current {
+                *parent = resolved;
+            }
+
+            *stack = span_stack;
+        }
+
+        self
+    }
+
+    fn with_trace_context(mut self, trace_context: Option<TraceContext>) -> Self {
+        if let Self::OnNewSpan { trace, .. } | Self::OnEvent { trace, .. } = &mut self {
+            *trace = trace_context;
+        }
+
+        self
+    }
+
+    // The synthetic event comes along with its own callsite registration.
+    fn on_msgs_dropped(dropped: u64) -> [Self; 2] {
+        let message = format!("{} tracing messages dropped due to queue overflow", dropped);
+        let mut payload = Payload::default();
+        payload.record("message", Value::String(message.clone()));
+        payload.record("dropped", Value::U64(dropped));
+
+        let line = line!();
+        let callsite = Callsite {
+            kind: CallsiteKind::Event,
+            level: Level::Warn,
+            name: format!("event {}:{}", file!(), line),
+            target: module_path!().into(),
+            module_path: Some(module_path!().into()),
+            file: Some(file!().into()),
+            line: Some(line),
+            fields: vec!["message".into(), "dropped".into()],
+        };
+        let callsite_id = callsite.id();
+
+        [
+            Self::OnRegisterCallsite {
+                callsite_id,
+                callsite,
+            },
+            Self::OnEvent {
+                message,
+                level: Level::Warn,
+                callsite_id,
+                parent: Parent::Root,
+                stack: None,
+                trace: None,
+                payload,
+            },
+        ]
+    }
 }
 
 impl From<(&tracing_core::Metadata<'_>, Parent, Payload, SpanId)> for MsgBody {
@@ -355,12 +735,10 @@ impl From<(&tracing_core::Metadata<'_>, Parent, Payload, SpanId)> for MsgBody {
         Self::OnNewSpan {
             span_id,
             level: (*metadata.level()).into(),
-            name: metadata.name().into(),
-            target: metadata.target().into(),
-            module_path: metadata.module_path().map(From::from),
-            file: metadata.file().map(From::from),
-            line: metadata.line(),
+            callsite_id: metadata.into(),
             parent,
+            stack: None,
+            trace: None,
             payload,
         }
     }
@@ -378,23 +756,34 @@ impl From<(&tracing_core::Metadata<'_>, Parent, Payload, String)> for MsgBody {
         Self::OnEvent {
             message,
             level: (*metadata.level()).into(),
-            name: metadata.name().into(),
-            target: metadata.target().into(),
-            module_path: metadata.module_path().map(From::from),
-            file: metadata.file().map(From::from),
-            line: metadata.line(),
+            callsite_id: metadata.into(),
             parent,
+            stack: None,
+            trace: None,
             payload,
         }
     }
 }
 
+// Attached to the kept `OnNewSpan` / `OnEvent` when sampling is enabled,
+// so that observers know the stored data is partial.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub struct Sampling {
+    pub ratio: f64,
+    // Messages of the same callsite suppressed by the rate limit since the previous one
+    pub rate_limited: u64,
+    // Kept only because of the level override
+    pub forced: bool,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct TracingMsg {
     pub timestamp: DateTime<Local>,
     pub thread_name: Option<String>,
     pub thread_id: ThreadId,
     pub task_id: Option<TaskId>,
+    #[serde(default)]
+    pub sampling: Option<Sampling>,
     #[serde(flatten)]
     pub body: MsgBody,
 }
@@ -412,6 +801,7 @@ impl From<MsgBody> for TracingMsg {
             thread_name,
             thread_id,
             task_id,
+            sampling: None,
             body,
         }
     }
@@ -518,7 +908,6 @@ pub enum CloseErrKind {
     LayerDropped,
     PushMsgErr,
     BulkPushErr,
-    BufferFull,
     Other,
 }
 
@@ -544,17 +933,44 @@ impl CloseErr {
     }
 }
 
+#[derive(Serialize, Deserialize, Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct CloseStats {
+    pub retries: u64,
+    pub dead_lettered: u64,
+    pub replayed: u64,
+    pub spool_evicted: u64,
+    pub flushed: u64,
+    pub abandoned: u64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash)]
-#[serde(transparent)]
-pub struct CloseMsg(Result<CloseOk, CloseErr>);
+pub struct CloseMsg {
+    result: Result<CloseOk, CloseErr>,
+    #[serde(default)]
+    stats: CloseStats,
+}
 
 impl CloseMsg {
     pub fn ok(value: CloseOk) -> Self {
-        Self(Ok(value))
+        Self {
+            result: Ok(value),
+            stats: Default::default(),
+        }
     }
 
     pub fn err(err: CloseErr) -> Self {
-        Self(Err(err))
+        Self {
+            result: Err(err),
+            stats: Default::default(),
+        }
+    }
+
+    pub fn with_stats(self, stats: CloseStats) -> Self {
+        Self { stats, ..self }
+    }
+
+    pub fn stats(&self) -> &CloseStats {
+        &self.stats
     }
 }
 
@@ -562,7 +978,7 @@ impl Deref for CloseMsg {
     type Target = Result<CloseOk, CloseErr>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.result
     }
 }
 
@@ -586,3 +1002,24 @@ pub trait PushMsg: Send + 'static {
         self.bulk_push(vec![msg])
     }
 }
+
+// Tells the retry of a batch (the layer pushes the very same msgs again) from a new one,
+// by its length and its first & last msgs, instead of comparing the whole batch.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct BatchId {
+    len: usize,
+    first: (DateTime<Local>, ThreadId),
+    last: (DateTime<Local>, ThreadId),
+}
+
+impl BatchId {
+    pub(crate) fn of(msgs: &[TracingMsg]) -> Option<Self> {
+        let id = |msg: &TracingMsg| (msg.timestamp, msg.thread_id);
+
+        Some(Self {
+            len: msgs.len(),
+            first: id(msgs.first()?),
+            last: id(msgs.last()?),
+        })
+    }
+}
@@ -0,0 +1,358 @@
+use super::{BatchId, CloseErr, CloseErrKind, CloseMsg, CloseTransport, PushMsg, TracingMsg};
+use std::{
+    error,
+    time::{Duration, Instant},
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TeeError<A: error::Error, B: error::Error> {
+    #[error("tee left error: `{0}`")]
+    Left(A),
+    #[error("tee right error: `{0}`")]
+    Right(B),
+    #[error("tee left error: `{0}`, right error: `{1}`")]
+    Both(A, B),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Branch {
+    Left,
+    Right,
+}
+
+// Pushes every message to both transports (concurrently), and fails if either of them fails.
+// A batch that only one branch failed is remembered (by its `BatchId`), so that when the layer retries it,
+// only that branch receives it again, the other one doesn't see duplicates.
+#[derive(Debug, Clone)]
+pub struct Tee<A, B> {
+    left: A,
+    right: B,
+    pending: Option<(Branch, BatchId)>,
+    left_err: Option<String>,
+    right_err: Option<String>,
+}
+
+impl<A, B> Tee<A, B> {
+    pub fn new(left: A, right: B) -> Self {
+        Self {
+            left,
+            right,
+            pending: None,
+            left_err: None,
+            right_err: None,
+        }
+    }
+
+    pub fn left(&self) -> &A {
+        &self.left
+    }
+
+    pub fn right(&self) -> &B {
+        &self.right
+    }
+}
+
+impl<A: PushMsg, B: PushMsg> PushMsg for Tee<A, B> {
+    type Error = TeeError<A::Error, B::Error>;
+
+    async fn bulk_push(&mut self, msgs: Vec<TracingMsg>) -> Result<(), Self::Error> {
+        let batch = BatchId::of(&msgs);
+        let only = match self.pending.take() {
+            Some((branch, pending)) if Some(pending) == batch => Some(branch),
+            _ => None,
+        };
+        let push_left = only != Some(Branch::Right);
+        let push_right = only != Some(Branch::Left);
+        let (left, right) = (&mut self.left, &mut self.right);
+        let (res_left, res_right) = futures::join!(
+            async {
+                match push_left {
+                    true => left.bulk_push(msgs.clone()).await,
+                    false => Ok(()),
+                }
+            },
+            async {
+                match push_right {
+                    true => right.bulk_push(msgs.clone()).await,
+                    false => Ok(()),
+                }
+            },
+        );
+
+        if push_left {
+            self.left_err = res_left.as_ref().err().map(ToString::to_string);
+        }
+
+        if push_right {
+            self.right_err = res_right.as_ref().err().map(ToString::to_string);
+        }
+
+        match (res_left, res_right) {
+            (Ok(()), Ok(())) => Ok(()),
+            (Err(left), Ok(())) => {
+                self.pending = batch.map(|batch| (Branch::Left, batch));
+                Err(TeeError::Left(left))
+            }
+            (Ok(()), Err(right)) => {
+                self.pending = batch.map(|batch| (Branch::Right, batch));
+                Err(TeeError::Right(right))
+            }
+            (Err(left), Err(right)) => Err(TeeError::Both(left, right)),
+        }
+    }
+}
+
+// When the layer shuts down on a push error, the failed branch is closed with its own error,
+// and the other one with `CloseErrKind::Other`.
+impl<A: CloseTransport, B: CloseTransport> CloseTransport for Tee<A, B> {
+    async fn close_transport(&mut self, msg: Option<CloseMsg>) {
+        let left_msg = msg.as_ref().map(|msg| branch_close(msg, &self.left_err));
+        let right_msg = msg.as_ref().map(|msg| branch_close(msg, &self.right_err));
+
+        futures::join!(
+            self.left.close_transport(left_msg),
+            self.right.close_transport(right_msg),
+        );
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum FallbackError<P: error::Error, S: error::Error> {
+    // The primary failed recently, and is not retried until `recheck_primary_after`.
+    #[error("fallback secondary error: `{0}`")]
+    Secondary(S),
+    #[error("fallback primary error: `{primary}`, secondary error: `{secondary}`")]
+    Both { primary: P, secondary: S },
+}
+
+// Pushes to the primary transport, and to the secondary one when the primary fails,
+// the push fails only if both of them fail.
+// After a failure, the primary is skipped until `recheck_primary_after` (30s by default) elapses.
+#[derive(Debug, Clone)]
+pub struct Fallback<P, S> {
+    primary: P,
+    secondary: S,
+    recheck_after: Duration,
+    failed_at: Option<Instant>,
+    primary_err: Option<String>,
+}
+
+impl<P, S> Fallback<P, S> {
+    pub fn new(primary: P, secondary: S) -> Self {
+        Self {
+            primary,
+            secondary,
+            recheck_after: Duration::from_secs(30),
+            failed_at: None,
+            primary_err: None,
+        }
+    }
+
+    pub fn recheck_primary_after(self, recheck_after: Duration) -> Self {
+        Self {
+            recheck_after,
+            ..self
+        }
+    }
+
+    pub fn primary(&self) -> &P {
+        &self.primary
+    }
+
+    pub fn secondary(&self) -> &S {
+        &self.secondary
+    }
+
+    pub fn is_failed_over(&self) -> bool {
+        self.failed_at.is_some()
+    }
+}
+
+impl<P: PushMsg, S: PushMsg> PushMsg for Fallback<P, S> {
+    type Error = FallbackError<P::Error, S::Error>;
+
+    async fn bulk_push(&mut self, msgs: Vec<TracingMsg>) -> Result<(), Self::Error> {
+        let recheck = !matches!(
+            self.failed_at,
+            Some(failed_at) if failed_at.elapsed() < self.recheck_after
+        );
+        let mut primary_err = None;
+
+        if recheck {
+            match self.primary.bulk_push(msgs.clone()).await {
+                Ok(()) => {
+                    self.failed_at = None;
+                    self.primary_err = None;
+                    return Ok(());
+                }
+                Err(err) => {
+                    self.failed_at = Some(Instant::now());
+                    self.primary_err = Some(err.to_string());
+                    primary_err = Some(err);
+                }
+            }
+        }
+
+        self.secondary
+            .bulk_push(msgs)
+            .await
+            .map_err(|secondary| match primary_err {
+                None => FallbackError::Secondary(secondary),
+                Some(primary) => FallbackError::Both { primary, secondary },
+            })
+    }
+}
+
+// A primary that is failed over at shutdown is closed with its last error (as `CloseErrKind::Other`
+// unless the layer shut down on a push error), the secondary with the layer's close message.
+impl<P: CloseTransport, S: CloseTransport> CloseTransport for Fallback<P, S> {
+    async fn close_transport(&mut self, msg: Option<CloseMsg>) {
+        let primary_msg = msg.as_ref().map(|msg| match (&**msg, &self.primary_err) {
+            (Ok(_), Some(err)) => {
+                CloseMsg::err(CloseErr::new(CloseErrKind::Other, err)).with_stats(*msg.stats())
+            }
+            _ => branch_close(msg, &self.primary_err),
+        });
+
+        futures::join!(
+            self.primary.close_transport(primary_msg),
+            self.secondary.close_transport(msg),
+        );
+    }
+}
+
+fn branch_close(msg: &CloseMsg, branch_err: &Option<String>) -> CloseMsg {
+    let Err(err) = &**msg else {
+        return msg.clone();
+    };
+
+    if !matches!(
+        err.kind,
+        CloseErrKind::PushMsgErr | CloseErrKind::BulkPushErr
+    ) {
+        return msg.clone();
+    }
+
+    let err = match branch_err {
+        Some(branch_err) => CloseErr::new(err.kind, branch_err),
+        None => CloseErr::new(CloseErrKind::Other, &err.display),
+    };
+
+    CloseMsg::err(err).with_stats(*msg.stats())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing_msg::{MsgBody, Payload, SpanId, Value};
+    use indexmap::IndexMap;
+    use std::{
+        io,
+        num::NonZeroU64,
+        sync::{Arc, Mutex},
+    };
+
+    // Fails the next `fails` pushes, and keeps the batches that went through.
+    #[derive(Clone, Default)]
+    struct Flaky(Arc<Mutex<(usize, Vec<Vec<TracingMsg>>)>>);
+
+    impl Flaky {
+        fn fail(&self, fails: usize) {
+            self.0.lock().unwrap().0 = fails;
+        }
+
+        fn pushed(&self) -> Vec<Vec<TracingMsg>> {
+            self.0.lock().unwrap().1.clone()
+        }
+    }
+
+    impl PushMsg for Flaky {
+        type Error = io::Error;
+
+        async fn bulk_push(&mut self, msgs: Vec<TracingMsg>) -> Result<(), Self::Error> {
+            let mut flaky = self.0.lock().unwrap();
+
+            if flaky.0 > 0 {
+                flaky.0 -= 1;
+                return Err(io::Error::other("flaky"));
+            }
+
+            flaky.1.push(msgs);
+            Ok(())
+        }
+    }
+
+    // NaN never equals itself, so the batches can't be compared as a whole.
+    fn batch(span: u64) -> Vec<TracingMsg> {
+        let span_id = SpanId(NonZeroU64::new(span).unwrap());
+        let payload = Payload(IndexMap::from([(
+            "ratio".into(),
+            vec![Value::F64(f64::NAN)],
+        )]));
+
+        vec![
+            MsgBody::OnEnter { span_id }.into(),
+            MsgBody::OnRecord { span_id, payload }.into(),
+        ]
+    }
+
+    #[tokio::test]
+    async fn tee_retries_only_the_failed_branch() {
+        let (left, right) = (Flaky::default(), Flaky::default());
+        let mut tee = Tee::new(left.clone(), right.clone());
+        let first = batch(1);
+
+        right.fail(1);
+        assert!(matches!(
+            tee.bulk_push(first.clone()).await,
+            Err(TeeError::Right(_))
+        ));
+        tee.bulk_push(first.clone()).await.unwrap();
+        assert_eq!(left.pushed().len(), 1);
+        assert_eq!(right.pushed().len(), 1);
+
+        // A new batch goes to both branches, even after a failure.
+        left.fail(1);
+        assert!(matches!(
+            tee.bulk_push(batch(2)).await,
+            Err(TeeError::Left(_))
+        ));
+        tee.bulk_push(batch(3)).await.unwrap();
+        assert_eq!(left.pushed().len(), 2);
+        assert_eq!(right.pushed().len(), 3);
+
+        left.fail(1);
+        right.fail(1);
+        assert!(matches!(
+            tee.bulk_push(first).await,
+            Err(TeeError::Both(..))
+        ));
+    }
+
+    #[tokio::test]
+    async fn fallback_skips_the_failed_primary_until_rechecked() {
+        let (primary, secondary) = (Flaky::default(), Flaky::default());
+        let mut fallback = Fallback::new(primary.clone(), secondary.clone())
+            .recheck_primary_after(Duration::from_millis(50));
+
+        primary.fail(1);
+        fallback.bulk_push(batch(1)).await.unwrap();
+        assert!(fallback.is_failed_over());
+        fallback.bulk_push(batch(2)).await.unwrap();
+        assert_eq!(primary.pushed().len(), 0);
+        assert_eq!(secondary.pushed().len(), 2);
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        fallback.bulk_push(batch(3)).await.unwrap();
+        assert!(!fallback.is_failed_over());
+        assert_eq!(primary.pushed().len(), 1);
+
+        primary.fail(1);
+        secondary.fail(1);
+        assert!(matches!(
+            fallback.bulk_push(batch(4)).await,
+            Err(FallbackError::Both { .. })
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+use std::{fmt, sync::Arc};
+use thiserror::Error;
+use tracing_core::Subscriber;
+use tracing_subscriber::{
+    filter::{EnvFilter, LevelFilter, ParseError},
+    layer::Filter,
+    reload,
+};
+
+pub type MsgFilter<S> = Box<dyn Filter<S> + Send + Sync + 'static>;
+
+#[derive(Error, Debug)]
+pub enum FilterError {
+    #[error("filter directives parse error: `{0}`")]
+    Parse(#[from] ParseError),
+    #[error("filter reload error: `{0}`")]
+    Reload(#[from] reload::Error),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub(crate) enum FilterSpec {
+    Level(LevelFilter),
+    Directives(String),
+}
+
+impl FilterSpec {
+    pub(crate) fn directives(directives: &str) -> Result<Self, ParseError> {
+        EnvFilter::try_new(directives)?;
+        Ok(Self::Directives(directives.into()))
+    }
+
+    pub(crate) fn into_filter<S: Subscriber>(self) -> MsgFilter<S> {
+        match self {
+            Self::Level(level_filter) => Box::new(level_filter),
+            Self::Directives(directives) => Box::new(EnvFilter::new(directives)),
+        }
+    }
+}
+
+type Reload = dyn Fn(FilterSpec) -> Result<(), reload::Error> + Send + Sync;
+
+#[derive(Clone)]
+pub struct FilterHandle(Arc<Reload>);
+
+impl FilterHandle {
+    pub(crate) fn new<S: Subscriber>(handle: reload::Handle<MsgFilter<S>, S>) -> Self {
+        Self(Arc::new(move |spec| handle.reload(spec.into_filter())))
+    }
+
+    pub fn set_level_filter(&self, level_filter: LevelFilter) -> Result<(), FilterError> {
+        Ok((self.0)(FilterSpec::Level(level_filter))?)
+    }
+
+    // e.g.: "hyper=warn,our_app::db=trace,[request{user_id=42}]=trace"
+    pub fn set_directives(&self, directives: &str) -> Result<(), FilterError> {
+        Ok((self.0)(FilterSpec::directives(directives)?)?)
+    }
+}
+
+impl fmt::Debug for FilterHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FilterHandle")
+            .field(&"reload::Handle")
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing_msg::in_memory::capture;
+
+    #[tokio::test]
+    async fn directives_can_be_reloaded() {
+        let transport = capture(
+            |builder| builder.set_directives("warn,chatty=debug").unwrap(),
+            |handle| async move {
+                tracing::info!("info 1");
+                tracing::debug!(target: "chatty", "chatty 1");
+                tracing::trace!(target: "chatty", "chatty trace 1");
+
+                handle.set_directives("info,chatty=off").unwrap();
+                tracing::info!("info 2");
+                tracing::warn!(target: "chatty", "chatty 2");
+
+                handle.set_level_filter(LevelFilter::TRACE).unwrap();
+                tracing::trace!(target: "chatty", "chatty trace 2");
+
+                assert!(matches!(
+                    handle.set_directives("chatty=loud"),
+                    Err(FilterError::Parse(_))
+                ));
+                tracing::trace!("trace 3");
+            },
+        )
+        .await;
+
+        let messages: Vec<_> = transport
+            .events()
+            .into_iter()
+            .map(|event| event.message)
+            .collect();
+        assert_eq!(
+            messages,
+            ["chatty 1", "info 2", "chatty trace 2", "trace 3"]
+        );
+    }
+}
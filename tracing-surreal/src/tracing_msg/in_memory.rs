@@ -0,0 +1,391 @@
+#[cfg(test)]
+use super::{filter::FilterHandle, layer::MsgLayerBuiler, GraceType, TracingLayerDefault};
+use super::{
+    CallsiteId, CloseMsg, CloseTransport, Level, MsgBody, Parent, Payload, PushMsg, SpanId,
+    TracingMsg, Value,
+};
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+#[derive(Default, Debug)]
+struct Captured {
+    msgs: Vec<TracingMsg>,
+    close_msg: Option<CloseMsg>,
+}
+
+// Records everything in memory, for testing instrumented code without a running SurrealDB.
+// Cheap to clone, all the clones share the same buffer, so keep one to inspect what was captured:
+// ```ignore
+// let transport = InMemoryTransport::new();
+// let (layer, routine) = transport.clone().tracing_layer_default().build();
+// ...
+// routine.graceful_shutdown().await?;
+// transport.assert_event("done").assert_in_span("request");
+// ```
+#[derive(Default, Clone, Debug)]
+pub struct InMemoryTransport(Arc<Mutex<Captured>>);
+
+impl InMemoryTransport {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Captured> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn msgs(&self) -> Vec<TracingMsg> {
+        self.lock().msgs.clone()
+    }
+
+    // Only set with `close_transport_on_shutdown()`.
+    pub fn close_msg(&self) -> Option<CloseMsg> {
+        self.lock().close_msg.clone()
+    }
+
+    pub fn clear(&self) {
+        let mut captured = self.lock();
+        captured.msgs.clear();
+        captured.close_msg = None;
+    }
+
+    pub fn spans(&self) -> Vec<CapturedSpan> {
+        self.captured().0
+    }
+
+    pub fn events(&self) -> Vec<CapturedEvent> {
+        self.captured().1
+    }
+
+    pub fn events_with_message(&self, message: &str) -> Vec<CapturedEvent> {
+        self.events_where(|event| event.message == message)
+    }
+
+    pub fn events_at_level(&self, level: Level) -> Vec<CapturedEvent> {
+        self.events_where(|event| event.level == level)
+    }
+
+    pub fn events_with_field(&self, name: &str, value: &Value) -> Vec<CapturedEvent> {
+        self.events_where(|event| event.has_field(name, value))
+    }
+
+    pub fn events_where(&self, f: impl Fn(&CapturedEvent) -> bool) -> Vec<CapturedEvent> {
+        self.events().into_iter().filter(f).collect()
+    }
+
+    pub fn span(&self, name: &str) -> Option<CapturedSpan> {
+        self.spans().into_iter().find(|span| span.name == name)
+    }
+
+    // Panics (listing the captured messages) if no event has the message, returns the first one.
+    #[track_caller]
+    pub fn assert_event(&self, message: &str) -> CapturedEvent {
+        let events = self.events();
+
+        match events.iter().find(|event| event.message == message) {
+            Some(event) => event.clone(),
+            None => panic!(
+                "no event with message `{message}`, captured: {:?}",
+                events
+                    .iter()
+                    .map(|event| &event.message)
+                    .collect::<Vec<_>>()
+            ),
+        }
+    }
+
+    #[track_caller]
+    pub fn assert_no_event(&self, message: &str) {
+        if let Some(event) = self.events_with_message(message).first() {
+            panic!("unexpected event: {event:?}");
+        }
+    }
+
+    // Panics if no span named `child` is nested (at any depth) in a span named `ancestor`.
+    #[track_caller]
+    pub fn assert_span_nested(&self, child: &str, ancestor: &str) {
+        let spans = self.spans();
+        let children = spans.iter().filter(|span| span.name == child);
+
+        if !children.clone().any(|span| span.is_in_span(ancestor)) {
+            panic!(
+                "no span `{child}` nested in `{ancestor}`, captured: {:?}",
+                children.map(|span| &span.scope).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    // Resolves span names (from the callsites) & scopes, following the order of the messages,
+    // since span ids may be reused once closed.
+    fn captured(&self) -> (Vec<CapturedSpan>, Vec<CapturedEvent>) {
+        let captured = self.lock();
+        let mut names = HashMap::<CallsiteId, String>::new();
+        let mut scopes = HashMap::<SpanId, Vec<String>>::new();
+        let mut spans = Vec::new();
+        let mut events = Vec::new();
+
+        let scope_of = |scopes: &HashMap<SpanId, Vec<String>>, parent: &Parent| match parent {
+            Parent::Explicit(span_id) => scopes.get(span_id).cloned().unwrap_or_default(),
+            _ => Vec::new(),
+        };
+
+        for msg in &captured.msgs {
+            match &msg.body {
+                MsgBody::OnRegisterCallsite {
+                    callsite_id,
+                    callsite,
+                } => {
+                    names.insert(*callsite_id, callsite.name.clone());
+                }
+                MsgBody::OnNewSpan {
+                    span_id,
+                    level,
+                    callsite_id,
+                    parent,
+                    payload,
+                    ..
+                } => {
+                    let name = names.get(callsite_id).cloned().unwrap_or_default();
+                    let scope = scope_of(&scopes, parent);
+                    let mut own_scope = scope.clone();
+                    own_scope.push(name.clone());
+                    scopes.insert(*span_id, own_scope);
+
+                    spans.push(CapturedSpan {
+                        span_id: *span_id,
+                        name,
+                        level: *level,
+                        payload: payload.clone(),
+                        scope,
+                    });
+                }
+                MsgBody::OnRecord { span_id, payload } => {
+                    let span = spans.iter_mut().rev().find(|span| span.span_id == *span_id);
+
+                    if let Some(span) = span {
+                        for (key, values) in payload.iter() {
+                            for value in values {
+                                span.payload.record(key, value.clone());
+                            }
+                        }
+                    }
+                }
+                MsgBody::OnEvent {
+                    message,
+                    level,
+                    parent,
+                    payload,
+                    ..
+                } => events.push(CapturedEvent {
+                    message: message.clone(),
+                    level: *level,
+                    payload: payload.clone(),
+                    scope: scope_of(&scopes, parent),
+                }),
+                _ => (),
+            }
+        }
+
+        (spans, events)
+    }
+}
+
+impl PushMsg for InMemoryTransport {
+    type Error = Infallible;
+
+    async fn bulk_push(&mut self, msgs: Vec<TracingMsg>) -> Result<(), Self::Error> {
+        self.lock().msgs.extend(msgs);
+        Ok(())
+    }
+}
+
+impl CloseTransport for InMemoryTransport {
+    async fn close_transport(&mut self, msg: Option<CloseMsg>) {
+        self.lock().close_msg = msg;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CapturedSpan {
+    pub span_id: SpanId,
+    pub name: String,
+    pub level: Level,
+    // Including the values recorded later
+    pub payload: Payload,
+    // Names of the enclosing spans, from the root down to the direct parent
+    pub scope: Vec<String>,
+}
+
+impl CapturedSpan {
+    pub fn field(&self, name: &str) -> Option<&Value> {
+        first_value(&self.payload, name)
+    }
+
+    pub fn is_in_span(&self, name: &str) -> bool {
+        self.scope.iter().any(|span| span == name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CapturedEvent {
+    pub message: String,
+    pub level: Level,
+    pub payload: Payload,
+    // Names of the enclosing spans, from the root down to the span in which the event occurred
+    pub scope: Vec<String>,
+}
+
+impl CapturedEvent {
+    pub fn field(&self, name: &str) -> Option<&Value> {
+        first_value(&self.payload, name)
+    }
+
+    pub fn has_field(&self, name: &str, value: &Value) -> bool {
+        self.payload
+            .get(name)
+            .is_some_and(|values| values.contains(value))
+    }
+
+    pub fn is_in_span(&self, name: &str) -> bool {
+        self.scope.iter().any(|span| span == name)
+    }
+
+    #[track_caller]
+    pub fn assert_level(&self, level: Level) -> &Self {
+        assert_eq!(self.level, level, "level of event `{}`", self.message);
+        self
+    }
+
+    #[track_caller]
+    pub fn assert_field(&self, name: &str, value: &Value) -> &Self {
+        assert!(
+            self.has_field(name, value),
+            "event `{}` has no field `{name}` = {value:?}, payload: {:?}",
+            self.message,
+            self.payload
+        );
+        self
+    }
+
+    #[track_caller]
+    pub fn assert_in_span(&self, name: &str) -> &Self {
+        assert!(
+            self.is_in_span(name),
+            "event `{}` is not in span `{name}`, scope: {:?}",
+            self.message,
+            self.scope
+        );
+        self
+    }
+}
+
+fn first_value<'a>(payload: &'a Payload, name: &str) -> Option<&'a Value> {
+    payload.get(name).and_then(|values| values.first())
+}
+
+// Runs `body` under a layer on `transport`, built by `build` from `tracing_layer_default()`
+// (with the ctrl-c shutdown disabled), then shuts the routine down gracefully.
+#[cfg(test)]
+pub(crate) async fn run_layer<T, B, F>(
+    transport: &T,
+    build: B,
+    body: impl FnOnce(FilterHandle) -> F,
+) -> GraceType
+where
+    T: CloseTransport + PushMsg + Clone + std::fmt::Debug,
+    B: FnOnce(MsgLayerBuiler<T>) -> MsgLayerBuiler<T>,
+    F: std::future::Future<Output = ()>,
+{
+    use tracing_subscriber::layer::SubscriberExt;
+
+    let builder = transport.tracing_layer_default().disable_ctrlc_shutdown();
+    let (layer, routine) = build(builder).build();
+    let subscriber = tracing_subscriber::registry().with(layer);
+
+    // Kept until the shutdown, since the routine stops with `LayerDropped` once it's dropped.
+    let guard = tracing::subscriber::set_default(subscriber);
+    body(routine.filter_handle().clone()).await;
+    let grace = routine.graceful_shutdown().await.unwrap().unwrap();
+    drop(guard);
+
+    grace
+}
+
+// `run_layer` on a new `InMemoryTransport`, which holds the captured msgs.
+#[cfg(test)]
+pub(crate) async fn capture<B, F>(
+    build: B,
+    body: impl FnOnce(FilterHandle) -> F,
+) -> InMemoryTransport
+where
+    B: FnOnce(MsgLayerBuiler<InMemoryTransport>) -> MsgLayerBuiler<InMemoryTransport>,
+    F: std::future::Future<Output = ()>,
+{
+    let transport = InMemoryTransport::new();
+    run_layer(&transport, build, body).await;
+    transport
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn captures_events_and_spans() {
+        let transport = InMemoryTransport::new();
+        let build = |builder: MsgLayerBuiler<_>| builder.close_transport_on_shutdown();
+        let grace = run_layer(&transport, build, |_| async {
+            let outer = tracing::info_span!("outer", user = "alice").entered();
+            let inner = tracing::debug_span!("inner", attempt = tracing::field::Empty);
+            inner.record("attempt", 2);
+            inner.in_scope(|| tracing::warn!(code = 42, "inside"));
+            drop(outer);
+            tracing::error!("outside");
+        })
+        .await;
+        assert_eq!(transport.close_msg().as_deref(), Some(&Ok(grace.into())));
+
+        transport
+            .assert_event("inside")
+            .assert_level(Level::Warn)
+            .assert_field("code", &Value::I64(42))
+            .assert_in_span("outer")
+            .assert_in_span("inner");
+        assert!(transport.assert_event("outside").scope.is_empty());
+        transport.assert_no_event("missing");
+        transport.assert_span_nested("inner", "outer");
+
+        assert_eq!(transport.events_at_level(Level::Error).len(), 1);
+        assert_eq!(
+            transport.events_with_field("code", &Value::I64(42))[0].message,
+            "inside"
+        );
+
+        let outer = transport.span("outer").unwrap();
+        assert_eq!(outer.field("user"), Some(&Value::String("alice".into())));
+        assert_eq!(
+            transport.span("inner").unwrap().field("attempt"),
+            Some(&Value::I64(2))
+        );
+
+        transport.clear();
+        assert!(transport.msgs().is_empty());
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "no span `outer` nested in `inner`")]
+    async fn span_nesting_is_checked() {
+        let transport = capture(
+            |builder| builder,
+            |_| async {
+                tracing::info_span!("outer")
+                    .in_scope(|| tracing::info_span!("inner").in_scope(|| ()));
+            },
+        )
+        .await;
+        transport.assert_span_nested("outer", "inner");
+    }
+}
@@ -1,64 +1,327 @@
 use super::{
-    CloseErr, CloseErrKind, CloseMsg, CloseTransport, GraceType, MsgBody, PushMsg, TracingMsg,
+    filter::{FilterHandle, FilterSpec, MsgFilter},
+    limit::{json_len, SizeLimits},
+    queue::{msg_queue, MsgReceiver, MsgSender, Routine},
+    redact::Redaction,
+    sample::{HeadSampled, RateLimit, SampledOut, Sampler, SamplingConfig},
+    spool::{Spool, SpoolConfig},
+    trace_context::TraceContext,
+    Callsite, CallsiteId, CloseErr, CloseErrKind, CloseMsg, CloseStats, CloseTransport,
+    DropCounters, GraceType, MsgBody, OverflowPolicy, Parent, PushMsg, Sampling, SpanFrame,
+    SpanTiming, TracingMsg,
 };
+use regex::Regex;
 use std::{
-    fmt::Debug,
+    collections::{HashMap, HashSet},
+    fmt::{self, Debug},
     future::Future,
-    io,
+    io, mem,
+    num::{NonZeroU32, NonZeroUsize},
+    path::PathBuf,
     pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
     task::{self, Poll},
+    time::{self, Duration},
 };
 use thiserror::Error;
 use tokio::{
     signal::ctrl_c,
-    sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
+    sync::mpsc::UnboundedSender,
     task::{JoinError, JoinHandle},
+    time::{interval, sleep, sleep_until, timeout_at, Instant, MissedTickBehavior},
 };
 use tokio_util::sync::CancellationToken;
 use tracing_core::{
     span::{self, Attributes, Record},
-    Event, Subscriber,
+    subscriber::Interest,
+    Event, Metadata, Subscriber,
+};
+use tracing_subscriber::{
+    filter::{Filtered, ParseError},
+    layer::{Context, Filter},
+    registry::{LookupSpan, SpanRef},
+    reload, Layer,
 };
-use tracing_subscriber::{filter::Filtered, layer::Context, Layer};
 
 pub use tracing_subscriber::filter::LevelFilter;
 
+const FLUSH_CHUNK_LEN: usize = 1024;
+
+// Kept in the span extensions, the same way `fmt::Layer` does with `FmtSpan::CLOSE`.
+#[derive(Debug, Copy, Clone)]
+struct Timing {
+    created: time::Instant,
+    last: time::Instant,
+    busy: Duration,
+    idle: Duration,
+    enters: u64,
+}
+
+impl Timing {
+    fn new() -> Self {
+        let now = time::Instant::now();
+
+        Self {
+            created: now,
+            last: now,
+            busy: Duration::ZERO,
+            idle: Duration::ZERO,
+            enters: 0,
+        }
+    }
+
+    fn enter(&mut self) {
+        let now = time::Instant::now();
+        self.idle += now - self.last;
+        self.last = now;
+        self.enters += 1;
+    }
+
+    fn exit(&mut self) {
+        let now = time::Instant::now();
+        self.busy += now - self.last;
+        self.last = now;
+    }
+
+    fn close(mut self) -> SpanTiming {
+        let now = time::Instant::now();
+        self.idle += now - self.last;
+        let nanos = |duration: Duration| duration.as_nanos().try_into().unwrap_or(u64::MAX);
+
+        SpanTiming {
+            busy_ns: nanos(self.busy),
+            idle_ns: nanos(self.idle),
+            total_ns: nanos(now - self.created),
+            enters: self.enters,
+        }
+    }
+}
+
+fn with_timing<S, R>(
+    ctx: &Context<'_, S>,
+    id: &span::Id,
+    f: impl FnOnce(&mut Timing) -> R,
+) -> Option<R>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    ctx.span(id)?.extensions_mut().get_mut::<Timing>().map(f)
+}
+
+// Filled by `register_callsite`, which runs again for every callsite whenever the interest
+// cache is rebuilt (e.g. by a `FilterHandle` reload), so registering is idempotent. The routine
+// sends a callsite along with the first message using it, see `RoutineState::sent_callsites`.
+#[derive(Clone, Default, Debug)]
+struct Callsites(Arc<Mutex<HashMap<CallsiteId, Callsite>>>);
+
+impl Callsites {
+    fn register(&self, metadata: &Metadata<'_>) {
+        let mut callsites = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        callsites
+            .entry(metadata.into())
+            .or_insert_with(|| metadata.into());
+    }
+
+    fn get(&self, callsite_id: &CallsiteId) -> Option<Callsite> {
+        let callsites = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        callsites.get(callsite_id).cloned()
+    }
+}
+
 #[derive(Clone, Debug)]
-pub struct MsgLayer(UnboundedSender<TracingMsg>);
+pub struct MsgLayer {
+    send: MsgSender,
+    callsites: Callsites,
+    span_stack: bool,
+    trace_context: bool,
+    sampler: Option<Arc<Sampler>>,
+    redaction: Option<Arc<Redaction>>,
+    size_limits: Option<SizeLimits>,
+}
 
-impl<S: Subscriber> Layer<S> for MsgLayer {
-    fn on_new_span(&self, attrs: &Attributes<'_>, id: &span::Id, _ctx: Context<'_, S>) {
-        self.0.send(MsgBody::on_new_span(attrs, id).into()).ok();
+impl MsgLayer {
+    fn send(&self, body: MsgBody) {
+        self.send_sampled(body, None);
     }
 
-    fn on_record(&self, span: &span::Id, values: &Record<'_>, _ctx: Context<'_, S>) {
-        self.0.send(MsgBody::on_record(span, values).into()).ok();
+    fn send_sampled(&self, mut body: MsgBody, sampling: Option<Sampling>) {
+        if let Some(redaction) = &self.redaction {
+            redaction.redact(&mut body);
+        }
+
+        let mut msg = TracingMsg::from(body);
+        msg.sampling = sampling;
+
+        if let Some(size_limits) = &self.size_limits {
+            size_limits.apply(&mut msg);
+        }
+
+        self.send.send(msg);
+    }
+
+    // The head decision (`None` without sampler), and `None` if dropped by the sampler,
+    // otherwise the `Sampling` to attach (if enabled),
+    // `remote` is the sampled flag of an incoming `traceparent` (only used for local roots).
+    fn sample<S>(
+        &self,
+        metadata: &Metadata<'_>,
+        parent: Option<&SpanRef<'_, S>>,
+        remote: Option<bool>,
+    ) -> (Option<bool>, Option<Option<Sampling>>)
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let Some(sampler) = &self.sampler else {
+            return (None, Some(None));
+        };
+
+        let parent = parent.and_then(|span| span.extensions().get::<HeadSampled>().map(|h| h.0));
+        let head = sampler.head(parent.or(remote));
+
+        (Some(head), sampler.sample(metadata, head).map(Some))
     }
 
-    fn on_follows_from(&self, span: &span::Id, follows: &span::Id, _ctx: Context<'_, S>) {
-        self.0
-            .send(MsgBody::on_follows_from(span, follows).into())
-            .ok();
+    fn trace_context_of<S>(&self, span: Option<&SpanRef<'_, S>>) -> Option<TraceContext>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        span.filter(|_| self.trace_context)
+            .and_then(|span| span.extensions().get::<TraceContext>().copied())
     }
 
-    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
-        self.0.send(MsgBody::on_event(event).into()).ok();
+    fn sampled_out<S>(&self, ctx: &Context<'_, S>, id: &span::Id) -> bool
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        self.sampler.is_some()
+            && ctx
+                .span(id)
+                .is_some_and(|span| span.extensions().get::<SampledOut>().is_some())
+    }
+
+    // `parent` is the span the new span or event is (going to be) nested in.
+    fn span_context<S>(&self, parent: Option<SpanRef<'_, S>>) -> (Parent, Option<Vec<SpanFrame>>)
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let stack = self.span_stack.then(|| {
+            parent
+                .iter()
+                .flat_map(|span| span.scope().from_root())
+                .map(|span| SpanFrame {
+                    span_id: span.id().into(),
+                    name: span.name().into(),
+                })
+                .collect()
+        });
+        let parent = parent.map_or(Parent::Root, |span| Parent::Explicit(span.id().into()));
+
+        (parent, stack)
     }
+}
 
-    fn on_enter(&self, id: &span::Id, _ctx: Context<'_, S>) {
-        self.0.send(MsgBody::on_enter(id).into()).ok();
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for MsgLayer {
+    fn register_callsite(&self, metadata: &'static Metadata<'static>) -> Interest {
+        self.callsites.register(metadata);
+        Interest::always()
     }
 
-    fn on_exit(&self, id: &span::Id, _ctx: Context<'_, S>) {
-        self.0.send(MsgBody::on_exit(id).into()).ok();
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id);
+        let parent = span.as_ref().and_then(|span| span.parent());
+        // An incoming `traceparent` takes precedence over the local parent.
+        let remote = self
+            .trace_context
+            .then(|| TraceContext::from_attrs(attrs))
+            .flatten();
+        let (head, sampling) = self.sample(
+            attrs.metadata(),
+            parent.as_ref(),
+            remote.map(|remote| remote.sampled),
+        );
+        // Also kept for sampled out spans, so that they can still be propagated.
+        let sampled = head.unwrap_or(true);
+        let trace = self.trace_context.then(|| {
+            match remote.or_else(|| self.trace_context_of(parent.as_ref())) {
+                None => TraceContext::root(sampled),
+                Some(parent) => parent.child(sampled),
+            }
+        });
+
+        if let Some(span) = &span {
+            let mut extensions = span.extensions_mut();
+
+            match sampling {
+                None => extensions.insert(SampledOut),
+                Some(_) => extensions.insert(Timing::new()),
+            }
+
+            if let Some(head) = head {
+                extensions.insert(HeadSampled(head));
+            }
+
+            if let Some(trace) = trace {
+                extensions.insert(trace);
+            }
+        }
+
+        if let Some(sampling) = sampling {
+            let (parent, stack) = self.span_context(parent);
+            let body = MsgBody::on_new_span(attrs, id)
+                .with_span_context(parent, stack)
+                .with_trace_context(trace);
+            self.send_sampled(body, sampling);
+        }
     }
 
-    fn on_close(&self, id: span::Id, _ctx: Context<'_, S>) {
-        self.0.send(MsgBody::on_close(id).into()).ok();
+    fn on_record(&self, span: &span::Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        if !self.sampled_out(&ctx, span) {
+            self.send(MsgBody::on_record(span, values));
+        }
+    }
+
+    fn on_follows_from(&self, span: &span::Id, follows: &span::Id, ctx: Context<'_, S>) {
+        if !self.sampled_out(&ctx, span) {
+            self.send(MsgBody::on_follows_from(span, follows));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let parent = ctx.event_span(event);
+
+        if let (_, Some(sampling)) = self.sample(event.metadata(), parent.as_ref(), None) {
+            let trace = self.trace_context_of(parent.as_ref());
+            let (parent, stack) = self.span_context(parent);
+            let body = MsgBody::on_event(event)
+                .with_span_context(parent, stack)
+                .with_trace_context(trace);
+            self.send_sampled(body, sampling);
+        }
+    }
+
+    fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if !self.sampled_out(&ctx, id) {
+            with_timing(&ctx, id, Timing::enter);
+            self.send(MsgBody::on_enter(id));
+        }
+    }
+
+    fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if !self.sampled_out(&ctx, id) {
+            with_timing(&ctx, id, Timing::exit);
+            self.send(MsgBody::on_exit(id));
+        }
+    }
+
+    fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        if !self.sampled_out(&ctx, &id) {
+            let timing = with_timing(&ctx, &id, |timing| timing.close());
+            self.send(MsgBody::on_close(id, timing));
+        }
     }
 
     fn on_id_change(&self, old: &span::Id, new: &span::Id, _ctx: Context<'_, S>) {
-        self.0.send(MsgBody::on_id_change(old, new).into()).ok();
+        self.send(MsgBody::on_id_change(old, new));
     }
 }
 
@@ -72,8 +335,6 @@ pub enum LayerError<T: PushMsg> {
     PushMsgErr(T::Error),
     #[error("bulk_push error: `{0}`")]
     BulkPushErr(T::Error),
-    #[error("msg buffer full")]
-    BufferFull,
 }
 
 impl<T: PushMsg> From<&LayerError<T>> for CloseErr {
@@ -83,7 +344,6 @@ impl<T: PushMsg> From<&LayerError<T>> for CloseErr {
             LayerError::LayerDropped => CloseErrKind::LayerDropped,
             LayerError::PushMsgErr(_) => CloseErrKind::PushMsgErr,
             LayerError::BulkPushErr(_) => CloseErrKind::BulkPushErr,
-            LayerError::BufferFull => CloseErrKind::BufferFull,
         };
 
         Self::new(kind, err)
@@ -105,10 +365,20 @@ impl<T: PushMsg> From<&RoutineOutput<T>> for CloseMsg {
 #[derive(Debug)]
 pub struct MsgRoutine<T: PushMsg> {
     shutdown_trigger: CancellationToken,
+    drop_counters: DropCounters,
+    filter_handle: FilterHandle,
     routine: JoinHandle<RoutineOutput<T>>,
 }
 
 impl<T: PushMsg> MsgRoutine<T> {
+    pub fn filter_handle(&self) -> &FilterHandle {
+        &self.filter_handle
+    }
+
+    pub fn drop_counters(&self) -> &DropCounters {
+        &self.drop_counters
+    }
+
     pub fn trigger_graceful_shutdown(&self) {
         self.shutdown_trigger.cancel();
     }
@@ -127,12 +397,172 @@ impl<T: PushMsg> Future for MsgRoutine<T> {
     }
 }
 
-pub type FilteredLayer<S> = Filtered<MsgLayer, LevelFilter, S>;
+pub type FilteredLayer<S> = Filtered<MsgLayer, reload::Layer<MsgFilter<S>, S>, S>;
+
+pub trait DeadLetterSink: Send + Sync + 'static {
+    fn dead_letter(&self, msgs: Vec<TracingMsg>, err: CloseErr);
+}
+
+impl<F> DeadLetterSink for F
+where
+    F: Fn(Vec<TracingMsg>, CloseErr) + Send + Sync + 'static,
+{
+    fn dead_letter(&self, msgs: Vec<TracingMsg>, err: CloseErr) {
+        self(msgs, err)
+    }
+}
+
+impl DeadLetterSink for UnboundedSender<(Vec<TracingMsg>, CloseErr)> {
+    fn dead_letter(&self, msgs: Vec<TracingMsg>, err: CloseErr) {
+        self.send((msgs, err)).ok();
+    }
+}
+
+#[derive(Clone)]
+struct DeadLetter(Arc<dyn DeadLetterSink>);
+
+impl fmt::Debug for DeadLetter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DeadLetter")
+            .field(&"DeadLetterSink")
+            .finish()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+struct RetryPolicy {
+    max_attempts: NonZeroU32,
+    base_backoff: Duration,
+    max_backoff: Duration,
+    deadline: Duration,
+}
+
+impl RetryPolicy {
+    fn backoff(&self, attempts: u32, elapsed: Duration) -> Option<Duration> {
+        if attempts >= self.max_attempts.get() {
+            return None;
+        }
+
+        let exp = self
+            .base_backoff
+            .saturating_mul(1 << (attempts - 1).min(31))
+            .min(self.max_backoff);
+        let backoff = exp.mul_f64(0.5 + fastrand::f64() * 0.5);
+
+        match elapsed + backoff <= self.deadline {
+            true => Some(backoff),
+            false => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Delivery {
+    Msg(Box<TracingMsg>),
+    Batch(Vec<TracingMsg>),
+}
+
+impl Delivery {
+    fn len(&self) -> u64 {
+        match self {
+            Self::Msg(_) => 1,
+            Self::Batch(msgs) => msgs.len() as u64,
+        }
+    }
+
+    fn into_msgs(self) -> Vec<TracingMsg> {
+        match self {
+            Self::Msg(msg) => vec![*msg],
+            Self::Batch(msgs) => msgs,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+struct Batching {
+    max_batch_len: NonZeroUsize,
+    max_batch_bytes: usize,
+    max_linger: Duration,
+}
+
+#[derive(Debug, Default)]
+struct Batch {
+    msgs: Vec<TracingMsg>,
+    bytes: usize,
+    deadline: Option<Instant>,
+}
+
+impl Batch {
+    fn add(&mut self, msg: TracingMsg, batching: &Batching) -> bool {
+        let bytes = json_len(&msg);
+
+        if self.msgs.is_empty() {
+            self.deadline = Some(Instant::now() + batching.max_linger);
+        }
+
+        self.msgs.push(msg);
+        self.bytes += bytes;
+
+        self.msgs.len() >= batching.max_batch_len.get() || self.bytes >= batching.max_batch_bytes
+    }
+
+    fn take(&mut self) -> Vec<TracingMsg> {
+        self.bytes = 0;
+        self.deadline = None;
+        mem::take(&mut self.msgs)
+    }
+}
+
+#[derive(Debug)]
+struct RoutineState {
+    batch: Batch,
+    spool: Option<Spool>,
+    // Callsites sent since the last delivery failure, cleared on failure (the transport
+    // may have reconnected, or the registration may be lost), so they're sent again on next use.
+    sent_callsites: HashSet<CallsiteId>,
+    callsites: Callsites,
+    shutdown: CancellationToken,
+}
+
+impl RoutineState {
+    // The registration of the callsite of `msg`, if not sent yet.
+    fn callsite_msg(&mut self, msg: &TracingMsg) -> Option<TracingMsg> {
+        let callsite_id = match &msg.body {
+            MsgBody::OnRegisterCallsite { .. } => return None,
+            body => body.callsite_id()?,
+        };
+
+        if !self.sent_callsites.insert(callsite_id) {
+            return None;
+        }
+
+        let callsite = self.callsites.get(&callsite_id)?;
+        let body = MsgBody::OnRegisterCallsite {
+            callsite_id,
+            callsite,
+        };
+
+        Some(body.into())
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct MsgLayerBuiler<T: CloseTransport + PushMsg + Clone + Debug> {
     transport: T,
-    level_filter: LevelFilter,
+    filter: FilterSpec,
+    queue_capacity: Option<NonZeroUsize>,
+    overflow_policy: OverflowPolicy,
+    batching: Option<Batching>,
+    retry: Option<RetryPolicy>,
+    dead_letter: Option<DeadLetter>,
+    spool: Option<SpoolConfig>,
+    flush_deadline: Option<Duration>,
+    stats: CloseStats,
+    span_stack: bool,
+    trace_context: bool,
+    sampling: SamplingConfig,
+    redaction: Redaction,
+    size_limits: SizeLimits,
     ctrlc_shutdown: bool,
     close_on_shutdown: bool,
     abort_on_error: bool,
@@ -141,14 +571,283 @@ pub struct MsgLayerBuiler<T: CloseTransport + PushMsg + Clone + Debug> {
 impl<T: CloseTransport + PushMsg + Clone + Debug> MsgLayerBuiler<T> {
     pub fn set_level_filter(self, level_filter: LevelFilter) -> Self {
         Self {
-            level_filter,
+            filter: FilterSpec::Level(level_filter),
             ..self
         }
     }
 
     pub fn disable_level_filter(self) -> Self {
         Self {
-            level_filter: LevelFilter::TRACE,
+            filter: FilterSpec::Level(LevelFilter::TRACE),
+            ..self
+        }
+    }
+
+    // e.g.: "hyper=warn,our_app::db=trace,[request{user_id=42}]=trace"
+    pub fn set_directives(self, directives: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            filter: FilterSpec::directives(directives)?,
+            ..self
+        })
+    }
+
+    pub fn bounded_queue(self, capacity: NonZeroUsize, overflow_policy: OverflowPolicy) -> Self {
+        Self {
+            queue_capacity: Some(capacity),
+            overflow_policy,
+            ..self
+        }
+    }
+
+    pub fn unbounded_queue(self) -> Self {
+        Self {
+            queue_capacity: None,
+            ..self
+        }
+    }
+
+    pub fn enable_batching(
+        self,
+        max_batch_len: NonZeroUsize,
+        max_batch_bytes: usize,
+        max_linger: Duration,
+    ) -> Self {
+        Self {
+            batching: Some(Batching {
+                max_batch_len,
+                max_batch_bytes,
+                max_linger,
+            }),
+            ..self
+        }
+    }
+
+    pub fn disable_batching(self) -> Self {
+        Self {
+            batching: None,
+            ..self
+        }
+    }
+
+    pub fn retry_on_error(
+        self,
+        max_attempts: NonZeroU32,
+        base_backoff: Duration,
+        max_backoff: Duration,
+        deadline: Duration,
+    ) -> Self {
+        Self {
+            retry: Some(RetryPolicy {
+                max_attempts,
+                base_backoff,
+                max_backoff,
+                deadline,
+            }),
+            ..self
+        }
+    }
+
+    pub fn disable_retry(self) -> Self {
+        Self {
+            retry: None,
+            ..self
+        }
+    }
+
+    pub fn dead_letter_sink(self, sink: impl DeadLetterSink) -> Self {
+        Self {
+            dead_letter: Some(DeadLetter(Arc::new(sink))),
+            ..self
+        }
+    }
+
+    pub fn spool_dir(
+        self,
+        dir: impl Into<PathBuf>,
+        max_segment_bytes: u64,
+        max_total_bytes: u64,
+        replay_interval: Duration,
+    ) -> Self {
+        Self {
+            spool: Some(SpoolConfig {
+                dir: dir.into(),
+                max_segment_bytes,
+                max_total_bytes,
+                replay_interval,
+            }),
+            ..self
+        }
+    }
+
+    pub fn disable_spool(self) -> Self {
+        Self {
+            spool: None,
+            ..self
+        }
+    }
+
+    pub fn flush_deadline(self, deadline: Duration) -> Self {
+        Self {
+            flush_deadline: Some(deadline),
+            ..self
+        }
+    }
+
+    // Attach the ids & names of all the enclosing spans to `OnNewSpan` & `OnEvent`.
+    pub fn capture_span_stack(self) -> Self {
+        Self {
+            span_stack: true,
+            ..self
+        }
+    }
+
+    pub fn disable_span_stack(self) -> Self {
+        Self {
+            span_stack: false,
+            ..self
+        }
+    }
+
+    // W3C trace & span ids are generated for every span (and read from
+    // the `traceparent` field if any), and attached to `OnNewSpan` & `OnEvent`.
+    pub fn propagate_trace_context(self) -> Self {
+        Self {
+            trace_context: true,
+            ..self
+        }
+    }
+
+    pub fn disable_trace_context(self) -> Self {
+        Self {
+            trace_context: false,
+            ..self
+        }
+    }
+
+    // Head based: the decision is taken on root spans (and on events outside of any span),
+    // and inherited by all their children.
+    pub fn sample_ratio(self, ratio: f64) -> Self {
+        Self {
+            sampling: SamplingConfig {
+                ratio: ratio.clamp(0.0, 1.0),
+                ..self.sampling
+            },
+            ..self
+        }
+    }
+
+    // Token bucket per callsite, for both spans & events.
+    pub fn rate_limit_per_callsite(self, per_second: NonZeroU32, burst: NonZeroU32) -> Self {
+        Self {
+            sampling: SamplingConfig {
+                rate_limit: Some(RateLimit { per_second, burst }),
+                ..self.sampling
+            },
+            ..self
+        }
+    }
+
+    // Spans & events at this level (or above) bypass both the sampling & the rate limit,
+    // e.g. `LevelFilter::WARN` to always keep WARN & ERROR.
+    pub fn always_keep_level(self, level_filter: LevelFilter) -> Self {
+        Self {
+            sampling: SamplingConfig {
+                always_keep: level_filter,
+                ..self.sampling
+            },
+            ..self
+        }
+    }
+
+    pub fn disable_sampling(self) -> Self {
+        Self {
+            sampling: Default::default(),
+            ..self
+        }
+    }
+
+    // The values of these fields (in spans, records & events) are replaced by "[redacted]".
+    pub fn redact_fields<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut redaction = self.redaction;
+        redaction
+            .deny_fields
+            .extend(fields.into_iter().map(Into::into));
+
+        Self { redaction, ..self }
+    }
+
+    // The values of these fields are replaced by their SHA-256, so they can still be correlated.
+    pub fn hash_fields<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut redaction = self.redaction;
+        redaction
+            .hash_fields
+            .extend(fields.into_iter().map(Into::into));
+
+        Self { redaction, ..self }
+    }
+
+    // Applies to the text values (including messages), `replacement` may refer to capture groups.
+    pub fn scrub_pattern(self, pattern: &str, replacement: &str) -> Result<Self, regex::Error> {
+        let mut redaction = self.redaction;
+        redaction
+            .patterns
+            .push((Regex::new(pattern)?, replacement.into()));
+
+        Ok(Self { redaction, ..self })
+    }
+
+    pub fn disable_redaction(self) -> Self {
+        Self {
+            redaction: Default::default(),
+            ..self
+        }
+    }
+
+    // Text & bytes values longer than this (in bytes) are cut, and marked as `Value::Truncated`.
+    // Applied after the redaction, along with the other size limits (see `disable_size_limits`).
+    pub fn truncate_values(self, max_len: usize) -> Self {
+        Self {
+            size_limits: SizeLimits {
+                max_value_len: Some(max_len),
+                ..self.size_limits
+            },
+            ..self
+        }
+    }
+
+    // Shared by all the values of a payload, in the order they were recorded.
+    pub fn max_payload_bytes(self, max_bytes: usize) -> Self {
+        Self {
+            size_limits: SizeLimits {
+                max_payload_bytes: Some(max_bytes),
+                ..self.size_limits
+            },
+            ..self
+        }
+    }
+
+    // Of the JSON encoded `TracingMsg`, enforced (approximately) by shrinking its payload.
+    pub fn max_msg_bytes(self, max_bytes: usize) -> Self {
+        Self {
+            size_limits: SizeLimits {
+                max_msg_bytes: Some(max_bytes),
+                ..self.size_limits
+            },
+            ..self
+        }
+    }
+
+    pub fn disable_size_limits(self) -> Self {
+        Self {
+            size_limits: Default::default(),
             ..self
         }
     }
@@ -176,16 +875,197 @@ impl<T: CloseTransport + PushMsg + Clone + Debug> MsgLayerBuiler<T> {
 
     async fn close(&mut self, output: RoutineOutput<T>) -> RoutineOutput<T> {
         if self.close_on_shutdown {
-            self.transport.close_transport(Some((&output).into())).await;
+            let msg = CloseMsg::from(&output).with_stats(self.stats);
+            self.transport.close_transport(Some(msg)).await;
         }
 
         output
     }
 
-    async fn push_msg(&mut self, msg: TracingMsg) -> Result<(), LayerError<T>> {
-        if let Err(err) = self.transport.push_msg(msg).await {
-            if self.abort_on_error {
-                self.close(Err(LayerError::PushMsgErr(err))).await?;
+    async fn check_io<R>(&mut self, res: io::Result<R>) -> Result<R, LayerError<T>> {
+        match res {
+            Ok(value) => Ok(value),
+            Err(err) => self.close(Err(err.into())).await.map(|_| unreachable!()),
+        }
+    }
+
+    async fn try_deliver(&mut self, delivery: Delivery) -> Result<(), LayerError<T>> {
+        match delivery {
+            Delivery::Msg(msg) => self
+                .transport
+                .push_msg(*msg)
+                .await
+                .map_err(LayerError::PushMsgErr),
+            Delivery::Batch(msgs) => self
+                .transport
+                .bulk_push(msgs)
+                .await
+                .map_err(LayerError::BulkPushErr),
+        }
+    }
+
+    async fn deliver(
+        &mut self,
+        delivery: Delivery,
+        state: &mut RoutineState,
+    ) -> Result<(), LayerError<T>> {
+        let len = delivery.len();
+        let keep = self.retry.is_some() || self.dead_letter.is_some();
+        let started = Instant::now();
+        let mut attempts = 0;
+        let mut delivery = Some(delivery);
+
+        let err = loop {
+            let current = delivery.take().unwrap();
+
+            if keep {
+                delivery = Some(current.clone());
+            }
+
+            attempts += 1;
+
+            let err = match self.try_deliver(current).await {
+                Ok(()) => match &mut state.spool {
+                    None => return Ok(()),
+                    Some(spool) => {
+                        let res = spool.ack(len).await;
+                        return self.check_io(res).await;
+                    }
+                },
+                Err(err) => err,
+            };
+
+            match self
+                .retry
+                .and_then(|retry| retry.backoff(attempts, started.elapsed()))
+            {
+                None => break err,
+                Some(backoff) => {
+                    self.stats.retries += 1;
+
+                    // Give up on shutdown, instead of holding `graceful_shutdown` until the deadline.
+                    tokio::select! {
+                        _ = sleep(backoff) => {}
+                        _ = state.shutdown.cancelled() => break err,
+                    }
+                }
+            }
+        };
+
+        state.sent_callsites.clear();
+
+        // Undelivered messages are already in the spool, and will be replayed later.
+        if let Some(spool) = &mut state.spool {
+            spool.enter_backlog();
+            return Ok(());
+        }
+
+        if let (Some(sink), Some(delivery)) = (&self.dead_letter, delivery) {
+            let msgs = delivery.into_msgs();
+            self.stats.dead_lettered += msgs.len() as u64;
+            sink.0.dead_letter(msgs, (&err).into());
+        }
+
+        if self.abort_on_error {
+            self.close(Err(err)).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn push_msg(
+        &mut self,
+        msg: TracingMsg,
+        state: &mut RoutineState,
+    ) -> Result<(), LayerError<T>> {
+        self.deliver(Delivery::Msg(Box::new(msg)), state).await
+    }
+
+    async fn push_batch(&mut self, state: &mut RoutineState) -> Result<(), LayerError<T>> {
+        let msgs = state.batch.take();
+
+        if msgs.is_empty() {
+            return Ok(());
+        }
+
+        self.deliver(Delivery::Batch(msgs), state).await
+    }
+
+    async fn spool_msg(
+        &mut self,
+        msg: &TracingMsg,
+        state: &mut RoutineState,
+    ) -> Result<(), LayerError<T>> {
+        if let Some(spool) = &mut state.spool {
+            let res = spool.append(msg).await;
+            self.stats.spool_evicted += self.check_io(res).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn replay(&mut self, state: &mut RoutineState) -> Result<(), LayerError<T>> {
+        let Some(spool) = &mut state.spool else {
+            return Ok(());
+        };
+
+        loop {
+            let res = spool.next_replay().await;
+            let Some(msgs) = self.check_io(res).await? else {
+                return Ok(());
+            };
+            let len = msgs.len() as u64;
+
+            if self.try_deliver(Delivery::Batch(msgs)).await.is_err() {
+                return Ok(());
+            }
+
+            self.stats.replayed += len;
+            let res = spool.replayed().await;
+            self.check_io(res).await?;
+        }
+    }
+
+    async fn handle_msg(
+        &mut self,
+        msg: TracingMsg,
+        state: &mut RoutineState,
+    ) -> Result<(), LayerError<T>> {
+        if let Some(callsite_msg) = state.callsite_msg(&msg) {
+            self.handle_one(callsite_msg, state).await?;
+        }
+
+        self.handle_one(msg, state).await
+    }
+
+    async fn handle_one(
+        &mut self,
+        msg: TracingMsg,
+        state: &mut RoutineState,
+    ) -> Result<(), LayerError<T>> {
+        self.spool_msg(&msg, state).await?;
+
+        if state.spool.as_ref().is_some_and(Spool::backlog) {
+            return Ok(());
+        }
+
+        match self.batching {
+            None => self.push_msg(msg, state).await,
+            Some(batching) => match state.batch.add(msg, &batching) {
+                true => self.push_batch(state).await,
+                false => Ok(()),
+            },
+        }
+    }
+
+    async fn report_dropped(
+        &mut self,
+        recv: &mut MsgReceiver,
+        state: &mut RoutineState,
+    ) -> Result<(), LayerError<T>> {
+        if let dropped @ 1.. = recv.take_unreported_drops() {
+            for body in MsgBody::on_msgs_dropped(dropped) {
+                self.handle_msg(body.into(), state).await?;
             }
         }
 
@@ -194,61 +1074,182 @@ impl<T: CloseTransport + PushMsg + Clone + Debug> MsgLayerBuiler<T> {
 
     async fn flush_close(
         mut self,
-        mut recv: UnboundedReceiver<TracingMsg>,
+        mut recv: MsgReceiver,
+        mut state: RoutineState,
         mut output: RoutineOutput<T>,
     ) -> RoutineOutput<T> {
         recv.close();
-        let mut msgs = Vec::new();
+        let deadline = self.flush_deadline.map(|timeout| Instant::now() + timeout);
+        let mut msgs = state.batch.take();
+        let mut drained = Vec::new();
+
+        while let Some(msg) = recv.try_recv() {
+            drained.extend(state.callsite_msg(&msg));
+            drained.push(msg);
+        }
+
+        if let dropped @ 1.. = recv.take_unreported_drops() {
+            drained.extend(MsgBody::on_msgs_dropped(dropped).map(From::from));
+        }
 
-        while let Ok(msg) = recv.try_recv() {
-            msgs.push(msg);
+        for msg in &drained {
+            self.spool_msg(msg, &mut state).await?;
         }
 
-        let mut chunks = msgs.chunks(1024);
+        msgs.append(&mut drained);
 
-        if let Some(chunk) = chunks.next() {
-            match self.transport.bulk_push(chunk.into()).await {
-                Err(err) => output = Err(LayerError::BulkPushErr(err)),
-                _ => {
-                    if chunks.next().is_some() {
-                        output = Err(LayerError::BufferFull);
+        if state.spool.as_ref().is_some_and(Spool::backlog) {
+            let replay = self.replay(&mut state);
+
+            // `msgs` are spooled too, so they are either counted as `replayed`, or left in the spool
+            // (for the next run) when the replay fails or times out.
+            match deadline {
+                None => replay.await?,
+                Some(deadline) => timeout_at(deadline, replay).await.unwrap_or(Ok(()))?,
+            }
+
+            return self.close(output).await;
+        }
+
+        let chunk_len = self
+            .batching
+            .map_or(FLUSH_CHUNK_LEN, |batching| batching.max_batch_len.get());
+        let mut msgs = msgs.into_iter();
+
+        loop {
+            let chunk: Vec<_> = msgs.by_ref().take(chunk_len).collect();
+            let len = chunk.len() as u64;
+
+            if chunk.is_empty() {
+                break;
+            }
+
+            let push = self.try_deliver(Delivery::Batch(chunk));
+            let res = match deadline {
+                None => Some(push.await),
+                Some(deadline) => timeout_at(deadline, push).await.ok(),
+            };
+
+            match res {
+                Some(Ok(())) => {
+                    self.stats.flushed += len;
+
+                    if let Some(spool) = &mut state.spool {
+                        if let Err(err) = spool.ack(len).await {
+                            output = Err(err.into());
+                        }
                     }
                 }
+                Some(Err(err)) => {
+                    self.stats.abandoned += len;
+                    output = Err(err);
+                    break;
+                }
+                None => {
+                    self.stats.abandoned += len;
+                    break;
+                }
             }
         }
 
+        self.stats.abandoned += msgs.len() as u64;
         self.close(output).await
     }
 
-    pub fn build<S: Subscriber>(self) -> (FilteredLayer<S>, MsgRoutine<T>) {
-        let level_filter = self.level_filter;
+    pub fn build<S: Subscriber + for<'a> LookupSpan<'a>>(
+        self,
+    ) -> (FilteredLayer<S>, MsgRoutine<T>) {
+        let filter = self.filter.clone().into_filter();
+        self.build_with_filter(filter)
+    }
+
+    pub fn build_with_filter<S, F>(self, filter: F) -> (FilteredLayer<S>, MsgRoutine<T>)
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+        F: Filter<S> + Send + Sync + 'static,
+    {
+        let (filter, reload_handle) = reload::Layer::new(Box::new(filter) as MsgFilter<S>);
+        let filter_handle = FilterHandle::new(reload_handle);
+        let span_stack = self.span_stack;
+        let trace_context = self.trace_context;
+        let sampler = (!self.sampling.is_noop()).then(|| Arc::new(Sampler::new(self.sampling)));
+        let redaction = (!self.redaction.is_noop()).then(|| Arc::new(self.redaction.clone()));
+        let size_limits = (!self.size_limits.is_noop()).then_some(self.size_limits);
         let mut builder = self;
-        let (send, mut recv) = unbounded_channel();
+        let (send, mut recv, drop_counters) =
+            msg_queue(builder.queue_capacity, builder.overflow_policy);
+        let callsites = Callsites::default();
+        let routine_callsites = callsites.clone();
         let shutdown_trigger = CancellationToken::new();
         let shutdown_waiter = shutdown_trigger.clone();
-        let routine = tokio::spawn(async move {
+        let routine = tokio::spawn(Routine::new(async move {
+            let spool = match builder.spool.clone() {
+                None => None,
+                Some(config) => match Spool::open(config).await {
+                    Ok(spool) => Some(spool),
+                    Err(err) => return builder.close(Err(err.into())).await,
+                },
+            };
+            let mut replay = interval(
+                spool
+                    .as_ref()
+                    .map_or(Duration::from_secs(1), Spool::replay_interval),
+            );
+            let mut state = RoutineState {
+                batch: Default::default(),
+                spool,
+                sent_callsites: HashSet::new(),
+                callsites: routine_callsites,
+                shutdown: shutdown_waiter.clone(),
+            };
+
+            replay.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
             loop {
+                let linger = state.batch.deadline.unwrap_or_else(Instant::now);
+                let backlog = state.spool.as_ref().is_some_and(Spool::backlog);
+
                 tokio::select! {
                     res = ctrl_c(), if builder.ctrlc_shutdown => {
-                        return builder
-                            .flush_close(recv, res.map(|_| GraceType::CtrlC).map_err(From::from))
-                            .await;
+                        let output = res.map(|_| GraceType::CtrlC).map_err(From::from);
+                        return builder.flush_close(recv, state, output).await;
                     }
                     _ = shutdown_waiter.cancelled() => {
-                        return builder.flush_close(recv, Ok(GraceType::Explicit)).await;
+                        return builder.flush_close(recv, state, Ok(GraceType::Explicit)).await;
+                    }
+                    _ = sleep_until(linger), if state.batch.deadline.is_some() => {
+                        builder.push_batch(&mut state).await?;
+                    }
+                    _ = replay.tick(), if backlog => {
+                        builder.replay(&mut state).await?;
                     }
                     msg = recv.recv() => match msg {
                         None => {
+                            builder.push_batch(&mut state).await?;
                             return builder.close(Err(LayerError::LayerDropped)).await;
                         }
-                        Some(msg) => builder.push_msg(msg).await?,
+                        Some(msg) => {
+                            builder.handle_msg(msg, &mut state).await?;
+                            builder.report_dropped(&mut recv, &mut state).await?;
+                        }
                     }
                 };
             }
-        });
-        let filtered_layer = MsgLayer(send).with_filter(level_filter);
+        }));
+        let filtered_layer = MsgLayer {
+            send,
+            callsites,
+            span_stack,
+            trace_context,
+            sampler,
+            redaction,
+            size_limits,
+        }
+        .with_filter(filter);
         let msg_routine = MsgRoutine {
             shutdown_trigger,
+            drop_counters,
+            filter_handle,
             routine,
         };
 
@@ -270,10 +1271,549 @@ where
     fn tracing_layer_default(&self) -> MsgLayerBuiler<Self::Transport> {
         MsgLayerBuiler {
             transport: self.clone(),
-            level_filter: LevelFilter::DEBUG,
+            filter: FilterSpec::Level(LevelFilter::DEBUG),
+            queue_capacity: None,
+            overflow_policy: Default::default(),
+            batching: None,
+            retry: None,
+            dead_letter: None,
+            spool: None,
+            flush_deadline: None,
+            stats: Default::default(),
+            span_stack: false,
+            trace_context: false,
+            sampling: Default::default(),
+            redaction: Default::default(),
+            size_limits: Default::default(),
             ctrlc_shutdown: true,
             close_on_shutdown: false,
             abort_on_error: true,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing_msg::{
+        in_memory::{capture, run_layer, CapturedEvent},
+        InMemoryTransport, Value,
+    };
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use tokio::sync::mpsc;
+
+    // Fails every push while `down`, and the next `fail_next` pushes
+    #[derive(Clone, Default, Debug)]
+    struct FlakyTransport {
+        inner: InMemoryTransport,
+        down: Arc<AtomicBool>,
+        fail_next: Arc<AtomicUsize>,
+        failures: Arc<AtomicUsize>,
+        // The len of every successful push
+        pushes: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl FlakyTransport {
+        fn pushes(&self) -> Vec<usize> {
+            self.pushes.lock().unwrap().clone()
+        }
+    }
+
+    impl PushMsg for FlakyTransport {
+        type Error = io::Error;
+
+        async fn bulk_push(&mut self, msgs: Vec<TracingMsg>) -> Result<(), Self::Error> {
+            let fail_next = self
+                .fail_next
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok();
+
+            if fail_next || self.down.load(Ordering::SeqCst) {
+                self.failures.fetch_add(1, Ordering::SeqCst);
+                return Err(io::Error::other("down"));
+            }
+
+            self.pushes.lock().unwrap().push(msgs.len());
+            let Ok(()) = self.inner.bulk_push(msgs).await;
+            Ok(())
+        }
+    }
+
+    impl CloseTransport for FlakyTransport {
+        async fn close_transport(&mut self, msg: Option<CloseMsg>) {
+            self.inner.close_transport(msg).await;
+        }
+    }
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("tracing-surreal-{}", ulid::Ulid::new()))
+    }
+
+    #[tokio::test]
+    async fn flush_close_counts_the_spool_backlog_as_replayed() {
+        let transport = FlakyTransport::default();
+        transport.down.store(true, Ordering::SeqCst);
+        let dir = temp_dir();
+        let build = |builder: MsgLayerBuiler<_>| {
+            builder.close_transport_on_shutdown().spool_dir(
+                &dir,
+                1 << 20,
+                1 << 24,
+                Duration::from_secs(3600),
+            )
+        };
+        run_layer(&transport, build, |_| async {
+            tracing::info!("first");
+
+            while transport.failures.load(Ordering::SeqCst) == 0 {
+                sleep(Duration::from_millis(10)).await;
+            }
+
+            transport.down.store(false, Ordering::SeqCst);
+            tracing::info!("second");
+        })
+        .await;
+
+        let close_msg = transport.inner.close_msg().unwrap();
+        let stats = close_msg.stats();
+        let msgs = transport.inner.msgs().len() as u64;
+        assert_eq!(transport.inner.events().len(), 2);
+        assert_eq!(stats.abandoned, 0);
+        assert_eq!(stats.replayed + stats.flushed, msgs);
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    async fn wait_for_events(transport: &FlakyTransport, len: usize) {
+        while transport.inner.events().len() < len {
+            sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn callsites_are_sent_on_first_use_and_after_failures() {
+        let transport = FlakyTransport::default();
+        let ping = || tracing::info!("ping");
+        let build = |builder: MsgLayerBuiler<_>| builder.discard_push_error();
+        let flaky = &transport;
+        run_layer(&transport, build, |handle| async move {
+            ping();
+            ping();
+            wait_for_events(flaky, 2).await;
+
+            // Rebuilds the interest cache, registering every callsite again.
+            handle.set_level_filter(LevelFilter::TRACE).unwrap();
+            ping();
+            wait_for_events(flaky, 3).await;
+
+            flaky.down.store(true, Ordering::SeqCst);
+            ping();
+
+            while flaky.failures.load(Ordering::SeqCst) == 0 {
+                sleep(Duration::from_millis(10)).await;
+            }
+
+            flaky.down.store(false, Ordering::SeqCst);
+            ping();
+            wait_for_events(flaky, 4).await;
+        })
+        .await;
+
+        let msgs = transport.inner.msgs();
+        let callsite_id = msgs[1].body.callsite_id().unwrap();
+        let registered: Vec<_> = msgs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, msg)| match &msg.body {
+                MsgBody::OnRegisterCallsite {
+                    callsite_id: id,
+                    callsite,
+                } if *id == callsite_id => Some((index, callsite.fields.clone())),
+                _ => None,
+            })
+            .collect();
+        let message = vec!["message".to_string()];
+        assert_eq!(registered, [(0, message.clone()), (4, message)]);
+    }
+
+    #[tokio::test]
+    async fn retries_until_the_push_succeeds() {
+        let transport = FlakyTransport::default();
+        transport.fail_next.store(2, Ordering::SeqCst);
+        let (sink, mut dead) = mpsc::unbounded_channel();
+        let build = |builder: MsgLayerBuiler<_>| {
+            builder
+                .close_transport_on_shutdown()
+                .retry_on_error(
+                    NonZeroU32::new(3).unwrap(),
+                    Duration::from_millis(1),
+                    Duration::from_millis(1),
+                    Duration::from_secs(10),
+                )
+                .dead_letter_sink(sink)
+        };
+        let flaky = &transport;
+        run_layer(&transport, build, |_| async move {
+            tracing::info!("ping");
+            wait_for_events(flaky, 1).await;
+        })
+        .await;
+
+        let close_msg = transport.inner.close_msg().unwrap();
+        let stats = close_msg.stats();
+        assert_eq!(transport.failures.load(Ordering::SeqCst), 2);
+        assert_eq!(stats.retries, 2);
+        assert_eq!(stats.dead_lettered, 0);
+        assert!(dead.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts_to_the_dead_letter_sink() {
+        let transport = FlakyTransport::default();
+        transport.down.store(true, Ordering::SeqCst);
+        let (sink, mut dead) = mpsc::unbounded_channel();
+        let build = |builder: MsgLayerBuiler<_>| {
+            builder
+                .close_transport_on_shutdown()
+                .discard_push_error()
+                .retry_on_error(
+                    NonZeroU32::new(3).unwrap(),
+                    Duration::from_millis(1),
+                    Duration::from_millis(1),
+                    Duration::from_secs(10),
+                )
+                .dead_letter_sink(sink)
+        };
+        let mut dead_lettered = Vec::new();
+        run_layer(&transport, build, |_| async {
+            tracing::info!("lost");
+
+            // The registration of the callsite, then the event, each delivered on its own.
+            for _ in 0..2 {
+                let (msgs, err) = dead.recv().await.unwrap();
+                assert_eq!(err.kind, CloseErrKind::PushMsgErr);
+                dead_lettered.extend(msgs);
+            }
+        })
+        .await;
+
+        let close_msg = transport.inner.close_msg().unwrap();
+        let stats = close_msg.stats();
+        assert!(matches!(
+            dead_lettered[0].body,
+            MsgBody::OnRegisterCallsite { .. }
+        ));
+        assert!(matches!(dead_lettered[1].body, MsgBody::OnEvent { .. }));
+        assert_eq!(transport.failures.load(Ordering::SeqCst), 6);
+        assert_eq!(stats.retries, 4);
+        assert_eq!(stats.dead_lettered, 2);
+        assert!(transport.inner.events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn gives_up_at_the_retry_deadline() {
+        let transport = FlakyTransport::default();
+        transport.down.store(true, Ordering::SeqCst);
+        let (sink, mut dead) = mpsc::unbounded_channel();
+        let build = |builder: MsgLayerBuiler<_>| {
+            builder
+                .close_transport_on_shutdown()
+                .discard_push_error()
+                .retry_on_error(
+                    NonZeroU32::new(1000).unwrap(),
+                    Duration::from_millis(20),
+                    Duration::from_millis(20),
+                    Duration::from_millis(100),
+                )
+                .dead_letter_sink(sink)
+        };
+        run_layer(&transport, build, |_| async {
+            tracing::info!("lost");
+
+            for _ in 0..2 {
+                let started = Instant::now();
+                dead.recv().await.unwrap();
+                assert!(started.elapsed() < Duration::from_secs(1));
+            }
+        })
+        .await;
+
+        // Backoffs of 10ms to 20ms fit 5 to 10 retries in the 100ms deadline, for both deliveries.
+        let close_msg = transport.inner.close_msg().unwrap();
+        let stats = close_msg.stats();
+        let failures = transport.failures.load(Ordering::SeqCst) as u64;
+        assert!((10..=22).contains(&failures), "{failures} failures");
+        assert_eq!(stats.retries, failures - 2);
+        assert_eq!(stats.dead_lettered, 2);
+    }
+
+    #[tokio::test]
+    async fn shutdown_interrupts_the_backoff() {
+        let transport = FlakyTransport::default();
+        transport.down.store(true, Ordering::SeqCst);
+        let (sink, mut dead) = mpsc::unbounded_channel();
+        let build = |builder: MsgLayerBuiler<_>| {
+            builder
+                .close_transport_on_shutdown()
+                .discard_push_error()
+                .retry_on_error(
+                    NonZeroU32::new(10).unwrap(),
+                    Duration::from_secs(60),
+                    Duration::from_secs(60),
+                    Duration::from_secs(600),
+                )
+                .dead_letter_sink(sink)
+        };
+        let flaky = &transport;
+        let run = run_layer(&transport, build, |_| async move {
+            tracing::info!("ping");
+
+            while flaky.failures.load(Ordering::SeqCst) == 0 {
+                sleep(Duration::from_millis(10)).await;
+            }
+
+            // Only the registration of the callsite waits on the backoff, the event goes through.
+            flaky.down.store(false, Ordering::SeqCst);
+        });
+        tokio::time::timeout(Duration::from_secs(5), run)
+            .await
+            .unwrap();
+
+        let close_msg = transport.inner.close_msg().unwrap();
+        let stats = close_msg.stats();
+        let (msgs, _) = dead.try_recv().unwrap();
+        assert!(matches!(
+            msgs[..],
+            [TracingMsg {
+                body: MsgBody::OnRegisterCallsite { .. },
+                ..
+            }]
+        ));
+        assert_eq!(stats.retries, 1);
+        assert_eq!(stats.dead_lettered, 1);
+        assert_eq!(transport.inner.events().len(), 1);
+    }
+
+    async fn wait_for_pushes(transport: &FlakyTransport, len: usize) {
+        while transport.pushes().len() < len {
+            sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    fn batching(
+        max_batch_len: usize,
+        max_batch_bytes: usize,
+        max_linger: Duration,
+    ) -> impl FnOnce(MsgLayerBuiler<FlakyTransport>) -> MsgLayerBuiler<FlakyTransport> {
+        move |builder| {
+            builder.enable_batching(
+                NonZeroUsize::new(max_batch_len).unwrap(),
+                max_batch_bytes,
+                max_linger,
+            )
+        }
+    }
+
+    fn pings(transport: &FlakyTransport) -> Vec<i64> {
+        let ping = |event: &CapturedEvent| match event.field("ping") {
+            Some(Value::I64(ping)) => Some(*ping),
+            _ => None,
+        };
+        transport.inner.events().iter().filter_map(ping).collect()
+    }
+
+    #[tokio::test]
+    async fn batch_is_pushed_at_max_batch_len() {
+        let transport = FlakyTransport::default();
+        let build = batching(3, usize::MAX, Duration::from_secs(3600));
+        let flaky = &transport;
+        run_layer(&transport, build, |_| async move {
+            for ping in 0..3 {
+                tracing::info!(ping);
+            }
+
+            // The registration of the callsite, then the first 2 events.
+            wait_for_pushes(flaky, 1).await;
+            assert_eq!(flaky.pushes(), [3]);
+            assert_eq!(pings(flaky), [0, 1]);
+        })
+        .await;
+
+        // The last event is left to the flush on shutdown.
+        assert_eq!(transport.pushes(), [3, 1]);
+        assert_eq!(pings(&transport), [0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn batch_is_pushed_at_max_batch_bytes() {
+        let transport = FlakyTransport::default();
+        let build = batching(usize::MAX, 10_000, Duration::from_secs(3600));
+        let flaky = &transport;
+        run_layer(&transport, build, |_| async move {
+            let pad = "x".repeat(5_000);
+
+            for ping in 0..3 {
+                tracing::info!(ping, pad);
+            }
+
+            // Over 10_000 bytes with the 2nd event.
+            wait_for_pushes(flaky, 1).await;
+            assert_eq!(flaky.pushes(), [3]);
+            assert_eq!(pings(flaky), [0, 1]);
+        })
+        .await;
+
+        assert_eq!(transport.pushes(), [3, 1]);
+        assert_eq!(pings(&transport), [0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn batch_is_pushed_after_max_linger() {
+        let transport = FlakyTransport::default();
+        let linger = Duration::from_millis(100);
+        let build = batching(usize::MAX, usize::MAX, linger);
+        let flaky = &transport;
+        run_layer(&transport, build, |_| async move {
+            let started = Instant::now();
+
+            for ping in 0..2 {
+                tracing::info!(ping);
+            }
+
+            wait_for_pushes(flaky, 1).await;
+            assert!(started.elapsed() >= linger);
+            assert_eq!(flaky.pushes(), [3]);
+            assert_eq!(pings(flaky), [0, 1]);
+        })
+        .await;
+
+        assert_eq!(transport.pushes(), [3]);
+    }
+
+    // The parent & stack of the `OnNewSpan` of `name`, or of the `OnEvent` of message `name`.
+    fn span_context_of(
+        transport: &InMemoryTransport,
+        name: &str,
+    ) -> (Parent, Option<Vec<SpanFrame>>) {
+        let span_id = transport.span(name).map(|span| span.span_id);
+
+        transport
+            .msgs()
+            .into_iter()
+            .find_map(|msg| match msg.body {
+                MsgBody::OnNewSpan {
+                    span_id: id,
+                    parent,
+                    stack,
+                    ..
+                } if Some(id) == span_id => Some((parent, stack)),
+                MsgBody::OnEvent {
+                    message,
+                    parent,
+                    stack,
+                    ..
+                } if message == name => Some((parent, stack)),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn contextual_parents_are_resolved() {
+        let transport = capture(
+            |builder| builder,
+            |_| async {
+                tracing::info!("outside");
+                let other = tracing::info_span!("other");
+                let outer = tracing::info_span!("outer");
+                let _entered = outer.enter();
+                tracing::info!("inside");
+                tracing::info_span!("contextual").in_scope(|| {});
+                tracing::info_span!(parent: None, "root").in_scope(|| {});
+                tracing::info_span!(parent: &other, "explicit").in_scope(|| {});
+                tracing::info!(parent: &other, "explicit event");
+            },
+        )
+        .await;
+
+        let outer = Parent::Explicit(transport.span("outer").unwrap().span_id);
+        let other = Parent::Explicit(transport.span("other").unwrap().span_id);
+        let parent_of = |name| span_context_of(&transport, name).0;
+        assert_eq!(parent_of("outside"), Parent::Root);
+        assert_eq!(parent_of("outer"), Parent::Root);
+        assert_eq!(parent_of("inside"), outer);
+        assert_eq!(parent_of("contextual"), outer);
+        assert_eq!(parent_of("root"), Parent::Root);
+        assert_eq!(parent_of("explicit"), other);
+        assert_eq!(parent_of("explicit event"), other);
+    }
+
+    #[tokio::test]
+    async fn span_stack_goes_from_the_root_to_the_parent() {
+        let build = |builder: MsgLayerBuiler<_>| builder.capture_span_stack();
+        let transport = capture(build, |_| async {
+            tracing::info!("outside");
+            tracing::info_span!("a").in_scope(|| {
+                tracing::info_span!("b").in_scope(|| {
+                    tracing::info_span!("c").in_scope(|| tracing::info!("inside"));
+                });
+            });
+        })
+        .await;
+
+        let frame = |name: &str| SpanFrame {
+            span_id: transport.span(name).unwrap().span_id,
+            name: name.into(),
+        };
+        let stack_of = |name| span_context_of(&transport, name).1;
+        assert_eq!(stack_of("outside"), Some(vec![]));
+        assert_eq!(stack_of("a"), Some(vec![]));
+        assert_eq!(stack_of("c"), Some(vec![frame("a"), frame("b")]));
+        assert_eq!(
+            stack_of("inside"),
+            Some(vec![frame("a"), frame("b"), frame("c")])
+        );
+
+        let transport = capture(
+            |builder| builder,
+            |_| async {
+                tracing::info_span!("a").in_scope(|| tracing::info!("inside"));
+            },
+        )
+        .await;
+        assert_eq!(span_context_of(&transport, "inside").1, None);
+    }
+
+    #[tokio::test]
+    async fn on_close_carries_the_span_timing() {
+        let transport = capture(
+            |builder| builder,
+            |_| async {
+                let span = tracing::info_span!("timed");
+
+                for _ in 0..3 {
+                    span.in_scope(|| std::thread::sleep(Duration::from_millis(10)));
+                    std::thread::sleep(Duration::from_millis(10));
+                }
+            },
+        )
+        .await;
+
+        let span_id = transport.span("timed").unwrap().span_id;
+        let timing = transport
+            .msgs()
+            .into_iter()
+            .find_map(|msg| match msg.body {
+                MsgBody::OnClose {
+                    span_id: id,
+                    timing,
+                } if id == span_id => timing,
+                _ => None,
+            })
+            .unwrap();
+        let millis = |ms| Duration::from_millis(ms).as_nanos() as u64;
+        assert_eq!(timing.enters, 3);
+        assert!(timing.busy_ns >= millis(30));
+        assert!(timing.idle_ns >= millis(30));
+        assert!(timing.busy_ns + timing.idle_ns <= timing.total_ns);
+    }
+}
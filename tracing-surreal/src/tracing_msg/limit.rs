@@ -0,0 +1,242 @@
+use super::{Payload, TracingMsg, Value};
+use serde::Serialize;
+use std::{io, mem};
+
+#[derive(Default)]
+struct ByteCounter(usize);
+
+impl io::Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+pub(crate) fn json_len<T: Serialize>(value: &T) -> usize {
+    let mut counter = ByteCounter::default();
+    serde_json::to_writer(&mut counter, value).ok();
+    counter.0
+}
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct SizeLimits {
+    pub(crate) max_value_len: Option<usize>,
+    pub(crate) max_payload_bytes: Option<usize>,
+    pub(crate) max_msg_bytes: Option<usize>,
+}
+
+impl SizeLimits {
+    pub(crate) fn is_noop(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub(crate) fn apply(&self, msg: &mut TracingMsg) {
+        let budget = self.max_payload_bytes.unwrap_or(usize::MAX);
+        msg.body
+            .map_payload(|payload| self.limit_payload(payload, budget));
+
+        let Some(max_msg_bytes) = self.max_msg_bytes else {
+            return;
+        };
+
+        let excess = json_len(msg).saturating_sub(max_msg_bytes);
+
+        // Only the payload can be shrunk, so the result is approximate
+        // (the JSON encoding & the truncation markers are not accounted for).
+        if excess != 0 {
+            msg.body.map_payload(|payload| {
+                let budget = payload_len(payload).saturating_sub(excess);
+                self.limit_payload(payload, budget);
+            });
+        }
+    }
+
+    fn limit_payload(&self, payload: &mut Payload, budget: usize) {
+        let mut remaining = budget;
+
+        for value in payload.0.values_mut().flatten() {
+            truncate(
+                value,
+                self.max_value_len.unwrap_or(usize::MAX).min(remaining),
+            );
+            remaining = remaining.saturating_sub(value_len(value));
+        }
+    }
+}
+
+fn payload_len(payload: &Payload) -> usize {
+    payload.values().flatten().map(value_len).sum()
+}
+
+fn value_len(value: &Value) -> usize {
+    match value {
+        Value::Debug(text) | Value::String(text) | Value::Error(text) => text.len(),
+        Value::Bytes(bytes) => bytes.len(),
+        Value::Truncated { partial, .. } => value_len(partial),
+        Value::F64(value) => mem::size_of_val(value),
+        Value::I64(value) => mem::size_of_val(value),
+        Value::U64(value) => mem::size_of_val(value),
+        Value::I128(value) => mem::size_of_val(value),
+        Value::U128(value) => mem::size_of_val(value),
+        Value::Bool(value) => mem::size_of_val(value),
+        Value::Map(value) => json_len(value),
+        Value::List(value) => json_len(value),
+        Value::Null => 0,
+    }
+}
+
+// Only text & bytes can be truncated, the other values are kept as is.
+fn truncate(value: &mut Value, max_len: usize) {
+    let original_len = value_len(value);
+
+    if original_len <= max_len {
+        return;
+    }
+
+    match value {
+        Value::Truncated { partial, .. } => {
+            cut(partial, max_len);
+        }
+        _ => {
+            if cut(value, max_len) {
+                let partial = Box::new(mem::replace(value, Value::Bool(false)));
+                let original_len = original_len as u64;
+                *value = Value::Truncated {
+                    partial,
+                    original_len,
+                };
+            }
+        }
+    }
+}
+
+fn cut(value: &mut Value, max_len: usize) -> bool {
+    match value {
+        Value::Debug(text) | Value::String(text) | Value::Error(text) => {
+            let end = (0..=max_len).rev().find(|i| text.is_char_boundary(*i));
+            text.truncate(end.unwrap_or_default());
+            true
+        }
+        Value::Bytes(bytes) => {
+            bytes.truncate(max_len);
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing_msg::{in_memory::capture, MsgBody, SpanId};
+    use indexmap::IndexMap;
+    use std::num::NonZeroU64;
+
+    fn truncated(partial: Value, original_len: u64) -> Value {
+        Value::Truncated {
+            partial: Box::new(partial),
+            original_len,
+        }
+    }
+
+    fn record(values: Vec<(&str, Value)>) -> TracingMsg {
+        let span_id = SpanId(NonZeroU64::MIN);
+        let payload = values
+            .into_iter()
+            .map(|(key, value)| (key.into(), vec![value]))
+            .collect::<IndexMap<_, _>>();
+
+        MsgBody::OnRecord {
+            span_id,
+            payload: Payload(payload),
+        }
+        .into()
+    }
+
+    fn payload_of(msg: &TracingMsg) -> &Payload {
+        match &msg.body {
+            MsgBody::OnRecord { payload, .. } => payload,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn values_are_cut_on_char_boundaries() {
+        let limits = SizeLimits {
+            max_value_len: Some(5),
+            ..Default::default()
+        };
+        let mut msg = record(vec![
+            ("text", Value::String("ééé".into())),
+            ("bytes", Value::Bytes(vec![0; 8])),
+            ("short", Value::String("ok".into())),
+            ("int", Value::U128(u128::MAX)),
+        ]);
+        limits.apply(&mut msg);
+
+        let payload = payload_of(&msg);
+        assert_eq!(payload["text"], [truncated(Value::String("éé".into()), 6)]);
+        assert_eq!(payload["bytes"], [truncated(Value::Bytes(vec![0; 5]), 8)]);
+        assert_eq!(payload["short"], [Value::String("ok".into())]);
+        assert_eq!(payload["int"], [Value::U128(u128::MAX)]);
+    }
+
+    #[test]
+    fn payload_and_msg_budgets() {
+        let limits = SizeLimits {
+            max_payload_bytes: Some(6),
+            ..Default::default()
+        };
+        let mut msg = record(vec![
+            ("a", Value::String("abcd".into())),
+            ("b", Value::String("efgh".into())),
+            ("c", Value::String("ijkl".into())),
+        ]);
+        limits.apply(&mut msg);
+
+        // Shared in the order the values were recorded
+        let payload = payload_of(&msg);
+        assert_eq!(payload["a"], [Value::String("abcd".into())]);
+        assert_eq!(payload["b"], [truncated(Value::String("ef".into()), 4)]);
+        assert_eq!(payload["c"], [truncated(Value::String("".into()), 4)]);
+
+        let limits = SizeLimits {
+            max_msg_bytes: Some(512),
+            ..Default::default()
+        };
+        let mut msg = record(vec![("blob", Value::Debug("x".repeat(4096)))]);
+        limits.apply(&mut msg);
+        assert!(json_len(&msg) < 600);
+        assert!(matches!(
+            payload_of(&msg)["blob"][0],
+            Value::Truncated {
+                original_len: 4096,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn truncates_after_the_redaction() {
+        let transport = capture(
+            |builder| builder.hash_fields(["user"]).truncate_values(16),
+            |_| async { tracing::info!(user = "alice", "hello") },
+        )
+        .await;
+
+        let event = transport.assert_event("hello");
+        let Some(Value::Truncated {
+            partial,
+            original_len,
+        }) = event.field("user")
+        else {
+            panic!("not truncated: {:?}", event.payload);
+        };
+        assert_eq!(*original_len, 71);
+        assert_eq!(partial.get_text().unwrap(), "sha256:2bd806c97");
+    }
+}
@@ -1,8 +1,8 @@
-use super::{CloseMsg, HelloMsg, Role, TracingMsg};
+use super::{Callsite, CloseMsg, HelloMsg, Role, TracingMsg};
 use chrono::{DateTime, Local};
 use either::Either;
 use serde::{Deserialize, Serialize};
-use std::{mem, net::SocketAddr};
+use std::{collections::HashSet, mem, net::SocketAddr};
 use tokio::sync::broadcast::Receiver;
 
 pub use tokio::sync::broadcast::error::RecvError;
@@ -40,6 +40,9 @@ pub struct CloseInfo {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct MsgInfo {
     pub client_info: Either<ClientId, ClientInfo>,
+    // The callsite of spans & events, as registered by the pusher
+    #[serde(default)]
+    pub callsite: Option<Callsite>,
     pub tracing_msg: TracingMsg,
 }
 
@@ -48,7 +51,7 @@ pub struct MsgInfo {
 pub enum ObserveMsg {
     OnClientHello(ClientId, ClientInfo),
     OnDisconnect(String, CloseInfo),
-    OnMsg(String, MsgInfo),
+    OnMsg(String, Box<MsgInfo>),
 }
 
 impl ObserveMsg {
@@ -67,6 +70,10 @@ pub struct Observer {
     history: Vec<ObserveMsg>,
     live: Receiver<ObserveMsg>,
     link: bool,
+    // Records committed out of key order may show up in both the history and the live messages,
+    // until a live message is newer than the whole history.
+    backfilled: HashSet<String>,
+    last_backfilled: Option<String>,
 }
 
 impl Observer {
@@ -79,14 +86,72 @@ impl Observer {
     }
 
     pub async fn next_live(&mut self) -> Result<ObserveMsg, RecvError> {
-        self.live.recv().await
+        loop {
+            let msg = self.live.recv().await?;
+            let key = msg.get_msg_key();
+
+            if self.last_backfilled.as_ref() < Some(&key) {
+                self.backfilled.clear();
+                self.last_backfilled = None;
+
+                return Ok(msg);
+            }
+
+            if !self.backfilled.remove(&key) {
+                return Ok(msg);
+            }
+        }
     }
 }
 
+// `history` in ascending key order, `live` subscribed before it was queried.
 pub fn observer(history: Vec<ObserveMsg>, live: Receiver<ObserveMsg>, link: bool) -> Observer {
+    let backfilled: HashSet<_> = history.iter().map(ObserveMsg::get_msg_key).collect();
+    let last_backfilled = backfilled.iter().max().cloned();
+
     Observer {
         history,
         live,
         link,
+        backfilled,
+        last_backfilled,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::broadcast;
+
+    fn hello(key: &str) -> ObserveMsg {
+        let client_id = surrealdb::RecordId::from(("clients", key)).into();
+        let client_info = ClientInfo {
+            hello_timestamp: Local::now(),
+            hello_msg: HelloMsg {
+                client_name: key.into(),
+                proc_env: None,
+            },
+            client_role: Role::host(),
+            client_addr: None,
+        };
+
+        ObserveMsg::OnClientHello(client_id, client_info)
+    }
+
+    #[tokio::test]
+    async fn dedups_until_newer_than_history() {
+        let (br_send, live) = broadcast::channel(16);
+        let mut observer = observer(vec![hello("a"), hello("c")], live, false);
+
+        for key in ["b", "c", "d", "a"] {
+            br_send.send(hello(key)).unwrap();
+        }
+
+        assert_eq!(observer.history().len(), 2);
+        assert_eq!(observer.next_live().await.unwrap().get_msg_key(), "b");
+        // "c" was backfilled, "d" is newer than the history, so "a" is no longer deduped
+        assert_eq!(observer.next_live().await.unwrap().get_msg_key(), "d");
+        assert_eq!(observer.next_live().await.unwrap().get_msg_key(), "a");
+        assert!(observer.backfilled.is_empty());
     }
 }
@@ -31,10 +31,12 @@ pub trait QueryMap {
     fn with_token(self, token: &str) -> Self;
     fn with_msg_format(self, format: MsgFormat) -> Self;
     fn with_query_history(self, query_history: QueryHistory) -> Self;
+    fn with_client_key(self, client_key: &str) -> Self;
 
     fn get_token(&self) -> Option<String>;
     fn get_msg_format(&self) -> MsgFormat;
     fn get_query_history(&self) -> QueryHistory;
+    fn get_client_key(&self) -> Option<String>;
 
     fn parse_or_default<'a, T>(&'a self, key: &str) -> T
     where
@@ -57,6 +59,11 @@ impl QueryMap for IndexMap<String, String> {
         self
     }
 
+    fn with_client_key(mut self, client_key: &str) -> Self {
+        self.insert("client".into(), client_key.into());
+        self
+    }
+
     fn get_token(&self) -> Option<String> {
         self.get("token").cloned()
     }
@@ -69,6 +76,10 @@ impl QueryMap for IndexMap<String, String> {
         self.parse_or_default("history")
     }
 
+    fn get_client_key(&self) -> Option<String> {
+        self.get("client").cloned()
+    }
+
     fn parse_or_default<'a, T>(&'a self, key: &str) -> T
     where
         T: Default + Deserialize<'a>,
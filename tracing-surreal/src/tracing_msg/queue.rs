@@ -0,0 +1,386 @@
+use super::TracingMsg;
+use std::{
+    cell::Cell,
+    collections::VecDeque,
+    future::Future,
+    num::{NonZeroU32, NonZeroUsize},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Condvar, Mutex, MutexGuard, PoisonError,
+    },
+    task::{Context, Poll},
+};
+use tokio::sync::Notify;
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum OverflowPolicy {
+    #[default]
+    DropNewest,
+    DropOldest,
+    // Blocks the thread that emits the tracing data until the routine catches up,
+    // so it must not be used on a `current_thread` runtime.
+    // The routine's own task (including the transport, while the routine polls it) drops the newest
+    // instead of blocking. Tracing emitted by the tasks or threads a transport spawns is not exempt:
+    // if the routine waits on them, filter their targets out, or use another policy.
+    Block,
+    // Keeps one out of every `n` overflowing messages (evicting the oldest one),
+    // and drops the rest.
+    Sample(NonZeroU32),
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    dropped_newest: AtomicU64,
+    dropped_oldest: AtomicU64,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DropCounters(Arc<Counters>);
+
+impl DropCounters {
+    pub fn dropped_newest(&self) -> u64 {
+        self.0.dropped_newest.load(Ordering::Relaxed)
+    }
+
+    pub fn dropped_oldest(&self) -> u64 {
+        self.0.dropped_oldest.load(Ordering::Relaxed)
+    }
+
+    pub fn total(&self) -> u64 {
+        self.dropped_newest() + self.dropped_oldest()
+    }
+
+    fn drop_newest(&self) {
+        self.0.dropped_newest.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn drop_oldest(&self) {
+        self.0.dropped_oldest.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+thread_local! {
+    // Set while the routine is being polled, see `Routine`.
+    static IN_ROUTINE: Cell<bool> = const { Cell::new(false) };
+}
+
+// Wraps the routine, so that the tracing it emits never blocks on the queue it's meant to drain.
+pub(crate) struct Routine<F>(Pin<Box<F>>);
+
+impl<F: Future> Routine<F> {
+    pub(crate) fn new(future: F) -> Self {
+        Self(Box::pin(future))
+    }
+}
+
+impl<F: Future> Future for Routine<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Restores the flag even if the routine panics.
+        struct Reset(bool);
+
+        impl Drop for Reset {
+            fn drop(&mut self) {
+                IN_ROUTINE.set(self.0);
+            }
+        }
+
+        let _reset = Reset(IN_ROUTINE.replace(true));
+        self.0.as_mut().poll(cx)
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    queue: VecDeque<TracingMsg>,
+    senders: usize,
+    closed: bool,
+    overflowed: u64,
+}
+
+#[derive(Debug)]
+struct Shared {
+    state: Mutex<State>,
+    capacity: Option<NonZeroUsize>,
+    policy: OverflowPolicy,
+    not_full: Condvar,
+    not_empty: Notify,
+    counters: DropCounters,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct MsgSender(Arc<Shared>);
+
+impl MsgSender {
+    pub(crate) fn send(&self, msg: TracingMsg) {
+        let shared = &self.0;
+        let mut state = shared.lock();
+
+        if let Some(capacity) = shared.capacity {
+            while !state.closed && state.queue.len() >= capacity.get() {
+                match shared.policy {
+                    OverflowPolicy::DropNewest => {
+                        shared.counters.drop_newest();
+                        return;
+                    }
+                    OverflowPolicy::DropOldest => {
+                        state.queue.pop_front();
+                        shared.counters.drop_oldest();
+                    }
+                    OverflowPolicy::Block if IN_ROUTINE.get() => {
+                        shared.counters.drop_newest();
+                        return;
+                    }
+                    OverflowPolicy::Block => {
+                        state = shared
+                            .not_full
+                            .wait(state)
+                            .unwrap_or_else(PoisonError::into_inner);
+                    }
+                    OverflowPolicy::Sample(n) => {
+                        state.overflowed += 1;
+
+                        if state.overflowed % u64::from(n.get()) != 0 {
+                            shared.counters.drop_newest();
+                            return;
+                        }
+
+                        state.queue.pop_front();
+                        shared.counters.drop_oldest();
+                    }
+                }
+            }
+        }
+
+        Self::push(shared, state, msg);
+    }
+
+    fn push(shared: &Shared, mut state: MutexGuard<'_, State>, msg: TracingMsg) {
+        // The routine is flushing or gone, so nothing will be delivered.
+        if state.closed {
+            shared.counters.drop_newest();
+            return;
+        }
+
+        state.queue.push_back(msg);
+        drop(state);
+        shared.not_empty.notify_one();
+    }
+}
+
+impl Clone for MsgSender {
+    fn clone(&self) -> Self {
+        self.0.lock().senders += 1;
+        Self(self.0.clone())
+    }
+}
+
+impl Drop for MsgSender {
+    fn drop(&mut self) {
+        let mut state = self.0.lock();
+        state.senders -= 1;
+
+        if state.senders == 0 {
+            drop(state);
+            self.0.not_empty.notify_one();
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct MsgReceiver {
+    shared: Arc<Shared>,
+    reported: u64,
+}
+
+impl MsgReceiver {
+    pub(crate) async fn recv(&mut self) -> Option<TracingMsg> {
+        loop {
+            {
+                let mut state = self.shared.lock();
+
+                if let Some(msg) = state.queue.pop_front() {
+                    drop(state);
+                    self.shared.not_full.notify_one();
+                    return Some(msg);
+                }
+
+                if state.senders == 0 {
+                    return None;
+                }
+            }
+
+            self.shared.not_empty.notified().await;
+        }
+    }
+
+    pub(crate) fn try_recv(&mut self) -> Option<TracingMsg> {
+        let msg = self.shared.lock().queue.pop_front();
+
+        if msg.is_some() {
+            self.shared.not_full.notify_one();
+        }
+
+        msg
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.shared.lock().closed = true;
+        self.shared.not_full.notify_all();
+    }
+
+    // Returns the number of messages dropped since the last report,
+    // but only once the queue has been drained.
+    pub(crate) fn take_unreported_drops(&mut self) -> u64 {
+        if !self.shared.lock().queue.is_empty() {
+            return 0;
+        }
+
+        let total = self.shared.counters.total();
+        let unreported = total - self.reported;
+        self.reported = total;
+        unreported
+    }
+}
+
+impl Drop for MsgReceiver {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+pub(crate) fn msg_queue(
+    capacity: Option<NonZeroUsize>,
+    policy: OverflowPolicy,
+) -> (MsgSender, MsgReceiver, DropCounters) {
+    let counters = DropCounters::default();
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::new(),
+            senders: 1,
+            closed: false,
+            overflowed: 0,
+        }),
+        capacity,
+        policy,
+        not_full: Condvar::new(),
+        not_empty: Notify::new(),
+        counters: counters.clone(),
+    });
+    let receiver = MsgReceiver {
+        shared: shared.clone(),
+        reported: 0,
+    };
+
+    (MsgSender(shared), receiver, counters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing_msg::{MsgBody, SpanId};
+    use std::{num::NonZeroU64, thread, time::Duration};
+
+    fn msg(n: u64) -> TracingMsg {
+        let span_id = SpanId(NonZeroU64::new(n).unwrap());
+        MsgBody::OnEnter { span_id }.into()
+    }
+
+    fn span_ids(recv: &mut MsgReceiver) -> Vec<u64> {
+        let mut span_ids = Vec::new();
+
+        while let Some(msg) = recv.try_recv() {
+            if let MsgBody::OnEnter { span_id } = msg.body {
+                span_ids.push(span_id.0.get());
+            }
+        }
+
+        span_ids
+    }
+
+    fn fill(policy: OverflowPolicy, len: u64) -> (MsgReceiver, DropCounters) {
+        let (send, recv, counters) = msg_queue(NonZeroUsize::new(2), policy);
+
+        for n in 1..=len {
+            send.send(msg(n));
+        }
+
+        (recv, counters)
+    }
+
+    #[test]
+    fn overflow_policies() {
+        let (mut recv, counters) = fill(OverflowPolicy::DropNewest, 5);
+        assert_eq!(span_ids(&mut recv), [1, 2]);
+        assert_eq!(
+            (counters.dropped_newest(), counters.dropped_oldest()),
+            (3, 0)
+        );
+
+        let (mut recv, counters) = fill(OverflowPolicy::DropOldest, 5);
+        assert_eq!(span_ids(&mut recv), [4, 5]);
+        assert_eq!(
+            (counters.dropped_newest(), counters.dropped_oldest()),
+            (0, 3)
+        );
+
+        let (mut recv, counters) = fill(OverflowPolicy::Sample(NonZeroU32::new(2).unwrap()), 6);
+        assert_eq!(span_ids(&mut recv), [4, 6]);
+        assert_eq!(
+            (counters.dropped_newest(), counters.dropped_oldest()),
+            (2, 2)
+        );
+        assert_eq!(recv.take_unreported_drops(), 4);
+        assert_eq!(recv.take_unreported_drops(), 0);
+    }
+
+    #[test]
+    fn block_waits_for_the_receiver() {
+        let (send, mut recv, counters) = msg_queue(NonZeroUsize::new(1), OverflowPolicy::Block);
+        send.send(msg(1));
+
+        let sender = thread::spawn(move || send.send(msg(2)));
+        thread::sleep(Duration::from_millis(50));
+        assert!(!sender.is_finished());
+
+        assert_eq!(span_ids(&mut recv), [1]);
+        sender.join().unwrap();
+        assert_eq!(span_ids(&mut recv), [2]);
+        assert_eq!(counters.total(), 0);
+    }
+
+    #[tokio::test]
+    async fn block_never_blocks_the_routine() {
+        let (send, mut recv, counters) = msg_queue(NonZeroUsize::new(1), OverflowPolicy::Block);
+        send.send(msg(1));
+
+        Routine::new(async { send.send(msg(2)) }).await;
+        assert_eq!(counters.dropped_newest(), 1);
+        assert_eq!(span_ids(&mut recv), [1]);
+    }
+
+    #[test]
+    fn sends_after_close_are_dropped() {
+        let (send, mut recv, counters) = msg_queue(NonZeroUsize::new(1), OverflowPolicy::Block);
+        send.send(msg(1));
+
+        let sender = thread::spawn(move || {
+            send.send(msg(2));
+            send.send(msg(3));
+        });
+        thread::sleep(Duration::from_millis(50));
+        recv.close();
+        sender.join().unwrap();
+
+        assert_eq!(span_ids(&mut recv), [1]);
+        assert_eq!(counters.dropped_newest(), 2);
+    }
+}
@@ -0,0 +1,161 @@
+use super::{MsgBody, Payload, Structured, Value};
+use regex::Regex;
+use sha2::{Digest, Sha256};
+
+const REDACTED: &str = "[redacted]";
+
+// Applied in this order: deny list, hashing, regex scrubbing (then `truncate_values`, see limit.rs).
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Redaction {
+    pub(crate) deny_fields: Vec<String>,
+    pub(crate) hash_fields: Vec<String>,
+    pub(crate) patterns: Vec<(Regex, String)>,
+}
+
+impl Redaction {
+    pub(crate) fn is_noop(&self) -> bool {
+        self.deny_fields.is_empty() && self.hash_fields.is_empty() && self.patterns.is_empty()
+    }
+
+    pub(crate) fn redact(&self, body: &mut MsgBody) {
+        body.map_payload(|payload| self.redact_payload(payload));
+    }
+
+    fn redact_payload(&self, payload: &mut Payload) {
+        for (key, values) in payload.0.iter_mut() {
+            let deny = matches(&self.deny_fields, key);
+            let hash = matches(&self.hash_fields, key);
+
+            for value in values.iter_mut() {
+                if deny {
+                    *value = Value::String(REDACTED.into());
+                } else if hash {
+                    *value = Value::String(format!("sha256:{:x}", Sha256::digest(bytes(value))));
+                } else {
+                    self.scrub(value);
+                }
+            }
+        }
+    }
+
+    fn scrub(&self, value: &mut Value) {
+        match value {
+            Value::Debug(text) | Value::String(text) | Value::Error(text) => self.scrub_text(text),
+            Value::Map(map) => map.iter_mut().for_each(|(k, v)| self.redact_nested(k, v)),
+            Value::List(list) => list.iter_mut().for_each(|v| self.scrub_nested(v)),
+            _ => (),
+        }
+    }
+
+    fn scrub_text(&self, text: &mut String) {
+        for (regex, replacement) in &self.patterns {
+            *text = regex.replace_all(text, replacement.as_str()).into_owned();
+        }
+    }
+
+    // The field names are matched against the keys of nested maps as well.
+    fn redact_nested(&self, key: &str, value: &mut Structured) {
+        if matches(&self.deny_fields, key) {
+            *value = Structured::String(REDACTED.into());
+        } else if matches(&self.hash_fields, key) {
+            let bytes = serde_json::to_vec(value).unwrap_or_default();
+            *value = Structured::String(format!("sha256:{:x}", Sha256::digest(bytes)));
+        } else {
+            self.scrub_nested(value);
+        }
+    }
+
+    fn scrub_nested(&self, value: &mut Structured) {
+        match value {
+            Structured::String(text) => self.scrub_text(text),
+            Structured::Map(map) => map.iter_mut().for_each(|(k, v)| self.redact_nested(k, v)),
+            Structured::List(list) => list.iter_mut().for_each(|v| self.scrub_nested(v)),
+            _ => (),
+        }
+    }
+}
+
+// Field names are matched case insensitively.
+fn matches(fields: &[String], key: &str) -> bool {
+    fields.iter().any(|field| field.eq_ignore_ascii_case(key))
+}
+
+fn bytes(value: &Value) -> Vec<u8> {
+    match value {
+        Value::Debug(text) | Value::String(text) | Value::Error(text) => text.as_bytes().into(),
+        Value::Bytes(bytes) => bytes.clone(),
+        Value::F64(value) => value.to_string().into_bytes(),
+        Value::I64(value) => value.to_string().into_bytes(),
+        Value::U64(value) => value.to_string().into_bytes(),
+        Value::I128(value) => value.to_string().into_bytes(),
+        Value::U128(value) => value.to_string().into_bytes(),
+        Value::Bool(value) => value.to_string().into_bytes(),
+        Value::Truncated { partial, .. } => bytes(partial),
+        Value::Map(value) => serde_json::to_vec(value).unwrap_or_default(),
+        Value::List(value) => serde_json::to_vec(value).unwrap_or_default(),
+        Value::Null => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing_msg::in_memory::capture;
+    use indexmap::IndexMap;
+
+    #[tokio::test]
+    async fn redacts_spans_records_and_events() {
+        let transport = capture(
+            |builder| {
+                builder
+                    .redact_fields(["Password"])
+                    .hash_fields(["user"])
+                    .scrub_pattern(r"\w+@example\.com", "<email>")
+                    .unwrap()
+            },
+            |_| async {
+                let span =
+                    tracing::info_span!("login", user = "alice", password = tracing::field::Empty);
+                span.record("password", "hunter2");
+                span.in_scope(|| {
+                    tracing::info!(password = "hunter2", "mail sent to bob@example.com")
+                });
+            },
+        )
+        .await;
+
+        let redacted = Value::String(REDACTED.into());
+        let hashed = format!("sha256:{:x}", Sha256::digest("alice"));
+        let span = transport.span("login").unwrap();
+        assert_eq!(span.field("user"), Some(&Value::String(hashed)));
+        assert_eq!(span.field("password"), Some(&redacted));
+
+        transport
+            .assert_event("mail sent to <email>")
+            .assert_field("password", &redacted);
+    }
+
+    #[test]
+    fn nested_maps_are_redacted_too() {
+        let redaction = Redaction {
+            deny_fields: vec!["token".into()],
+            patterns: vec![(Regex::new(r"\d{4}").unwrap(), "****".into())],
+            ..Default::default()
+        };
+        let nested = IndexMap::from([
+            ("token".into(), Structured::String("secret".into())),
+            ("card".into(), Structured::String("card 1234".into())),
+        ]);
+        let mut payload = Payload(IndexMap::from([(
+            "request".into(),
+            vec![Value::Map(nested)],
+        )]));
+        redaction.redact_payload(&mut payload);
+
+        let expected = IndexMap::from([
+            ("token".into(), Structured::String(REDACTED.into())),
+            ("card".into(), Structured::String("card ****".into())),
+        ]);
+        assert_eq!(payload["request"], [Value::Map(expected)]);
+    }
+}
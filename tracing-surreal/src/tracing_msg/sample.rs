@@ -0,0 +1,225 @@
+use super::Sampling;
+use std::{
+    collections::HashMap,
+    mem,
+    num::NonZeroU32,
+    sync::{Mutex, PoisonError},
+    time::Instant,
+};
+use tracing_core::{callsite::Identifier, Metadata};
+use tracing_subscriber::filter::LevelFilter;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct RateLimit {
+    pub(crate) per_second: NonZeroU32,
+    pub(crate) burst: NonZeroU32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct SamplingConfig {
+    pub(crate) ratio: f64,
+    pub(crate) rate_limit: Option<RateLimit>,
+    pub(crate) always_keep: LevelFilter,
+}
+
+impl Default for SamplingConfig {
+    fn default() -> Self {
+        Self {
+            ratio: 1.0,
+            rate_limit: None,
+            always_keep: LevelFilter::OFF,
+        }
+    }
+}
+
+impl SamplingConfig {
+    pub(crate) fn is_noop(&self) -> bool {
+        self.ratio >= 1.0 && self.rate_limit.is_none()
+    }
+}
+
+// Marks the spans dropped by the sampler in their extensions,
+// so that their records, enters etc. get dropped as well.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct SampledOut;
+
+// The head decision of a span, inherited by its children whether the span itself is kept or not
+// (a span forced by the level override doesn't bring its children back in).
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct HeadSampled(pub(crate) bool);
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last: Instant,
+    suppressed: u64,
+}
+
+#[derive(Debug)]
+pub(crate) struct Sampler {
+    config: SamplingConfig,
+    buckets: Mutex<HashMap<Identifier, Bucket>>,
+}
+
+impl Sampler {
+    pub(crate) fn new(config: SamplingConfig) -> Self {
+        Self {
+            config,
+            buckets: Default::default(),
+        }
+    }
+
+    // `parent` is the head decision of the enclosing span (if any).
+    pub(crate) fn head(&self, parent: Option<bool>) -> bool {
+        parent.unwrap_or_else(|| fastrand::f64() < self.config.ratio)
+    }
+
+    // Returns `None` if the span or event should be dropped.
+    pub(crate) fn sample(&self, metadata: &Metadata<'_>, head: bool) -> Option<Sampling> {
+        let ratio = self.config.ratio;
+
+        if *metadata.level() <= self.config.always_keep {
+            return Some(Sampling {
+                ratio,
+                rate_limited: 0,
+                forced: !head,
+            });
+        }
+
+        if !head {
+            return None;
+        }
+
+        let rate_limited = match self.config.rate_limit {
+            None => 0,
+            Some(limit) => self.take_token(metadata.callsite(), limit)?,
+        };
+
+        Some(Sampling {
+            ratio,
+            rate_limited,
+            forced: false,
+        })
+    }
+
+    // Returns how many messages of this callsite were suppressed
+    // since the last one that got through, or `None` if out of tokens.
+    fn take_token(&self, callsite: Identifier, limit: RateLimit) -> Option<u64> {
+        let now = Instant::now();
+        let burst = f64::from(limit.burst.get());
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        let bucket = buckets.entry(callsite).or_insert(Bucket {
+            tokens: burst,
+            last: now,
+            suppressed: 0,
+        });
+
+        let refill = (now - bucket.last).as_secs_f64() * f64::from(limit.per_second.get());
+        bucket.tokens = (bucket.tokens + refill).min(burst);
+        bucket.last = now;
+
+        if bucket.tokens < 1.0 {
+            bucket.suppressed += 1;
+            return None;
+        }
+
+        bucket.tokens -= 1.0;
+        Some(mem::take(&mut bucket.suppressed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing_msg::{in_memory::capture, MsgBody, TracingMsg};
+    use std::time::Duration;
+
+    fn events(msgs: Vec<TracingMsg>) -> Vec<(String, Option<Sampling>)> {
+        msgs.into_iter()
+            .filter_map(|msg| match msg.body {
+                MsgBody::OnEvent { message, .. } => Some((message, msg.sampling)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn sampled_out_spans_drop_their_children() {
+        let transport = capture(
+            |builder| {
+                builder
+                    .sample_ratio(0.0)
+                    .always_keep_level(LevelFilter::WARN)
+            },
+            |_| async {
+                tracing::info_span!("request").in_scope(|| {
+                    tracing::info!("dropped");
+                    tracing::warn!("kept");
+                });
+                tracing::info!("dropped too");
+            },
+        )
+        .await;
+
+        let forced = Sampling {
+            ratio: 0.0,
+            rate_limited: 0,
+            forced: true,
+        };
+        assert!(transport.span("request").is_none());
+        assert_eq!(events(transport.msgs()), [("kept".into(), Some(forced))]);
+    }
+
+    #[tokio::test]
+    async fn forced_spans_keep_the_head_decision() {
+        let transport = capture(
+            |builder| {
+                builder
+                    .sample_ratio(0.0)
+                    .always_keep_level(LevelFilter::WARN)
+            },
+            |_| async {
+                let root = tracing::info_span!("root");
+                let forced = tracing::warn_span!(parent: &root, "forced");
+                let child = tracing::info_span!(parent: &forced, "child");
+                child.in_scope(|| tracing::info!("dropped"));
+                forced.in_scope(|| tracing::error!("kept"));
+            },
+        )
+        .await;
+
+        // Kept because of the level override, its children still follow the dropped root.
+        assert!(transport.span("forced").is_some());
+        assert!(transport.span("root").is_none());
+        assert!(transport.span("child").is_none());
+
+        let kept: Vec<_> = events(transport.msgs())
+            .into_iter()
+            .map(|(message, sampling)| (message, sampling.unwrap().forced))
+            .collect();
+        assert_eq!(kept, [("kept".to_owned(), true)]);
+    }
+
+    #[tokio::test]
+    async fn rate_limit_counts_the_suppressed_msgs() {
+        let per_second = NonZeroU32::new(10).unwrap();
+        let burst = NonZeroU32::new(2).unwrap();
+        let tick = |n: u32| tracing::info!("tick {}", n);
+        let transport = capture(
+            |builder| builder.rate_limit_per_callsite(per_second, burst),
+            |_| async {
+                (0..5).for_each(tick);
+                tokio::time::sleep(Duration::from_millis(150)).await;
+                tick(5);
+            },
+        )
+        .await;
+
+        let kept: Vec<_> = events(transport.msgs())
+            .into_iter()
+            .map(|(message, sampling)| (message, sampling.unwrap().rate_limited))
+            .collect();
+        let expected = [("tick 0", 0), ("tick 1", 0), ("tick 5", 3)];
+        assert_eq!(kept, expected.map(|(message, n)| (message.to_owned(), n)));
+    }
+}
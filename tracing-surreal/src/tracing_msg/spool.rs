@@ -0,0 +1,430 @@
+use super::TracingMsg;
+use std::{
+    collections::VecDeque,
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+use tokio::task::spawn_blocking;
+
+// Frames are `u32` (little endian) length prefixed MessagePack, because bincode
+// cannot encode the flattened & internally tagged `MsgBody` of `TracingMsg`.
+const EXTENSION: &str = "spool";
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct SpoolConfig {
+    pub(crate) dir: PathBuf,
+    pub(crate) max_segment_bytes: u64,
+    pub(crate) max_total_bytes: u64,
+    pub(crate) replay_interval: Duration,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Segment {
+    id: u64,
+    bytes: u64,
+    frames: u64,
+    // Delivered frames, the oldest ones of the segment
+    acked: u64,
+}
+
+impl Segment {
+    fn empty(id: u64) -> Self {
+        Self {
+            id,
+            bytes: 0,
+            frames: 0,
+            acked: 0,
+        }
+    }
+
+    fn unacked(&self) -> u64 {
+        self.frames - self.acked
+    }
+}
+
+fn encode(msg: &TracingMsg) -> io::Result<Vec<u8>> {
+    let frame = rmp_serde::to_vec_named(msg).map_err(io::Error::other)?;
+    let len = u32::try_from(frame.len()).map_err(io::Error::other)?;
+    let mut buf = Vec::with_capacity(frame.len() + 4);
+    buf.extend_from_slice(&len.to_le_bytes());
+    buf.extend_from_slice(&frame);
+    Ok(buf)
+}
+
+fn split_frames(data: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let mut rest = data;
+
+    std::iter::from_fn(move || {
+        let len = u32::from_le_bytes(rest.get(..4)?.try_into().ok()?) as usize;
+        let frame = rest.get(4..4 + len)?;
+        rest = &rest[4 + len..];
+        Some(frame)
+    })
+}
+
+#[derive(Debug)]
+struct Inner {
+    config: SpoolConfig,
+    sealed: VecDeque<Segment>,
+    active: Segment,
+    writer: BufWriter<File>,
+    backlog: bool,
+}
+
+impl Inner {
+    fn open(config: SpoolConfig) -> io::Result<Self> {
+        fs::create_dir_all(&config.dir)?;
+
+        let mut ids = Vec::new();
+
+        for entry in fs::read_dir(&config.dir)? {
+            let path = entry?.path();
+
+            if path.extension().is_some_and(|ext| ext == EXTENSION) {
+                if let Some(id) = path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .and_then(|stem| stem.parse().ok())
+                {
+                    ids.push(id);
+                }
+            }
+        }
+
+        ids.sort_unstable();
+
+        let mut sealed = VecDeque::new();
+
+        for id in ids {
+            let path = segment_path(&config.dir, id);
+            let data = fs::read(&path)?;
+            let (bytes, frames) = split_frames(&data).fold((0, 0), |(bytes, frames), frame| {
+                (bytes + 4 + frame.len() as u64, frames + 1)
+            });
+
+            match frames {
+                0 => fs::remove_file(&path)?,
+                _ => sealed.push_back(Segment {
+                    frames,
+                    bytes,
+                    ..Segment::empty(id)
+                }),
+            }
+        }
+
+        let active = Segment::empty(sealed.back().map_or(0, |segment| segment.id + 1));
+        let writer = create_writer(&config.dir, active.id)?;
+        let backlog = !sealed.is_empty();
+
+        Ok(Self {
+            config,
+            sealed,
+            active,
+            writer,
+            backlog,
+        })
+    }
+
+    fn segments(&mut self) -> impl Iterator<Item = &mut Segment> {
+        self.sealed.iter_mut().chain([&mut self.active])
+    }
+
+    fn unacked(&mut self) -> u64 {
+        self.segments().map(|segment| segment.unacked()).sum()
+    }
+
+    fn append(&mut self, frame: Vec<u8>) -> io::Result<u64> {
+        self.writer.write_all(&frame)?;
+        self.writer.flush()?;
+        self.active.bytes += frame.len() as u64;
+        self.active.frames += 1;
+
+        if self.active.bytes >= self.config.max_segment_bytes {
+            self.seal()?;
+        }
+
+        self.enforce_cap()
+    }
+
+    fn ack(&mut self, mut frames: u64) -> io::Result<()> {
+        // Msgs are delivered in the order they were appended.
+        for segment in self.segments() {
+            let acked = frames.min(segment.unacked());
+            segment.acked += acked;
+            frames -= acked;
+        }
+
+        if self.backlog || self.unacked() != 0 {
+            return Ok(());
+        }
+
+        while let Some(segment) = self.sealed.pop_front() {
+            fs::remove_file(segment_path(&self.config.dir, segment.id))?;
+        }
+
+        if self.active.frames != 0 {
+            self.writer.flush()?;
+            self.writer.get_ref().set_len(0)?;
+            self.active = Segment::empty(self.active.id);
+        }
+
+        Ok(())
+    }
+
+    fn next_replay(&mut self) -> io::Result<Option<Vec<TracingMsg>>> {
+        if self.sealed.is_empty() {
+            self.seal()?;
+        }
+
+        let Some(segment) = self.sealed.front() else {
+            self.backlog = false;
+            return Ok(None);
+        };
+
+        let data = fs::read(segment_path(&self.config.dir, segment.id))?;
+
+        Ok(Some(
+            split_frames(&data)
+                .filter_map(|frame| rmp_serde::from_slice(frame).ok())
+                .collect(),
+        ))
+    }
+
+    fn replayed(&mut self) -> io::Result<()> {
+        if let Some(segment) = self.sealed.pop_front() {
+            fs::remove_file(segment_path(&self.config.dir, segment.id))?;
+        }
+
+        if self.sealed.is_empty() && self.active.frames == 0 {
+            self.backlog = false;
+        }
+
+        Ok(())
+    }
+
+    fn seal(&mut self) -> io::Result<()> {
+        if self.active.frames == 0 {
+            return Ok(());
+        }
+
+        self.writer.flush()?;
+        let next = Segment::empty(self.active.id + 1);
+        self.writer = create_writer(&self.config.dir, next.id)?;
+        self.sealed.push_back(self.active);
+        self.active = next;
+        Ok(())
+    }
+
+    // Returns the number of unacked frames evicted, the acked ones are not lost.
+    fn enforce_cap(&mut self) -> io::Result<u64> {
+        let mut total = self.active.bytes + self.sealed.iter().map(|s| s.bytes).sum::<u64>();
+        let mut evicted = 0;
+
+        while total > self.config.max_total_bytes {
+            let Some(segment) = self.sealed.pop_front() else {
+                break;
+            };
+
+            fs::remove_file(segment_path(&self.config.dir, segment.id))?;
+            total -= segment.bytes;
+            evicted += segment.unacked();
+        }
+
+        Ok(evicted)
+    }
+}
+
+// Write-ahead spool: every message is appended before it is delivered,
+// and acknowledged (truncated) once the transport has accepted it.
+// Replay is at-least-once: a segment which was only partially delivered
+// before a crash will be delivered again in full.
+#[derive(Debug)]
+pub(crate) struct Spool(Arc<Mutex<Inner>>);
+
+impl Spool {
+    pub(crate) async fn open(config: SpoolConfig) -> io::Result<Self> {
+        let inner = spawn_blocking(move || Inner::open(config))
+            .await
+            .map_err(io::Error::other)??;
+
+        Ok(Self(Arc::new(Mutex::new(inner))))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    // The file IO runs on the blocking threads, like in `FileSink`.
+    async fn blocking<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut Inner) -> io::Result<R> + Send + 'static,
+    ) -> io::Result<R> {
+        let inner = self.0.clone();
+
+        spawn_blocking(move || f(&mut inner.lock().unwrap_or_else(PoisonError::into_inner)))
+            .await
+            .map_err(io::Error::other)?
+    }
+
+    pub(crate) fn replay_interval(&self) -> Duration {
+        self.lock().config.replay_interval
+    }
+
+    pub(crate) fn backlog(&self) -> bool {
+        self.lock().backlog
+    }
+
+    pub(crate) fn enter_backlog(&mut self) {
+        self.lock().backlog = true;
+    }
+
+    // Returns the number of frames evicted to respect `max_total_bytes`.
+    pub(crate) async fn append(&mut self, msg: &TracingMsg) -> io::Result<u64> {
+        let frame = encode(msg)?;
+        self.blocking(|inner| inner.append(frame)).await
+    }
+
+    pub(crate) async fn ack(&mut self, frames: u64) -> io::Result<()> {
+        self.blocking(move |inner| inner.ack(frames)).await
+    }
+
+    pub(crate) async fn next_replay(&mut self) -> io::Result<Option<Vec<TracingMsg>>> {
+        self.blocking(Inner::next_replay).await
+    }
+
+    pub(crate) async fn replayed(&mut self) -> io::Result<()> {
+        self.blocking(Inner::replayed).await
+    }
+}
+
+fn segment_path(dir: &Path, id: u64) -> PathBuf {
+    dir.join(format!("{:020}.{}", id, EXTENSION))
+}
+
+fn create_writer(dir: &Path, id: u64) -> io::Result<BufWriter<File>> {
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(segment_path(dir, id))?;
+
+    Ok(BufWriter::new(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing_msg::{MsgBody, SpanId};
+    use std::num::NonZeroU64;
+
+    fn config(max_segment_bytes: u64, max_total_bytes: u64) -> SpoolConfig {
+        SpoolConfig {
+            dir: std::env::temp_dir().join(format!("tracing-surreal-{}", ulid::Ulid::new())),
+            max_segment_bytes,
+            max_total_bytes,
+            replay_interval: Duration::from_secs(1),
+        }
+    }
+
+    fn enter(span: u64) -> TracingMsg {
+        let span_id = SpanId(NonZeroU64::new(span).unwrap());
+        MsgBody::OnEnter { span_id }.into()
+    }
+
+    async fn replay_all(spool: &mut Spool) -> Vec<TracingMsg> {
+        let mut msgs = Vec::new();
+
+        while let Some(replay) = spool.next_replay().await.unwrap() {
+            msgs.extend(replay);
+            spool.replayed().await.unwrap();
+        }
+
+        msgs
+    }
+
+    #[tokio::test]
+    async fn acked_msgs_are_not_replayed() {
+        let config = config(1 << 20, 1 << 24);
+        let mut spool = Spool::open(config.clone()).await.unwrap();
+
+        for span in 1..=3 {
+            spool.append(&enter(span)).await.unwrap();
+        }
+
+        spool.ack(3).await.unwrap();
+        drop(spool);
+
+        let mut spool = Spool::open(config.clone()).await.unwrap();
+        assert!(!spool.backlog());
+        assert!(replay_all(&mut spool).await.is_empty());
+        fs::remove_dir_all(config.dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn unacked_msgs_are_replayed_after_reopen() {
+        let config = config(1 << 20, 1 << 24);
+        let mut spool = Spool::open(config.clone()).await.unwrap();
+        let msgs: Vec<_> = (1..=3).map(enter).collect();
+
+        for msg in &msgs {
+            spool.append(msg).await.unwrap();
+        }
+
+        // Partially delivered, so the whole segment is replayed (at-least-once).
+        spool.ack(1).await.unwrap();
+        drop(spool);
+
+        let mut spool = Spool::open(config.clone()).await.unwrap();
+        assert!(spool.backlog());
+        assert_eq!(replay_all(&mut spool).await, msgs);
+        assert!(!spool.backlog());
+        fs::remove_dir_all(config.dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn oldest_segments_are_evicted() {
+        let frame_bytes = encode(&enter(1)).unwrap().len() as u64;
+        // Every frame seals its own segment, and only two of them fit.
+        let config = config(1, 2 * frame_bytes);
+        let mut spool = Spool::open(config.clone()).await.unwrap();
+        let mut evicted = 0;
+
+        for span in 1..=5 {
+            evicted += spool.append(&enter(span)).await.unwrap();
+        }
+
+        assert_eq!(evicted, 3);
+
+        spool.enter_backlog();
+        let replayed: Vec<_> = replay_all(&mut spool)
+            .await
+            .into_iter()
+            .map(|msg| msg.body)
+            .collect();
+        let expected: Vec<_> = [4, 5].map(|span| enter(span).body).into();
+        assert_eq!(replayed, expected);
+        fs::remove_dir_all(config.dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn acked_frames_are_not_counted_as_evicted() {
+        let frame_bytes = encode(&enter(1)).unwrap().len() as u64;
+        let config = config(1, 2 * frame_bytes);
+        let mut spool = Spool::open(config.clone()).await.unwrap();
+        spool.append(&enter(1)).await.unwrap();
+        spool.append(&enter(2)).await.unwrap();
+        spool.ack(1).await.unwrap();
+
+        // Evicts the acked segment of 1, then the unacked one of 2.
+        assert_eq!(spool.append(&enter(3)).await.unwrap(), 0);
+        assert_eq!(spool.append(&enter(4)).await.unwrap(), 1);
+        assert_eq!(spool.lock().unacked(), 2);
+
+        spool.ack(2).await.unwrap();
+        assert_eq!(spool.lock().unacked(), 0);
+        assert!(replay_all(&mut spool).await.is_empty());
+        fs::remove_dir_all(config.dir).unwrap();
+    }
+}
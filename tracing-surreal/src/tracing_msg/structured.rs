@@ -0,0 +1,244 @@
+use super::Structured;
+use indexmap::IndexMap;
+use valuable::{Fields, NamedValues, Valuable, Value, Visit};
+
+#[derive(Default)]
+struct ListVisitor(Vec<Structured>);
+
+impl Visit for ListVisitor {
+    fn visit_value(&mut self, value: Value<'_>) {
+        self.0.push(value.into());
+    }
+
+    fn visit_unnamed_fields(&mut self, values: &[Value<'_>]) {
+        self.0
+            .extend(values.iter().map(|value| Structured::from(*value)));
+    }
+}
+
+#[derive(Default)]
+struct MapVisitor(IndexMap<String, Structured>);
+
+impl Visit for MapVisitor {
+    fn visit_value(&mut self, value: Value<'_>) {
+        if let Value::Structable(value) = value {
+            value.visit(self);
+        }
+    }
+
+    fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+        for (field, value) in named_values {
+            self.0.insert(field.name().into(), (*value).into());
+        }
+    }
+
+    fn visit_entry(&mut self, key: Value<'_>, value: Value<'_>) {
+        let key = match key.into() {
+            Structured::String(key) => key,
+            key => serde_json::to_string(&key).unwrap_or_default(),
+        };
+
+        self.0.insert(key, value.into());
+    }
+}
+
+// Structs & maps become `Map`, tuples & lists become `List`,
+// and enums are externally tagged (like serde does by default).
+fn fields_of(fields: &Fields<'_>, value: &dyn Valuable) -> Structured {
+    match fields {
+        Fields::Named(_) => {
+            let mut visitor = MapVisitor::default();
+            value.visit(&mut visitor);
+            Structured::Map(visitor.0)
+        }
+        Fields::Unnamed(_) => {
+            let mut visitor = ListVisitor::default();
+            value.visit(&mut visitor);
+            Structured::List(visitor.0)
+        }
+    }
+}
+
+// Small unsigned integers are kept signed, so that they roundtrip through the untagged `Structured`.
+fn unsigned(value: u128) -> Structured {
+    i64::try_from(value)
+        .map(Structured::I64)
+        .or_else(|_| u64::try_from(value).map(Structured::U64))
+        .unwrap_or_else(|_| Structured::String(value.to_string()))
+}
+
+impl From<Value<'_>> for Structured {
+    fn from(value: Value<'_>) -> Self {
+        match value {
+            Value::Bool(value) => Self::Bool(value),
+            Value::Char(value) => Self::String(value.into()),
+            Value::F32(value) => Self::F64(value.into()),
+            Value::F64(value) => Self::F64(value),
+            Value::I8(value) => Self::I64(value.into()),
+            Value::I16(value) => Self::I64(value.into()),
+            Value::I32(value) => Self::I64(value.into()),
+            Value::I64(value) => Self::I64(value),
+            Value::I128(value) => {
+                i64::try_from(value).map_or(Self::String(value.to_string()), Self::I64)
+            }
+            Value::Isize(value) => Self::I64(value as i64),
+            Value::U8(value) => Self::I64(value.into()),
+            Value::U16(value) => Self::I64(value.into()),
+            Value::U32(value) => Self::I64(value.into()),
+            Value::U64(value) => unsigned(value.into()),
+            Value::U128(value) => unsigned(value),
+            Value::Usize(value) => unsigned(value as u128),
+            Value::String(value) => Self::String(value.into()),
+            Value::Path(value) => Self::String(value.display().to_string()),
+            Value::Error(value) => Self::String(value.to_string()),
+            Value::Listable(value) => {
+                let mut visitor = ListVisitor::default();
+                value.visit(&mut visitor);
+                Self::List(visitor.0)
+            }
+            Value::Mappable(value) => {
+                let mut visitor = MapVisitor::default();
+                value.visit(&mut visitor);
+                Self::Map(visitor.0)
+            }
+            Value::Structable(value) => fields_of(value.definition().fields(), &value),
+            Value::Enumerable(value) => {
+                let variant = value.variant();
+
+                match variant.fields() {
+                    Fields::Named(&[]) | Fields::Unnamed(0) => Self::String(variant.name().into()),
+                    fields => Self::Map(IndexMap::from([(
+                        variant.name().into(),
+                        fields_of(fields, &value),
+                    )])),
+                }
+            }
+            Value::Tuplable(value) => {
+                let mut visitor = ListVisitor::default();
+                value.visit(&mut visitor);
+                Self::List(visitor.0)
+            }
+            Value::Unit => Self::Null,
+            // `valuable::Value` is non exhaustive
+            _ => Self::Null,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing_msg::{
+        in_memory::capture, MsgBody, Payload, TracingMsg, Value as FieldValue,
+    };
+    use serde_json::json;
+
+    #[derive(Valuable)]
+    struct User {
+        id: u64,
+        name: &'static str,
+    }
+
+    #[derive(Valuable)]
+    enum Auth {
+        Anonymous,
+        Token(&'static str, u32),
+        Login { user: User },
+    }
+
+    #[derive(Valuable)]
+    struct Request {
+        user: User,
+        tags: Vec<&'static str>,
+        auth: Vec<Auth>,
+        point: (i8, f64),
+    }
+
+    fn request() -> Request {
+        Request {
+            user: User {
+                id: 42,
+                name: "alice",
+            },
+            tags: vec!["a", "b"],
+            auth: vec![
+                Auth::Anonymous,
+                Auth::Token("secret", 3600),
+                Auth::Login {
+                    user: User { id: 7, name: "bob" },
+                },
+            ],
+            point: (-1, 0.5),
+        }
+    }
+
+    #[test]
+    fn enums_keep_their_fields() {
+        let structured = Structured::from(request().as_value());
+        let json = serde_json::to_value(structured).unwrap();
+
+        assert_eq!(
+            json,
+            json!({
+                "user": { "id": 42, "name": "alice" },
+                "tags": ["a", "b"],
+                "auth": [
+                    "Anonymous",
+                    { "Token": ["secret", 3600] },
+                    { "Login": { "user": { "id": 7, "name": "bob" } } },
+                ],
+                "point": [-1, 0.5],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn nested_fields_have_plain_paths() {
+        let transport = capture(
+            |builder| builder,
+            |_| async { tracing::info!(request = request().as_value(), "handled") },
+        )
+        .await;
+
+        let msg = transport
+            .msgs()
+            .into_iter()
+            .find(
+                |msg| matches!(&msg.body, MsgBody::OnEvent { message, .. } if message == "handled"),
+            )
+            .unwrap();
+        let json = serde_json::to_value(&msg).unwrap();
+        assert_eq!(json["payload"]["request"]["user"]["id"], 42);
+        assert_eq!(
+            json["payload"]["request"]["auth"][2]["Login"]["user"]["name"],
+            "bob"
+        );
+        assert_eq!(json["payload"]["message"][0]["value"], "handled");
+        assert_eq!(serde_json::from_value::<TracingMsg>(json).unwrap(), msg);
+    }
+
+    #[test]
+    fn only_a_single_map_is_stored_bare() {
+        let mut payload = Payload::default();
+        let map = IndexMap::from([("id".into(), Structured::I64(1))]);
+        payload.record("map", FieldValue::Map(map));
+        payload.record("once", FieldValue::I64(1));
+        payload.record("list", FieldValue::List(vec![Structured::I64(1)]));
+        payload.record("twice", FieldValue::I64(1));
+        payload.record("twice", FieldValue::I64(2));
+        payload.insert_empty("empty");
+
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "map": { "id": 1 },
+                "once": [{ "type": "i64", "value": 1 }],
+                "list": [[1]],
+                "twice": [{ "type": "i64", "value": 1 }, { "type": "i64", "value": 2 }],
+                "empty": [],
+            })
+        );
+        assert_eq!(serde_json::from_value::<Payload>(json).unwrap(), payload);
+    }
+}
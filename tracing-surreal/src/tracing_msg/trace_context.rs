@@ -0,0 +1,314 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    num::{NonZeroU128, NonZeroU64, ParseIntError},
+    str::FromStr,
+};
+use thiserror::Error;
+use tracing_core::{dispatcher, field, span::Attributes};
+use tracing_subscriber::{registry::LookupSpan, Registry};
+
+// The span field an incoming W3C `traceparent` header is read from (when the span is created),
+// e.g. `info_span!("request", traceparent = %header)`.
+pub const TRACEPARENT_FIELD: &str = "traceparent";
+
+const SAMPLED_FLAG: u8 = 0x01;
+
+// Serialized as hex (W3C / OpenTelemetry format) since SurrealDB integers are `i64`.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[serde(into = "String", try_from = "String")]
+pub struct TraceId(pub NonZeroU128);
+
+impl TraceId {
+    pub fn random() -> Self {
+        Self(NonZeroU128::MIN.saturating_add(fastrand::u128(..u128::MAX)))
+    }
+}
+
+impl fmt::Display for TraceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:032x}", self.0)
+    }
+}
+
+impl From<TraceId> for String {
+    fn from(value: TraceId) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<String> for TraceId {
+    type Error = TraceparentError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl FromStr for TraceId {
+    type Err = TraceparentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = u128::from_str_radix(lower_hex(s, 32)?, 16)?;
+        NonZeroU128::new(id)
+            .map(Self)
+            .ok_or(TraceparentError::ZeroId)
+    }
+}
+
+// Unlike `SpanId`, never reused & unique across processes.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[serde(into = "String", try_from = "String")]
+pub struct TraceSpanId(pub NonZeroU64);
+
+impl TraceSpanId {
+    pub fn random() -> Self {
+        Self(NonZeroU64::MIN.saturating_add(fastrand::u64(..u64::MAX)))
+    }
+}
+
+impl fmt::Display for TraceSpanId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl From<TraceSpanId> for String {
+    fn from(value: TraceSpanId) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<String> for TraceSpanId {
+    type Error = TraceparentError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl FromStr for TraceSpanId {
+    type Err = TraceparentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = u64::from_str_radix(lower_hex(s, 16)?, 16)?;
+        NonZeroU64::new(id)
+            .map(Self)
+            .ok_or(TraceparentError::ZeroId)
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TraceparentError {
+    #[error("malformed traceparent")]
+    Format,
+    #[error("unsupported traceparent version: `{0:02x}`")]
+    Version(u8),
+    #[error("all zero trace id or parent id")]
+    ZeroId,
+    #[error("invalid hex: `{0}`")]
+    Hex(#[from] ParseIntError),
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TraceContext {
+    pub trace_id: TraceId,
+    pub span_id: TraceSpanId,
+    // Either the enclosing span, or the remote span from an incoming `traceparent`
+    pub parent_id: Option<TraceSpanId>,
+    pub sampled: bool,
+}
+
+impl TraceContext {
+    pub fn root(sampled: bool) -> Self {
+        Self {
+            trace_id: TraceId::random(),
+            span_id: TraceSpanId::random(),
+            parent_id: None,
+            sampled,
+        }
+    }
+
+    pub fn child(&self, sampled: bool) -> Self {
+        Self {
+            trace_id: self.trace_id,
+            span_id: TraceSpanId::random(),
+            parent_id: Some(self.span_id),
+            sampled,
+        }
+    }
+
+    // The context of the current span, as recorded by `MsgLayer` (requires a `Registry` based subscriber),
+    // format it with `to_string()` to propagate it as an outgoing `traceparent` header.
+    pub fn current() -> Option<Self> {
+        dispatcher::get_default(|dispatch| {
+            let registry = dispatch.downcast_ref::<Registry>()?;
+            let current = dispatch.current_span();
+            let span = registry.span(current.id()?)?;
+            let trace = span.extensions().get::<Self>().copied();
+
+            trace
+        })
+    }
+
+    pub(crate) fn from_attrs(attrs: &Attributes<'_>) -> Option<Self> {
+        let mut visitor = TraceparentVisitor(None);
+        attrs.record(&mut visitor);
+        visitor.0?.parse().ok()
+    }
+}
+
+// `version-trace_id-parent_id-flags`, where versions above `00` may append more fields.
+impl FromStr for TraceContext {
+    type Err = TraceparentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().splitn(5, '-');
+        let mut next = || parts.next().ok_or(TraceparentError::Format);
+        let (version, trace_id, parent_id, flags) = (next()?, next()?, next()?, next()?);
+        let rest = parts.next();
+
+        let version = u8::from_str_radix(lower_hex(version, 2)?, 16)?;
+        match version {
+            0xff => return Err(TraceparentError::Version(version)),
+            0x00 if rest.is_some() => return Err(TraceparentError::Format),
+            _ => (),
+        }
+
+        Ok(Self {
+            trace_id: trace_id.parse()?,
+            span_id: parent_id.parse()?,
+            parent_id: None,
+            sampled: u8::from_str_radix(lower_hex(flags, 2)?, 16)? & SAMPLED_FLAG != 0,
+        })
+    }
+}
+
+impl fmt::Display for TraceContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flags = if self.sampled { SAMPLED_FLAG } else { 0 };
+        write!(f, "00-{}-{}-{:02x}", self.trace_id, self.span_id, flags)
+    }
+}
+
+// The W3C grammar only allows lowercase hex, which `from_str_radix` alone doesn't enforce
+// (it also accepts uppercase digits and a leading `+`).
+fn lower_hex(s: &str, len: usize) -> Result<&str, TraceparentError> {
+    match s.len() == len && s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')) {
+        true => Ok(s),
+        false => Err(TraceparentError::Format),
+    }
+}
+
+struct TraceparentVisitor(Option<String>);
+
+impl field::Visit for TraceparentVisitor {
+    fn record_debug(&mut self, field: &field::Field, value: &dyn fmt::Debug) {
+        if field.name() == TRACEPARENT_FIELD {
+            self.0 = Some(format!("{:?}", value));
+        }
+    }
+
+    fn record_str(&mut self, field: &field::Field, value: &str) {
+        if field.name() == TRACEPARENT_FIELD {
+            self.0 = Some(value.into());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing_msg::{in_memory::capture, MsgBody};
+
+    const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn parses_traceparent() {
+        let trace: TraceContext = TRACEPARENT.parse().unwrap();
+        assert_eq!(trace.to_string(), TRACEPARENT);
+        assert!(trace.sampled && trace.parent_id.is_none());
+
+        // Later versions may append more fields.
+        let trace: TraceContext = format!("01{}-ab", &TRACEPARENT[2..]).parse().unwrap();
+        assert_eq!(trace.to_string(), TRACEPARENT);
+
+        let parse = |s: &str| s.parse::<TraceContext>().unwrap_err();
+        assert_eq!(
+            parse(&format!("ff{}", &TRACEPARENT[2..])),
+            TraceparentError::Version(0xff)
+        );
+        assert_eq!(
+            parse(&format!("{}-ab", TRACEPARENT)),
+            TraceparentError::Format
+        );
+        assert_eq!(
+            parse("00-4bf92f35-00f067aa0ba902b7-01"),
+            TraceparentError::Format
+        );
+        assert_eq!(
+            parse(&format!("00-{}-00f067aa0ba902b7-01", "0".repeat(32))),
+            TraceparentError::ZeroId
+        );
+
+        for invalid in ["xyz9", "4BF9", "+bf9"] {
+            let traceparent = TRACEPARENT.replace("4bf9", invalid);
+            assert_eq!(
+                parse(&traceparent),
+                TraceparentError::Format,
+                "{}",
+                traceparent
+            );
+        }
+
+        assert_eq!(
+            parse(&TRACEPARENT.replace("-01", "-+1")),
+            TraceparentError::Format
+        );
+        assert_eq!(
+            parse(&TRACEPARENT.replace("00f0", "+0f0")),
+            TraceparentError::Format
+        );
+        assert_eq!(
+            parse(&TRACEPARENT.replace("00-4b", "0A-4b")),
+            TraceparentError::Format
+        );
+    }
+
+    #[tokio::test]
+    async fn continues_the_remote_trace() {
+        let mut current = None;
+        let transport = capture(
+            |builder| builder.propagate_trace_context(),
+            |_| async {
+                let span = tracing::info_span!("request", traceparent = TRACEPARENT);
+                current = span.in_scope(|| {
+                    tracing::info_span!("db").in_scope(|| tracing::info!("query"));
+                    TraceContext::current()
+                });
+            },
+        )
+        .await;
+
+        let traces: Vec<_> = transport
+            .msgs()
+            .into_iter()
+            .filter_map(|msg| match msg.body {
+                MsgBody::OnNewSpan { trace, .. } | MsgBody::OnEvent { trace, .. } => trace,
+                _ => None,
+            })
+            .collect();
+        let [request, db, query] = traces[..] else {
+            panic!("unexpected trace contexts: {:?}", traces);
+        };
+        let remote: TraceContext = TRACEPARENT.parse().unwrap();
+
+        assert_eq!(current, Some(request));
+        assert_eq!(request.parent_id, Some(remote.span_id));
+        assert_eq!(db.parent_id, Some(request.span_id));
+        assert_eq!(query, db);
+        assert!([request, db]
+            .iter()
+            .all(|trace| trace.trace_id == remote.trace_id));
+    }
+}
@@ -0,0 +1,557 @@
+use crate::tracing_msg::{
+    query_map::QueryMap, CloseErr, CloseMsg, CloseOk, CloseStats, CloseTransport, HelloMsg,
+    MsgFormat, ProcEnv, PushMsg, TracingMsg,
+};
+use futures::{SinkExt, StreamExt};
+use indexmap::IndexMap;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::{net::TcpStream, sync::Mutex};
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{
+        self,
+        http::StatusCode,
+        protocol::{frame::coding::CloseCode, CloseFrame},
+        Message,
+    },
+    MaybeTlsStream, WebSocketStream,
+};
+use ulid::Ulid;
+
+pub use crate::tracing_msg;
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+#[derive(Error, Debug)]
+pub enum WsPusherError {
+    #[error("websocket error: `{0}`")]
+    Ws(#[from] Box<tungstenite::Error>),
+    #[error("handshake rejected: `{0}` `{1}`")]
+    Rejected(StatusCode, String),
+    #[error("serde_qs error: `{0}`")]
+    Query(#[from] serde_qs::Error),
+    #[error("serde_json error: `{0}`")]
+    Json(#[from] serde_json::Error),
+    #[error("rmp_serde encode error: `{0}`")]
+    MsgpackEncode(#[from] rmp_serde::encode::Error),
+    #[error("rmp_serde decode error: `{0}`")]
+    MsgpackDecode(#[from] rmp_serde::decode::Error),
+    // Bincode cannot encode the flattened & internally tagged `MsgBody` of `TracingMsg`.
+    #[error("unsupported msg format: `{0:?}`")]
+    UnsupportedFormat(MsgFormat),
+    #[error("unexpected frame")]
+    UnexpectedFrame,
+    #[error("server error: `{0}`")]
+    Server(String),
+    #[error("connection closed")]
+    Closed,
+}
+
+impl From<tungstenite::Error> for WsPusherError {
+    fn from(err: tungstenite::Error) -> Self {
+        match err {
+            tungstenite::Error::Http(resp) => {
+                let body = resp.body().as_deref().unwrap_or_default();
+                Self::Rejected(resp.status(), String::from_utf8_lossy(body).into())
+            }
+            err => Self::Ws(Box::new(err)),
+        }
+    }
+}
+
+// Text frames for `Json`, binary frames for `Msgpack`.
+fn encode<T: Serialize>(value: &T, format: MsgFormat) -> Result<Message, WsPusherError> {
+    match format {
+        MsgFormat::Json => Ok(Message::text(serde_json::to_string(value)?)),
+        MsgFormat::Msgpack => Ok(Message::binary(rmp_serde::to_vec_named(value)?)),
+        MsgFormat::Bincode => Err(WsPusherError::UnsupportedFormat(format)),
+    }
+}
+
+fn decode<T: DeserializeOwned>(msg: &Message, format: MsgFormat) -> Result<T, WsPusherError> {
+    match (format, msg) {
+        (MsgFormat::Json, Message::Text(text)) => Ok(serde_json::from_str(text)?),
+        (MsgFormat::Msgpack, Message::Binary(data)) => Ok(rmp_serde::from_slice(data)?),
+        (MsgFormat::Bincode, _) => Err(WsPusherError::UnsupportedFormat(format)),
+        _ => Err(WsPusherError::UnexpectedFrame),
+    }
+}
+
+// What a pusher sends after the query string handshake (which carries the `client_key`):
+// a `Hello` first (again on every reconnect, so that the server can resume the client), then `Msgs`.
+// The `CloseMsg` goes in the close frame, see `close_reason`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PushFrame {
+    Hello {
+        resumed: bool,
+        hello_msg: Box<HelloMsg>,
+    },
+    Msgs(Vec<TracingMsg>),
+}
+
+impl PushFrame {
+    pub fn encode(&self, format: MsgFormat) -> Result<Message, WsPusherError> {
+        encode(self, format)
+    }
+
+    pub fn decode(msg: &Message, format: MsgFormat) -> Result<Self, WsPusherError> {
+        decode(msg, format)
+    }
+}
+
+// The server's reply to every `PushFrame`, so that a push only succeeds once it's stored.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PushAck {
+    Ok,
+    Err(String),
+}
+
+impl PushAck {
+    pub fn encode(&self, format: MsgFormat) -> Result<Message, WsPusherError> {
+        encode(self, format)
+    }
+
+    pub fn decode(msg: &Message, format: MsgFormat) -> Result<Self, WsPusherError> {
+        decode(msg, format)
+    }
+}
+
+// The reason of a close frame is limited to 123 bytes (RFC 6455), so the `CloseMsg` is written as
+// a JSON tuple of its result & stats, cutting the error display short (then the stats) to fit.
+const MAX_CLOSE_REASON: usize = 123;
+
+type CloseReason = (Result<CloseOk, CloseErr>, [u64; 6]);
+
+pub fn close_reason(msg: &CloseMsg) -> String {
+    let stats = msg.stats();
+    let mut reason: CloseReason = (
+        (**msg).clone(),
+        [
+            stats.retries,
+            stats.dead_lettered,
+            stats.replayed,
+            stats.spool_evicted,
+            stats.flushed,
+            stats.abandoned,
+        ],
+    );
+
+    loop {
+        let text = serde_json::to_string(&reason).unwrap_or_default();
+        let over = text.len().saturating_sub(MAX_CLOSE_REASON);
+
+        match &mut reason {
+            _ if over == 0 => return text,
+            (Err(err), _) if !err.display.is_empty() => {
+                let mut len = err.display.len().saturating_sub(over);
+
+                while !err.display.is_char_boundary(len) {
+                    len -= 1;
+                }
+
+                err.display.truncate(len);
+            }
+            (_, stats) if *stats != [0; 6] => *stats = [0; 6],
+            _ => return String::new(),
+        }
+    }
+}
+
+// `None` for an empty (or foreign) reason.
+pub fn parse_close_reason(reason: &str) -> Option<CloseMsg> {
+    let (result, stats) = serde_json::from_str::<CloseReason>(reason).ok()?;
+    let [retries, dead_lettered, replayed, spool_evicted, flushed, abandoned] = stats;
+    let msg = match result {
+        Ok(ok) => CloseMsg::ok(ok),
+        Err(err) => CloseMsg::err(err),
+    };
+
+    Some(msg.with_stats(CloseStats {
+        retries,
+        dead_lettered,
+        replayed,
+        spool_evicted,
+        flushed,
+        abandoned,
+    }))
+}
+
+#[derive(Clone, Debug)]
+pub struct WsPusherBuilder {
+    url: String,
+    client_name: String,
+    token: Option<String>,
+    format: MsgFormat,
+    query_map: IndexMap<String, String>,
+    proc_env: bool,
+}
+
+impl WsPusherBuilder {
+    pub fn token(self, token: &str) -> Self {
+        Self {
+            token: Some(token.into()),
+            ..self
+        }
+    }
+
+    pub fn msg_format(self, format: MsgFormat) -> Self {
+        Self { format, ..self }
+    }
+
+    // Extra query string pairs sent on the handshake.
+    pub fn query_map(self, query_map: IndexMap<String, String>) -> Self {
+        Self { query_map, ..self }
+    }
+
+    pub fn disable_proc_env(self) -> Self {
+        Self {
+            proc_env: false,
+            ..self
+        }
+    }
+
+    pub async fn connect(self) -> Result<WsPusher, WsPusherError> {
+        if self.format == MsgFormat::Bincode {
+            return Err(WsPusherError::UnsupportedFormat(self.format));
+        }
+
+        let client_key = Ulid::new().to_string();
+        let mut query_map = self
+            .query_map
+            .with_msg_format(self.format)
+            .with_client_key(&client_key);
+
+        if let Some(token) = &self.token {
+            query_map = query_map.with_token(token);
+        }
+
+        let url = format!("{}?{}", self.url, serde_qs::to_string(&query_map)?);
+        let proc_env = match self.proc_env {
+            false => None,
+            true => tokio::task::spawn_blocking(ProcEnv::create).await.ok(),
+        };
+        let hello_msg = HelloMsg {
+            client_name: self.client_name,
+            proc_env,
+        };
+        let mut inner = Inner {
+            url,
+            format: self.format,
+            client_key,
+            hello_msg,
+            stream: None,
+            resumed: false,
+        };
+        inner.stream().await?;
+
+        Ok(WsPusher(Arc::new(Mutex::new(inner))))
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    url: String,
+    format: MsgFormat,
+    client_key: String,
+    hello_msg: HelloMsg,
+    stream: Option<WsStream>,
+    resumed: bool,
+}
+
+impl Inner {
+    // Sends a frame & waits for its ack, without reconnecting.
+    async fn send_on(
+        stream: &mut WsStream,
+        frame: &PushFrame,
+        format: MsgFormat,
+    ) -> Result<(), WsPusherError> {
+        stream.send(frame.encode(format)?).await?;
+
+        // Pings are answered by `tungstenite` itself.
+        loop {
+            match stream.next().await {
+                None | Some(Ok(Message::Close(_))) => return Err(WsPusherError::Closed),
+                Some(Err(err)) => return Err(err.into()),
+                Some(Ok(Message::Ping(_) | Message::Pong(_))) => continue,
+                Some(Ok(msg)) => {
+                    return match PushAck::decode(&msg, format)? {
+                        PushAck::Ok => Ok(()),
+                        PushAck::Err(err) => Err(WsPusherError::Server(err)),
+                    }
+                }
+            }
+        }
+    }
+
+    // (Re)connects if needed, the `Hello` is sent again after every reconnect.
+    async fn stream(&mut self) -> Result<&mut WsStream, WsPusherError> {
+        if self.stream.is_none() {
+            let (mut stream, _resp) = connect_async(self.url.as_str()).await?;
+            let hello = PushFrame::Hello {
+                resumed: self.resumed,
+                hello_msg: Box::new(self.hello_msg.clone()),
+            };
+
+            Self::send_on(&mut stream, &hello, self.format).await?;
+            self.resumed = true;
+            self.stream = Some(stream);
+        }
+
+        self.stream.as_mut().ok_or(WsPusherError::Closed)
+    }
+
+    // The connection is dropped on error (except for the ones the server reports),
+    // so that the next (retried) push reconnects.
+    async fn send(&mut self, frame: PushFrame) -> Result<(), WsPusherError> {
+        let format = self.format;
+        let res = Self::send_on(self.stream().await?, &frame, format).await;
+
+        if let Err(err) = &res {
+            if !matches!(err, WsPusherError::Server(_)) {
+                self.stream = None;
+            }
+        }
+
+        res
+    }
+}
+
+// Cheap to clone, all the clones share the same connection.
+#[derive(Clone, Debug)]
+pub struct WsPusher(Arc<Mutex<Inner>>);
+
+impl WsPusher {
+    // e.g. `ws://127.0.0.1:8192/pusher`
+    pub fn builder(url: &str, client_name: &str) -> WsPusherBuilder {
+        WsPusherBuilder {
+            url: url.into(),
+            client_name: client_name.into(),
+            token: None,
+            format: MsgFormat::Msgpack,
+            query_map: Default::default(),
+            proc_env: true,
+        }
+    }
+
+    pub async fn client_key(&self) -> String {
+        self.0.lock().await.client_key.clone()
+    }
+}
+
+impl PushMsg for WsPusher {
+    type Error = WsPusherError;
+
+    async fn bulk_push(&mut self, msgs: Vec<TracingMsg>) -> Result<(), Self::Error> {
+        self.0.lock().await.send(PushFrame::Msgs(msgs)).await
+    }
+}
+
+impl CloseTransport for WsPusher {
+    async fn close_transport(&mut self, msg: Option<CloseMsg>) {
+        let mut inner = self.0.lock().await;
+        let code = match msg.as_ref().map(|msg| msg.is_ok()) {
+            Some(false) => CloseCode::Error,
+            _ => CloseCode::Normal,
+        };
+
+        // Reconnects if needed, so that the server gets the `CloseMsg`.
+        if msg.is_some() && inner.stream().await.is_err() {
+            return;
+        }
+
+        if let Some(mut stream) = inner.stream.take() {
+            let frame = CloseFrame {
+                code,
+                reason: msg.as_ref().map(close_reason).unwrap_or_default().into(),
+            };
+
+            if stream.close(Some(frame)).await.is_ok() {
+                // Wait for the close reply, so that the server has seen everything.
+                while let Some(Ok(_)) = stream.next().await {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing_msg::{CloseErrKind, GraceType};
+
+    fn stats() -> CloseStats {
+        CloseStats {
+            retries: 1,
+            dead_lettered: 2,
+            replayed: 3,
+            spool_evicted: 4,
+            flushed: 5,
+            abandoned: 6,
+        }
+    }
+
+    #[test]
+    fn close_msg_fits_the_close_frame() {
+        let ok = CloseMsg::ok(GraceType::Explicit.into()).with_stats(stats());
+        assert_eq!(parse_close_reason(&close_reason(&ok)), Some(ok));
+
+        let err = CloseErr::new(CloseErrKind::BulkPushErr, "é".repeat(100));
+        let reason = close_reason(&CloseMsg::err(err).with_stats(stats()));
+        assert!(reason.len() <= MAX_CLOSE_REASON);
+
+        let msg = parse_close_reason(&reason).unwrap();
+        let err = msg.as_ref().unwrap_err();
+        assert_eq!(err.kind, CloseErrKind::BulkPushErr);
+        assert!(!err.display.is_empty() && "é".repeat(100).starts_with(&err.display));
+        assert_eq!(msg.stats(), &stats());
+
+        assert_eq!(parse_close_reason(""), None);
+        assert_eq!(parse_close_reason("bye"), None);
+    }
+
+    #[cfg(feature = "kv-mem")]
+    #[tokio::test]
+    async fn pushes_to_the_server() {
+        use crate::{
+            stop::Stop,
+            tmp::server::BuildServerDefault,
+            tracing_msg::{MsgBody, SpanId},
+        };
+        use std::num::NonZeroU64;
+
+        let (stop, _routine) = Stop::builder_mem("ws")
+            .await
+            .unwrap()
+            .disable_ctrlc_shutdown()
+            .init()
+            .await
+            .unwrap();
+        let server = stop
+            .build_server_default()
+            .disable_ctrlc_shutdown()
+            .bind_addrs("127.0.0.1:0")
+            .await
+            .unwrap()
+            .start()
+            .await
+            .unwrap();
+        let url = format!("ws://{}/pusher", server.get_local_addr());
+        let span_id = SpanId(NonZeroU64::MIN);
+        let msgs = vec![MsgBody::OnEnter { span_id }.into()];
+
+        for format in [MsgFormat::Json, MsgFormat::Msgpack] {
+            let mut pusher = WsPusher::builder(&url, "loopback")
+                .msg_format(format)
+                .disable_proc_env()
+                .connect()
+                .await
+                .unwrap();
+            pusher.bulk_push(msgs.clone()).await.unwrap();
+
+            // Dropped without a close frame, so the next push resumes the same client.
+            pusher.0.lock().await.stream = None;
+            pusher.bulk_push(msgs.clone()).await.unwrap();
+
+            let close_msg = CloseMsg::ok(GraceType::Explicit.into()).with_stats(stats());
+            pusher.close_transport(Some(close_msg)).await;
+        }
+
+        let ts = stop.formatted_timestamp();
+        let query = "SELECT VALUE id FROM type::table($table)";
+        let count = |table: String| async {
+            let mut res = stop.db().query(query).bind(("table", table)).await.unwrap();
+            res.take::<Vec<surrealdb::RecordId>>(0).unwrap().len()
+        };
+        assert_eq!(count(format!("{ts}-msg")).await, 4);
+        // Along with the host's own client
+        assert_eq!(count(format!("{ts}-clients")).await, 3);
+
+        let mut res = stop
+            .db()
+            .query("SELECT VALUE h_close_stats.flushed FROM type::table($table)")
+            .bind(("table", format!("{ts}-disconnects")))
+            .await
+            .unwrap();
+        let flushed: Vec<u64> = res.take(0).unwrap();
+        assert_eq!(flushed, [5, 5]);
+
+        server.graceful_shutdown().await.unwrap().unwrap();
+    }
+
+    #[cfg(feature = "kv-mem")]
+    #[tokio::test]
+    async fn dropped_pushers_are_closed_if_not_resumed() {
+        use crate::{
+            stop::Stop,
+            tmp::server::BuildServerDefault,
+            tracing_msg::{MsgBody, SpanId},
+        };
+        use std::{
+            num::NonZeroU64,
+            time::{Duration, Instant},
+        };
+
+        let (stop, _routine) = Stop::builder_mem("ws")
+            .await
+            .unwrap()
+            .disable_ctrlc_shutdown()
+            .init()
+            .await
+            .unwrap();
+        let server = stop
+            .build_server_default()
+            .disable_ctrlc_shutdown()
+            .pusher_resume_timeout(Duration::from_millis(100))
+            .bind_addrs("127.0.0.1:0")
+            .await
+            .unwrap()
+            .start()
+            .await
+            .unwrap();
+        let url = format!("ws://{}/pusher", server.get_local_addr());
+        let span_id = SpanId(NonZeroU64::MIN);
+        let msgs = vec![MsgBody::OnEnter { span_id }.into()];
+        let mut pusher = WsPusher::builder(&url, "loopback")
+            .disable_proc_env()
+            .connect()
+            .await
+            .unwrap();
+        pusher.bulk_push(msgs.clone()).await.unwrap();
+        pusher.0.lock().await.stream = None;
+
+        let ts = stop.formatted_timestamp();
+        let query = "SELECT VALUE g_err_msg FROM type::table($table)";
+        let disconnects = || async {
+            let table = format!("{ts}-disconnects");
+            let mut res = stop.db().query(query).bind(("table", table)).await.unwrap();
+            res.take::<Vec<Option<String>>>(0).unwrap()
+        };
+        let started = Instant::now();
+
+        while disconnects().await.is_empty() {
+            assert!(started.elapsed() < Duration::from_secs(5));
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+
+        let err_msg = "connection dropped, not resumed".to_string();
+        assert_eq!(disconnects().await, [Some(err_msg)]);
+
+        // Too late to resume, so it's a new client.
+        pusher.bulk_push(msgs).await.unwrap();
+        let query = "SELECT VALUE id FROM type::table($table)";
+        let mut res = stop
+            .db()
+            .query(query)
+            .bind(("table", format!("{ts}-clients")))
+            .await
+            .unwrap();
+        // Along with the host's own client
+        assert_eq!(res.take::<Vec<surrealdb::RecordId>>(0).unwrap().len(), 3);
+
+        pusher.close_transport(None).await;
+        server.graceful_shutdown().await.unwrap().unwrap();
+    }
+}
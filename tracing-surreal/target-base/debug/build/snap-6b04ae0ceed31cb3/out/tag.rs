@@ -0,0 +1,258 @@
+pub const TAG_LOOKUP_TABLE: [u16; 256] = [
+    1,
+    2052,
+    4097,
+    8193,
+    2,
+    2053,
+    4098,
+    8194,
+    3,
+    2054,
+    4099,
+    8195,
+    4,
+    2055,
+    4100,
+    8196,
+    5,
+    2056,
+    4101,
+    8197,
+    6,
+    2057,
+    4102,
+    8198,
+    7,
+    2058,
+    4103,
+    8199,
+    8,
+    2059,
+    4104,
+    8200,
+    9,
+    2308,
+    4105,
+    8201,
+    10,
+    2309,
+    4106,
+    8202,
+    11,
+    2310,
+    4107,
+    8203,
+    12,
+    2311,
+    4108,
+    8204,
+    13,
+    2312,
+    4109,
+    8205,
+    14,
+    2313,
+    4110,
+    8206,
+    15,
+    2314,
+    4111,
+    8207,
+    16,
+    2315,
+    4112,
+    8208,
+    17,
+    2564,
+    4113,
+    8209,
+    18,
+    2565,
+    4114,
+    8210,
+    19,
+    2566,
+    4115,
+    8211,
+    20,
+    2567,
+    4116,
+    8212,
+    21,
+    2568,
+    4117,
+    8213,
+    22,
+    2569,
+    4118,
+    8214,
+    23,
+    2570,
+    4119,
+    8215,
+    24,
+    2571,
+    4120,
+    8216,
+    25,
+    2820,
+    4121,
+    8217,
+    26,
+    2821,
+    4122,
+    8218,
+    27,
+    2822,
+    4123,
+    8219,
+    28,
+    2823,
+    4124,
+    8220,
+    29,
+    2824,
+    4125,
+    8221,
+    30,
+    2825,
+    4126,
+    8222,
+    31,
+    2826,
+    4127,
+    8223,
+    32,
+    2827,
+    4128,
+    8224,
+    33,
+    3076,
+    4129,
+    8225,
+    34,
+    3077,
+    4130,
+    8226,
+    35,
+    3078,
+    4131,
+    8227,
+    36,
+    3079,
+    4132,
+    8228,
+    37,
+    3080,
+    4133,
+    8229,
+    38,
+    3081,
+    4134,
+    8230,
+    39,
+    3082,
+    4135,
+    8231,
+    40,
+    3083,
+    4136,
+    8232,
+    41,
+    3332,
+    4137,
+    8233,
+    42,
+    3333,
+    4138,
+    8234,
+    43,
+    3334,
+    4139,
+    8235,
+    44,
+    3335,
+    4140,
+    8236,
+    45,
+    3336,
+    4141,
+    8237,
+    46,
+    3337,
+    4142,
+    8238,
+    47,
+    3338,
+    4143,
+    8239,
+    48,
+    3339,
+    4144,
+    8240,
+    49,
+    3588,
+    4145,
+    8241,
+    50,
+    3589,
+    4146,
+    8242,
+    51,
+    3590,
+    4147,
+    8243,
+    52,
+    3591,
+    4148,
+    8244,
+    53,
+    3592,
+    4149,
+    8245,
+    54,
+    3593,
+    4150,
+    8246,
+    55,
+    3594,
+    4151,
+    8247,
+    56,
+    3595,
+    4152,
+    8248,
+    57,
+    3844,
+    4153,
+    8249,
+    58,
+    3845,
+    4154,
+    8250,
+    59,
+    3846,
+    4155,
+    8251,
+    60,
+    3847,
+    4156,
+    8252,
+    2048,
+    3848,
+    4157,
+    8253,
+    4096,
+    3849,
+    4158,
+    8254,
+    6144,
+    3850,
+    4159,
+    8255,
+    8192,
+    3851,
+    4160,
+    8256,
+];
@@ -0,0 +1,3604 @@
+pub type LocalName = ::string_cache::Atom<LocalNameStaticSet>;
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct LocalNameStaticSet;
+impl ::string_cache::StaticAtomSet for LocalNameStaticSet {
+    fn get() -> &'static ::string_cache::PhfStrSet {
+        static SET: ::string_cache::PhfStrSet = ::string_cache::PhfStrSet {
+            key: 16287231350648472473u64,
+            disps: &[
+                (0u32, 13u32),
+                (0u32, 2u32),
+                (0u32, 3u32),
+                (0u32, 6u32),
+                (0u32, 0u32),
+                (0u32, 4u32),
+                (0u32, 1u32),
+                (0u32, 9u32),
+                (0u32, 7u32),
+                (0u32, 12u32),
+                (0u32, 4u32),
+                (0u32, 0u32),
+                (0u32, 4u32),
+                (0u32, 1u32),
+                (0u32, 7u32),
+                (0u32, 29u32),
+                (0u32, 3u32),
+                (0u32, 25u32),
+                (0u32, 44u32),
+                (0u32, 7u32),
+                (0u32, 1u32),
+                (0u32, 11u32),
+                (0u32, 0u32),
+                (0u32, 3u32),
+                (0u32, 0u32),
+                (0u32, 0u32),
+                (0u32, 10u32),
+                (0u32, 4u32),
+                (0u32, 72u32),
+                (0u32, 1u32),
+                (0u32, 28u32),
+                (0u32, 33u32),
+                (0u32, 26u32),
+                (0u32, 1u32),
+                (0u32, 1u32),
+                (0u32, 32u32),
+                (0u32, 0u32),
+                (0u32, 17u32),
+                (0u32, 5u32),
+                (0u32, 0u32),
+                (0u32, 6u32),
+                (0u32, 0u32),
+                (0u32, 31u32),
+                (0u32, 12u32),
+                (0u32, 51u32),
+                (0u32, 52u32),
+                (0u32, 65u32),
+                (0u32, 30u32),
+                (0u32, 12u32),
+                (0u32, 0u32),
+                (0u32, 19u32),
+                (0u32, 9u32),
+                (0u32, 0u32),
+                (0u32, 70u32),
+                (0u32, 0u32),
+                (0u32, 98u32),
+                (0u32, 1u32),
+                (0u32, 10u32),
+                (0u32, 46u32),
+                (0u32, 62u32),
+                (0u32, 31u32),
+                (0u32, 6u32),
+                (0u32, 18u32),
+                (0u32, 10u32),
+                (0u32, 30u32),
+                (0u32, 6u32),
+                (0u32, 5u32),
+                (0u32, 1u32),
+                (0u32, 9u32),
+                (0u32, 36u32),
+                (0u32, 29u32),
+                (0u32, 3u32),
+                (0u32, 7u32),
+                (0u32, 21u32),
+                (0u32, 3u32),
+                (0u32, 12u32),
+                (0u32, 1u32),
+                (0u32, 27u32),
+                (0u32, 6u32),
+                (0u32, 30u32),
+                (0u32, 0u32),
+                (0u32, 2u32),
+                (0u32, 0u32),
+                (0u32, 5u32),
+                (0u32, 83u32),
+                (0u32, 0u32),
+                (0u32, 5u32),
+                (0u32, 2u32),
+                (0u32, 8u32),
+                (0u32, 3u32),
+                (0u32, 6u32),
+                (0u32, 1u32),
+                (0u32, 17u32),
+                (0u32, 45u32),
+                (0u32, 15u32),
+                (0u32, 7u32),
+                (0u32, 44u32),
+                (0u32, 3u32),
+                (0u32, 1u32),
+                (0u32, 13u32),
+                (0u32, 0u32),
+                (0u32, 133u32),
+                (0u32, 7u32),
+                (0u32, 144u32),
+                (0u32, 55u32),
+                (0u32, 5u32),
+                (0u32, 68u32),
+                (0u32, 21u32),
+                (0u32, 9u32),
+                (0u32, 28u32),
+                (0u32, 90u32),
+                (0u32, 35u32),
+                (0u32, 67u32),
+                (0u32, 0u32),
+                (0u32, 40u32),
+                (0u32, 24u32),
+                (0u32, 8u32),
+                (0u32, 5u32),
+                (0u32, 1u32),
+                (0u32, 16u32),
+                (0u32, 209u32),
+                (0u32, 3u32),
+                (0u32, 99u32),
+                (0u32, 1u32),
+                (0u32, 16u32),
+                (0u32, 16u32),
+                (0u32, 0u32),
+                (0u32, 59u32),
+                (0u32, 0u32),
+                (0u32, 13u32),
+                (0u32, 90u32),
+                (0u32, 63u32),
+                (0u32, 9u32),
+                (0u32, 6u32),
+                (0u32, 4u32),
+                (0u32, 0u32),
+                (0u32, 5u32),
+                (0u32, 6u32),
+                (0u32, 111u32),
+                (0u32, 3u32),
+                (0u32, 42u32),
+                (0u32, 4u32),
+                (0u32, 27u32),
+                (0u32, 0u32),
+                (0u32, 109u32),
+                (0u32, 3u32),
+                (0u32, 5u32),
+                (0u32, 34u32),
+                (0u32, 152u32),
+                (0u32, 0u32),
+                (0u32, 197u32),
+                (0u32, 50u32),
+                (0u32, 22u32),
+                (0u32, 92u32),
+                (0u32, 1u32),
+                (0u32, 410u32),
+                (0u32, 210u32),
+                (0u32, 148u32),
+                (0u32, 50u32),
+                (0u32, 6u32),
+                (0u32, 121u32),
+                (0u32, 6u32),
+                (0u32, 0u32),
+                (0u32, 106u32),
+                (0u32, 15u32),
+                (0u32, 16u32),
+                (0u32, 0u32),
+                (0u32, 165u32),
+                (0u32, 4u32),
+                (0u32, 22u32),
+                (0u32, 40u32),
+                (0u32, 11u32),
+                (1u32, 1u32),
+                (0u32, 83u32),
+                (0u32, 296u32),
+                (0u32, 9u32),
+                (0u32, 51u32),
+                (0u32, 0u32),
+                (0u32, 97u32),
+                (0u32, 0u32),
+                (0u32, 390u32),
+                (0u32, 1u32),
+                (0u32, 49u32),
+                (0u32, 5u32),
+                (0u32, 1u32),
+                (0u32, 179u32),
+                (0u32, 52u32),
+                (0u32, 57u32),
+                (0u32, 307u32),
+                (0u32, 35u32),
+                (0u32, 2u32),
+                (0u32, 0u32),
+                (0u32, 0u32),
+                (0u32, 89u32),
+                (0u32, 15u32),
+                (0u32, 172u32),
+                (0u32, 0u32),
+                (0u32, 59u32),
+                (0u32, 90u32),
+                (0u32, 169u32),
+                (0u32, 161u32),
+                (0u32, 172u32),
+                (0u32, 27u32),
+                (0u32, 296u32),
+                (0u32, 277u32),
+                (0u32, 126u32),
+                (0u32, 121u32),
+                (0u32, 32u32),
+                (0u32, 0u32),
+                (0u32, 0u32),
+                (0u32, 81u32),
+                (0u32, 6u32),
+                (0u32, 194u32),
+                (0u32, 118u32),
+                (0u32, 107u32),
+                (0u32, 4u32),
+                (0u32, 28u32),
+                (0u32, 4u32),
+                (0u32, 101u32),
+                (0u32, 151u32),
+                (0u32, 656u32),
+            ],
+            atoms: &[
+                "equivalent",
+                "pointsAtY",
+                "stretchy",
+                "glyphRef",
+                "naturalnumbers",
+                "keyPoints",
+                "solidColor",
+                "pathlength",
+                "altGlyph",
+                "contentScriptType",
+                "unselectable",
+                "solid-color",
+                "ondragstart",
+                "xChannelSelector",
+                "onrowexit",
+                "yChannelSelector",
+                "oncanplay",
+                "feComponentTransfer",
+                "line-height",
+                "diffuseConstant",
+                "hatchContentUnits",
+                "underline-position",
+                "spreadmethod",
+                "strikethrough-thickness",
+                "filterUnits",
+                "font-face-uri",
+                "requiredExtensions",
+                "textpath",
+                "gradientUnits",
+                "equalrows",
+                "rationals",
+                "displaystyle",
+                "fill-rule",
+                "baseProfile",
+                "aria-setsize",
+                "pointsAtZ",
+                "onunload",
+                "encoding",
+                "vert-origin-x",
+                "irrelevant",
+                "flood-color",
+                "mozbrowser",
+                "aria-grab",
+                "oncanplaythrough",
+                "font-face-format",
+                "clipPathUnits",
+                "horiz-origin-y",
+                "radiogroup",
+                "font-size-adjust",
+                "numoctaves",
+                "glyph-orientation-vertical",
+                "lineargradient",
+                "imaginaryi",
+                "itemscope",
+                "clip-rule",
+                "feGaussianBlur",
+                "fecolormatrix",
+                "aria-secret",
+                "aria-live",
+                "aria-colindextext",
+                "mathematical",
+                "formaction",
+                "shadowrootclonable",
+                "columnlines",
+                "primitiveunits",
+                "stroke-miterlimit",
+                "reversed",
+                "divergence",
+                "aria-pressed",
+                "onerrorupdate",
+                "keyTimes",
+                "ondragover",
+                "noscript",
+                "aria-haspopup",
+                "viewTarget",
+                "xmlns:xlink",
+                "repeat-max",
+                "onfilterchange",
+                "patternTransform",
+                "fontstyle",
+                "shadowrootserializable",
+                "kernelUnitLength",
+                "requiredfeatures",
+                "x-height",
+                "aria-activedescendant",
+                "xchannelselector",
+                "markerWidth",
+                "hidefocus",
+                "ondataavailable",
+                "aria-invalid",
+                "glyphref",
+                "patterntransform",
+                "ondatasetchanged",
+                "rowspacing",
+                "aria-expanded",
+                "onmouseover",
+                "aria-datatype",
+                "notprsubset",
+                "xlink:arcrole",
+                "onbounce",
+                "scriptsizemultiplier",
+                "codebase",
+                "stop-color",
+                "exponent",
+                "columnspan",
+                "text-anchor",
+                "v-hanging",
+                "filterunits",
+                "factorof",
+                "onbeforeactivate",
+                "keysplines",
+                "baseline-shift",
+                "aria-valuemin",
+                "xml:base",
+                "ondragenter",
+                "attributetype",
+                "feoffset",
+                "letter-spacing",
+                "baseline",
+                "oncellchange",
+                "missing-glyph",
+                "alignment-baseline",
+                "radialgradient",
+                "lighting-color",
+                "maskcontentunits",
+                "startoffset",
+                "onloadedmetadata",
+                "controls",
+                "shadowrootmode",
+                "shape-rendering",
+                "annotation",
+                "fegaussianblur",
+                "stddeviation",
+                "pointer-events",
+                "itemtype",
+                "repeatCount",
+                "otherwise",
+                "ondragdrop",
+                "cellpadding",
+                "clipPath",
+                "prsubset",
+                "plaintext",
+                "mathvariant",
+                "onafterupdate",
+                "marker-start",
+                "stroke-width",
+                "aria-braillelabel",
+                "underline-thickness",
+                "ondragleave",
+                "markerwidth",
+                "vector-effect",
+                "onafterprint",
+                "repeatDur",
+                "formenctype",
+                "innertext",
+                "vectorproduct",
+                "onpageswap",
+                "aria-labelledby",
+                "marginwidth",
+                "basefrequency",
+                "fespecularlighting",
+                "aria-channel",
+                "alignmentscope",
+                "markerUnits",
+                "onbeforeprint",
+                "baseFrequency",
+                "xml:lang",
+                "limitingConeAngle",
+                "edgeMode",
+                "animateMotion",
+                "hatchUnits",
+                "controllerchange",
+                "lengthAdjust",
+                "pointsAtX",
+                "glyph-name",
+                "required",
+                "eulergamma",
+                "horiz-adv-x",
+                "datalist",
+                "preserveAspectRatio",
+                "mathcolor",
+                "mmultiscripts",
+                "aria-owns",
+                "fespotlight",
+                "blockquote",
+                "aria-modal",
+                "onseeking",
+                "veryverythickmathspace",
+                "tableValues",
+                "animatemotion",
+                "v-alphabetic",
+                "exponentiale",
+                "shadowrootdelegatesfocus",
+                "marginheight",
+                "ondeactivate",
+                "arabic-form",
+                "aria-valuetext",
+                "contenteditable",
+                "hatchpath",
+                "onfinish",
+                "spellcheck",
+                "onrowsinserted",
+                "onrowenter",
+                "onlanguagechange",
+                "clip-path",
+                "aria-flowto",
+                "formnovalidate",
+                "manifest",
+                "aria-multiline",
+                "foreignObject",
+                "feConvolveMatrix",
+                "onmouseenter",
+                "animateTransform",
+                "feMergeNode",
+                "attributename",
+                "onstorage",
+                "aria-sort",
+                "momentabout",
+                "mprescripts",
+                "xlink:actuate",
+                "aria-disabled",
+                "aria-brailleroledescription",
+                "basefont",
+                "solidcolor",
+                "onmousewheel",
+                "gradientunits",
+                "datatemplate",
+                "maskUnits",
+                "preservealpha",
+                "textlength",
+                "preserveAlpha",
+                "background",
+                "diffuseconstant",
+                "notation",
+                "vert-origin-y",
+                "overline-position",
+                "enable-background",
+                "oncommand",
+                "rowalign",
+                "actiontype",
+                "stroke-dasharray",
+                "aria-details",
+                "tablevalues",
+                "autosubmit",
+                "onbeforeunload",
+                "factorial",
+                "stitchtiles",
+                "groupalign",
+                "oncontextmenu",
+                "repeat-start",
+                "accentunder",
+                "additive",
+                "fediffuselighting",
+                "fedistantlight",
+                "aria-description",
+                "decoding",
+                "fecomponenttransfer",
+                "progress",
+                "formmethod",
+                "specularExponent",
+                "specularConstant",
+                "altglyphdef",
+                "draggable",
+                "onoffline",
+                "onbefordeactivate",
+                "marker-end",
+                "onpropertychange",
+                "thinmathspace",
+                "onbeforepaste",
+                "zoomAndPan",
+                "attributeName",
+                "onbeforecut",
+                "surfacescale",
+                "feDistantLight",
+                "variance",
+                "textarea",
+                "aria-checked",
+                "statechange",
+                "transform-origin",
+                "edgemode",
+                "kernelMatrix",
+                "mphantom",
+                "color-interpolation-filters",
+                "aria-colcount",
+                "cap-height",
+                "altglyph",
+                "novalidate",
+                "aria-posinset",
+                "semantics",
+                "gradientTransform",
+                "definitionURL",
+                "animatetransform",
+                "keytimes",
+                "ondragexit",
+                "separator",
+                "onsuspend",
+                "onmousemove",
+                "innerText",
+                "feSpotLight",
+                "onmessageerror",
+                "elevation",
+                "markerheight",
+                "feColorMatrix",
+                "animation",
+                "prefetch",
+                "lengthadjust",
+                "equalcolumns",
+                "partialdiff",
+                "integrity",
+                "outerproduct",
+                "popovertarget",
+                "symmetric",
+                "alphabetic",
+                "translate",
+                "operator",
+                "formtarget",
+                "autofocus",
+                "zoomandpan",
+                "direction",
+                "feDropShadow",
+                "columnwidth",
+                "onmousedown",
+                "animatecolor",
+                "aria-rowcount",
+                "oncuechange",
+                "onresize",
+                "frameborder",
+                "download",
+                "v-mathematical",
+                "preserveaspectratio",
+                "ideographic",
+                "aria-valuenow",
+                "requiredFeatures",
+                "frameset",
+                "mathbackground",
+                "noresize",
+                "clippathunits",
+                "polyline",
+                "metadata",
+                "quotient",
+                "notanumber",
+                "femorphology",
+                "aria-orientation",
+                "aria-multiselectable",
+                "infinity",
+                "startOffset",
+                "linearGradient",
+                "menuitem",
+                "onbeforeupdate",
+                "fePointLight",
+                "font-face",
+                "colorspace",
+                "aria-rowindex",
+                "aria-busy",
+                "complexes",
+                "datetime",
+                "fill-opacity",
+                "horiz-origin-x",
+                "font-face-name",
+                "columnalign",
+                "strikethrough-position",
+                "onformchange",
+                "closedby",
+                "spreadMethod",
+                "transpose",
+                "aria-dropeffect",
+                "imaginary",
+                "scrolling",
+                "onscroll",
+                "onpagehide",
+                "onmovestart",
+                "fedropshadow",
+                "munderover",
+                "selection",
+                "glyph-orientation-horizontal",
+                "itemprop",
+                "font-style",
+                "ontoggle",
+                "selected",
+                "http-equiv",
+                "maskunits",
+                "dataformatas",
+                "onsubmit",
+                "fetchpriority",
+                "verythinmathspace",
+                "mathsize",
+                "fedisplacementmap",
+                "calcMode",
+                "separators",
+                "flood-opacity",
+                "overline-thickness",
+                "onreadystatechange",
+                "columnspacing",
+                "specification",
+                "aria-rowindextext",
+                "specularconstant",
+                "cellspacing",
+                "baseprofile",
+                "requiredextensions",
+                "attributeType",
+                "veryverythinmathspace",
+                "onbeforetoggle",
+                "unicode-bidi",
+                "hatchunits",
+                "repeat-min",
+                "nomodule",
+                "thickmathspace",
+                "contentStyleType",
+                "figcaption",
+                "onforminput",
+                "feturbulence",
+                "readonly",
+                "onvolumechange",
+                "aria-grabbed",
+                "font-variant",
+                "stdDeviation",
+                "onbeforecopy",
+                "ondatasetcomplete",
+                "timelinebegin",
+                "domainofapplication",
+                "onunhandledrejection",
+                "occurrence",
+                "annotation-xml",
+                "aria-label",
+                "conjugate",
+                "patternUnits",
+                "aria-level",
+                "aria-errormessage",
+                "onmoveend",
+                "feDiffuseLighting",
+                "aria-autocomplete",
+                "calcmode",
+                "onselectstart",
+                "aria-rowspan",
+                "codetype",
+                "hatchcontentunits",
+                "intersect",
+                "fontweight",
+                "onmouseup",
+                "vert-adv-y",
+                "noframes",
+                "colgroup",
+                "font-face-src",
+                "onmessage",
+                "kernelmatrix",
+                "filterRes",
+                "xlink:title",
+                "rendering-intent",
+                "surfaceScale",
+                "feOffset",
+                "contextmenu",
+                "intercept",
+                "units-per-em",
+                "aria-relevant",
+                "contentscripttype",
+                "aria-keyshortcuts",
+                "onloadstart",
+                "maligngroup",
+                "minlength",
+                "ontimeupdate",
+                "altGlyphDef",
+                "commandfor",
+                "ondurationchange",
+                "popovertargetaction",
+                "fecomposite",
+                "feDisplacementMap",
+                "scriptminsize",
+                "stitchTiles",
+                "exportparts",
+                "stroke-opacity",
+                "oncontrolselect",
+                "tabindex",
+                "pathLength",
+                "ychannelselector",
+                "stroke-linejoin",
+                "text-decoration",
+                "foreignobject",
+                "onlosecapture",
+                "onwaiting",
+                "feSpecularLighting",
+                "color-profile",
+                "ondblclick",
+                "font-stretch",
+                "allowfullscreen",
+                "autoplay",
+                "aria-placeholder",
+                "patternunits",
+                "onactivate",
+                "maskContentUnits",
+                "specularexponent",
+                "solid-opacity",
+                "aria-readonly",
+                "orientation",
+                "kernelunitlength",
+                "notsubset",
+                "onkeypress",
+                "superscriptshift",
+                "onplaying",
+                "crossorigin",
+                "accesskey",
+                "onmouseout",
+                "definitionurl",
+                "onbeforeeditfocus",
+                "filterres",
+                "transform",
+                "fepointlight",
+                "pointsatx",
+                "property",
+                "template",
+                "bevelled",
+                "onfocusout",
+                "systemLanguage",
+                "marker-mid",
+                "referrerpolicy",
+                "onrowsdelete",
+                "radialGradient",
+                "language",
+                "maxlength",
+                "codomain",
+                "aria-valuemax",
+                "keypoints",
+                "aria-current",
+                "xlink:type",
+                "font-size",
+                "onpagereveal",
+                "onhashchange",
+                "interval",
+                "externalresourcesrequired",
+                "mlabeledtr",
+                "accept-charset",
+                "onkeydown",
+                "markerHeight",
+                "unicode-range",
+                "onprogress",
+                "definition-src",
+                "valuetype",
+                "textContent",
+                "placeholder",
+                "lowlimit",
+                "emptyset",
+                "subscriptshift",
+                "font-family",
+                "xlink:role",
+                "condition",
+                "visibility",
+                "aria-describedby",
+                "aria-selected",
+                "externalResourcesRequired",
+                "aria-colspan",
+                "seamless",
+                "multiple",
+                "onrepeat",
+                "aria-templateid",
+                "pointsaty",
+                "integers",
+                "fontfamily",
+                "disabled",
+                "xlink:href",
+                "onchange",
+                "feMorphology",
+                "autocomplete",
+                "limitingconeangle",
+                "stroke-linecap",
+                "font-weight",
+                "writing-mode",
+                "aria-atomic",
+                "femergenode",
+                "malignmark",
+                "onstalled",
+                "oninvalid",
+                "scalarproduct",
+                "paint-order",
+                "cartesianproduct",
+                "textLength",
+                "xml:space",
+                "linebreak",
+                "pointsatz",
+                "stroke-dashoffset",
+                "ondragend",
+                "text-rendering",
+                "longdesc",
+                "matrixrow",
+                "altGlyphItem",
+                "textPath",
+                "panose-1",
+                "piecewise",
+                "onloadeddata",
+                "primitiveUnits",
+                "word-spacing",
+                "aria-colindex",
+                "listener",
+                "aria-hidden",
+                "framespacing",
+                "inputmode",
+                "gradienttransform",
+                "systemlanguage",
+                "feTurbulence",
+                "repeatdur",
+                "hreflang",
+                "fontsize",
+                "overflow",
+                "playbackorder",
+                "repeatcount",
+                "color-interpolation",
+                "laplacian",
+                "patterncontentunits",
+                "amplitude",
+                "ononline",
+                "clippath",
+                "accent-height",
+                "mask-type",
+                "color-rendering",
+                "multicol",
+                "aria-required",
+                "oncancel",
+                "numOctaves",
+                "scrolldelay",
+                "onemptied",
+                "patternContentUnits",
+                "imagesrcset",
+                "altglyphitem",
+                "onselect",
+                "animateColor",
+                "dominant-baseline",
+                "selectedcontent",
+                "viewtarget",
+                "xlink:show",
+                "textcontent",
+                "onseeked",
+                "mediummathspace",
+                "accumulate",
+                "onmouseleave",
+                "aria-controls",
+                "contentstyletype",
+                "rowlines",
+                "stop-opacity",
+                "v-ideographic",
+                "menclose",
+                "imagesizes",
+                "repeat-template",
+                "feconvolvematrix",
+                "selector",
+                "image-rendering",
+                "fieldset",
+                "feComposite",
+                "keySplines",
+                "aria-roledescription",
+                "verythickmathspace",
+                "blocking",
+                "shadowrootslotassignment",
+                "movablelimits",
+                "scriptlevel",
+                "onpageshow",
+                "onpopstate",
+                "onfocusin",
+                "onratechange",
+                "determinant",
+                "optgroup",
+                "linethickness",
+                "onrejectionhandled",
+                "markerunits",
+            ],
+            hashes: &[
+                8162890770801760110u64,
+                7482152495886765298u64,
+                380175198224159113u64,
+                1943396322189803586u64,
+                12578651769171744361u64,
+                11504322127759350242u64,
+                12894127548429581645u64,
+                12681702040631353636u64,
+                16832952078278511302u64,
+                11200066976718602115u64,
+                17273783375506402555u64,
+                8075241218972327651u64,
+                948366238046721450u64,
+                10882481605205518304u64,
+                13285878991146465995u64,
+                4958591162803759124u64,
+                7147053961600232659u64,
+                1322540941395281429u64,
+                1404345977259363219u64,
+                10937115354443044206u64,
+                8879958039058318953u64,
+                10383844763498212033u64,
+                5368407172309957713u64,
+                9937398884862820521u64,
+                11710421870747252410u64,
+                15870389981351963149u64,
+                9906630272957935354u64,
+                841881687945544549u64,
+                15552056641201705230u64,
+                2236979365752974556u64,
+                13446903438423373180u64,
+                3727746509018910738u64,
+                6550198141838115538u64,
+                6472584029976214037u64,
+                3490505097458234624u64,
+                18402446748762902475u64,
+                16808185820215659308u64,
+                7092451053268843878u64,
+                13327703471992316626u64,
+                609239567718194356u64,
+                6163646292213838662u64,
+                3105093181973311925u64,
+                17318161671000819834u64,
+                3206868602586053147u64,
+                16512911822557136777u64,
+                724420580675652304u64,
+                5403909634148321569u64,
+                4018672435420546222u64,
+                5293009724365877319u64,
+                11390391933635827537u64,
+                9411903352405460569u64,
+                552524920082046694u64,
+                5290261851030778080u64,
+                13854331106638894809u64,
+                5926036648316474754u64,
+                11877936035122496282u64,
+                14314263226516237373u64,
+                7213341985911478942u64,
+                1915147776011023498u64,
+                3695238874148576822u64,
+                9242076653175929662u64,
+                10584315595035775343u64,
+                7147654852431417768u64,
+                2854361922394853890u64,
+                14420463210050541815u64,
+                9485244679583912964u64,
+                9414878739679470357u64,
+                13135839384806875120u64,
+                1400677891301241565u64,
+                1727818447048984184u64,
+                526415547450468525u64,
+                5087752133349262852u64,
+                13263565564267353264u64,
+                14794716919734157098u64,
+                10342986527739115472u64,
+                8391702825611581757u64,
+                13892140442694108790u64,
+                17466082190525823175u64,
+                7974706044647671604u64,
+                15647353483434962950u64,
+                10967775686578091135u64,
+                5030090827463612969u64,
+                18001351972404430979u64,
+                17065292463231840581u64,
+                17663650007154477478u64,
+                13833095868755250754u64,
+                15410844051821222958u64,
+                12032354797249645520u64,
+                12392865713155640008u64,
+                7739311874414084512u64,
+                899002827850844675u64,
+                13799033302127126258u64,
+                18236877620132206722u64,
+                9199904192575829033u64,
+                1577236306945550063u64,
+                5243687463046481694u64,
+                2116920626872115528u64,
+                11718886950623552295u64,
+                2252453466737532729u64,
+                18157528463807803162u64,
+                15723490931879110621u64,
+                3067680443478769717u64,
+                8826471554229530285u64,
+                15188772433913566087u64,
+                18224845559811589020u64,
+                3075795149169053674u64,
+                14911052123554309496u64,
+                8125408178628109692u64,
+                11454948622421116474u64,
+                6283807284211649285u64,
+                13219235498613473381u64,
+                5848257447615630874u64,
+                12122472898445093368u64,
+                3651555382374995720u64,
+                18216420039859638970u64,
+                16999463458216246051u64,
+                160004270290307667u64,
+                12322413865994966619u64,
+                13375111080324293865u64,
+                5103294334086096423u64,
+                9555644988880054465u64,
+                5504023521592032454u64,
+                16720235592318258247u64,
+                17760236814116586653u64,
+                1754456471548643684u64,
+                2991333721477607977u64,
+                7049218891627444016u64,
+                4883012492792501923u64,
+                1886268310185546799u64,
+                12999714205386810875u64,
+                16655182345142421535u64,
+                10336199855840168212u64,
+                17068049106652715310u64,
+                17389172695382090507u64,
+                10380503806027524913u64,
+                17634755653999710570u64,
+                2912094358848504850u64,
+                15355050423887395735u64,
+                12544524296309734146u64,
+                8959782436375713750u64,
+                10378877832167752494u64,
+                10391082835568669292u64,
+                4423514331728477498u64,
+                6185937598467860055u64,
+                15360859441455919569u64,
+                8942235316269278117u64,
+                8830627514674037762u64,
+                1548439095397455687u64,
+                3211768358836794287u64,
+                3105185929661476907u64,
+                13675027361991106138u64,
+                13749606716887014948u64,
+                13331344551179030973u64,
+                5204765673744141623u64,
+                8145903917812268751u64,
+                18069507508533749069u64,
+                16634120543370810626u64,
+                14766869702573030691u64,
+                13334640834986891116u64,
+                2216433794524556842u64,
+                14938062728355574929u64,
+                4924345654854188161u64,
+                2643193748760182383u64,
+                16095641988779326836u64,
+                14950390900731309311u64,
+                16584686066453709632u64,
+                10277637304402770060u64,
+                9720000007195031383u64,
+                79570134532585445u64,
+                1316945973978645119u64,
+                3223206905330307052u64,
+                8826112778535570330u64,
+                15042828698664071060u64,
+                14635323022279945023u64,
+                3647042025275706844u64,
+                18151042660734561784u64,
+                361307917404942771u64,
+                555663048043239687u64,
+                7440500159765190697u64,
+                17736555972380895006u64,
+                82261375755339529u64,
+                11551333781529941829u64,
+                32901799882839191u64,
+                8802361480546999645u64,
+                6246424720614749810u64,
+                10917192815529312991u64,
+                17758515817341922682u64,
+                7312646458107259078u64,
+                5262384688300575980u64,
+                17635906927761215018u64,
+                8323378240480977664u64,
+                3627108361963527613u64,
+                3891112186850080987u64,
+                4437108452185623799u64,
+                15494586643842593068u64,
+                8529188154338450996u64,
+                3857696836899594440u64,
+                8540112077780804961u64,
+                15840705120874739604u64,
+                16347539644346813288u64,
+                15992107580290671948u64,
+                8695797677211281351u64,
+                7262876849464939918u64,
+                8647777254104820543u64,
+                12820940010026450907u64,
+                12775020078915016503u64,
+                59983423624790113u64,
+                8186733459370020851u64,
+                6732307939424836223u64,
+                12478092416824408154u64,
+                5302080839313406714u64,
+                13333049069339891949u64,
+                14341424578137433119u64,
+                18227492088728098000u64,
+                14979595770988783321u64,
+                1978014273653351386u64,
+                12550537094093154795u64,
+                6408633290135919704u64,
+                8792565182170052262u64,
+                9871169416815252869u64,
+                13400292454053717359u64,
+                11074136480087500910u64,
+                4153585344025556316u64,
+                7252828757279912883u64,
+                12927845630796195766u64,
+                2015827608168649627u64,
+                6028590891733071469u64,
+                13587557903731315211u64,
+                17207568479679735473u64,
+                6210201503779216283u64,
+                14208843119185028142u64,
+                16996900168528028741u64,
+                9545710358824637762u64,
+                1104235985805869877u64,
+                7296651166165311715u64,
+                13542653649306535591u64,
+                10827406542179518319u64,
+                4177106760572210284u64,
+                2099946910275304589u64,
+                816679293260362126u64,
+                6722491504542495878u64,
+                16176766679451004064u64,
+                10113457983792130980u64,
+                7316035773147989853u64,
+                16526522930027882536u64,
+                6360728668318893515u64,
+                16974470614478314325u64,
+                13507540613373078105u64,
+                5707449783092162241u64,
+                16349725287368685666u64,
+                4461987817795269346u64,
+                15947167653335962866u64,
+                10787834108517954622u64,
+                11308307577938405517u64,
+                16618160621164218328u64,
+                8491500683664041637u64,
+                15991700736722556217u64,
+                5326250455147801095u64,
+                9053069813784674904u64,
+                4013083037363258764u64,
+                7728805418650068317u64,
+                10078200988540458699u64,
+                9357261129757254151u64,
+                3574909531110717181u64,
+                7134102523914595223u64,
+                8123762259635899741u64,
+                8650134924486449521u64,
+                18015958214021256052u64,
+                10130656993672727985u64,
+                12332795948716861335u64,
+                12328033689143192912u64,
+                1799572015872789354u64,
+                7432345893341236589u64,
+                13961876799731820940u64,
+                5502556531146604518u64,
+                2467641252055648142u64,
+                8646653553318363711u64,
+                13113985905047270990u64,
+                9290922199981055417u64,
+                4348594605340360500u64,
+                7710668733681228602u64,
+                16854394238646717682u64,
+                1913866374273413301u64,
+                7489232734583408887u64,
+                9487843743816048020u64,
+                11515099732189593573u64,
+                109935604967493434u64,
+                4718064693021062982u64,
+                13101496170049414170u64,
+                5681424744825190577u64,
+                9482472736553042318u64,
+                8290410206605816244u64,
+                8969636912573513681u64,
+                17147684533775769536u64,
+                1135951568961433840u64,
+                6907572251983141233u64,
+                967234849575544152u64,
+                10692151963018386993u64,
+                15851420152597678815u64,
+                15269335426792411540u64,
+                2490880808100152504u64,
+                14725687969297095833u64,
+                7493449085618762942u64,
+                12549615465178508300u64,
+                12942256138682625473u64,
+                17891714033584539754u64,
+                13607309900946846531u64,
+                2574987241339858068u64,
+                15968652570232222872u64,
+                8899060484028414467u64,
+                8301807140076177605u64,
+                16993249460705560832u64,
+                6902627390869202142u64,
+                13533309122846844761u64,
+                2750738469216317576u64,
+                13482464308883596078u64,
+                1845402382241859854u64,
+                8492651777697214837u64,
+                9093198899457062947u64,
+                10134952132700149865u64,
+                11678393401506102747u64,
+                8438122701028135451u64,
+                6515757107225431576u64,
+                4537229389277777907u64,
+                5069927991632274713u64,
+                10513769893712339218u64,
+                8697430356110702087u64,
+                11964590604446440610u64,
+                9776537781886737728u64,
+                3464818945871328408u64,
+                16765069429751964820u64,
+                4966838075922148064u64,
+                5311659708165493236u64,
+                16855367388198830527u64,
+                4054407116117059905u64,
+                546648126247734102u64,
+                16573498109049311440u64,
+                4179214155366413345u64,
+                1848939726724605253u64,
+                18350383906859906392u64,
+                14581489106112650000u64,
+                11329248092209042830u64,
+                5788081170797068047u64,
+                14703836487799294389u64,
+                5095231675053148675u64,
+                12275429349780322469u64,
+                11858826244658553276u64,
+                13457909686389124092u64,
+                4132189414131968353u64,
+                9090667790179186665u64,
+                1273336967965726275u64,
+                9522729722908495134u64,
+                9608851049753896421u64,
+                5030917524384969945u64,
+                2710715855941301106u64,
+                7279896948875726503u64,
+                11424643160084094557u64,
+                8894868817073614020u64,
+                17739202672775467940u64,
+                4566936692803669502u64,
+                16486849769066731444u64,
+                7513362577076264811u64,
+                4995770999564579761u64,
+                8984431951504241361u64,
+                7884140058164597022u64,
+                1266825411800726823u64,
+                962174361703543354u64,
+                12234840884982952319u64,
+                987825100500518560u64,
+                12035660832533332664u64,
+                3406991498215278424u64,
+                12797129211604725112u64,
+                292632127972250009u64,
+                16805469100770931488u64,
+                15763731848326793518u64,
+                13690957470801293533u64,
+                6491895245844903097u64,
+                18322809011727229392u64,
+                11732646084722133933u64,
+                4256654555018001113u64,
+                10666915010201152956u64,
+                3109642779042279640u64,
+                13604376599255055037u64,
+                11852831660005446971u64,
+                4696478222143430434u64,
+                15752343871176859158u64,
+                8159859547536719378u64,
+                15094408189043849973u64,
+                8578385500486214866u64,
+                14492684290841982596u64,
+                14938509045525580088u64,
+                362146618858564275u64,
+                5568942505233859435u64,
+                169758320936340190u64,
+                10424191954142901563u64,
+                18009702312204844396u64,
+                17933927046842288846u64,
+                14083285887105951276u64,
+                105491388483974066u64,
+                224918343251957589u64,
+                4256930577289798578u64,
+                13838331287093976646u64,
+                8498243004526379922u64,
+                11507552783864239368u64,
+                2897606353404245131u64,
+                5468037198633384453u64,
+                6871284117199221027u64,
+                16959905807615877978u64,
+                16234532522814360719u64,
+                8911597028323424769u64,
+                15115104753196802236u64,
+                12591915474090285321u64,
+                4347754721191788558u64,
+                15836448468466266410u64,
+                13489577703402302438u64,
+                3993498287257425558u64,
+                15699347199513036606u64,
+                13131865387723208523u64,
+                12879495826682883559u64,
+                6122179940123156700u64,
+                18306594763667554896u64,
+                10070153831302817404u64,
+                5892148781220765454u64,
+                8249761248033460544u64,
+                6663253641806034913u64,
+                7075841574811621094u64,
+                6934241693001277551u64,
+                10192395645328264776u64,
+                9433380354619166936u64,
+                18238073894632591089u64,
+                9250483815478174917u64,
+                10659388052790772181u64,
+                18216489185732516337u64,
+                1417412322164395195u64,
+                11586887037125482018u64,
+                11939154294674114967u64,
+                7219185356336372450u64,
+                1671908911607787498u64,
+                7329058684134999053u64,
+                13103404034070113447u64,
+                9522894934778399839u64,
+                11434423726863307222u64,
+                10819867407681102841u64,
+                9404582968636393359u64,
+                16616907334932608792u64,
+                12405378626465515550u64,
+                1516246500592316996u64,
+                2110754499560085686u64,
+                1538602210026172660u64,
+                10767355007780023872u64,
+                8638434582102191414u64,
+                14601192570000557882u64,
+                8534401882532401662u64,
+                3993127645860411083u64,
+                11405773558226351528u64,
+                12860248819991184919u64,
+                2815259367051731173u64,
+                8930528630715480141u64,
+                16267291171849761690u64,
+                1899068405258247575u64,
+                11759125475615393135u64,
+                16028452925218907139u64,
+                2586063099334947657u64,
+                1021605242394458548u64,
+                3442505103529272632u64,
+                2186220005161766178u64,
+                6206943031805379052u64,
+                343985658595833420u64,
+                1164330358979083156u64,
+                13703873158521461414u64,
+                1226371059919002655u64,
+                5288504469745286286u64,
+                5533013203412219936u64,
+                13647775007451502777u64,
+                9084994591144160977u64,
+                4106507115964453468u64,
+                15099767092449816154u64,
+                13091917937073931753u64,
+                4059100928965081975u64,
+                14101816991065035967u64,
+                5395136100182754294u64,
+                16479716160736622981u64,
+                15564484004846403929u64,
+                4417353256660334603u64,
+                2910423706280855043u64,
+                7589410513041511047u64,
+                13635314541344087702u64,
+                5144363543246486196u64,
+                8045041488940152807u64,
+                18004103208081205981u64,
+                9099379811890426160u64,
+                6715084744691268821u64,
+                15688276929319117585u64,
+                2884548947581040002u64,
+                9780196094650111690u64,
+                1952443093819258814u64,
+                4384535400225850476u64,
+                2865533871406720354u64,
+                13920915390376334063u64,
+                11270279520725447517u64,
+                16391065045799339058u64,
+                1241647221571894859u64,
+                1312628707641240849u64,
+                14861969803860420374u64,
+                588273382047303947u64,
+                1139997952281443911u64,
+                8694313447992114992u64,
+                2225495408550964556u64,
+                4236498994653782395u64,
+                4402778987627384937u64,
+                168574889944777378u64,
+                12297984158682975929u64,
+                7085255608290932476u64,
+                14133168066568008171u64,
+                12558845941824812890u64,
+                16396433925399113660u64,
+                5879493850814844724u64,
+                7387993088197054845u64,
+                16641366590889448700u64,
+                2572860429169977987u64,
+                13948996066997470740u64,
+                14624964277010401718u64,
+                8141622434584461018u64,
+                18426061176723071468u64,
+                14048588144064102136u64,
+                13022982625927568599u64,
+                15319599769821719943u64,
+                13292970746587312431u64,
+                9890886696837810900u64,
+                14497239228744031490u64,
+                4229509767386832071u64,
+                7671680577318099383u64,
+                17893367542184083970u64,
+                8542452825634360298u64,
+                6556049350569868595u64,
+                1736463663606817729u64,
+                16963529728182324664u64,
+                16719741064356118639u64,
+                1695846094420406756u64,
+                13526448456155182816u64,
+                14446843388896945475u64,
+                6112215457307500868u64,
+                14327114842093281281u64,
+                16791411252156026876u64,
+                15648367252846613701u64,
+                8116626948005676071u64,
+                8861300794079159545u64,
+                12669863084666477165u64,
+                8822156231185506881u64,
+                5107252262309040453u64,
+                11060909039890439228u64,
+                13833215931691156311u64,
+                10643337648300401419u64,
+                17903312387791593490u64,
+                8440438071982292020u64,
+                17321949804048464620u64,
+                486360791864461174u64,
+                12818966406029264103u64,
+                11844126994113469083u64,
+                6999132381474671080u64,
+                17618450192840312696u64,
+                2770084541724502107u64,
+                891009942049393263u64,
+                17870363092563761977u64,
+                6814782429866028896u64,
+                2515916472336425256u64,
+                12454563805342166574u64,
+                11608559576653685279u64,
+                15393994635298022182u64,
+                7262359305491832456u64,
+                17495095115657847879u64,
+                12358119042818818424u64,
+                4827709861764337110u64,
+                15978686569847976694u64,
+                16187855830772578421u64,
+                11101456649455718632u64,
+                12704086621633822496u64,
+                9760237928578763408u64,
+                13256369416005970919u64,
+                8657267208798592204u64,
+                6604909837049110185u64,
+                16440982411380786186u64,
+                888778800147459444u64,
+                4006070319123778463u64,
+                2724237215706939666u64,
+                2141621889504481493u64,
+                6007678614315119017u64,
+                17925072110441707272u64,
+                9422761034805448838u64,
+                6818553998967672426u64,
+                18442819927044365677u64,
+                1460439462456960078u64,
+                4894086546514612511u64,
+                189178246746295357u64,
+                13754291555236782305u64,
+                2763096927287051521u64,
+                15263048249823138736u64,
+                15186610502826686764u64,
+                17278814135348201247u64,
+                7938944114759379100u64,
+                3002844340014717661u64,
+                9286322735482737652u64,
+                8603541888599047493u64,
+                10187708253564161648u64,
+                12483535625813592006u64,
+                8866164706133843125u64,
+                10808313420035381194u64,
+                11464883001728002227u64,
+                18305168726991465976u64,
+                6862348236496140968u64,
+                6745448141202464443u64,
+                6893332877580684825u64,
+                8474487013978260041u64,
+                18432269679787228855u64,
+                1054970830469339955u64,
+                13296554049371463344u64,
+                6986569377724766712u64,
+                14177976969931239135u64,
+                8575007844517566368u64,
+                4690872754465893930u64,
+                6983869167434277464u64,
+                16372849714941070222u64,
+                1073779157912222144u64,
+                3522657571179625783u64,
+                6326490129634247871u64,
+                12725100524396062158u64,
+                12559421104333261478u64,
+                17845211036638008937u64,
+                9204480745021961054u64,
+                13813823837845807758u64,
+                7219004403314463462u64,
+                3734991583390531250u64,
+                17501731672172605062u64,
+                15936903860247972398u64,
+                2837714970784700370u64,
+                9262237931182335810u64,
+                15403811843642986782u64,
+                5163975801051475362u64,
+                1411195220933832070u64,
+                12865171160498822772u64,
+                3385698110085293885u64,
+                17009233953989577925u64,
+                3808227368440434000u64,
+                5332967137902587563u64,
+                17889740109775490149u64,
+                3867433294443795315u64,
+                11669725503159928528u64,
+                8758224276557376886u64,
+                17065519040149085978u64,
+                10944677000502770704u64,
+                17618473139838322069u64,
+                7998790253751411932u64,
+                16417592299156030377u64,
+                10739929398375405659u64,
+                659440303614626226u64,
+                14257919214984740240u64,
+                879172118650076321u64,
+                12590602134255629942u64,
+                4055100128809882969u64,
+                9548584971491811541u64,
+                9031365223676700799u64,
+            ],
+        };
+        &SET
+    }
+}
+pub const ATOM_LOCALNAME__65_71_75_69_76_61_6C_65_6E_74: LocalName = LocalName::pack_static(0u32);
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_59: LocalName = LocalName::pack_static(1u32);
+pub const ATOM_LOCALNAME__73_74_72_65_74_63_68_79: LocalName = LocalName::pack_static(2u32);
+pub const ATOM_LOCALNAME__67_6C_79_70_68_52_65_66: LocalName = LocalName::pack_static(3u32);
+pub const ATOM_LOCALNAME__6E_61_74_75_72_61_6C_6E_75_6D_62_65_72_73: LocalName =
+    LocalName::pack_static(4u32);
+pub const ATOM_LOCALNAME__6B_65_79_50_6F_69_6E_74_73: LocalName = LocalName::pack_static(5u32);
+pub const ATOM_LOCALNAME__73_6F_6C_69_64_43_6F_6C_6F_72: LocalName = LocalName::pack_static(6u32);
+pub const ATOM_LOCALNAME__70_61_74_68_6C_65_6E_67_74_68: LocalName = LocalName::pack_static(7u32);
+pub const ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68: LocalName = LocalName::pack_static(8u32);
+pub const ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_53_63_72_69_70_74_54_79_70_65: LocalName =
+    LocalName::pack_static(9u32);
+pub const ATOM_LOCALNAME__75_6E_73_65_6C_65_63_74_61_62_6C_65: LocalName =
+    LocalName::pack_static(10u32);
+pub const ATOM_LOCALNAME__73_6F_6C_69_64_2D_63_6F_6C_6F_72: LocalName =
+    LocalName::pack_static(11u32);
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67_73_74_61_72_74: LocalName =
+    LocalName::pack_static(12u32);
+pub const ATOM_LOCALNAME__78_43_68_61_6E_6E_65_6C_53_65_6C_65_63_74_6F_72: LocalName =
+    LocalName::pack_static(13u32);
+pub const ATOM_LOCALNAME__6F_6E_72_6F_77_65_78_69_74: LocalName = LocalName::pack_static(14u32);
+pub const ATOM_LOCALNAME__79_43_68_61_6E_6E_65_6C_53_65_6C_65_63_74_6F_72: LocalName =
+    LocalName::pack_static(15u32);
+pub const ATOM_LOCALNAME__6F_6E_63_61_6E_70_6C_61_79: LocalName = LocalName::pack_static(16u32);
+pub const ATOM_LOCALNAME__66_65_43_6F_6D_70_6F_6E_65_6E_74_54_72_61_6E_73_66_65_72: LocalName =
+    LocalName::pack_static(17u32);
+pub const ATOM_LOCALNAME__6C_69_6E_65_2D_68_65_69_67_68_74: LocalName =
+    LocalName::pack_static(18u32);
+pub const ATOM_LOCALNAME__64_69_66_66_75_73_65_43_6F_6E_73_74_61_6E_74: LocalName =
+    LocalName::pack_static(19u32);
+pub const ATOM_LOCALNAME__68_61_74_63_68_43_6F_6E_74_65_6E_74_55_6E_69_74_73: LocalName =
+    LocalName::pack_static(20u32);
+pub const ATOM_LOCALNAME__75_6E_64_65_72_6C_69_6E_65_2D_70_6F_73_69_74_69_6F_6E: LocalName =
+    LocalName::pack_static(21u32);
+pub const ATOM_LOCALNAME__73_70_72_65_61_64_6D_65_74_68_6F_64: LocalName =
+    LocalName::pack_static(22u32);
+pub const ATOM_LOCALNAME__73_74_72_69_6B_65_74_68_72_6F_75_67_68_2D_74_68_69_63_6B_6E_65_73_73:
+    LocalName = LocalName::pack_static(23u32);
+pub const ATOM_LOCALNAME__66_69_6C_74_65_72_55_6E_69_74_73: LocalName =
+    LocalName::pack_static(24u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_75_72_69: LocalName =
+    LocalName::pack_static(25u32);
+pub const ATOM_LOCALNAME__72_65_71_75_69_72_65_64_45_78_74_65_6E_73_69_6F_6E_73: LocalName =
+    LocalName::pack_static(26u32);
+pub const ATOM_LOCALNAME__74_65_78_74_70_61_74_68: LocalName = LocalName::pack_static(27u32);
+pub const ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_55_6E_69_74_73: LocalName =
+    LocalName::pack_static(28u32);
+pub const ATOM_LOCALNAME__65_71_75_61_6C_72_6F_77_73: LocalName = LocalName::pack_static(29u32);
+pub const ATOM_LOCALNAME__72_61_74_69_6F_6E_61_6C_73: LocalName = LocalName::pack_static(30u32);
+pub const ATOM_LOCALNAME__64_69_73_70_6C_61_79_73_74_79_6C_65: LocalName =
+    LocalName::pack_static(31u32);
+pub const ATOM_LOCALNAME__66_69_6C_6C_2D_72_75_6C_65: LocalName = LocalName::pack_static(32u32);
+pub const ATOM_LOCALNAME__62_61_73_65_50_72_6F_66_69_6C_65: LocalName =
+    LocalName::pack_static(33u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_73_65_74_73_69_7A_65: LocalName =
+    LocalName::pack_static(34u32);
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_5A: LocalName = LocalName::pack_static(35u32);
+pub const ATOM_LOCALNAME__6F_6E_75_6E_6C_6F_61_64: LocalName = LocalName::pack_static(36u32);
+pub const ATOM_LOCALNAME__65_6E_63_6F_64_69_6E_67: LocalName = LocalName::pack_static(37u32);
+pub const ATOM_LOCALNAME__76_65_72_74_2D_6F_72_69_67_69_6E_2D_78: LocalName =
+    LocalName::pack_static(38u32);
+pub const ATOM_LOCALNAME__69_72_72_65_6C_65_76_61_6E_74: LocalName = LocalName::pack_static(39u32);
+pub const ATOM_LOCALNAME__66_6C_6F_6F_64_2D_63_6F_6C_6F_72: LocalName =
+    LocalName::pack_static(40u32);
+pub const ATOM_LOCALNAME__6D_6F_7A_62_72_6F_77_73_65_72: LocalName = LocalName::pack_static(41u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_67_72_61_62: LocalName = LocalName::pack_static(42u32);
+pub const ATOM_LOCALNAME__6F_6E_63_61_6E_70_6C_61_79_74_68_72_6F_75_67_68: LocalName =
+    LocalName::pack_static(43u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_66_6F_72_6D_61_74: LocalName =
+    LocalName::pack_static(44u32);
+pub const ATOM_LOCALNAME__63_6C_69_70_50_61_74_68_55_6E_69_74_73: LocalName =
+    LocalName::pack_static(45u32);
+pub const ATOM_LOCALNAME__68_6F_72_69_7A_2D_6F_72_69_67_69_6E_2D_79: LocalName =
+    LocalName::pack_static(46u32);
+pub const ATOM_LOCALNAME__72_61_64_69_6F_67_72_6F_75_70: LocalName = LocalName::pack_static(47u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_73_69_7A_65_2D_61_64_6A_75_73_74: LocalName =
+    LocalName::pack_static(48u32);
+pub const ATOM_LOCALNAME__6E_75_6D_6F_63_74_61_76_65_73: LocalName = LocalName::pack_static(49u32);
+pub const ATOM_LOCALNAME__67_6C_79_70_68_2D_6F_72_69_65_6E_74_61_74_69_6F_6E_2D_76_65_72_74_69_63_61_6C : LocalName = LocalName :: pack_static (50u32) ;
+pub const ATOM_LOCALNAME__6C_69_6E_65_61_72_67_72_61_64_69_65_6E_74: LocalName =
+    LocalName::pack_static(51u32);
+pub const ATOM_LOCALNAME__69_6D_61_67_69_6E_61_72_79_69: LocalName = LocalName::pack_static(52u32);
+pub const ATOM_LOCALNAME__69_74_65_6D_73_63_6F_70_65: LocalName = LocalName::pack_static(53u32);
+pub const ATOM_LOCALNAME__63_6C_69_70_2D_72_75_6C_65: LocalName = LocalName::pack_static(54u32);
+pub const ATOM_LOCALNAME__66_65_47_61_75_73_73_69_61_6E_42_6C_75_72: LocalName =
+    LocalName::pack_static(55u32);
+pub const ATOM_LOCALNAME__66_65_63_6F_6C_6F_72_6D_61_74_72_69_78: LocalName =
+    LocalName::pack_static(56u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_73_65_63_72_65_74: LocalName =
+    LocalName::pack_static(57u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6C_69_76_65: LocalName = LocalName::pack_static(58u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_69_6E_64_65_78_74_65_78_74: LocalName =
+    LocalName::pack_static(59u32);
+pub const ATOM_LOCALNAME__6D_61_74_68_65_6D_61_74_69_63_61_6C: LocalName =
+    LocalName::pack_static(60u32);
+pub const ATOM_LOCALNAME__66_6F_72_6D_61_63_74_69_6F_6E: LocalName = LocalName::pack_static(61u32);
+pub const ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_63_6C_6F_6E_61_62_6C_65: LocalName =
+    LocalName::pack_static(62u32);
+pub const ATOM_LOCALNAME__63_6F_6C_75_6D_6E_6C_69_6E_65_73: LocalName =
+    LocalName::pack_static(63u32);
+pub const ATOM_LOCALNAME__70_72_69_6D_69_74_69_76_65_75_6E_69_74_73: LocalName =
+    LocalName::pack_static(64u32);
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6D_69_74_65_72_6C_69_6D_69_74: LocalName =
+    LocalName::pack_static(65u32);
+pub const ATOM_LOCALNAME__72_65_76_65_72_73_65_64: LocalName = LocalName::pack_static(66u32);
+pub const ATOM_LOCALNAME__64_69_76_65_72_67_65_6E_63_65: LocalName = LocalName::pack_static(67u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_70_72_65_73_73_65_64: LocalName =
+    LocalName::pack_static(68u32);
+pub const ATOM_LOCALNAME__6F_6E_65_72_72_6F_72_75_70_64_61_74_65: LocalName =
+    LocalName::pack_static(69u32);
+pub const ATOM_LOCALNAME__6B_65_79_54_69_6D_65_73: LocalName = LocalName::pack_static(70u32);
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67_6F_76_65_72: LocalName = LocalName::pack_static(71u32);
+pub const ATOM_LOCALNAME__6E_6F_73_63_72_69_70_74: LocalName = LocalName::pack_static(72u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_68_61_73_70_6F_70_75_70: LocalName =
+    LocalName::pack_static(73u32);
+pub const ATOM_LOCALNAME__76_69_65_77_54_61_72_67_65_74: LocalName = LocalName::pack_static(74u32);
+pub const ATOM_LOCALNAME__78_6D_6C_6E_73_3A_78_6C_69_6E_6B: LocalName =
+    LocalName::pack_static(75u32);
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_2D_6D_61_78: LocalName = LocalName::pack_static(76u32);
+pub const ATOM_LOCALNAME__6F_6E_66_69_6C_74_65_72_63_68_61_6E_67_65: LocalName =
+    LocalName::pack_static(77u32);
+pub const ATOM_LOCALNAME__70_61_74_74_65_72_6E_54_72_61_6E_73_66_6F_72_6D: LocalName =
+    LocalName::pack_static(78u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_73_74_79_6C_65: LocalName = LocalName::pack_static(79u32);
+pub const ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_73_65_72_69_61_6C_69_7A_61_62_6C_65:
+    LocalName = LocalName::pack_static(80u32);
+pub const ATOM_LOCALNAME__6B_65_72_6E_65_6C_55_6E_69_74_4C_65_6E_67_74_68: LocalName =
+    LocalName::pack_static(81u32);
+pub const ATOM_LOCALNAME__72_65_71_75_69_72_65_64_66_65_61_74_75_72_65_73: LocalName =
+    LocalName::pack_static(82u32);
+pub const ATOM_LOCALNAME__78_2D_68_65_69_67_68_74: LocalName = LocalName::pack_static(83u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_61_63_74_69_76_65_64_65_73_63_65_6E_64_61_6E_74:
+    LocalName = LocalName::pack_static(84u32);
+pub const ATOM_LOCALNAME__78_63_68_61_6E_6E_65_6C_73_65_6C_65_63_74_6F_72: LocalName =
+    LocalName::pack_static(85u32);
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_57_69_64_74_68: LocalName =
+    LocalName::pack_static(86u32);
+pub const ATOM_LOCALNAME__68_69_64_65_66_6F_63_75_73: LocalName = LocalName::pack_static(87u32);
+pub const ATOM_LOCALNAME__6F_6E_64_61_74_61_61_76_61_69_6C_61_62_6C_65: LocalName =
+    LocalName::pack_static(88u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_69_6E_76_61_6C_69_64: LocalName =
+    LocalName::pack_static(89u32);
+pub const ATOM_LOCALNAME__67_6C_79_70_68_72_65_66: LocalName = LocalName::pack_static(90u32);
+pub const ATOM_LOCALNAME__70_61_74_74_65_72_6E_74_72_61_6E_73_66_6F_72_6D: LocalName =
+    LocalName::pack_static(91u32);
+pub const ATOM_LOCALNAME__6F_6E_64_61_74_61_73_65_74_63_68_61_6E_67_65_64: LocalName =
+    LocalName::pack_static(92u32);
+pub const ATOM_LOCALNAME__72_6F_77_73_70_61_63_69_6E_67: LocalName = LocalName::pack_static(93u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_65_78_70_61_6E_64_65_64: LocalName =
+    LocalName::pack_static(94u32);
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6F_76_65_72: LocalName =
+    LocalName::pack_static(95u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_64_61_74_61_74_79_70_65: LocalName =
+    LocalName::pack_static(96u32);
+pub const ATOM_LOCALNAME__6E_6F_74_70_72_73_75_62_73_65_74: LocalName =
+    LocalName::pack_static(97u32);
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B_3A_61_72_63_72_6F_6C_65: LocalName =
+    LocalName::pack_static(98u32);
+pub const ATOM_LOCALNAME__6F_6E_62_6F_75_6E_63_65: LocalName = LocalName::pack_static(99u32);
+pub const ATOM_LOCALNAME__73_63_72_69_70_74_73_69_7A_65_6D_75_6C_74_69_70_6C_69_65_72: LocalName =
+    LocalName::pack_static(100u32);
+pub const ATOM_LOCALNAME__63_6F_64_65_62_61_73_65: LocalName = LocalName::pack_static(101u32);
+pub const ATOM_LOCALNAME__73_74_6F_70_2D_63_6F_6C_6F_72: LocalName = LocalName::pack_static(102u32);
+pub const ATOM_LOCALNAME__65_78_70_6F_6E_65_6E_74: LocalName = LocalName::pack_static(103u32);
+pub const ATOM_LOCALNAME__63_6F_6C_75_6D_6E_73_70_61_6E: LocalName = LocalName::pack_static(104u32);
+pub const ATOM_LOCALNAME__74_65_78_74_2D_61_6E_63_68_6F_72: LocalName =
+    LocalName::pack_static(105u32);
+pub const ATOM_LOCALNAME__76_2D_68_61_6E_67_69_6E_67: LocalName = LocalName::pack_static(106u32);
+pub const ATOM_LOCALNAME__66_69_6C_74_65_72_75_6E_69_74_73: LocalName =
+    LocalName::pack_static(107u32);
+pub const ATOM_LOCALNAME__66_61_63_74_6F_72_6F_66: LocalName = LocalName::pack_static(108u32);
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_61_63_74_69_76_61_74_65: LocalName =
+    LocalName::pack_static(109u32);
+pub const ATOM_LOCALNAME__6B_65_79_73_70_6C_69_6E_65_73: LocalName = LocalName::pack_static(110u32);
+pub const ATOM_LOCALNAME__62_61_73_65_6C_69_6E_65_2D_73_68_69_66_74: LocalName =
+    LocalName::pack_static(111u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_6D_69_6E: LocalName =
+    LocalName::pack_static(112u32);
+pub const ATOM_LOCALNAME__78_6D_6C_3A_62_61_73_65: LocalName = LocalName::pack_static(113u32);
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67_65_6E_74_65_72: LocalName =
+    LocalName::pack_static(114u32);
+pub const ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_74_79_70_65: LocalName =
+    LocalName::pack_static(115u32);
+pub const ATOM_LOCALNAME__66_65_6F_66_66_73_65_74: LocalName = LocalName::pack_static(116u32);
+pub const ATOM_LOCALNAME__6C_65_74_74_65_72_2D_73_70_61_63_69_6E_67: LocalName =
+    LocalName::pack_static(117u32);
+pub const ATOM_LOCALNAME__62_61_73_65_6C_69_6E_65: LocalName = LocalName::pack_static(118u32);
+pub const ATOM_LOCALNAME__6F_6E_63_65_6C_6C_63_68_61_6E_67_65: LocalName =
+    LocalName::pack_static(119u32);
+pub const ATOM_LOCALNAME__6D_69_73_73_69_6E_67_2D_67_6C_79_70_68: LocalName =
+    LocalName::pack_static(120u32);
+pub const ATOM_LOCALNAME__61_6C_69_67_6E_6D_65_6E_74_2D_62_61_73_65_6C_69_6E_65: LocalName =
+    LocalName::pack_static(121u32);
+pub const ATOM_LOCALNAME__72_61_64_69_61_6C_67_72_61_64_69_65_6E_74: LocalName =
+    LocalName::pack_static(122u32);
+pub const ATOM_LOCALNAME__6C_69_67_68_74_69_6E_67_2D_63_6F_6C_6F_72: LocalName =
+    LocalName::pack_static(123u32);
+pub const ATOM_LOCALNAME__6D_61_73_6B_63_6F_6E_74_65_6E_74_75_6E_69_74_73: LocalName =
+    LocalName::pack_static(124u32);
+pub const ATOM_LOCALNAME__73_74_61_72_74_6F_66_66_73_65_74: LocalName =
+    LocalName::pack_static(125u32);
+pub const ATOM_LOCALNAME__6F_6E_6C_6F_61_64_65_64_6D_65_74_61_64_61_74_61: LocalName =
+    LocalName::pack_static(126u32);
+pub const ATOM_LOCALNAME__63_6F_6E_74_72_6F_6C_73: LocalName = LocalName::pack_static(127u32);
+pub const ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_6D_6F_64_65: LocalName =
+    LocalName::pack_static(128u32);
+pub const ATOM_LOCALNAME__73_68_61_70_65_2D_72_65_6E_64_65_72_69_6E_67: LocalName =
+    LocalName::pack_static(129u32);
+pub const ATOM_LOCALNAME__61_6E_6E_6F_74_61_74_69_6F_6E: LocalName = LocalName::pack_static(130u32);
+pub const ATOM_LOCALNAME__66_65_67_61_75_73_73_69_61_6E_62_6C_75_72: LocalName =
+    LocalName::pack_static(131u32);
+pub const ATOM_LOCALNAME__73_74_64_64_65_76_69_61_74_69_6F_6E: LocalName =
+    LocalName::pack_static(132u32);
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_65_72_2D_65_76_65_6E_74_73: LocalName =
+    LocalName::pack_static(133u32);
+pub const ATOM_LOCALNAME__69_74_65_6D_74_79_70_65: LocalName = LocalName::pack_static(134u32);
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_43_6F_75_6E_74: LocalName =
+    LocalName::pack_static(135u32);
+pub const ATOM_LOCALNAME__6F_74_68_65_72_77_69_73_65: LocalName = LocalName::pack_static(136u32);
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67_64_72_6F_70: LocalName = LocalName::pack_static(137u32);
+pub const ATOM_LOCALNAME__63_65_6C_6C_70_61_64_64_69_6E_67: LocalName =
+    LocalName::pack_static(138u32);
+pub const ATOM_LOCALNAME__63_6C_69_70_50_61_74_68: LocalName = LocalName::pack_static(139u32);
+pub const ATOM_LOCALNAME__70_72_73_75_62_73_65_74: LocalName = LocalName::pack_static(140u32);
+pub const ATOM_LOCALNAME__70_6C_61_69_6E_74_65_78_74: LocalName = LocalName::pack_static(141u32);
+pub const ATOM_LOCALNAME__6D_61_74_68_76_61_72_69_61_6E_74: LocalName =
+    LocalName::pack_static(142u32);
+pub const ATOM_LOCALNAME__6F_6E_61_66_74_65_72_75_70_64_61_74_65: LocalName =
+    LocalName::pack_static(143u32);
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_2D_73_74_61_72_74: LocalName =
+    LocalName::pack_static(144u32);
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_77_69_64_74_68: LocalName =
+    LocalName::pack_static(145u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_62_72_61_69_6C_6C_65_6C_61_62_65_6C: LocalName =
+    LocalName::pack_static(146u32);
+pub const ATOM_LOCALNAME__75_6E_64_65_72_6C_69_6E_65_2D_74_68_69_63_6B_6E_65_73_73: LocalName =
+    LocalName::pack_static(147u32);
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67_6C_65_61_76_65: LocalName =
+    LocalName::pack_static(148u32);
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_77_69_64_74_68: LocalName =
+    LocalName::pack_static(149u32);
+pub const ATOM_LOCALNAME__76_65_63_74_6F_72_2D_65_66_66_65_63_74: LocalName =
+    LocalName::pack_static(150u32);
+pub const ATOM_LOCALNAME__6F_6E_61_66_74_65_72_70_72_69_6E_74: LocalName =
+    LocalName::pack_static(151u32);
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_44_75_72: LocalName = LocalName::pack_static(152u32);
+pub const ATOM_LOCALNAME__66_6F_72_6D_65_6E_63_74_79_70_65: LocalName =
+    LocalName::pack_static(153u32);
+pub const ATOM_LOCALNAME__69_6E_6E_65_72_74_65_78_74: LocalName = LocalName::pack_static(154u32);
+pub const ATOM_LOCALNAME__76_65_63_74_6F_72_70_72_6F_64_75_63_74: LocalName =
+    LocalName::pack_static(155u32);
+pub const ATOM_LOCALNAME__6F_6E_70_61_67_65_73_77_61_70: LocalName = LocalName::pack_static(156u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6C_61_62_65_6C_6C_65_64_62_79: LocalName =
+    LocalName::pack_static(157u32);
+pub const ATOM_LOCALNAME__6D_61_72_67_69_6E_77_69_64_74_68: LocalName =
+    LocalName::pack_static(158u32);
+pub const ATOM_LOCALNAME__62_61_73_65_66_72_65_71_75_65_6E_63_79: LocalName =
+    LocalName::pack_static(159u32);
+pub const ATOM_LOCALNAME__66_65_73_70_65_63_75_6C_61_72_6C_69_67_68_74_69_6E_67: LocalName =
+    LocalName::pack_static(160u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_68_61_6E_6E_65_6C: LocalName =
+    LocalName::pack_static(161u32);
+pub const ATOM_LOCALNAME__61_6C_69_67_6E_6D_65_6E_74_73_63_6F_70_65: LocalName =
+    LocalName::pack_static(162u32);
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_55_6E_69_74_73: LocalName =
+    LocalName::pack_static(163u32);
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_70_72_69_6E_74: LocalName =
+    LocalName::pack_static(164u32);
+pub const ATOM_LOCALNAME__62_61_73_65_46_72_65_71_75_65_6E_63_79: LocalName =
+    LocalName::pack_static(165u32);
+pub const ATOM_LOCALNAME__78_6D_6C_3A_6C_61_6E_67: LocalName = LocalName::pack_static(166u32);
+pub const ATOM_LOCALNAME__6C_69_6D_69_74_69_6E_67_43_6F_6E_65_41_6E_67_6C_65: LocalName =
+    LocalName::pack_static(167u32);
+pub const ATOM_LOCALNAME__65_64_67_65_4D_6F_64_65: LocalName = LocalName::pack_static(168u32);
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_65_4D_6F_74_69_6F_6E: LocalName =
+    LocalName::pack_static(169u32);
+pub const ATOM_LOCALNAME__68_61_74_63_68_55_6E_69_74_73: LocalName = LocalName::pack_static(170u32);
+pub const ATOM_LOCALNAME__63_6F_6E_74_72_6F_6C_6C_65_72_63_68_61_6E_67_65: LocalName =
+    LocalName::pack_static(171u32);
+pub const ATOM_LOCALNAME__6C_65_6E_67_74_68_41_64_6A_75_73_74: LocalName =
+    LocalName::pack_static(172u32);
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_58: LocalName = LocalName::pack_static(173u32);
+pub const ATOM_LOCALNAME__67_6C_79_70_68_2D_6E_61_6D_65: LocalName = LocalName::pack_static(174u32);
+pub const ATOM_LOCALNAME__72_65_71_75_69_72_65_64: LocalName = LocalName::pack_static(175u32);
+pub const ATOM_LOCALNAME__65_75_6C_65_72_67_61_6D_6D_61: LocalName = LocalName::pack_static(176u32);
+pub const ATOM_LOCALNAME__68_6F_72_69_7A_2D_61_64_76_2D_78: LocalName =
+    LocalName::pack_static(177u32);
+pub const ATOM_LOCALNAME__64_61_74_61_6C_69_73_74: LocalName = LocalName::pack_static(178u32);
+pub const ATOM_LOCALNAME__70_72_65_73_65_72_76_65_41_73_70_65_63_74_52_61_74_69_6F: LocalName =
+    LocalName::pack_static(179u32);
+pub const ATOM_LOCALNAME__6D_61_74_68_63_6F_6C_6F_72: LocalName = LocalName::pack_static(180u32);
+pub const ATOM_LOCALNAME__6D_6D_75_6C_74_69_73_63_72_69_70_74_73: LocalName =
+    LocalName::pack_static(181u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6F_77_6E_73: LocalName = LocalName::pack_static(182u32);
+pub const ATOM_LOCALNAME__66_65_73_70_6F_74_6C_69_67_68_74: LocalName =
+    LocalName::pack_static(183u32);
+pub const ATOM_LOCALNAME__62_6C_6F_63_6B_71_75_6F_74_65: LocalName = LocalName::pack_static(184u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6D_6F_64_61_6C: LocalName = LocalName::pack_static(185u32);
+pub const ATOM_LOCALNAME__6F_6E_73_65_65_6B_69_6E_67: LocalName = LocalName::pack_static(186u32);
+pub const ATOM_LOCALNAME__76_65_72_79_76_65_72_79_74_68_69_63_6B_6D_61_74_68_73_70_61_63_65:
+    LocalName = LocalName::pack_static(187u32);
+pub const ATOM_LOCALNAME__74_61_62_6C_65_56_61_6C_75_65_73: LocalName =
+    LocalName::pack_static(188u32);
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_65_6D_6F_74_69_6F_6E: LocalName =
+    LocalName::pack_static(189u32);
+pub const ATOM_LOCALNAME__76_2D_61_6C_70_68_61_62_65_74_69_63: LocalName =
+    LocalName::pack_static(190u32);
+pub const ATOM_LOCALNAME__65_78_70_6F_6E_65_6E_74_69_61_6C_65: LocalName =
+    LocalName::pack_static(191u32);
+pub const ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_64_65_6C_65_67_61_74_65_73_66_6F_63_75_73 : LocalName = LocalName :: pack_static (192u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_67_69_6E_68_65_69_67_68_74: LocalName =
+    LocalName::pack_static(193u32);
+pub const ATOM_LOCALNAME__6F_6E_64_65_61_63_74_69_76_61_74_65: LocalName =
+    LocalName::pack_static(194u32);
+pub const ATOM_LOCALNAME__61_72_61_62_69_63_2D_66_6F_72_6D: LocalName =
+    LocalName::pack_static(195u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_74_65_78_74: LocalName =
+    LocalName::pack_static(196u32);
+pub const ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_65_64_69_74_61_62_6C_65: LocalName =
+    LocalName::pack_static(197u32);
+pub const ATOM_LOCALNAME__68_61_74_63_68_70_61_74_68: LocalName = LocalName::pack_static(198u32);
+pub const ATOM_LOCALNAME__6F_6E_66_69_6E_69_73_68: LocalName = LocalName::pack_static(199u32);
+pub const ATOM_LOCALNAME__73_70_65_6C_6C_63_68_65_63_6B: LocalName = LocalName::pack_static(200u32);
+pub const ATOM_LOCALNAME__6F_6E_72_6F_77_73_69_6E_73_65_72_74_65_64: LocalName =
+    LocalName::pack_static(201u32);
+pub const ATOM_LOCALNAME__6F_6E_72_6F_77_65_6E_74_65_72: LocalName = LocalName::pack_static(202u32);
+pub const ATOM_LOCALNAME__6F_6E_6C_61_6E_67_75_61_67_65_63_68_61_6E_67_65: LocalName =
+    LocalName::pack_static(203u32);
+pub const ATOM_LOCALNAME__63_6C_69_70_2D_70_61_74_68: LocalName = LocalName::pack_static(204u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_66_6C_6F_77_74_6F: LocalName =
+    LocalName::pack_static(205u32);
+pub const ATOM_LOCALNAME__66_6F_72_6D_6E_6F_76_61_6C_69_64_61_74_65: LocalName =
+    LocalName::pack_static(206u32);
+pub const ATOM_LOCALNAME__6D_61_6E_69_66_65_73_74: LocalName = LocalName::pack_static(207u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6D_75_6C_74_69_6C_69_6E_65: LocalName =
+    LocalName::pack_static(208u32);
+pub const ATOM_LOCALNAME__66_6F_72_65_69_67_6E_4F_62_6A_65_63_74: LocalName =
+    LocalName::pack_static(209u32);
+pub const ATOM_LOCALNAME__66_65_43_6F_6E_76_6F_6C_76_65_4D_61_74_72_69_78: LocalName =
+    LocalName::pack_static(210u32);
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_65_6E_74_65_72: LocalName =
+    LocalName::pack_static(211u32);
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_65_54_72_61_6E_73_66_6F_72_6D: LocalName =
+    LocalName::pack_static(212u32);
+pub const ATOM_LOCALNAME__66_65_4D_65_72_67_65_4E_6F_64_65: LocalName =
+    LocalName::pack_static(213u32);
+pub const ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_6E_61_6D_65: LocalName =
+    LocalName::pack_static(214u32);
+pub const ATOM_LOCALNAME__6F_6E_73_74_6F_72_61_67_65: LocalName = LocalName::pack_static(215u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_73_6F_72_74: LocalName = LocalName::pack_static(216u32);
+pub const ATOM_LOCALNAME__6D_6F_6D_65_6E_74_61_62_6F_75_74: LocalName =
+    LocalName::pack_static(217u32);
+pub const ATOM_LOCALNAME__6D_70_72_65_73_63_72_69_70_74_73: LocalName =
+    LocalName::pack_static(218u32);
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B_3A_61_63_74_75_61_74_65: LocalName =
+    LocalName::pack_static(219u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_64_69_73_61_62_6C_65_64: LocalName =
+    LocalName::pack_static(220u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_62_72_61_69_6C_6C_65_72_6F_6C_65_64_65_73_63_72_69_70_74_69_6F_6E : LocalName = LocalName :: pack_static (221u32) ;
+pub const ATOM_LOCALNAME__62_61_73_65_66_6F_6E_74: LocalName = LocalName::pack_static(222u32);
+pub const ATOM_LOCALNAME__73_6F_6C_69_64_63_6F_6C_6F_72: LocalName = LocalName::pack_static(223u32);
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_77_68_65_65_6C: LocalName =
+    LocalName::pack_static(224u32);
+pub const ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_75_6E_69_74_73: LocalName =
+    LocalName::pack_static(225u32);
+pub const ATOM_LOCALNAME__64_61_74_61_74_65_6D_70_6C_61_74_65: LocalName =
+    LocalName::pack_static(226u32);
+pub const ATOM_LOCALNAME__6D_61_73_6B_55_6E_69_74_73: LocalName = LocalName::pack_static(227u32);
+pub const ATOM_LOCALNAME__70_72_65_73_65_72_76_65_61_6C_70_68_61: LocalName =
+    LocalName::pack_static(228u32);
+pub const ATOM_LOCALNAME__74_65_78_74_6C_65_6E_67_74_68: LocalName = LocalName::pack_static(229u32);
+pub const ATOM_LOCALNAME__70_72_65_73_65_72_76_65_41_6C_70_68_61: LocalName =
+    LocalName::pack_static(230u32);
+pub const ATOM_LOCALNAME__62_61_63_6B_67_72_6F_75_6E_64: LocalName = LocalName::pack_static(231u32);
+pub const ATOM_LOCALNAME__64_69_66_66_75_73_65_63_6F_6E_73_74_61_6E_74: LocalName =
+    LocalName::pack_static(232u32);
+pub const ATOM_LOCALNAME__6E_6F_74_61_74_69_6F_6E: LocalName = LocalName::pack_static(233u32);
+pub const ATOM_LOCALNAME__76_65_72_74_2D_6F_72_69_67_69_6E_2D_79: LocalName =
+    LocalName::pack_static(234u32);
+pub const ATOM_LOCALNAME__6F_76_65_72_6C_69_6E_65_2D_70_6F_73_69_74_69_6F_6E: LocalName =
+    LocalName::pack_static(235u32);
+pub const ATOM_LOCALNAME__65_6E_61_62_6C_65_2D_62_61_63_6B_67_72_6F_75_6E_64: LocalName =
+    LocalName::pack_static(236u32);
+pub const ATOM_LOCALNAME__6F_6E_63_6F_6D_6D_61_6E_64: LocalName = LocalName::pack_static(237u32);
+pub const ATOM_LOCALNAME__72_6F_77_61_6C_69_67_6E: LocalName = LocalName::pack_static(238u32);
+pub const ATOM_LOCALNAME__61_63_74_69_6F_6E_74_79_70_65: LocalName = LocalName::pack_static(239u32);
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_64_61_73_68_61_72_72_61_79: LocalName =
+    LocalName::pack_static(240u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_64_65_74_61_69_6C_73: LocalName =
+    LocalName::pack_static(241u32);
+pub const ATOM_LOCALNAME__74_61_62_6C_65_76_61_6C_75_65_73: LocalName =
+    LocalName::pack_static(242u32);
+pub const ATOM_LOCALNAME__61_75_74_6F_73_75_62_6D_69_74: LocalName = LocalName::pack_static(243u32);
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_75_6E_6C_6F_61_64: LocalName =
+    LocalName::pack_static(244u32);
+pub const ATOM_LOCALNAME__66_61_63_74_6F_72_69_61_6C: LocalName = LocalName::pack_static(245u32);
+pub const ATOM_LOCALNAME__73_74_69_74_63_68_74_69_6C_65_73: LocalName =
+    LocalName::pack_static(246u32);
+pub const ATOM_LOCALNAME__67_72_6F_75_70_61_6C_69_67_6E: LocalName = LocalName::pack_static(247u32);
+pub const ATOM_LOCALNAME__6F_6E_63_6F_6E_74_65_78_74_6D_65_6E_75: LocalName =
+    LocalName::pack_static(248u32);
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_2D_73_74_61_72_74: LocalName =
+    LocalName::pack_static(249u32);
+pub const ATOM_LOCALNAME__61_63_63_65_6E_74_75_6E_64_65_72: LocalName =
+    LocalName::pack_static(250u32);
+pub const ATOM_LOCALNAME__61_64_64_69_74_69_76_65: LocalName = LocalName::pack_static(251u32);
+pub const ATOM_LOCALNAME__66_65_64_69_66_66_75_73_65_6C_69_67_68_74_69_6E_67: LocalName =
+    LocalName::pack_static(252u32);
+pub const ATOM_LOCALNAME__66_65_64_69_73_74_61_6E_74_6C_69_67_68_74: LocalName =
+    LocalName::pack_static(253u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_64_65_73_63_72_69_70_74_69_6F_6E: LocalName =
+    LocalName::pack_static(254u32);
+pub const ATOM_LOCALNAME__64_65_63_6F_64_69_6E_67: LocalName = LocalName::pack_static(255u32);
+pub const ATOM_LOCALNAME__66_65_63_6F_6D_70_6F_6E_65_6E_74_74_72_61_6E_73_66_65_72: LocalName =
+    LocalName::pack_static(256u32);
+pub const ATOM_LOCALNAME__70_72_6F_67_72_65_73_73: LocalName = LocalName::pack_static(257u32);
+pub const ATOM_LOCALNAME__66_6F_72_6D_6D_65_74_68_6F_64: LocalName = LocalName::pack_static(258u32);
+pub const ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_45_78_70_6F_6E_65_6E_74: LocalName =
+    LocalName::pack_static(259u32);
+pub const ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_43_6F_6E_73_74_61_6E_74: LocalName =
+    LocalName::pack_static(260u32);
+pub const ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68_64_65_66: LocalName =
+    LocalName::pack_static(261u32);
+pub const ATOM_LOCALNAME__64_72_61_67_67_61_62_6C_65: LocalName = LocalName::pack_static(262u32);
+pub const ATOM_LOCALNAME__6F_6E_6F_66_66_6C_69_6E_65: LocalName = LocalName::pack_static(263u32);
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_64_65_61_63_74_69_76_61_74_65: LocalName =
+    LocalName::pack_static(264u32);
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_2D_65_6E_64: LocalName = LocalName::pack_static(265u32);
+pub const ATOM_LOCALNAME__6F_6E_70_72_6F_70_65_72_74_79_63_68_61_6E_67_65: LocalName =
+    LocalName::pack_static(266u32);
+pub const ATOM_LOCALNAME__74_68_69_6E_6D_61_74_68_73_70_61_63_65: LocalName =
+    LocalName::pack_static(267u32);
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_70_61_73_74_65: LocalName =
+    LocalName::pack_static(268u32);
+pub const ATOM_LOCALNAME__7A_6F_6F_6D_41_6E_64_50_61_6E: LocalName = LocalName::pack_static(269u32);
+pub const ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_4E_61_6D_65: LocalName =
+    LocalName::pack_static(270u32);
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_63_75_74: LocalName =
+    LocalName::pack_static(271u32);
+pub const ATOM_LOCALNAME__73_75_72_66_61_63_65_73_63_61_6C_65: LocalName =
+    LocalName::pack_static(272u32);
+pub const ATOM_LOCALNAME__66_65_44_69_73_74_61_6E_74_4C_69_67_68_74: LocalName =
+    LocalName::pack_static(273u32);
+pub const ATOM_LOCALNAME__76_61_72_69_61_6E_63_65: LocalName = LocalName::pack_static(274u32);
+pub const ATOM_LOCALNAME__74_65_78_74_61_72_65_61: LocalName = LocalName::pack_static(275u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_68_65_63_6B_65_64: LocalName =
+    LocalName::pack_static(276u32);
+pub const ATOM_LOCALNAME__73_74_61_74_65_63_68_61_6E_67_65: LocalName =
+    LocalName::pack_static(277u32);
+pub const ATOM_LOCALNAME__74_72_61_6E_73_66_6F_72_6D_2D_6F_72_69_67_69_6E: LocalName =
+    LocalName::pack_static(278u32);
+pub const ATOM_LOCALNAME__65_64_67_65_6D_6F_64_65: LocalName = LocalName::pack_static(279u32);
+pub const ATOM_LOCALNAME__6B_65_72_6E_65_6C_4D_61_74_72_69_78: LocalName =
+    LocalName::pack_static(280u32);
+pub const ATOM_LOCALNAME__6D_70_68_61_6E_74_6F_6D: LocalName = LocalName::pack_static(281u32);
+pub const ATOM_LOCALNAME__63_6F_6C_6F_72_2D_69_6E_74_65_72_70_6F_6C_61_74_69_6F_6E_2D_66_69_6C_74_65_72_73 : LocalName = LocalName :: pack_static (282u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_63_6F_75_6E_74: LocalName =
+    LocalName::pack_static(283u32);
+pub const ATOM_LOCALNAME__63_61_70_2D_68_65_69_67_68_74: LocalName = LocalName::pack_static(284u32);
+pub const ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68: LocalName = LocalName::pack_static(285u32);
+pub const ATOM_LOCALNAME__6E_6F_76_61_6C_69_64_61_74_65: LocalName = LocalName::pack_static(286u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_70_6F_73_69_6E_73_65_74: LocalName =
+    LocalName::pack_static(287u32);
+pub const ATOM_LOCALNAME__73_65_6D_61_6E_74_69_63_73: LocalName = LocalName::pack_static(288u32);
+pub const ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_54_72_61_6E_73_66_6F_72_6D: LocalName =
+    LocalName::pack_static(289u32);
+pub const ATOM_LOCALNAME__64_65_66_69_6E_69_74_69_6F_6E_55_52_4C: LocalName =
+    LocalName::pack_static(290u32);
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_65_74_72_61_6E_73_66_6F_72_6D: LocalName =
+    LocalName::pack_static(291u32);
+pub const ATOM_LOCALNAME__6B_65_79_74_69_6D_65_73: LocalName = LocalName::pack_static(292u32);
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67_65_78_69_74: LocalName = LocalName::pack_static(293u32);
+pub const ATOM_LOCALNAME__73_65_70_61_72_61_74_6F_72: LocalName = LocalName::pack_static(294u32);
+pub const ATOM_LOCALNAME__6F_6E_73_75_73_70_65_6E_64: LocalName = LocalName::pack_static(295u32);
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6D_6F_76_65: LocalName =
+    LocalName::pack_static(296u32);
+pub const ATOM_LOCALNAME__69_6E_6E_65_72_54_65_78_74: LocalName = LocalName::pack_static(297u32);
+pub const ATOM_LOCALNAME__66_65_53_70_6F_74_4C_69_67_68_74: LocalName =
+    LocalName::pack_static(298u32);
+pub const ATOM_LOCALNAME__6F_6E_6D_65_73_73_61_67_65_65_72_72_6F_72: LocalName =
+    LocalName::pack_static(299u32);
+pub const ATOM_LOCALNAME__65_6C_65_76_61_74_69_6F_6E: LocalName = LocalName::pack_static(300u32);
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_68_65_69_67_68_74: LocalName =
+    LocalName::pack_static(301u32);
+pub const ATOM_LOCALNAME__66_65_43_6F_6C_6F_72_4D_61_74_72_69_78: LocalName =
+    LocalName::pack_static(302u32);
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_69_6F_6E: LocalName = LocalName::pack_static(303u32);
+pub const ATOM_LOCALNAME__70_72_65_66_65_74_63_68: LocalName = LocalName::pack_static(304u32);
+pub const ATOM_LOCALNAME__6C_65_6E_67_74_68_61_64_6A_75_73_74: LocalName =
+    LocalName::pack_static(305u32);
+pub const ATOM_LOCALNAME__65_71_75_61_6C_63_6F_6C_75_6D_6E_73: LocalName =
+    LocalName::pack_static(306u32);
+pub const ATOM_LOCALNAME__70_61_72_74_69_61_6C_64_69_66_66: LocalName =
+    LocalName::pack_static(307u32);
+pub const ATOM_LOCALNAME__69_6E_74_65_67_72_69_74_79: LocalName = LocalName::pack_static(308u32);
+pub const ATOM_LOCALNAME__6F_75_74_65_72_70_72_6F_64_75_63_74: LocalName =
+    LocalName::pack_static(309u32);
+pub const ATOM_LOCALNAME__70_6F_70_6F_76_65_72_74_61_72_67_65_74: LocalName =
+    LocalName::pack_static(310u32);
+pub const ATOM_LOCALNAME__73_79_6D_6D_65_74_72_69_63: LocalName = LocalName::pack_static(311u32);
+pub const ATOM_LOCALNAME__61_6C_70_68_61_62_65_74_69_63: LocalName = LocalName::pack_static(312u32);
+pub const ATOM_LOCALNAME__74_72_61_6E_73_6C_61_74_65: LocalName = LocalName::pack_static(313u32);
+pub const ATOM_LOCALNAME__6F_70_65_72_61_74_6F_72: LocalName = LocalName::pack_static(314u32);
+pub const ATOM_LOCALNAME__66_6F_72_6D_74_61_72_67_65_74: LocalName = LocalName::pack_static(315u32);
+pub const ATOM_LOCALNAME__61_75_74_6F_66_6F_63_75_73: LocalName = LocalName::pack_static(316u32);
+pub const ATOM_LOCALNAME__7A_6F_6F_6D_61_6E_64_70_61_6E: LocalName = LocalName::pack_static(317u32);
+pub const ATOM_LOCALNAME__64_69_72_65_63_74_69_6F_6E: LocalName = LocalName::pack_static(318u32);
+pub const ATOM_LOCALNAME__66_65_44_72_6F_70_53_68_61_64_6F_77: LocalName =
+    LocalName::pack_static(319u32);
+pub const ATOM_LOCALNAME__63_6F_6C_75_6D_6E_77_69_64_74_68: LocalName =
+    LocalName::pack_static(320u32);
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_64_6F_77_6E: LocalName =
+    LocalName::pack_static(321u32);
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_65_63_6F_6C_6F_72: LocalName =
+    LocalName::pack_static(322u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_63_6F_75_6E_74: LocalName =
+    LocalName::pack_static(323u32);
+pub const ATOM_LOCALNAME__6F_6E_63_75_65_63_68_61_6E_67_65: LocalName =
+    LocalName::pack_static(324u32);
+pub const ATOM_LOCALNAME__6F_6E_72_65_73_69_7A_65: LocalName = LocalName::pack_static(325u32);
+pub const ATOM_LOCALNAME__66_72_61_6D_65_62_6F_72_64_65_72: LocalName =
+    LocalName::pack_static(326u32);
+pub const ATOM_LOCALNAME__64_6F_77_6E_6C_6F_61_64: LocalName = LocalName::pack_static(327u32);
+pub const ATOM_LOCALNAME__76_2D_6D_61_74_68_65_6D_61_74_69_63_61_6C: LocalName =
+    LocalName::pack_static(328u32);
+pub const ATOM_LOCALNAME__70_72_65_73_65_72_76_65_61_73_70_65_63_74_72_61_74_69_6F: LocalName =
+    LocalName::pack_static(329u32);
+pub const ATOM_LOCALNAME__69_64_65_6F_67_72_61_70_68_69_63: LocalName =
+    LocalName::pack_static(330u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_6E_6F_77: LocalName =
+    LocalName::pack_static(331u32);
+pub const ATOM_LOCALNAME__72_65_71_75_69_72_65_64_46_65_61_74_75_72_65_73: LocalName =
+    LocalName::pack_static(332u32);
+pub const ATOM_LOCALNAME__66_72_61_6D_65_73_65_74: LocalName = LocalName::pack_static(333u32);
+pub const ATOM_LOCALNAME__6D_61_74_68_62_61_63_6B_67_72_6F_75_6E_64: LocalName =
+    LocalName::pack_static(334u32);
+pub const ATOM_LOCALNAME__6E_6F_72_65_73_69_7A_65: LocalName = LocalName::pack_static(335u32);
+pub const ATOM_LOCALNAME__63_6C_69_70_70_61_74_68_75_6E_69_74_73: LocalName =
+    LocalName::pack_static(336u32);
+pub const ATOM_LOCALNAME__70_6F_6C_79_6C_69_6E_65: LocalName = LocalName::pack_static(337u32);
+pub const ATOM_LOCALNAME__6D_65_74_61_64_61_74_61: LocalName = LocalName::pack_static(338u32);
+pub const ATOM_LOCALNAME__71_75_6F_74_69_65_6E_74: LocalName = LocalName::pack_static(339u32);
+pub const ATOM_LOCALNAME__6E_6F_74_61_6E_75_6D_62_65_72: LocalName = LocalName::pack_static(340u32);
+pub const ATOM_LOCALNAME__66_65_6D_6F_72_70_68_6F_6C_6F_67_79: LocalName =
+    LocalName::pack_static(341u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6F_72_69_65_6E_74_61_74_69_6F_6E: LocalName =
+    LocalName::pack_static(342u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6D_75_6C_74_69_73_65_6C_65_63_74_61_62_6C_65: LocalName =
+    LocalName::pack_static(343u32);
+pub const ATOM_LOCALNAME__69_6E_66_69_6E_69_74_79: LocalName = LocalName::pack_static(344u32);
+pub const ATOM_LOCALNAME__73_74_61_72_74_4F_66_66_73_65_74: LocalName =
+    LocalName::pack_static(345u32);
+pub const ATOM_LOCALNAME__6C_69_6E_65_61_72_47_72_61_64_69_65_6E_74: LocalName =
+    LocalName::pack_static(346u32);
+pub const ATOM_LOCALNAME__6D_65_6E_75_69_74_65_6D: LocalName = LocalName::pack_static(347u32);
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_75_70_64_61_74_65: LocalName =
+    LocalName::pack_static(348u32);
+pub const ATOM_LOCALNAME__66_65_50_6F_69_6E_74_4C_69_67_68_74: LocalName =
+    LocalName::pack_static(349u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65: LocalName = LocalName::pack_static(350u32);
+pub const ATOM_LOCALNAME__63_6F_6C_6F_72_73_70_61_63_65: LocalName = LocalName::pack_static(351u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_69_6E_64_65_78: LocalName =
+    LocalName::pack_static(352u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_62_75_73_79: LocalName = LocalName::pack_static(353u32);
+pub const ATOM_LOCALNAME__63_6F_6D_70_6C_65_78_65_73: LocalName = LocalName::pack_static(354u32);
+pub const ATOM_LOCALNAME__64_61_74_65_74_69_6D_65: LocalName = LocalName::pack_static(355u32);
+pub const ATOM_LOCALNAME__66_69_6C_6C_2D_6F_70_61_63_69_74_79: LocalName =
+    LocalName::pack_static(356u32);
+pub const ATOM_LOCALNAME__68_6F_72_69_7A_2D_6F_72_69_67_69_6E_2D_78: LocalName =
+    LocalName::pack_static(357u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_6E_61_6D_65: LocalName =
+    LocalName::pack_static(358u32);
+pub const ATOM_LOCALNAME__63_6F_6C_75_6D_6E_61_6C_69_67_6E: LocalName =
+    LocalName::pack_static(359u32);
+pub const ATOM_LOCALNAME__73_74_72_69_6B_65_74_68_72_6F_75_67_68_2D_70_6F_73_69_74_69_6F_6E:
+    LocalName = LocalName::pack_static(360u32);
+pub const ATOM_LOCALNAME__6F_6E_66_6F_72_6D_63_68_61_6E_67_65: LocalName =
+    LocalName::pack_static(361u32);
+pub const ATOM_LOCALNAME__63_6C_6F_73_65_64_62_79: LocalName = LocalName::pack_static(362u32);
+pub const ATOM_LOCALNAME__73_70_72_65_61_64_4D_65_74_68_6F_64: LocalName =
+    LocalName::pack_static(363u32);
+pub const ATOM_LOCALNAME__74_72_61_6E_73_70_6F_73_65: LocalName = LocalName::pack_static(364u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_64_72_6F_70_65_66_66_65_63_74: LocalName =
+    LocalName::pack_static(365u32);
+pub const ATOM_LOCALNAME__69_6D_61_67_69_6E_61_72_79: LocalName = LocalName::pack_static(366u32);
+pub const ATOM_LOCALNAME__73_63_72_6F_6C_6C_69_6E_67: LocalName = LocalName::pack_static(367u32);
+pub const ATOM_LOCALNAME__6F_6E_73_63_72_6F_6C_6C: LocalName = LocalName::pack_static(368u32);
+pub const ATOM_LOCALNAME__6F_6E_70_61_67_65_68_69_64_65: LocalName = LocalName::pack_static(369u32);
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_76_65_73_74_61_72_74: LocalName =
+    LocalName::pack_static(370u32);
+pub const ATOM_LOCALNAME__66_65_64_72_6F_70_73_68_61_64_6F_77: LocalName =
+    LocalName::pack_static(371u32);
+pub const ATOM_LOCALNAME__6D_75_6E_64_65_72_6F_76_65_72: LocalName = LocalName::pack_static(372u32);
+pub const ATOM_LOCALNAME__73_65_6C_65_63_74_69_6F_6E: LocalName = LocalName::pack_static(373u32);
+pub const ATOM_LOCALNAME__67_6C_79_70_68_2D_6F_72_69_65_6E_74_61_74_69_6F_6E_2D_68_6F_72_69_7A_6F_6E_74_61_6C : LocalName = LocalName :: pack_static (374u32) ;
+pub const ATOM_LOCALNAME__69_74_65_6D_70_72_6F_70: LocalName = LocalName::pack_static(375u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_73_74_79_6C_65: LocalName = LocalName::pack_static(376u32);
+pub const ATOM_LOCALNAME__6F_6E_74_6F_67_67_6C_65: LocalName = LocalName::pack_static(377u32);
+pub const ATOM_LOCALNAME__73_65_6C_65_63_74_65_64: LocalName = LocalName::pack_static(378u32);
+pub const ATOM_LOCALNAME__68_74_74_70_2D_65_71_75_69_76: LocalName = LocalName::pack_static(379u32);
+pub const ATOM_LOCALNAME__6D_61_73_6B_75_6E_69_74_73: LocalName = LocalName::pack_static(380u32);
+pub const ATOM_LOCALNAME__64_61_74_61_66_6F_72_6D_61_74_61_73: LocalName =
+    LocalName::pack_static(381u32);
+pub const ATOM_LOCALNAME__6F_6E_73_75_62_6D_69_74: LocalName = LocalName::pack_static(382u32);
+pub const ATOM_LOCALNAME__66_65_74_63_68_70_72_69_6F_72_69_74_79: LocalName =
+    LocalName::pack_static(383u32);
+pub const ATOM_LOCALNAME__76_65_72_79_74_68_69_6E_6D_61_74_68_73_70_61_63_65: LocalName =
+    LocalName::pack_static(384u32);
+pub const ATOM_LOCALNAME__6D_61_74_68_73_69_7A_65: LocalName = LocalName::pack_static(385u32);
+pub const ATOM_LOCALNAME__66_65_64_69_73_70_6C_61_63_65_6D_65_6E_74_6D_61_70: LocalName =
+    LocalName::pack_static(386u32);
+pub const ATOM_LOCALNAME__63_61_6C_63_4D_6F_64_65: LocalName = LocalName::pack_static(387u32);
+pub const ATOM_LOCALNAME__73_65_70_61_72_61_74_6F_72_73: LocalName = LocalName::pack_static(388u32);
+pub const ATOM_LOCALNAME__66_6C_6F_6F_64_2D_6F_70_61_63_69_74_79: LocalName =
+    LocalName::pack_static(389u32);
+pub const ATOM_LOCALNAME__6F_76_65_72_6C_69_6E_65_2D_74_68_69_63_6B_6E_65_73_73: LocalName =
+    LocalName::pack_static(390u32);
+pub const ATOM_LOCALNAME__6F_6E_72_65_61_64_79_73_74_61_74_65_63_68_61_6E_67_65: LocalName =
+    LocalName::pack_static(391u32);
+pub const ATOM_LOCALNAME__63_6F_6C_75_6D_6E_73_70_61_63_69_6E_67: LocalName =
+    LocalName::pack_static(392u32);
+pub const ATOM_LOCALNAME__73_70_65_63_69_66_69_63_61_74_69_6F_6E: LocalName =
+    LocalName::pack_static(393u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_69_6E_64_65_78_74_65_78_74: LocalName =
+    LocalName::pack_static(394u32);
+pub const ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_63_6F_6E_73_74_61_6E_74: LocalName =
+    LocalName::pack_static(395u32);
+pub const ATOM_LOCALNAME__63_65_6C_6C_73_70_61_63_69_6E_67: LocalName =
+    LocalName::pack_static(396u32);
+pub const ATOM_LOCALNAME__62_61_73_65_70_72_6F_66_69_6C_65: LocalName =
+    LocalName::pack_static(397u32);
+pub const ATOM_LOCALNAME__72_65_71_75_69_72_65_64_65_78_74_65_6E_73_69_6F_6E_73: LocalName =
+    LocalName::pack_static(398u32);
+pub const ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_54_79_70_65: LocalName =
+    LocalName::pack_static(399u32);
+pub const ATOM_LOCALNAME__76_65_72_79_76_65_72_79_74_68_69_6E_6D_61_74_68_73_70_61_63_65:
+    LocalName = LocalName::pack_static(400u32);
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_74_6F_67_67_6C_65: LocalName =
+    LocalName::pack_static(401u32);
+pub const ATOM_LOCALNAME__75_6E_69_63_6F_64_65_2D_62_69_64_69: LocalName =
+    LocalName::pack_static(402u32);
+pub const ATOM_LOCALNAME__68_61_74_63_68_75_6E_69_74_73: LocalName = LocalName::pack_static(403u32);
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_2D_6D_69_6E: LocalName = LocalName::pack_static(404u32);
+pub const ATOM_LOCALNAME__6E_6F_6D_6F_64_75_6C_65: LocalName = LocalName::pack_static(405u32);
+pub const ATOM_LOCALNAME__74_68_69_63_6B_6D_61_74_68_73_70_61_63_65: LocalName =
+    LocalName::pack_static(406u32);
+pub const ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_53_74_79_6C_65_54_79_70_65: LocalName =
+    LocalName::pack_static(407u32);
+pub const ATOM_LOCALNAME__66_69_67_63_61_70_74_69_6F_6E: LocalName = LocalName::pack_static(408u32);
+pub const ATOM_LOCALNAME__6F_6E_66_6F_72_6D_69_6E_70_75_74: LocalName =
+    LocalName::pack_static(409u32);
+pub const ATOM_LOCALNAME__66_65_74_75_72_62_75_6C_65_6E_63_65: LocalName =
+    LocalName::pack_static(410u32);
+pub const ATOM_LOCALNAME__72_65_61_64_6F_6E_6C_79: LocalName = LocalName::pack_static(411u32);
+pub const ATOM_LOCALNAME__6F_6E_76_6F_6C_75_6D_65_63_68_61_6E_67_65: LocalName =
+    LocalName::pack_static(412u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_67_72_61_62_62_65_64: LocalName =
+    LocalName::pack_static(413u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_76_61_72_69_61_6E_74: LocalName =
+    LocalName::pack_static(414u32);
+pub const ATOM_LOCALNAME__73_74_64_44_65_76_69_61_74_69_6F_6E: LocalName =
+    LocalName::pack_static(415u32);
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_63_6F_70_79: LocalName =
+    LocalName::pack_static(416u32);
+pub const ATOM_LOCALNAME__6F_6E_64_61_74_61_73_65_74_63_6F_6D_70_6C_65_74_65: LocalName =
+    LocalName::pack_static(417u32);
+pub const ATOM_LOCALNAME__74_69_6D_65_6C_69_6E_65_62_65_67_69_6E: LocalName =
+    LocalName::pack_static(418u32);
+pub const ATOM_LOCALNAME__64_6F_6D_61_69_6E_6F_66_61_70_70_6C_69_63_61_74_69_6F_6E: LocalName =
+    LocalName::pack_static(419u32);
+pub const ATOM_LOCALNAME__6F_6E_75_6E_68_61_6E_64_6C_65_64_72_65_6A_65_63_74_69_6F_6E: LocalName =
+    LocalName::pack_static(420u32);
+pub const ATOM_LOCALNAME__6F_63_63_75_72_72_65_6E_63_65: LocalName = LocalName::pack_static(421u32);
+pub const ATOM_LOCALNAME__61_6E_6E_6F_74_61_74_69_6F_6E_2D_78_6D_6C: LocalName =
+    LocalName::pack_static(422u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6C_61_62_65_6C: LocalName = LocalName::pack_static(423u32);
+pub const ATOM_LOCALNAME__63_6F_6E_6A_75_67_61_74_65: LocalName = LocalName::pack_static(424u32);
+pub const ATOM_LOCALNAME__70_61_74_74_65_72_6E_55_6E_69_74_73: LocalName =
+    LocalName::pack_static(425u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6C_65_76_65_6C: LocalName = LocalName::pack_static(426u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_65_72_72_6F_72_6D_65_73_73_61_67_65: LocalName =
+    LocalName::pack_static(427u32);
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_76_65_65_6E_64: LocalName = LocalName::pack_static(428u32);
+pub const ATOM_LOCALNAME__66_65_44_69_66_66_75_73_65_4C_69_67_68_74_69_6E_67: LocalName =
+    LocalName::pack_static(429u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_61_75_74_6F_63_6F_6D_70_6C_65_74_65: LocalName =
+    LocalName::pack_static(430u32);
+pub const ATOM_LOCALNAME__63_61_6C_63_6D_6F_64_65: LocalName = LocalName::pack_static(431u32);
+pub const ATOM_LOCALNAME__6F_6E_73_65_6C_65_63_74_73_74_61_72_74: LocalName =
+    LocalName::pack_static(432u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_73_70_61_6E: LocalName =
+    LocalName::pack_static(433u32);
+pub const ATOM_LOCALNAME__63_6F_64_65_74_79_70_65: LocalName = LocalName::pack_static(434u32);
+pub const ATOM_LOCALNAME__68_61_74_63_68_63_6F_6E_74_65_6E_74_75_6E_69_74_73: LocalName =
+    LocalName::pack_static(435u32);
+pub const ATOM_LOCALNAME__69_6E_74_65_72_73_65_63_74: LocalName = LocalName::pack_static(436u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_77_65_69_67_68_74: LocalName = LocalName::pack_static(437u32);
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_75_70: LocalName = LocalName::pack_static(438u32);
+pub const ATOM_LOCALNAME__76_65_72_74_2D_61_64_76_2D_79: LocalName = LocalName::pack_static(439u32);
+pub const ATOM_LOCALNAME__6E_6F_66_72_61_6D_65_73: LocalName = LocalName::pack_static(440u32);
+pub const ATOM_LOCALNAME__63_6F_6C_67_72_6F_75_70: LocalName = LocalName::pack_static(441u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_73_72_63: LocalName =
+    LocalName::pack_static(442u32);
+pub const ATOM_LOCALNAME__6F_6E_6D_65_73_73_61_67_65: LocalName = LocalName::pack_static(443u32);
+pub const ATOM_LOCALNAME__6B_65_72_6E_65_6C_6D_61_74_72_69_78: LocalName =
+    LocalName::pack_static(444u32);
+pub const ATOM_LOCALNAME__66_69_6C_74_65_72_52_65_73: LocalName = LocalName::pack_static(445u32);
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B_3A_74_69_74_6C_65: LocalName =
+    LocalName::pack_static(446u32);
+pub const ATOM_LOCALNAME__72_65_6E_64_65_72_69_6E_67_2D_69_6E_74_65_6E_74: LocalName =
+    LocalName::pack_static(447u32);
+pub const ATOM_LOCALNAME__73_75_72_66_61_63_65_53_63_61_6C_65: LocalName =
+    LocalName::pack_static(448u32);
+pub const ATOM_LOCALNAME__66_65_4F_66_66_73_65_74: LocalName = LocalName::pack_static(449u32);
+pub const ATOM_LOCALNAME__63_6F_6E_74_65_78_74_6D_65_6E_75: LocalName =
+    LocalName::pack_static(450u32);
+pub const ATOM_LOCALNAME__69_6E_74_65_72_63_65_70_74: LocalName = LocalName::pack_static(451u32);
+pub const ATOM_LOCALNAME__75_6E_69_74_73_2D_70_65_72_2D_65_6D: LocalName =
+    LocalName::pack_static(452u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_65_6C_65_76_61_6E_74: LocalName =
+    LocalName::pack_static(453u32);
+pub const ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_73_63_72_69_70_74_74_79_70_65: LocalName =
+    LocalName::pack_static(454u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6B_65_79_73_68_6F_72_74_63_75_74_73: LocalName =
+    LocalName::pack_static(455u32);
+pub const ATOM_LOCALNAME__6F_6E_6C_6F_61_64_73_74_61_72_74: LocalName =
+    LocalName::pack_static(456u32);
+pub const ATOM_LOCALNAME__6D_61_6C_69_67_6E_67_72_6F_75_70: LocalName =
+    LocalName::pack_static(457u32);
+pub const ATOM_LOCALNAME__6D_69_6E_6C_65_6E_67_74_68: LocalName = LocalName::pack_static(458u32);
+pub const ATOM_LOCALNAME__6F_6E_74_69_6D_65_75_70_64_61_74_65: LocalName =
+    LocalName::pack_static(459u32);
+pub const ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68_44_65_66: LocalName =
+    LocalName::pack_static(460u32);
+pub const ATOM_LOCALNAME__63_6F_6D_6D_61_6E_64_66_6F_72: LocalName = LocalName::pack_static(461u32);
+pub const ATOM_LOCALNAME__6F_6E_64_75_72_61_74_69_6F_6E_63_68_61_6E_67_65: LocalName =
+    LocalName::pack_static(462u32);
+pub const ATOM_LOCALNAME__70_6F_70_6F_76_65_72_74_61_72_67_65_74_61_63_74_69_6F_6E: LocalName =
+    LocalName::pack_static(463u32);
+pub const ATOM_LOCALNAME__66_65_63_6F_6D_70_6F_73_69_74_65: LocalName =
+    LocalName::pack_static(464u32);
+pub const ATOM_LOCALNAME__66_65_44_69_73_70_6C_61_63_65_6D_65_6E_74_4D_61_70: LocalName =
+    LocalName::pack_static(465u32);
+pub const ATOM_LOCALNAME__73_63_72_69_70_74_6D_69_6E_73_69_7A_65: LocalName =
+    LocalName::pack_static(466u32);
+pub const ATOM_LOCALNAME__73_74_69_74_63_68_54_69_6C_65_73: LocalName =
+    LocalName::pack_static(467u32);
+pub const ATOM_LOCALNAME__65_78_70_6F_72_74_70_61_72_74_73: LocalName =
+    LocalName::pack_static(468u32);
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6F_70_61_63_69_74_79: LocalName =
+    LocalName::pack_static(469u32);
+pub const ATOM_LOCALNAME__6F_6E_63_6F_6E_74_72_6F_6C_73_65_6C_65_63_74: LocalName =
+    LocalName::pack_static(470u32);
+pub const ATOM_LOCALNAME__74_61_62_69_6E_64_65_78: LocalName = LocalName::pack_static(471u32);
+pub const ATOM_LOCALNAME__70_61_74_68_4C_65_6E_67_74_68: LocalName = LocalName::pack_static(472u32);
+pub const ATOM_LOCALNAME__79_63_68_61_6E_6E_65_6C_73_65_6C_65_63_74_6F_72: LocalName =
+    LocalName::pack_static(473u32);
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6C_69_6E_65_6A_6F_69_6E: LocalName =
+    LocalName::pack_static(474u32);
+pub const ATOM_LOCALNAME__74_65_78_74_2D_64_65_63_6F_72_61_74_69_6F_6E: LocalName =
+    LocalName::pack_static(475u32);
+pub const ATOM_LOCALNAME__66_6F_72_65_69_67_6E_6F_62_6A_65_63_74: LocalName =
+    LocalName::pack_static(476u32);
+pub const ATOM_LOCALNAME__6F_6E_6C_6F_73_65_63_61_70_74_75_72_65: LocalName =
+    LocalName::pack_static(477u32);
+pub const ATOM_LOCALNAME__6F_6E_77_61_69_74_69_6E_67: LocalName = LocalName::pack_static(478u32);
+pub const ATOM_LOCALNAME__66_65_53_70_65_63_75_6C_61_72_4C_69_67_68_74_69_6E_67: LocalName =
+    LocalName::pack_static(479u32);
+pub const ATOM_LOCALNAME__63_6F_6C_6F_72_2D_70_72_6F_66_69_6C_65: LocalName =
+    LocalName::pack_static(480u32);
+pub const ATOM_LOCALNAME__6F_6E_64_62_6C_63_6C_69_63_6B: LocalName = LocalName::pack_static(481u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_73_74_72_65_74_63_68: LocalName =
+    LocalName::pack_static(482u32);
+pub const ATOM_LOCALNAME__61_6C_6C_6F_77_66_75_6C_6C_73_63_72_65_65_6E: LocalName =
+    LocalName::pack_static(483u32);
+pub const ATOM_LOCALNAME__61_75_74_6F_70_6C_61_79: LocalName = LocalName::pack_static(484u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_70_6C_61_63_65_68_6F_6C_64_65_72: LocalName =
+    LocalName::pack_static(485u32);
+pub const ATOM_LOCALNAME__70_61_74_74_65_72_6E_75_6E_69_74_73: LocalName =
+    LocalName::pack_static(486u32);
+pub const ATOM_LOCALNAME__6F_6E_61_63_74_69_76_61_74_65: LocalName = LocalName::pack_static(487u32);
+pub const ATOM_LOCALNAME__6D_61_73_6B_43_6F_6E_74_65_6E_74_55_6E_69_74_73: LocalName =
+    LocalName::pack_static(488u32);
+pub const ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_65_78_70_6F_6E_65_6E_74: LocalName =
+    LocalName::pack_static(489u32);
+pub const ATOM_LOCALNAME__73_6F_6C_69_64_2D_6F_70_61_63_69_74_79: LocalName =
+    LocalName::pack_static(490u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_65_61_64_6F_6E_6C_79: LocalName =
+    LocalName::pack_static(491u32);
+pub const ATOM_LOCALNAME__6F_72_69_65_6E_74_61_74_69_6F_6E: LocalName =
+    LocalName::pack_static(492u32);
+pub const ATOM_LOCALNAME__6B_65_72_6E_65_6C_75_6E_69_74_6C_65_6E_67_74_68: LocalName =
+    LocalName::pack_static(493u32);
+pub const ATOM_LOCALNAME__6E_6F_74_73_75_62_73_65_74: LocalName = LocalName::pack_static(494u32);
+pub const ATOM_LOCALNAME__6F_6E_6B_65_79_70_72_65_73_73: LocalName = LocalName::pack_static(495u32);
+pub const ATOM_LOCALNAME__73_75_70_65_72_73_63_72_69_70_74_73_68_69_66_74: LocalName =
+    LocalName::pack_static(496u32);
+pub const ATOM_LOCALNAME__6F_6E_70_6C_61_79_69_6E_67: LocalName = LocalName::pack_static(497u32);
+pub const ATOM_LOCALNAME__63_72_6F_73_73_6F_72_69_67_69_6E: LocalName =
+    LocalName::pack_static(498u32);
+pub const ATOM_LOCALNAME__61_63_63_65_73_73_6B_65_79: LocalName = LocalName::pack_static(499u32);
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6F_75_74: LocalName = LocalName::pack_static(500u32);
+pub const ATOM_LOCALNAME__64_65_66_69_6E_69_74_69_6F_6E_75_72_6C: LocalName =
+    LocalName::pack_static(501u32);
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_65_64_69_74_66_6F_63_75_73: LocalName =
+    LocalName::pack_static(502u32);
+pub const ATOM_LOCALNAME__66_69_6C_74_65_72_72_65_73: LocalName = LocalName::pack_static(503u32);
+pub const ATOM_LOCALNAME__74_72_61_6E_73_66_6F_72_6D: LocalName = LocalName::pack_static(504u32);
+pub const ATOM_LOCALNAME__66_65_70_6F_69_6E_74_6C_69_67_68_74: LocalName =
+    LocalName::pack_static(505u32);
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_78: LocalName = LocalName::pack_static(506u32);
+pub const ATOM_LOCALNAME__70_72_6F_70_65_72_74_79: LocalName = LocalName::pack_static(507u32);
+pub const ATOM_LOCALNAME__74_65_6D_70_6C_61_74_65: LocalName = LocalName::pack_static(508u32);
+pub const ATOM_LOCALNAME__62_65_76_65_6C_6C_65_64: LocalName = LocalName::pack_static(509u32);
+pub const ATOM_LOCALNAME__6F_6E_66_6F_63_75_73_6F_75_74: LocalName = LocalName::pack_static(510u32);
+pub const ATOM_LOCALNAME__73_79_73_74_65_6D_4C_61_6E_67_75_61_67_65: LocalName =
+    LocalName::pack_static(511u32);
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_2D_6D_69_64: LocalName = LocalName::pack_static(512u32);
+pub const ATOM_LOCALNAME__72_65_66_65_72_72_65_72_70_6F_6C_69_63_79: LocalName =
+    LocalName::pack_static(513u32);
+pub const ATOM_LOCALNAME__6F_6E_72_6F_77_73_64_65_6C_65_74_65: LocalName =
+    LocalName::pack_static(514u32);
+pub const ATOM_LOCALNAME__72_61_64_69_61_6C_47_72_61_64_69_65_6E_74: LocalName =
+    LocalName::pack_static(515u32);
+pub const ATOM_LOCALNAME__6C_61_6E_67_75_61_67_65: LocalName = LocalName::pack_static(516u32);
+pub const ATOM_LOCALNAME__6D_61_78_6C_65_6E_67_74_68: LocalName = LocalName::pack_static(517u32);
+pub const ATOM_LOCALNAME__63_6F_64_6F_6D_61_69_6E: LocalName = LocalName::pack_static(518u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_6D_61_78: LocalName =
+    LocalName::pack_static(519u32);
+pub const ATOM_LOCALNAME__6B_65_79_70_6F_69_6E_74_73: LocalName = LocalName::pack_static(520u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_75_72_72_65_6E_74: LocalName =
+    LocalName::pack_static(521u32);
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B_3A_74_79_70_65: LocalName = LocalName::pack_static(522u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_73_69_7A_65: LocalName = LocalName::pack_static(523u32);
+pub const ATOM_LOCALNAME__6F_6E_70_61_67_65_72_65_76_65_61_6C: LocalName =
+    LocalName::pack_static(524u32);
+pub const ATOM_LOCALNAME__6F_6E_68_61_73_68_63_68_61_6E_67_65: LocalName =
+    LocalName::pack_static(525u32);
+pub const ATOM_LOCALNAME__69_6E_74_65_72_76_61_6C: LocalName = LocalName::pack_static(526u32);
+pub const ATOM_LOCALNAME__65_78_74_65_72_6E_61_6C_72_65_73_6F_75_72_63_65_73_72_65_71_75_69_72_65_64 : LocalName = LocalName :: pack_static (527u32) ;
+pub const ATOM_LOCALNAME__6D_6C_61_62_65_6C_65_64_74_72: LocalName = LocalName::pack_static(528u32);
+pub const ATOM_LOCALNAME__61_63_63_65_70_74_2D_63_68_61_72_73_65_74: LocalName =
+    LocalName::pack_static(529u32);
+pub const ATOM_LOCALNAME__6F_6E_6B_65_79_64_6F_77_6E: LocalName = LocalName::pack_static(530u32);
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_48_65_69_67_68_74: LocalName =
+    LocalName::pack_static(531u32);
+pub const ATOM_LOCALNAME__75_6E_69_63_6F_64_65_2D_72_61_6E_67_65: LocalName =
+    LocalName::pack_static(532u32);
+pub const ATOM_LOCALNAME__6F_6E_70_72_6F_67_72_65_73_73: LocalName = LocalName::pack_static(533u32);
+pub const ATOM_LOCALNAME__64_65_66_69_6E_69_74_69_6F_6E_2D_73_72_63: LocalName =
+    LocalName::pack_static(534u32);
+pub const ATOM_LOCALNAME__76_61_6C_75_65_74_79_70_65: LocalName = LocalName::pack_static(535u32);
+pub const ATOM_LOCALNAME__74_65_78_74_43_6F_6E_74_65_6E_74: LocalName =
+    LocalName::pack_static(536u32);
+pub const ATOM_LOCALNAME__70_6C_61_63_65_68_6F_6C_64_65_72: LocalName =
+    LocalName::pack_static(537u32);
+pub const ATOM_LOCALNAME__6C_6F_77_6C_69_6D_69_74: LocalName = LocalName::pack_static(538u32);
+pub const ATOM_LOCALNAME__65_6D_70_74_79_73_65_74: LocalName = LocalName::pack_static(539u32);
+pub const ATOM_LOCALNAME__73_75_62_73_63_72_69_70_74_73_68_69_66_74: LocalName =
+    LocalName::pack_static(540u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_6D_69_6C_79: LocalName =
+    LocalName::pack_static(541u32);
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B_3A_72_6F_6C_65: LocalName = LocalName::pack_static(542u32);
+pub const ATOM_LOCALNAME__63_6F_6E_64_69_74_69_6F_6E: LocalName = LocalName::pack_static(543u32);
+pub const ATOM_LOCALNAME__76_69_73_69_62_69_6C_69_74_79: LocalName = LocalName::pack_static(544u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_64_65_73_63_72_69_62_65_64_62_79: LocalName =
+    LocalName::pack_static(545u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_73_65_6C_65_63_74_65_64: LocalName =
+    LocalName::pack_static(546u32);
+pub const ATOM_LOCALNAME__65_78_74_65_72_6E_61_6C_52_65_73_6F_75_72_63_65_73_52_65_71_75_69_72_65_64 : LocalName = LocalName :: pack_static (547u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_73_70_61_6E: LocalName =
+    LocalName::pack_static(548u32);
+pub const ATOM_LOCALNAME__73_65_61_6D_6C_65_73_73: LocalName = LocalName::pack_static(549u32);
+pub const ATOM_LOCALNAME__6D_75_6C_74_69_70_6C_65: LocalName = LocalName::pack_static(550u32);
+pub const ATOM_LOCALNAME__6F_6E_72_65_70_65_61_74: LocalName = LocalName::pack_static(551u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_74_65_6D_70_6C_61_74_65_69_64: LocalName =
+    LocalName::pack_static(552u32);
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_79: LocalName = LocalName::pack_static(553u32);
+pub const ATOM_LOCALNAME__69_6E_74_65_67_65_72_73: LocalName = LocalName::pack_static(554u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_66_61_6D_69_6C_79: LocalName = LocalName::pack_static(555u32);
+pub const ATOM_LOCALNAME__64_69_73_61_62_6C_65_64: LocalName = LocalName::pack_static(556u32);
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B_3A_68_72_65_66: LocalName = LocalName::pack_static(557u32);
+pub const ATOM_LOCALNAME__6F_6E_63_68_61_6E_67_65: LocalName = LocalName::pack_static(558u32);
+pub const ATOM_LOCALNAME__66_65_4D_6F_72_70_68_6F_6C_6F_67_79: LocalName =
+    LocalName::pack_static(559u32);
+pub const ATOM_LOCALNAME__61_75_74_6F_63_6F_6D_70_6C_65_74_65: LocalName =
+    LocalName::pack_static(560u32);
+pub const ATOM_LOCALNAME__6C_69_6D_69_74_69_6E_67_63_6F_6E_65_61_6E_67_6C_65: LocalName =
+    LocalName::pack_static(561u32);
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6C_69_6E_65_63_61_70: LocalName =
+    LocalName::pack_static(562u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_77_65_69_67_68_74: LocalName =
+    LocalName::pack_static(563u32);
+pub const ATOM_LOCALNAME__77_72_69_74_69_6E_67_2D_6D_6F_64_65: LocalName =
+    LocalName::pack_static(564u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_61_74_6F_6D_69_63: LocalName =
+    LocalName::pack_static(565u32);
+pub const ATOM_LOCALNAME__66_65_6D_65_72_67_65_6E_6F_64_65: LocalName =
+    LocalName::pack_static(566u32);
+pub const ATOM_LOCALNAME__6D_61_6C_69_67_6E_6D_61_72_6B: LocalName = LocalName::pack_static(567u32);
+pub const ATOM_LOCALNAME__6F_6E_73_74_61_6C_6C_65_64: LocalName = LocalName::pack_static(568u32);
+pub const ATOM_LOCALNAME__6F_6E_69_6E_76_61_6C_69_64: LocalName = LocalName::pack_static(569u32);
+pub const ATOM_LOCALNAME__73_63_61_6C_61_72_70_72_6F_64_75_63_74: LocalName =
+    LocalName::pack_static(570u32);
+pub const ATOM_LOCALNAME__70_61_69_6E_74_2D_6F_72_64_65_72: LocalName =
+    LocalName::pack_static(571u32);
+pub const ATOM_LOCALNAME__63_61_72_74_65_73_69_61_6E_70_72_6F_64_75_63_74: LocalName =
+    LocalName::pack_static(572u32);
+pub const ATOM_LOCALNAME__74_65_78_74_4C_65_6E_67_74_68: LocalName = LocalName::pack_static(573u32);
+pub const ATOM_LOCALNAME__78_6D_6C_3A_73_70_61_63_65: LocalName = LocalName::pack_static(574u32);
+pub const ATOM_LOCALNAME__6C_69_6E_65_62_72_65_61_6B: LocalName = LocalName::pack_static(575u32);
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_7A: LocalName = LocalName::pack_static(576u32);
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_64_61_73_68_6F_66_66_73_65_74: LocalName =
+    LocalName::pack_static(577u32);
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67_65_6E_64: LocalName = LocalName::pack_static(578u32);
+pub const ATOM_LOCALNAME__74_65_78_74_2D_72_65_6E_64_65_72_69_6E_67: LocalName =
+    LocalName::pack_static(579u32);
+pub const ATOM_LOCALNAME__6C_6F_6E_67_64_65_73_63: LocalName = LocalName::pack_static(580u32);
+pub const ATOM_LOCALNAME__6D_61_74_72_69_78_72_6F_77: LocalName = LocalName::pack_static(581u32);
+pub const ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68_49_74_65_6D: LocalName =
+    LocalName::pack_static(582u32);
+pub const ATOM_LOCALNAME__74_65_78_74_50_61_74_68: LocalName = LocalName::pack_static(583u32);
+pub const ATOM_LOCALNAME__70_61_6E_6F_73_65_2D_31: LocalName = LocalName::pack_static(584u32);
+pub const ATOM_LOCALNAME__70_69_65_63_65_77_69_73_65: LocalName = LocalName::pack_static(585u32);
+pub const ATOM_LOCALNAME__6F_6E_6C_6F_61_64_65_64_64_61_74_61: LocalName =
+    LocalName::pack_static(586u32);
+pub const ATOM_LOCALNAME__70_72_69_6D_69_74_69_76_65_55_6E_69_74_73: LocalName =
+    LocalName::pack_static(587u32);
+pub const ATOM_LOCALNAME__77_6F_72_64_2D_73_70_61_63_69_6E_67: LocalName =
+    LocalName::pack_static(588u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_69_6E_64_65_78: LocalName =
+    LocalName::pack_static(589u32);
+pub const ATOM_LOCALNAME__6C_69_73_74_65_6E_65_72: LocalName = LocalName::pack_static(590u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_68_69_64_64_65_6E: LocalName =
+    LocalName::pack_static(591u32);
+pub const ATOM_LOCALNAME__66_72_61_6D_65_73_70_61_63_69_6E_67: LocalName =
+    LocalName::pack_static(592u32);
+pub const ATOM_LOCALNAME__69_6E_70_75_74_6D_6F_64_65: LocalName = LocalName::pack_static(593u32);
+pub const ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_74_72_61_6E_73_66_6F_72_6D: LocalName =
+    LocalName::pack_static(594u32);
+pub const ATOM_LOCALNAME__73_79_73_74_65_6D_6C_61_6E_67_75_61_67_65: LocalName =
+    LocalName::pack_static(595u32);
+pub const ATOM_LOCALNAME__66_65_54_75_72_62_75_6C_65_6E_63_65: LocalName =
+    LocalName::pack_static(596u32);
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_64_75_72: LocalName = LocalName::pack_static(597u32);
+pub const ATOM_LOCALNAME__68_72_65_66_6C_61_6E_67: LocalName = LocalName::pack_static(598u32);
+pub const ATOM_LOCALNAME__66_6F_6E_74_73_69_7A_65: LocalName = LocalName::pack_static(599u32);
+pub const ATOM_LOCALNAME__6F_76_65_72_66_6C_6F_77: LocalName = LocalName::pack_static(600u32);
+pub const ATOM_LOCALNAME__70_6C_61_79_62_61_63_6B_6F_72_64_65_72: LocalName =
+    LocalName::pack_static(601u32);
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_63_6F_75_6E_74: LocalName =
+    LocalName::pack_static(602u32);
+pub const ATOM_LOCALNAME__63_6F_6C_6F_72_2D_69_6E_74_65_72_70_6F_6C_61_74_69_6F_6E: LocalName =
+    LocalName::pack_static(603u32);
+pub const ATOM_LOCALNAME__6C_61_70_6C_61_63_69_61_6E: LocalName = LocalName::pack_static(604u32);
+pub const ATOM_LOCALNAME__70_61_74_74_65_72_6E_63_6F_6E_74_65_6E_74_75_6E_69_74_73: LocalName =
+    LocalName::pack_static(605u32);
+pub const ATOM_LOCALNAME__61_6D_70_6C_69_74_75_64_65: LocalName = LocalName::pack_static(606u32);
+pub const ATOM_LOCALNAME__6F_6E_6F_6E_6C_69_6E_65: LocalName = LocalName::pack_static(607u32);
+pub const ATOM_LOCALNAME__63_6C_69_70_70_61_74_68: LocalName = LocalName::pack_static(608u32);
+pub const ATOM_LOCALNAME__61_63_63_65_6E_74_2D_68_65_69_67_68_74: LocalName =
+    LocalName::pack_static(609u32);
+pub const ATOM_LOCALNAME__6D_61_73_6B_2D_74_79_70_65: LocalName = LocalName::pack_static(610u32);
+pub const ATOM_LOCALNAME__63_6F_6C_6F_72_2D_72_65_6E_64_65_72_69_6E_67: LocalName =
+    LocalName::pack_static(611u32);
+pub const ATOM_LOCALNAME__6D_75_6C_74_69_63_6F_6C: LocalName = LocalName::pack_static(612u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_65_71_75_69_72_65_64: LocalName =
+    LocalName::pack_static(613u32);
+pub const ATOM_LOCALNAME__6F_6E_63_61_6E_63_65_6C: LocalName = LocalName::pack_static(614u32);
+pub const ATOM_LOCALNAME__6E_75_6D_4F_63_74_61_76_65_73: LocalName = LocalName::pack_static(615u32);
+pub const ATOM_LOCALNAME__73_63_72_6F_6C_6C_64_65_6C_61_79: LocalName =
+    LocalName::pack_static(616u32);
+pub const ATOM_LOCALNAME__6F_6E_65_6D_70_74_69_65_64: LocalName = LocalName::pack_static(617u32);
+pub const ATOM_LOCALNAME__70_61_74_74_65_72_6E_43_6F_6E_74_65_6E_74_55_6E_69_74_73: LocalName =
+    LocalName::pack_static(618u32);
+pub const ATOM_LOCALNAME__69_6D_61_67_65_73_72_63_73_65_74: LocalName =
+    LocalName::pack_static(619u32);
+pub const ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68_69_74_65_6D: LocalName =
+    LocalName::pack_static(620u32);
+pub const ATOM_LOCALNAME__6F_6E_73_65_6C_65_63_74: LocalName = LocalName::pack_static(621u32);
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_65_43_6F_6C_6F_72: LocalName =
+    LocalName::pack_static(622u32);
+pub const ATOM_LOCALNAME__64_6F_6D_69_6E_61_6E_74_2D_62_61_73_65_6C_69_6E_65: LocalName =
+    LocalName::pack_static(623u32);
+pub const ATOM_LOCALNAME__73_65_6C_65_63_74_65_64_63_6F_6E_74_65_6E_74: LocalName =
+    LocalName::pack_static(624u32);
+pub const ATOM_LOCALNAME__76_69_65_77_74_61_72_67_65_74: LocalName = LocalName::pack_static(625u32);
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B_3A_73_68_6F_77: LocalName = LocalName::pack_static(626u32);
+pub const ATOM_LOCALNAME__74_65_78_74_63_6F_6E_74_65_6E_74: LocalName =
+    LocalName::pack_static(627u32);
+pub const ATOM_LOCALNAME__6F_6E_73_65_65_6B_65_64: LocalName = LocalName::pack_static(628u32);
+pub const ATOM_LOCALNAME__6D_65_64_69_75_6D_6D_61_74_68_73_70_61_63_65: LocalName =
+    LocalName::pack_static(629u32);
+pub const ATOM_LOCALNAME__61_63_63_75_6D_75_6C_61_74_65: LocalName = LocalName::pack_static(630u32);
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6C_65_61_76_65: LocalName =
+    LocalName::pack_static(631u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6E_74_72_6F_6C_73: LocalName =
+    LocalName::pack_static(632u32);
+pub const ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_73_74_79_6C_65_74_79_70_65: LocalName =
+    LocalName::pack_static(633u32);
+pub const ATOM_LOCALNAME__72_6F_77_6C_69_6E_65_73: LocalName = LocalName::pack_static(634u32);
+pub const ATOM_LOCALNAME__73_74_6F_70_2D_6F_70_61_63_69_74_79: LocalName =
+    LocalName::pack_static(635u32);
+pub const ATOM_LOCALNAME__76_2D_69_64_65_6F_67_72_61_70_68_69_63: LocalName =
+    LocalName::pack_static(636u32);
+pub const ATOM_LOCALNAME__6D_65_6E_63_6C_6F_73_65: LocalName = LocalName::pack_static(637u32);
+pub const ATOM_LOCALNAME__69_6D_61_67_65_73_69_7A_65_73: LocalName = LocalName::pack_static(638u32);
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_2D_74_65_6D_70_6C_61_74_65: LocalName =
+    LocalName::pack_static(639u32);
+pub const ATOM_LOCALNAME__66_65_63_6F_6E_76_6F_6C_76_65_6D_61_74_72_69_78: LocalName =
+    LocalName::pack_static(640u32);
+pub const ATOM_LOCALNAME__73_65_6C_65_63_74_6F_72: LocalName = LocalName::pack_static(641u32);
+pub const ATOM_LOCALNAME__69_6D_61_67_65_2D_72_65_6E_64_65_72_69_6E_67: LocalName =
+    LocalName::pack_static(642u32);
+pub const ATOM_LOCALNAME__66_69_65_6C_64_73_65_74: LocalName = LocalName::pack_static(643u32);
+pub const ATOM_LOCALNAME__66_65_43_6F_6D_70_6F_73_69_74_65: LocalName =
+    LocalName::pack_static(644u32);
+pub const ATOM_LOCALNAME__6B_65_79_53_70_6C_69_6E_65_73: LocalName = LocalName::pack_static(645u32);
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_6F_6C_65_64_65_73_63_72_69_70_74_69_6F_6E: LocalName =
+    LocalName::pack_static(646u32);
+pub const ATOM_LOCALNAME__76_65_72_79_74_68_69_63_6B_6D_61_74_68_73_70_61_63_65: LocalName =
+    LocalName::pack_static(647u32);
+pub const ATOM_LOCALNAME__62_6C_6F_63_6B_69_6E_67: LocalName = LocalName::pack_static(648u32);
+pub const ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_73_6C_6F_74_61_73_73_69_67_6E_6D_65_6E_74 : LocalName = LocalName :: pack_static (649u32) ;
+pub const ATOM_LOCALNAME__6D_6F_76_61_62_6C_65_6C_69_6D_69_74_73: LocalName =
+    LocalName::pack_static(650u32);
+pub const ATOM_LOCALNAME__73_63_72_69_70_74_6C_65_76_65_6C: LocalName =
+    LocalName::pack_static(651u32);
+pub const ATOM_LOCALNAME__6F_6E_70_61_67_65_73_68_6F_77: LocalName = LocalName::pack_static(652u32);
+pub const ATOM_LOCALNAME__6F_6E_70_6F_70_73_74_61_74_65: LocalName = LocalName::pack_static(653u32);
+pub const ATOM_LOCALNAME__6F_6E_66_6F_63_75_73_69_6E: LocalName = LocalName::pack_static(654u32);
+pub const ATOM_LOCALNAME__6F_6E_72_61_74_65_63_68_61_6E_67_65: LocalName =
+    LocalName::pack_static(655u32);
+pub const ATOM_LOCALNAME__64_65_74_65_72_6D_69_6E_61_6E_74: LocalName =
+    LocalName::pack_static(656u32);
+pub const ATOM_LOCALNAME__6F_70_74_67_72_6F_75_70: LocalName = LocalName::pack_static(657u32);
+pub const ATOM_LOCALNAME__6C_69_6E_65_74_68_69_63_6B_6E_65_73_73: LocalName =
+    LocalName::pack_static(658u32);
+pub const ATOM_LOCALNAME__6F_6E_72_65_6A_65_63_74_69_6F_6E_68_61_6E_64_6C_65_64: LocalName =
+    LocalName::pack_static(659u32);
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_75_6E_69_74_73: LocalName =
+    LocalName::pack_static(660u32);
+pub const ATOM_LOCALNAME_: LocalName = LocalName::pack_inline(0u64, 0u8);
+pub const ATOM_LOCALNAME__2A: LocalName = LocalName::pack_inline(10752u64, 1u8);
+pub const ATOM_LOCALNAME__61: LocalName = LocalName::pack_inline(24832u64, 1u8);
+pub const ATOM_LOCALNAME__61_62_62_72: LocalName = LocalName::pack_inline(491276886272u64, 4u8);
+pub const ATOM_LOCALNAME__61_62_73: LocalName = LocalName::pack_inline(1935827200u64, 3u8);
+pub const ATOM_LOCALNAME__61_63_63_65_6E_74: LocalName =
+    LocalName::pack_inline(32772479036645632u64, 6u8);
+pub const ATOM_LOCALNAME__61_63_63_65_70_74: LocalName =
+    LocalName::pack_inline(32774678059901184u64, 6u8);
+pub const ATOM_LOCALNAME__61_63_72_6F_6E_79_6D: LocalName =
+    LocalName::pack_inline(7888457647255675136u64, 7u8);
+pub const ATOM_LOCALNAME__61_63_74_69_6F_6E: LocalName =
+    LocalName::pack_inline(31084746153091328u64, 6u8);
+pub const ATOM_LOCALNAME__61_63_74_69_76_65: LocalName =
+    LocalName::pack_inline(28559167944089856u64, 6u8);
+pub const ATOM_LOCALNAME__61_63_74_75_61_74_65: LocalName =
+    LocalName::pack_inline(7310575252220895488u64, 7u8);
+pub const ATOM_LOCALNAME__61_64_64_72_65_73_73: LocalName =
+    LocalName::pack_inline(8319104478668415232u64, 7u8);
+pub const ATOM_LOCALNAME__61_6C_69_67_6E: LocalName =
+    LocalName::pack_inline(121390429397248u64, 5u8);
+pub const ATOM_LOCALNAME__61_6C_69_6E_6B: LocalName =
+    LocalName::pack_inline(118121959284992u64, 5u8);
+pub const ATOM_LOCALNAME__61_6C_6C_6F_77: LocalName =
+    LocalName::pack_inline(131320444117248u64, 5u8);
+pub const ATOM_LOCALNAME__61_6C_70_68_61: LocalName =
+    LocalName::pack_inline(107101190643968u64, 5u8);
+pub const ATOM_LOCALNAME__61_6C_74: LocalName = LocalName::pack_inline(1953259776u64, 3u8);
+pub const ATOM_LOCALNAME__61_6C_74_69_6D_67: LocalName =
+    LocalName::pack_inline(29112222293451008u64, 6u8);
+pub const ATOM_LOCALNAME__61_6C_74_74_65_78_74: LocalName =
+    LocalName::pack_inline(8392569456448790784u64, 7u8);
+pub const ATOM_LOCALNAME__61_6E_64: LocalName = LocalName::pack_inline(1684955392u64, 3u8);
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_65: LocalName =
+    LocalName::pack_inline(7310575217677328640u64, 7u8);
+pub const ATOM_LOCALNAME__61_70_70_6C_65_74: LocalName =
+    LocalName::pack_inline(32762613715722496u64, 6u8);
+pub const ATOM_LOCALNAME__61_70_70_6C_79: LocalName =
+    LocalName::pack_inline(133506649841920u64, 5u8);
+pub const ATOM_LOCALNAME__61_70_70_72_6F_78: LocalName =
+    LocalName::pack_inline(33899534508646656u64, 6u8);
+pub const ATOM_LOCALNAME__61_72_63_63_6F_73: LocalName =
+    LocalName::pack_inline(32492094982611200u64, 6u8);
+pub const ATOM_LOCALNAME__61_72_63_63_6F_73_68: LocalName =
+    LocalName::pack_inline(7526481874927116544u64, 7u8);
+pub const ATOM_LOCALNAME__61_72_63_63_6F_74: LocalName =
+    LocalName::pack_inline(32773569959321856u64, 6u8);
+pub const ATOM_LOCALNAME__61_72_63_63_6F_74_68: LocalName =
+    LocalName::pack_inline(7526763349903827200u64, 7u8);
+pub const ATOM_LOCALNAME__61_72_63_63_73_63: LocalName =
+    LocalName::pack_inline(27992893401751808u64, 6u8);
+pub const ATOM_LOCALNAME__61_72_63_63_73_63_68: LocalName =
+    LocalName::pack_inline(7521982673346257152u64, 7u8);
+pub const ATOM_LOCALNAME__61_72_63_68_69_76_65: LocalName =
+    LocalName::pack_inline(7311146942148534528u64, 7u8);
+pub const ATOM_LOCALNAME__61_72_63_72_6F_6C_65: LocalName =
+    LocalName::pack_inline(7308338832400867584u64, 7u8);
+pub const ATOM_LOCALNAME__61_72_63_73_65_63: LocalName =
+    LocalName::pack_inline(27977568958439680u64, 6u8);
+pub const ATOM_LOCALNAME__61_72_63_73_65_63_68: LocalName =
+    LocalName::pack_inline(7521967348902945024u64, 7u8);
+pub const ATOM_LOCALNAME__61_72_63_73_69_6E: LocalName =
+    LocalName::pack_inline(31078191748768000u64, 6u8);
+pub const ATOM_LOCALNAME__61_72_63_73_69_6E_68: LocalName =
+    LocalName::pack_inline(7525067971693273344u64, 7u8);
+pub const ATOM_LOCALNAME__61_72_63_74_61_6E: LocalName =
+    LocalName::pack_inline(31069399950713088u64, 6u8);
+pub const ATOM_LOCALNAME__61_72_63_74_61_6E_68: LocalName =
+    LocalName::pack_inline(7525059179895218432u64, 7u8);
+pub const ATOM_LOCALNAME__61_72_65_61: LocalName = LocalName::pack_inline(418313822464u64, 4u8);
+pub const ATOM_LOCALNAME__61_72_67: LocalName = LocalName::pack_inline(1735549184u64, 3u8);
+pub const ATOM_LOCALNAME__61_72_74_69_63_6C_65: LocalName =
+    LocalName::pack_inline(7308325599891841280u64, 7u8);
+pub const ATOM_LOCALNAME__61_73: LocalName = LocalName::pack_inline(7561472u64, 2u8);
+pub const ATOM_LOCALNAME__61_73_63_65_6E_74: LocalName =
+    LocalName::pack_inline(32772479037694208u64, 6u8);
+pub const ATOM_LOCALNAME__61_73_69_64_65: LocalName =
+    LocalName::pack_inline(111481940304128u64, 5u8);
+pub const ATOM_LOCALNAME__61_73_79_6E_63: LocalName =
+    LocalName::pack_inline(109326135156992u64, 5u8);
+pub const ATOM_LOCALNAME__61_75_64_69_6F: LocalName =
+    LocalName::pack_inline(122498447663360u64, 5u8);
+pub const ATOM_LOCALNAME__61_78_69_73: LocalName = LocalName::pack_inline(495690735872u64, 4u8);
+pub const ATOM_LOCALNAME__61_7A_69_6D_75_74_68: LocalName =
+    LocalName::pack_inline(7526769990024454400u64, 7u8);
+pub const ATOM_LOCALNAME__62: LocalName = LocalName::pack_inline(25088u64, 1u8);
+pub const ATOM_LOCALNAME__62_61_73_65: LocalName = LocalName::pack_inline(435727458816u64, 4u8);
+pub const ATOM_LOCALNAME__62_62_6F_78: LocalName = LocalName::pack_inline(517264794112u64, 4u8);
+pub const ATOM_LOCALNAME__62_64_69: LocalName = LocalName::pack_inline(1768186368u64, 3u8);
+pub const ATOM_LOCALNAME__62_64_6F: LocalName = LocalName::pack_inline(1868849664u64, 3u8);
+pub const ATOM_LOCALNAME__62_65_67_69_6E: LocalName =
+    LocalName::pack_inline(121398985318912u64, 5u8);
+pub const ATOM_LOCALNAME__62_67_63_6F_6C_6F_72: LocalName =
+    LocalName::pack_inline(8245928668403556864u64, 7u8);
+pub const ATOM_LOCALNAME__62_67_73_6F_75_6E_64: LocalName =
+    LocalName::pack_inline(7236850772768940544u64, 7u8);
+pub const ATOM_LOCALNAME__62_69_61_73: LocalName = LocalName::pack_inline(495555535360u64, 4u8);
+pub const ATOM_LOCALNAME__62_69_67: LocalName = LocalName::pack_inline(1734959616u64, 3u8);
+pub const ATOM_LOCALNAME__62_6C_69_6E_6B: LocalName =
+    LocalName::pack_inline(118121959285248u64, 5u8);
+pub const ATOM_LOCALNAME__62_6F_64_79: LocalName = LocalName::pack_inline(521376064000u64, 4u8);
+pub const ATOM_LOCALNAME__62_6F_72_64_65_72: LocalName =
+    LocalName::pack_inline(32199629436051968u64, 6u8);
+pub const ATOM_LOCALNAME__62_72: LocalName = LocalName::pack_inline(7496192u64, 2u8);
+pub const ATOM_LOCALNAME__62_75_74_74_6F_6E: LocalName =
+    LocalName::pack_inline(31084793398911488u64, 6u8);
+pub const ATOM_LOCALNAME__62_76_61_72: LocalName = LocalName::pack_inline(491261420032u64, 4u8);
+pub const ATOM_LOCALNAME__62_79: LocalName = LocalName::pack_inline(7954944u64, 2u8);
+pub const ATOM_LOCALNAME__63_61_6E_76_61_73: LocalName =
+    LocalName::pack_inline(32476783607636736u64, 6u8);
+pub const ATOM_LOCALNAME__63_61_70_74_69_6F_6E: LocalName =
+    LocalName::pack_inline(7957695015409509120u64, 7u8);
+pub const ATOM_LOCALNAME__63_61_72_64: LocalName = LocalName::pack_inline(431415714560u64, 4u8);
+pub const ATOM_LOCALNAME__63_65_69_6C_69_6E_67: LocalName =
+    LocalName::pack_inline(7453010347690386176u64, 7u8);
+pub const ATOM_LOCALNAME__63_65_6E_74_65_72: LocalName =
+    LocalName::pack_inline(32199698087764736u64, 6u8);
+pub const ATOM_LOCALNAME__63_68_61_72: LocalName = LocalName::pack_inline(491260502784u64, 4u8);
+pub const ATOM_LOCALNAME__63_68_61_72_6F_66_66: LocalName =
+    LocalName::pack_inline(7378707576544322304u64, 7u8);
+pub const ATOM_LOCALNAME__63_68_61_72_73_65_74: LocalName =
+    LocalName::pack_inline(8387236816145113856u64, 7u8);
+pub const ATOM_LOCALNAME__63_68_65_63_6B_65_64: LocalName =
+    LocalName::pack_inline(7234306451087844096u64, 7u8);
+pub const ATOM_LOCALNAME__63_69: LocalName = LocalName::pack_inline(6906624u64, 2u8);
+pub const ATOM_LOCALNAME__63_69_72_63_6C_65: LocalName =
+    LocalName::pack_inline(28548147024847616u64, 6u8);
+pub const ATOM_LOCALNAME__63_69_74_65: LocalName = LocalName::pack_inline(435744760576u64, 4u8);
+pub const ATOM_LOCALNAME__63_6C_61_73_73: LocalName =
+    LocalName::pack_inline(126939392926464u64, 5u8);
+pub const ATOM_LOCALNAME__63_6C_61_73_73_69_64: LocalName =
+    LocalName::pack_inline(7235441215740338944u64, 7u8);
+pub const ATOM_LOCALNAME__63_6C_65_61_72: LocalName =
+    LocalName::pack_inline(125762638996224u64, 5u8);
+pub const ATOM_LOCALNAME__63_6C_69_70: LocalName = LocalName::pack_inline(482805048064u64, 4u8);
+pub const ATOM_LOCALNAME__63_6C_6F_73_65: LocalName =
+    LocalName::pack_inline(111546465018624u64, 5u8);
+pub const ATOM_LOCALNAME__63_6C_6F_73_75_72_65: LocalName =
+    LocalName::pack_inline(7310034283826799360u64, 7u8);
+pub const ATOM_LOCALNAME__63_6E: LocalName = LocalName::pack_inline(7234304u64, 2u8);
+pub const ATOM_LOCALNAME__63_6F_64_65: LocalName = LocalName::pack_inline(435476718336u64, 4u8);
+pub const ATOM_LOCALNAME__63_6F_6C: LocalName = LocalName::pack_inline(1819239168u64, 3u8);
+pub const ATOM_LOCALNAME__63_6F_6C_6F_72: LocalName =
+    LocalName::pack_inline(125822886175488u64, 5u8);
+pub const ATOM_LOCALNAME__63_6F_6C_73: LocalName = LocalName::pack_inline(495740478208u64, 4u8);
+pub const ATOM_LOCALNAME__63_6F_6C_73_70_61_6E: LocalName =
+    LocalName::pack_inline(7953762057955795712u64, 7u8);
+pub const ATOM_LOCALNAME__63_6F_6D_6D_61_6E_64: LocalName =
+    LocalName::pack_inline(7236828773846311680u64, 7u8);
+pub const ATOM_LOCALNAME__63_6F_6D_70_61_63_74: LocalName =
+    LocalName::pack_inline(8386654066594243328u64, 7u8);
+pub const ATOM_LOCALNAME__63_6F_6D_70_6F_73_65: LocalName =
+    LocalName::pack_inline(7310309148815483648u64, 7u8);
+pub const ATOM_LOCALNAME__63_6F_6E_74_65_6E_74: LocalName =
+    LocalName::pack_inline(8389754706581218048u64, 7u8);
+pub const ATOM_LOCALNAME__63_6F_6F_72_64_73: LocalName =
+    LocalName::pack_inline(32480064980345600u64, 6u8);
+pub const ATOM_LOCALNAME__63_6F_73: LocalName = LocalName::pack_inline(1936679680u64, 3u8);
+pub const ATOM_LOCALNAME__63_6F_73_68: LocalName = LocalName::pack_inline(448613278464u64, 4u8);
+pub const ATOM_LOCALNAME__63_6F_74: LocalName = LocalName::pack_inline(1953456896u64, 3u8);
+pub const ATOM_LOCALNAME__63_6F_74_68: LocalName = LocalName::pack_inline(448630055680u64, 4u8);
+pub const ATOM_LOCALNAME__63_73_63: LocalName = LocalName::pack_inline(1668506368u64, 3u8);
+pub const ATOM_LOCALNAME__63_73_63_68: LocalName = LocalName::pack_inline(448345105152u64, 4u8);
+pub const ATOM_LOCALNAME__63_73_79_6D_62_6F_6C: LocalName =
+    LocalName::pack_inline(7813572100839662336u64, 7u8);
+pub const ATOM_LOCALNAME__63_75_72_6C: LocalName = LocalName::pack_inline(465776763648u64, 4u8);
+pub const ATOM_LOCALNAME__63_75_72_73_6F_72: LocalName =
+    LocalName::pack_inline(32210688977232640u64, 6u8);
+pub const ATOM_LOCALNAME__63_78: LocalName = LocalName::pack_inline(7889664u64, 2u8);
+pub const ATOM_LOCALNAME__63_79: LocalName = LocalName::pack_inline(7955200u64, 2u8);
+pub const ATOM_LOCALNAME__64: LocalName = LocalName::pack_inline(25600u64, 1u8);
+pub const ATOM_LOCALNAME__64_61_74_61: LocalName = LocalName::pack_inline(418564367360u64, 4u8);
+pub const ATOM_LOCALNAME__64_61_74_61_66_6C_64: LocalName =
+    LocalName::pack_inline(7236271270027944960u64, 7u8);
+pub const ATOM_LOCALNAME__64_61_74_61_73_72_63: LocalName =
+    LocalName::pack_inline(7165916819501442048u64, 7u8);
+pub const ATOM_LOCALNAME__64_64: LocalName = LocalName::pack_inline(6579200u64, 2u8);
+pub const ATOM_LOCALNAME__64_65_63_6C_61_72_65: LocalName =
+    LocalName::pack_inline(7310012263327687680u64, 7u8);
+pub const ATOM_LOCALNAME__64_65_66_61_75_6C_74: LocalName =
+    LocalName::pack_inline(8389209267074589696u64, 7u8);
+pub const ATOM_LOCALNAME__64_65_66_65_72: LocalName =
+    LocalName::pack_inline(125779835184128u64, 5u8);
+pub const ATOM_LOCALNAME__64_65_66_73: LocalName = LocalName::pack_inline(495639159808u64, 4u8);
+pub const ATOM_LOCALNAME__64_65_67_72_65_65: LocalName =
+    LocalName::pack_inline(28540514683151360u64, 6u8);
+pub const ATOM_LOCALNAME__64_65_6C: LocalName = LocalName::pack_inline(1818584064u64, 3u8);
+pub const ATOM_LOCALNAME__64_65_70_74_68: LocalName =
+    LocalName::pack_inline(114849311187968u64, 5u8);
+pub const ATOM_LOCALNAME__64_65_73_63: LocalName = LocalName::pack_inline(427137786880u64, 4u8);
+pub const ATOM_LOCALNAME__64_65_73_63_65_6E_74: LocalName =
+    LocalName::pack_inline(8389754633650004992u64, 7u8);
+pub const ATOM_LOCALNAME__64_65_74_61_69_6C_73: LocalName =
+    LocalName::pack_inline(8317138479132009472u64, 7u8);
+pub const ATOM_LOCALNAME__64_66_6E: LocalName = LocalName::pack_inline(1852204032u64, 3u8);
+pub const ATOM_LOCALNAME__64_69_61_6C_6F_67: LocalName =
+    LocalName::pack_inline(29114433882645504u64, 6u8);
+pub const ATOM_LOCALNAME__64_69_66_66: LocalName = LocalName::pack_inline(439804847104u64, 4u8);
+pub const ATOM_LOCALNAME__64_69_72: LocalName = LocalName::pack_inline(1919509504u64, 3u8);
+pub const ATOM_LOCALNAME__64_69_72_6E_61_6D_65: LocalName =
+    LocalName::pack_inline(7308604897285989376u64, 7u8);
+pub const ATOM_LOCALNAME__64_69_73_63_61_72_64: LocalName =
+    LocalName::pack_inline(7237954630903751680u64, 7u8);
+pub const ATOM_LOCALNAME__64_69_73_70_6C_61_79: LocalName =
+    LocalName::pack_inline(8746391181558637568u64, 7u8);
+pub const ATOM_LOCALNAME__64_69_76: LocalName = LocalName::pack_inline(1986618368u64, 3u8);
+pub const ATOM_LOCALNAME__64_69_76_69_64_65: LocalName =
+    LocalName::pack_inline(28539376768738304u64, 6u8);
+pub const ATOM_LOCALNAME__64_69_76_69_73_6F_72: LocalName =
+    LocalName::pack_inline(8245936339534046208u64, 7u8);
+pub const ATOM_LOCALNAME__64_6C: LocalName = LocalName::pack_inline(7103488u64, 2u8);
+pub const ATOM_LOCALNAME__64_6F_6D_61_69_6E: LocalName =
+    LocalName::pack_inline(31078114606932992u64, 6u8);
+pub const ATOM_LOCALNAME__64_74: LocalName = LocalName::pack_inline(7627776u64, 2u8);
+pub const ATOM_LOCALNAME__64_75_72: LocalName = LocalName::pack_inline(1920295936u64, 3u8);
+pub const ATOM_LOCALNAME__64_78: LocalName = LocalName::pack_inline(7889920u64, 2u8);
+pub const ATOM_LOCALNAME__64_79: LocalName = LocalName::pack_inline(7955456u64, 2u8);
+pub const ATOM_LOCALNAME__65_64_67_65: LocalName = LocalName::pack_inline(435526329600u64, 4u8);
+pub const ATOM_LOCALNAME__65_6C_6C_69_70_73_65: LocalName =
+    LocalName::pack_inline(7310310218245367040u64, 7u8);
+pub const ATOM_LOCALNAME__65_6D: LocalName = LocalName::pack_inline(7169280u64, 2u8);
+pub const ATOM_LOCALNAME__65_6D_62_65_64: LocalName =
+    LocalName::pack_inline(110386605810944u64, 5u8);
+pub const ATOM_LOCALNAME__65_6E_63_74_79_70_65: LocalName =
+    LocalName::pack_inline(7309475736013661440u64, 7u8);
+pub const ATOM_LOCALNAME__65_6E_64: LocalName = LocalName::pack_inline(1684956416u64, 3u8);
+pub const ATOM_LOCALNAME__65_71: LocalName = LocalName::pack_inline(7431424u64, 2u8);
+pub const ATOM_LOCALNAME__65_76_65_6E_74: LocalName =
+    LocalName::pack_inline(128017497482496u64, 5u8);
+pub const ATOM_LOCALNAME__65_78_69_73_74_73: LocalName =
+    LocalName::pack_inline(32497661361284352u64, 6u8);
+pub const ATOM_LOCALNAME__65_78_70: LocalName = LocalName::pack_inline(1886938368u64, 3u8);
+pub const ATOM_LOCALNAME__66_61_63_65: LocalName = LocalName::pack_inline(435459024384u64, 4u8);
+pub const ATOM_LOCALNAME__66_61_6C_73_65: LocalName =
+    LocalName::pack_inline(111546413966848u64, 5u8);
+pub const ATOM_LOCALNAME__66_65_42_6C_65_6E_64: LocalName =
+    LocalName::pack_inline(7236833166875780608u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_46_6C_6F_6F_64: LocalName =
+    LocalName::pack_inline(7237125637035877888u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_46_75_6E_63_41: LocalName =
+    LocalName::pack_inline(4711731085130950144u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_46_75_6E_63_42: LocalName =
+    LocalName::pack_inline(4783788679168878080u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_46_75_6E_63_47: LocalName =
+    LocalName::pack_inline(5144076649358517760u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_46_75_6E_63_52: LocalName =
+    LocalName::pack_inline(5936710183775725056u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_49_6D_61_67_65: LocalName =
+    LocalName::pack_inline(7306916042442630656u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_4D_65_72_67_65: LocalName =
+    LocalName::pack_inline(7306934699847673344u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_54_69_6C_65: LocalName =
+    LocalName::pack_inline(28548172291073536u64, 6u8);
+pub const ATOM_LOCALNAME__66_65_62_6C_65_6E_64: LocalName =
+    LocalName::pack_inline(7236833167412651520u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_66_6C_6F_6F_64: LocalName =
+    LocalName::pack_inline(7237125637572748800u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_66_75_6E_63_61: LocalName =
+    LocalName::pack_inline(7017574094881515008u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_66_75_6E_63_62: LocalName =
+    LocalName::pack_inline(7089631688919442944u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_66_75_6E_63_67: LocalName =
+    LocalName::pack_inline(7449919659109082624u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_66_75_6E_63_72: LocalName =
+    LocalName::pack_inline(8242553193526289920u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_69_6D_61_67_65: LocalName =
+    LocalName::pack_inline(7306916042979501568u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_6D_65_72_67_65: LocalName =
+    LocalName::pack_inline(7306934700384544256u64, 7u8);
+pub const ATOM_LOCALNAME__66_65_6E_63_65: LocalName =
+    LocalName::pack_inline(111477728306688u64, 5u8);
+pub const ATOM_LOCALNAME__66_65_74_63_68: LocalName =
+    LocalName::pack_inline(114776363853312u64, 5u8);
+pub const ATOM_LOCALNAME__66_65_74_69_6C_65: LocalName =
+    LocalName::pack_inline(28548172827944448u64, 6u8);
+pub const ATOM_LOCALNAME__66_69_67_75_72_65: LocalName =
+    LocalName::pack_inline(28554821219476992u64, 6u8);
+pub const ATOM_LOCALNAME__66_69_6C_6C: LocalName = LocalName::pack_inline(465675314688u64, 4u8);
+pub const ATOM_LOCALNAME__66_69_6C_74_65_72: LocalName =
+    LocalName::pack_inline(32199698054473216u64, 6u8);
+pub const ATOM_LOCALNAME__66_6C_6F_6F_72: LocalName =
+    LocalName::pack_inline(125822936311296u64, 5u8);
+pub const ATOM_LOCALNAME__66_6E: LocalName = LocalName::pack_inline(7235072u64, 2u8);
+pub const ATOM_LOCALNAME__66_6F_6E_74: LocalName = LocalName::pack_inline(500069000704u64, 4u8);
+pub const ATOM_LOCALNAME__66_6F_6F_74_65_72: LocalName =
+    LocalName::pack_inline(32199698105198080u64, 6u8);
+pub const ATOM_LOCALNAME__66_6F_72: LocalName = LocalName::pack_inline(1919903232u64, 3u8);
+pub const ATOM_LOCALNAME__66_6F_72_61_6C_6C: LocalName =
+    LocalName::pack_inline(30518463272281600u64, 6u8);
+pub const ATOM_LOCALNAME__66_6F_72_6D: LocalName = LocalName::pack_inline(470071338496u64, 4u8);
+pub const ATOM_LOCALNAME__66_6F_72_6D_61_74: LocalName =
+    LocalName::pack_inline(32758219997668864u64, 6u8);
+pub const ATOM_LOCALNAME__66_72: LocalName = LocalName::pack_inline(7497216u64, 2u8);
+pub const ATOM_LOCALNAME__66_72_61_6D_65: LocalName =
+    LocalName::pack_inline(111520460727808u64, 5u8);
+pub const ATOM_LOCALNAME__66_72_6F_6D: LocalName = LocalName::pack_inline(470021203456u64, 4u8);
+pub const ATOM_LOCALNAME__66_78: LocalName = LocalName::pack_inline(7890432u64, 2u8);
+pub const ATOM_LOCALNAME__66_79: LocalName = LocalName::pack_inline(7955968u64, 2u8);
+pub const ATOM_LOCALNAME__67: LocalName = LocalName::pack_inline(26368u64, 1u8);
+pub const ATOM_LOCALNAME__67_31: LocalName = LocalName::pack_inline(3237632u64, 2u8);
+pub const ATOM_LOCALNAME__67_32: LocalName = LocalName::pack_inline(3303168u64, 2u8);
+pub const ATOM_LOCALNAME__67_63_64: LocalName = LocalName::pack_inline(1684236032u64, 3u8);
+pub const ATOM_LOCALNAME__67_65_71: LocalName = LocalName::pack_inline(1902470912u64, 3u8);
+pub const ATOM_LOCALNAME__67_6C_79_70_68: LocalName =
+    LocalName::pack_inline(114832282773248u64, 5u8);
+pub const ATOM_LOCALNAME__67_72_61_64: LocalName = LocalName::pack_inline(431131617024u64, 4u8);
+pub const ATOM_LOCALNAME__67_74: LocalName = LocalName::pack_inline(7628544u64, 2u8);
+pub const ATOM_LOCALNAME__68_31: LocalName = LocalName::pack_inline(3237888u64, 2u8);
+pub const ATOM_LOCALNAME__68_32: LocalName = LocalName::pack_inline(3303424u64, 2u8);
+pub const ATOM_LOCALNAME__68_33: LocalName = LocalName::pack_inline(3368960u64, 2u8);
+pub const ATOM_LOCALNAME__68_34: LocalName = LocalName::pack_inline(3434496u64, 2u8);
+pub const ATOM_LOCALNAME__68_35: LocalName = LocalName::pack_inline(3500032u64, 2u8);
+pub const ATOM_LOCALNAME__68_36: LocalName = LocalName::pack_inline(3565568u64, 2u8);
+pub const ATOM_LOCALNAME__68_61_6E_64_6C_65_72: LocalName =
+    LocalName::pack_inline(8243113871575967744u64, 7u8);
+pub const ATOM_LOCALNAME__68_61_6E_67_69_6E_67: LocalName =
+    LocalName::pack_inline(7453010326299174912u64, 7u8);
+pub const ATOM_LOCALNAME__68_61_74_63_68: LocalName =
+    LocalName::pack_inline(114776363591680u64, 5u8);
+pub const ATOM_LOCALNAME__68_65_61_64: LocalName = LocalName::pack_inline(431130765312u64, 4u8);
+pub const ATOM_LOCALNAME__68_65_61_64_65_72: LocalName =
+    LocalName::pack_inline(32199629150185472u64, 6u8);
+pub const ATOM_LOCALNAME__68_65_61_64_65_72_73: LocalName =
+    LocalName::pack_inline(8318822943511898112u64, 7u8);
+pub const ATOM_LOCALNAME__68_65_69_67_68_74: LocalName =
+    LocalName::pack_inline(32765890657609728u64, 6u8);
+pub const ATOM_LOCALNAME__68_67_72_6F_75_70: LocalName =
+    LocalName::pack_inline(31654318912792576u64, 6u8);
+pub const ATOM_LOCALNAME__68_69_64_64_65_6E: LocalName =
+    LocalName::pack_inline(31073729293936640u64, 6u8);
+pub const ATOM_LOCALNAME__68_69_67_68: LocalName = LocalName::pack_inline(448411559936u64, 4u8);
+pub const ATOM_LOCALNAME__68_6B_65_72_6E: LocalName =
+    LocalName::pack_inline(121437606864896u64, 5u8);
+pub const ATOM_LOCALNAME__68_72: LocalName = LocalName::pack_inline(7497728u64, 2u8);
+pub const ATOM_LOCALNAME__68_72_65_66: LocalName = LocalName::pack_inline(439788660736u64, 4u8);
+pub const ATOM_LOCALNAME__68_73_70_61_63_65: LocalName =
+    LocalName::pack_inline(28538242797365248u64, 6u8);
+pub const ATOM_LOCALNAME__68_74_6D_6C: LocalName = LocalName::pack_inline(465692813312u64, 4u8);
+pub const ATOM_LOCALNAME__69: LocalName = LocalName::pack_inline(26880u64, 1u8);
+pub const ATOM_LOCALNAME__69_63_6F_6E: LocalName = LocalName::pack_inline(474315188480u64, 4u8);
+pub const ATOM_LOCALNAME__69_64: LocalName = LocalName::pack_inline(6580480u64, 2u8);
+pub const ATOM_LOCALNAME__69_64_65_6E_74: LocalName =
+    LocalName::pack_inline(128017496303872u64, 5u8);
+pub const ATOM_LOCALNAME__69_66_72_61_6D_65: LocalName =
+    LocalName::pack_inline(28549237946345728u64, 6u8);
+pub const ATOM_LOCALNAME__69_6D_61_67_65: LocalName =
+    LocalName::pack_inline(111494690597120u64, 5u8);
+pub const ATOM_LOCALNAME__69_6D_67: LocalName = LocalName::pack_inline(1735223552u64, 3u8);
+pub const ATOM_LOCALNAME__69_6D_70_6C_69_65_73: LocalName =
+    LocalName::pack_inline(8315168201473091840u64, 7u8);
+pub const ATOM_LOCALNAME__69_6E: LocalName = LocalName::pack_inline(7235840u64, 2u8);
+pub const ATOM_LOCALNAME__69_6E_32: LocalName = LocalName::pack_inline(846096640u64, 3u8);
+pub const ATOM_LOCALNAME__69_6E_64_65_78: LocalName =
+    LocalName::pack_inline(132376871987456u64, 5u8);
+pub const ATOM_LOCALNAME__69_6E_65_72_74: LocalName =
+    LocalName::pack_inline(128034676828416u64, 5u8);
+pub const ATOM_LOCALNAME__69_6E_70_75_74: LocalName =
+    LocalName::pack_inline(128047746279680u64, 5u8);
+pub const ATOM_LOCALNAME__69_6E_73: LocalName = LocalName::pack_inline(1936615680u64, 3u8);
+pub const ATOM_LOCALNAME__69_6E_74: LocalName = LocalName::pack_inline(1953392896u64, 3u8);
+pub const ATOM_LOCALNAME__69_6E_76_65_72_73_65: LocalName =
+    LocalName::pack_inline(7310312400256657664u64, 7u8);
+pub const ATOM_LOCALNAME__69_73_69_6E_64_65_78: LocalName =
+    LocalName::pack_inline(8675450682577479936u64, 7u8);
+pub const ATOM_LOCALNAME__69_73_6D_61_70: LocalName =
+    LocalName::pack_inline(123563750418688u64, 5u8);
+pub const ATOM_LOCALNAME__69_74_65_6D_69_64: LocalName =
+    LocalName::pack_inline(28263416245545216u64, 6u8);
+pub const ATOM_LOCALNAME__69_74_65_6D_72_65_66: LocalName =
+    LocalName::pack_inline(7378429378695555328u64, 7u8);
+pub const ATOM_LOCALNAME__6B: LocalName = LocalName::pack_inline(27392u64, 1u8);
+pub const ATOM_LOCALNAME__6B_31: LocalName = LocalName::pack_inline(3238656u64, 2u8);
+pub const ATOM_LOCALNAME__6B_32: LocalName = LocalName::pack_inline(3304192u64, 2u8);
+pub const ATOM_LOCALNAME__6B_33: LocalName = LocalName::pack_inline(3369728u64, 2u8);
+pub const ATOM_LOCALNAME__6B_34: LocalName = LocalName::pack_inline(3435264u64, 2u8);
+pub const ATOM_LOCALNAME__6B_62_64: LocalName = LocalName::pack_inline(1684171520u64, 3u8);
+pub const ATOM_LOCALNAME__6B_65_72_6E_69_6E_67: LocalName =
+    LocalName::pack_inline(7453010356431317760u64, 7u8);
+pub const ATOM_LOCALNAME__6B_65_79_67_65_6E: LocalName =
+    LocalName::pack_inline(31073742530898688u64, 6u8);
+pub const ATOM_LOCALNAME__6B_69_6E_64: LocalName = LocalName::pack_inline(431349132032u64, 4u8);
+pub const ATOM_LOCALNAME__6C_61_62_65_6C: LocalName =
+    LocalName::pack_inline(119182698048512u64, 5u8);
+pub const ATOM_LOCALNAME__6C_61_6D_62_64_61: LocalName =
+    LocalName::pack_inline(27413446645607424u64, 6u8);
+pub const ATOM_LOCALNAME__6C_61_6E_67: LocalName = LocalName::pack_inline(444233509888u64, 4u8);
+pub const ATOM_LOCALNAME__6C_61_72_67_65_6F_70: LocalName =
+    LocalName::pack_inline(8101805749637835776u64, 7u8);
+pub const ATOM_LOCALNAME__6C_63_6D: LocalName = LocalName::pack_inline(1835232256u64, 3u8);
+pub const ATOM_LOCALNAME__6C_65_67_65_6E_64: LocalName =
+    LocalName::pack_inline(28268879476517888u64, 6u8);
+pub const ATOM_LOCALNAME__6C_65_71: LocalName = LocalName::pack_inline(1902472192u64, 3u8);
+pub const ATOM_LOCALNAME__6C_69: LocalName = LocalName::pack_inline(6908928u64, 2u8);
+pub const ATOM_LOCALNAME__6C_69_6D_69_74: LocalName =
+    LocalName::pack_inline(127996156013568u64, 5u8);
+pub const ATOM_LOCALNAME__6C_69_6E_65: LocalName = LocalName::pack_inline(435644099584u64, 4u8);
+pub const ATOM_LOCALNAME__6C_69_6E_6B: LocalName = LocalName::pack_inline(461413903360u64, 4u8);
+pub const ATOM_LOCALNAME__6C_69_73_74: LocalName = LocalName::pack_inline(500152495104u64, 4u8);
+pub const ATOM_LOCALNAME__6C_69_73_74_69_6E_67: LocalName =
+    LocalName::pack_inline(7453010382218161152u64, 7u8);
+pub const ATOM_LOCALNAME__6C_6E: LocalName = LocalName::pack_inline(7236608u64, 2u8);
+pub const ATOM_LOCALNAME__6C_6F_61_64_69_6E_67: LocalName =
+    LocalName::pack_inline(7453010313197087744u64, 7u8);
+pub const ATOM_LOCALNAME__6C_6F_63_61_6C: LocalName =
+    LocalName::pack_inline(119165535874048u64, 5u8);
+pub const ATOM_LOCALNAME__6C_6F_67: LocalName = LocalName::pack_inline(1735355392u64, 3u8);
+pub const ATOM_LOCALNAME__6C_6F_67_62_61_73_65: LocalName =
+    LocalName::pack_inline(7310293695422491648u64, 7u8);
+pub const ATOM_LOCALNAME__6C_6F_6F_70: LocalName = LocalName::pack_inline(482905910272u64, 4u8);
+pub const ATOM_LOCALNAME__6C_6F_77: LocalName = LocalName::pack_inline(2003790848u64, 3u8);
+pub const ATOM_LOCALNAME__6C_6F_77_73_72_63: LocalName =
+    LocalName::pack_inline(27991862944951296u64, 6u8);
+pub const ATOM_LOCALNAME__6C_71_75_6F_74_65: LocalName =
+    LocalName::pack_inline(28556994708335616u64, 6u8);
+pub const ATOM_LOCALNAME__6C_73_70_61_63_65: LocalName =
+    LocalName::pack_inline(28538242797366272u64, 6u8);
+pub const ATOM_LOCALNAME__6C_74: LocalName = LocalName::pack_inline(7629824u64, 2u8);
+pub const ATOM_LOCALNAME__6D_61_63_72_6F_73: LocalName =
+    LocalName::pack_inline(32492159406009600u64, 6u8);
+pub const ATOM_LOCALNAME__6D_61_63_74_69_6F_6E: LocalName =
+    LocalName::pack_inline(7957695015191407872u64, 7u8);
+pub const ATOM_LOCALNAME__6D_61_69_6E: LocalName = LocalName::pack_inline(474214395136u64, 4u8);
+pub const ATOM_LOCALNAME__6D_61_70: LocalName = LocalName::pack_inline(1885433088u64, 3u8);
+pub const ATOM_LOCALNAME__6D_61_72_6B: LocalName = LocalName::pack_inline(461480488192u64, 4u8);
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72: LocalName =
+    LocalName::pack_inline(32199659499908352u64, 6u8);
+pub const ATOM_LOCALNAME__6D_61_72_71_75_65_65: LocalName =
+    LocalName::pack_inline(7306375100589239552u64, 7u8);
+pub const ATOM_LOCALNAME__6D_61_73_6B: LocalName = LocalName::pack_inline(461497265408u64, 4u8);
+pub const ATOM_LOCALNAME__6D_61_74_68: LocalName = LocalName::pack_inline(448629140736u64, 4u8);
+pub const ATOM_LOCALNAME__6D_61_74_72_69_78: LocalName =
+    LocalName::pack_inline(33892937505008896u64, 6u8);
+pub const ATOM_LOCALNAME__6D_61_78: LocalName = LocalName::pack_inline(2019650816u64, 3u8);
+pub const ATOM_LOCALNAME__6D_61_78_73_69_7A_65: LocalName =
+    LocalName::pack_inline(7312272889651227904u64, 7u8);
+pub const ATOM_LOCALNAME__6D_65_61_6E: LocalName = LocalName::pack_inline(474080439552u64, 4u8);
+pub const ATOM_LOCALNAME__6D_65_64_69_61: LocalName =
+    LocalName::pack_inline(107105283828992u64, 5u8);
+pub const ATOM_LOCALNAME__6D_65_64_69_61_6E: LocalName =
+    LocalName::pack_inline(31069352722001152u64, 6u8);
+pub const ATOM_LOCALNAME__6D_65_6E_75: LocalName = LocalName::pack_inline(504363314432u64, 4u8);
+pub const ATOM_LOCALNAME__6D_65_72_72_6F_72: LocalName =
+    LocalName::pack_inline(32210684681219328u64, 6u8);
+pub const ATOM_LOCALNAME__6D_65_74_61: LocalName = LocalName::pack_inline(418564631808u64, 4u8);
+pub const ATOM_LOCALNAME__6D_65_74_65_72: LocalName =
+    LocalName::pack_inline(125780070067456u64, 5u8);
+pub const ATOM_LOCALNAME__6D_65_74_68_6F_64: LocalName =
+    LocalName::pack_inline(28269992091151616u64, 6u8);
+pub const ATOM_LOCALNAME__6D_66_65_6E_63_65_64: LocalName =
+    LocalName::pack_inline(7234297702239333632u64, 7u8);
+pub const ATOM_LOCALNAME__6D_66_72_61_63: LocalName =
+    LocalName::pack_inline(109270182292736u64, 5u8);
+pub const ATOM_LOCALNAME__6D_67_6C_79_70_68: LocalName =
+    LocalName::pack_inline(29397064389979392u64, 6u8);
+pub const ATOM_LOCALNAME__6D_69: LocalName = LocalName::pack_inline(6909184u64, 2u8);
+pub const ATOM_LOCALNAME__6D_69_6E: LocalName = LocalName::pack_inline(1852402944u64, 3u8);
+pub const ATOM_LOCALNAME__6D_69_6E_73_69_7A_65: LocalName =
+    LocalName::pack_inline(7312272889483980032u64, 7u8);
+pub const ATOM_LOCALNAME__6D_69_6E_75_73: LocalName =
+    LocalName::pack_inline(126948200770816u64, 5u8);
+pub const ATOM_LOCALNAME__6D_6E: LocalName = LocalName::pack_inline(7236864u64, 2u8);
+pub const ATOM_LOCALNAME__6D_6F: LocalName = LocalName::pack_inline(7302400u64, 2u8);
+pub const ATOM_LOCALNAME__6D_6F_64_65: LocalName = LocalName::pack_inline(435476720896u64, 4u8);
+pub const ATOM_LOCALNAME__6D_6F_6D_65_6E_74: LocalName =
+    LocalName::pack_inline(32772479205207296u64, 6u8);
+pub const ATOM_LOCALNAME__6D_6F_76_65_72: LocalName =
+    LocalName::pack_inline(125780104277248u64, 5u8);
+pub const ATOM_LOCALNAME__6D_70_61_64_64_65_64: LocalName =
+    LocalName::pack_inline(7234298758734834944u64, 7u8);
+pub const ATOM_LOCALNAME__6D_70_61_74_68: LocalName =
+    LocalName::pack_inline(114849060252928u64, 5u8);
+pub const ATOM_LOCALNAME__6D_72_6F_6F_74: LocalName =
+    LocalName::pack_inline(128021959961856u64, 5u8);
+pub const ATOM_LOCALNAME__6D_72_6F_77: LocalName = LocalName::pack_inline(512970878208u64, 4u8);
+pub const ATOM_LOCALNAME__6D_73: LocalName = LocalName::pack_inline(7564544u64, 2u8);
+pub const ATOM_LOCALNAME__6D_73_70_61_63_65: LocalName =
+    LocalName::pack_inline(28538242797366528u64, 6u8);
+pub const ATOM_LOCALNAME__6D_73_71_72_74: LocalName =
+    LocalName::pack_inline(128034878483712u64, 5u8);
+pub const ATOM_LOCALNAME__6D_73_74_79_6C_65: LocalName =
+    LocalName::pack_inline(28548241548340480u64, 6u8);
+pub const ATOM_LOCALNAME__6D_73_75_62: LocalName = LocalName::pack_inline(422877293824u64, 4u8);
+pub const ATOM_LOCALNAME__6D_73_75_62_73_75_70: LocalName =
+    LocalName::pack_inline(8103509971237563648u64, 7u8);
+pub const ATOM_LOCALNAME__6D_73_75_70: LocalName = LocalName::pack_inline(483006835968u64, 4u8);
+pub const ATOM_LOCALNAME__6D_74_61_62_6C_65: LocalName =
+    LocalName::pack_inline(28548142445391104u64, 6u8);
+pub const ATOM_LOCALNAME__6D_74_64: LocalName = LocalName::pack_inline(1685351680u64, 3u8);
+pub const ATOM_LOCALNAME__6D_74_65_78_74: LocalName =
+    LocalName::pack_inline(128060447026432u64, 5u8);
+pub const ATOM_LOCALNAME__6D_74_72: LocalName = LocalName::pack_inline(1920232704u64, 3u8);
+pub const ATOM_LOCALNAME__6D_75_6E_64_65_72: LocalName =
+    LocalName::pack_inline(32199629369339136u64, 6u8);
+pub const ATOM_LOCALNAME__6D_75_74_65_64: LocalName =
+    LocalName::pack_inline(110386908327168u64, 5u8);
+pub const ATOM_LOCALNAME__6E_61_6D_65: LocalName = LocalName::pack_inline(435626798592u64, 4u8);
+pub const ATOM_LOCALNAME__6E_61_72_67_73: LocalName =
+    LocalName::pack_inline(126888137813504u64, 5u8);
+pub const ATOM_LOCALNAME__6E_61_76: LocalName = LocalName::pack_inline(1986096640u64, 3u8);
+pub const ATOM_LOCALNAME__6E_65_71: LocalName = LocalName::pack_inline(1902472704u64, 3u8);
+pub const ATOM_LOCALNAME__6E_65_73_74: LocalName = LocalName::pack_inline(500152233472u64, 4u8);
+pub const ATOM_LOCALNAME__6E_65_78_74_69_64: LocalName =
+    LocalName::pack_inline(28263446628101632u64, 6u8);
+pub const ATOM_LOCALNAME__6E_6F_62_72: LocalName = LocalName::pack_inline(491277741568u64, 4u8);
+pub const ATOM_LOCALNAME__6E_6F_65_6D_62_65_64: LocalName =
+    LocalName::pack_inline(7234296598433328640u64, 7u8);
+pub const ATOM_LOCALNAME__6E_6F_68_72_65_66: LocalName =
+    LocalName::pack_inline(28821989677297152u64, 6u8);
+pub const ATOM_LOCALNAME__6E_6F_6E_63_65: LocalName =
+    LocalName::pack_inline(111477728964096u64, 5u8);
+pub const ATOM_LOCALNAME__6E_6F_6E_65: LocalName = LocalName::pack_inline(435644493312u64, 4u8);
+pub const ATOM_LOCALNAME__6E_6F_73_68_61_64_65: LocalName =
+    LocalName::pack_inline(7306071596742962688u64, 7u8);
+pub const ATOM_LOCALNAME__6E_6F_74: LocalName = LocalName::pack_inline(1953459712u64, 3u8);
+pub const ATOM_LOCALNAME__6E_6F_74_69_6E: LocalName =
+    LocalName::pack_inline(121399204081152u64, 5u8);
+pub const ATOM_LOCALNAME__6E_6F_77_72_61_70: LocalName =
+    LocalName::pack_inline(31632341649550848u64, 6u8);
+pub const ATOM_LOCALNAME__6F_62_6A_65_63_74: LocalName =
+    LocalName::pack_inline(32760384526118656u64, 6u8);
+pub const ATOM_LOCALNAME__6F_66_66_73_65_74: LocalName =
+    LocalName::pack_inline(32762643612069632u64, 6u8);
+pub const ATOM_LOCALNAME__6F_6C: LocalName = LocalName::pack_inline(7106304u64, 2u8);
+pub const ATOM_LOCALNAME__6F_6E_61_62_6F_72_74: LocalName =
+    LocalName::pack_inline(8390891524076760832u64, 7u8);
+pub const ATOM_LOCALNAME__6F_6E_62_65_67_69_6E: LocalName =
+    LocalName::pack_inline(7956003901867454208u64, 7u8);
+pub const ATOM_LOCALNAME__6F_6E_62_6C_75_72: LocalName =
+    LocalName::pack_inline(32217255713337088u64, 6u8);
+pub const ATOM_LOCALNAME__6F_6E_63_6C_69_63_6B: LocalName =
+    LocalName::pack_inline(7738144498998210304u64, 7u8);
+pub const ATOM_LOCALNAME__6F_6E_63_6C_6F_73_65: LocalName =
+    LocalName::pack_inline(7310309131467779840u64, 7u8);
+pub const ATOM_LOCALNAME__6F_6E_63_6F_70_79: LocalName =
+    LocalName::pack_inline(34182095893851904u64, 6u8);
+pub const ATOM_LOCALNAME__6F_6E_63_75_74: LocalName =
+    LocalName::pack_inline(128047528177408u64, 5u8);
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67: LocalName =
+    LocalName::pack_inline(29099066540322560u64, 6u8);
+pub const ATOM_LOCALNAME__6F_6E_64_72_6F_70: LocalName =
+    LocalName::pack_inline(31647734493507328u64, 6u8);
+pub const ATOM_LOCALNAME__6F_6E_65_6E_64: LocalName =
+    LocalName::pack_inline(110425310916352u64, 5u8);
+pub const ATOM_LOCALNAME__6F_6E_65_6E_64_65_64: LocalName =
+    LocalName::pack_inline(7234298801751486208u64, 7u8);
+pub const ATOM_LOCALNAME__6F_6E_65_72_72_6F_72: LocalName =
+    LocalName::pack_inline(8245935278392241920u64, 7u8);
+pub const ATOM_LOCALNAME__6F_6E_66_6F_63_75_73: LocalName =
+    LocalName::pack_inline(8319665216747892480u64, 7u8);
+pub const ATOM_LOCALNAME__6F_6E_68_65_6C_70: LocalName =
+    LocalName::pack_inline(31644380191158016u64, 6u8);
+pub const ATOM_LOCALNAME__6F_6E_69_6E_70_75_74: LocalName =
+    LocalName::pack_inline(8391737100192345856u64, 7u8);
+pub const ATOM_LOCALNAME__6F_6E_6B_65_79_75_70: LocalName =
+    LocalName::pack_inline(8103516581024132864u64, 7u8);
+pub const ATOM_LOCALNAME__6F_6E_6C_6F_61_64: LocalName =
+    LocalName::pack_inline(28254628859506432u64, 6u8);
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_76_65: LocalName =
+    LocalName::pack_inline(28559193597177600u64, 6u8);
+pub const ATOM_LOCALNAME__6F_6E_70_61_73_74_65: LocalName =
+    LocalName::pack_inline(7310594957464465152u64, 7u8);
+pub const ATOM_LOCALNAME__6F_6E_70_61_75_73_65: LocalName =
+    LocalName::pack_inline(7310315681511010048u64, 7u8);
+pub const ATOM_LOCALNAME__6F_6E_70_6C_61_79: LocalName =
+    LocalName::pack_inline(34165590552637184u64, 6u8);
+pub const ATOM_LOCALNAME__6F_6E_72_65_73_65_74: LocalName =
+    LocalName::pack_inline(8387236760596147968u64, 7u8);
+pub const ATOM_LOCALNAME__6F_6E_73_68_6F_77: LocalName =
+    LocalName::pack_inline(33618016632467200u64, 6u8);
+pub const ATOM_LOCALNAME__6F_6E_73_74_61_72_74: LocalName =
+    LocalName::pack_inline(8390876208525373184u64, 7u8);
+pub const ATOM_LOCALNAME__6F_6E_73_74_6F_70: LocalName =
+    LocalName::pack_inline(31647743335100160u64, 6u8);
+pub const ATOM_LOCALNAME__6F_6E_77_68_65_65_6C: LocalName =
+    LocalName::pack_inline(7810760628098723584u64, 7u8);
+pub const ATOM_LOCALNAME__6F_6E_7A_6F_6F_6D: LocalName =
+    LocalName::pack_inline(30803297047572224u64, 6u8);
+pub const ATOM_LOCALNAME__6F_70_61_63_69_74_79: LocalName =
+    LocalName::pack_inline(8751735851445153536u64, 7u8);
+pub const ATOM_LOCALNAME__6F_70_65_6E: LocalName = LocalName::pack_inline(474148269824u64, 4u8);
+pub const ATOM_LOCALNAME__6F_70_74_69_6D_75_6D: LocalName =
+    LocalName::pack_inline(7887330622101810944u64, 7u8);
+pub const ATOM_LOCALNAME__6F_70_74_69_6F_6E: LocalName =
+    LocalName::pack_inline(31084746153946880u64, 6u8);
+pub const ATOM_LOCALNAME__6F_72: LocalName = LocalName::pack_inline(7499520u64, 2u8);
+pub const ATOM_LOCALNAME__6F_72_64_65_72: LocalName =
+    LocalName::pack_inline(125779802484480u64, 5u8);
+pub const ATOM_LOCALNAME__6F_72_69_65_6E_74: LocalName =
+    LocalName::pack_inline(32772479138295552u64, 6u8);
+pub const ATOM_LOCALNAME__6F_72_69_67_69_6E: LocalName =
+    LocalName::pack_inline(31078140309827328u64, 6u8);
+pub const ATOM_LOCALNAME__6F_74_68_65_72: LocalName =
+    LocalName::pack_inline(125779869724416u64, 5u8);
+pub const ATOM_LOCALNAME__6F_75_74_70_75_74: LocalName =
+    LocalName::pack_inline(32780223149076224u64, 6u8);
+pub const ATOM_LOCALNAME__70: LocalName = LocalName::pack_inline(28672u64, 1u8);
+pub const ATOM_LOCALNAME__70_61_72_61_6D: LocalName =
+    LocalName::pack_inline(120265298243584u64, 5u8);
+pub const ATOM_LOCALNAME__70_61_72_73_65: LocalName =
+    LocalName::pack_inline(111546514632704u64, 5u8);
+pub const ATOM_LOCALNAME__70_61_72_74: LocalName = LocalName::pack_inline(500135194624u64, 4u8);
+pub const ATOM_LOCALNAME__70_61_74_68: LocalName = LocalName::pack_inline(448629141504u64, 4u8);
+pub const ATOM_LOCALNAME__70_61_74_74_65_72_6E: LocalName =
+    LocalName::pack_inline(7958535042360242176u64, 7u8);
+pub const ATOM_LOCALNAME__70_69: LocalName = LocalName::pack_inline(6909952u64, 2u8);
+pub const ATOM_LOCALNAME__70_69_63_74_75_72_65: LocalName =
+    LocalName::pack_inline(7310034287920246784u64, 7u8);
+pub const ATOM_LOCALNAME__70_69_65_63_65: LocalName =
+    LocalName::pack_inline(111477577576448u64, 5u8);
+pub const ATOM_LOCALNAME__70_69_6E_67: LocalName = LocalName::pack_inline(444234035200u64, 4u8);
+pub const ATOM_LOCALNAME__70_69_74_63_68: LocalName =
+    LocalName::pack_inline(114776364118016u64, 5u8);
+pub const ATOM_LOCALNAME__70_6C_75_73: LocalName = LocalName::pack_inline(495891279872u64, 4u8);
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_73: LocalName =
+    LocalName::pack_inline(32497639885860864u64, 6u8);
+pub const ATOM_LOCALNAME__70_6F_6C_79_67_6F_6E: LocalName =
+    LocalName::pack_inline(7957692837794902016u64, 7u8);
+pub const ATOM_LOCALNAME__70_6F_70_6F_76_65_72: LocalName =
+    LocalName::pack_inline(8243124913971359744u64, 7u8);
+pub const ATOM_LOCALNAME__70_6F_73_74_65_72: LocalName =
+    LocalName::pack_inline(32199698172309504u64, 6u8);
+pub const ATOM_LOCALNAME__70_6F_77_65_72: LocalName =
+    LocalName::pack_inline(125780121055232u64, 5u8);
+pub const ATOM_LOCALNAME__70_72_65: LocalName = LocalName::pack_inline(1701998592u64, 3u8);
+pub const ATOM_LOCALNAME__70_72_65_6C_6F_61_64: LocalName =
+    LocalName::pack_inline(7233184987882876928u64, 7u8);
+pub const ATOM_LOCALNAME__70_72_69_6D_65_73: LocalName =
+    LocalName::pack_inline(32481142916673536u64, 6u8);
+pub const ATOM_LOCALNAME__70_72_6F_64_75_63_74: LocalName =
+    LocalName::pack_inline(8386676005320945664u64, 7u8);
+pub const ATOM_LOCALNAME__70_72_6F_66_69_6C_65: LocalName =
+    LocalName::pack_inline(7308332183992823808u64, 7u8);
+pub const ATOM_LOCALNAME__70_72_6F_6D_70_74: LocalName =
+    LocalName::pack_inline(32774712621953024u64, 6u8);
+pub const ATOM_LOCALNAME__71: LocalName = LocalName::pack_inline(28928u64, 1u8);
+pub const ATOM_LOCALNAME__72: LocalName = LocalName::pack_inline(29184u64, 1u8);
+pub const ATOM_LOCALNAME__72_61_64_69_75_73: LocalName =
+    LocalName::pack_inline(32498717837849088u64, 6u8);
+pub const ATOM_LOCALNAME__72_62: LocalName = LocalName::pack_inline(6451712u64, 2u8);
+pub const ATOM_LOCALNAME__72_65_61_6C: LocalName = LocalName::pack_inline(465490506240u64, 4u8);
+pub const ATOM_LOCALNAME__72_65_61_6C_73: LocalName =
+    LocalName::pack_inline(126909327700480u64, 5u8);
+pub const ATOM_LOCALNAME__72_65_63_74: LocalName = LocalName::pack_inline(499883799040u64, 4u8);
+pub const ATOM_LOCALNAME__72_65_66_58: LocalName = LocalName::pack_inline(379675046400u64, 4u8);
+pub const ATOM_LOCALNAME__72_65_66_59: LocalName = LocalName::pack_inline(383970013696u64, 4u8);
+pub const ATOM_LOCALNAME__72_65_66_78: LocalName = LocalName::pack_inline(517113999872u64, 4u8);
+pub const ATOM_LOCALNAME__72_65_66_79: LocalName = LocalName::pack_inline(521408967168u64, 4u8);
+pub const ATOM_LOCALNAME__72_65_6C: LocalName = LocalName::pack_inline(1818587648u64, 3u8);
+pub const ATOM_LOCALNAME__72_65_6C_6E: LocalName = LocalName::pack_inline(474264990208u64, 4u8);
+pub const ATOM_LOCALNAME__72_65_6D: LocalName = LocalName::pack_inline(1835364864u64, 3u8);
+pub const ATOM_LOCALNAME__72_65_70_65_61_74: LocalName =
+    LocalName::pack_inline(32758185603723776u64, 6u8);
+pub const ATOM_LOCALNAME__72_65_70_6C_61_63_65: LocalName =
+    LocalName::pack_inline(7305790138895135232u64, 7u8);
+pub const ATOM_LOCALNAME__72_65_73_74_61_72_74: LocalName =
+    LocalName::pack_inline(8390876208524784128u64, 7u8);
+pub const ATOM_LOCALNAME__72_65_73_75_6C_74: LocalName =
+    LocalName::pack_inline(32770349001437696u64, 6u8);
+pub const ATOM_LOCALNAME__72_65_76: LocalName = LocalName::pack_inline(1986359808u64, 3u8);
+pub const ATOM_LOCALNAME__72_6F_6C_65: LocalName = LocalName::pack_inline(435610939904u64, 4u8);
+pub const ATOM_LOCALNAME__72_6F_6F_74: LocalName = LocalName::pack_inline(500085780992u64, 4u8);
+pub const ATOM_LOCALNAME__72_6F_74_61_74_65: LocalName =
+    LocalName::pack_inline(28556934561886720u64, 6u8);
+pub const ATOM_LOCALNAME__72_6F_77_73: LocalName = LocalName::pack_inline(495925031424u64, 4u8);
+pub const ATOM_LOCALNAME__72_6F_77_73_70_61_6E: LocalName =
+    LocalName::pack_inline(7953762058140348928u64, 7u8);
+pub const ATOM_LOCALNAME__72_70: LocalName = LocalName::pack_inline(7369216u64, 2u8);
+pub const ATOM_LOCALNAME__72_71_75_6F_74_65: LocalName =
+    LocalName::pack_inline(28556994708337152u64, 6u8);
+pub const ATOM_LOCALNAME__72_73_70_61_63_65: LocalName =
+    LocalName::pack_inline(28538242797367808u64, 6u8);
+pub const ATOM_LOCALNAME__72_74: LocalName = LocalName::pack_inline(7631360u64, 2u8);
+pub const ATOM_LOCALNAME__72_74_63: LocalName = LocalName::pack_inline(1668575744u64, 3u8);
+pub const ATOM_LOCALNAME__72_75_62_79: LocalName = LocalName::pack_inline(521342906880u64, 4u8);
+pub const ATOM_LOCALNAME__72_75_6C_65: LocalName = LocalName::pack_inline(435611333120u64, 4u8);
+pub const ATOM_LOCALNAME__72_75_6C_65_73: LocalName =
+    LocalName::pack_inline(126879448527360u64, 5u8);
+pub const ATOM_LOCALNAME__72_78: LocalName = LocalName::pack_inline(7893504u64, 2u8);
+pub const ATOM_LOCALNAME__72_79: LocalName = LocalName::pack_inline(7959040u64, 2u8);
+pub const ATOM_LOCALNAME__73: LocalName = LocalName::pack_inline(29440u64, 1u8);
+pub const ATOM_LOCALNAME__73_61_6D_70: LocalName = LocalName::pack_inline(482871440128u64, 4u8);
+pub const ATOM_LOCALNAME__73_61_6E_64_62_6F_78: LocalName =
+    LocalName::pack_inline(8678263190454366976u64, 7u8);
+pub const ATOM_LOCALNAME__73_63_61_6C_65: LocalName =
+    LocalName::pack_inline(111516164780800u64, 5u8);
+pub const ATOM_LOCALNAME__73_63_68_65_6D_65: LocalName =
+    LocalName::pack_inline(28549254958248704u64, 6u8);
+pub const ATOM_LOCALNAME__73_63_6F_70_65: LocalName =
+    LocalName::pack_inline(111533579531008u64, 5u8);
+pub const ATOM_LOCALNAME__73_63_6F_70_65_64: LocalName =
+    LocalName::pack_inline(28259031250596608u64, 6u8);
+pub const ATOM_LOCALNAME__73_63_72_69_70_74: LocalName =
+    LocalName::pack_inline(32774695491433216u64, 6u8);
+pub const ATOM_LOCALNAME__73_64_65_76: LocalName = LocalName::pack_inline(508507222784u64, 4u8);
+pub const ATOM_LOCALNAME__73_65_61_72_63_68: LocalName =
+    LocalName::pack_inline(29382740489368320u64, 6u8);
+pub const ATOM_LOCALNAME__73_65_63: LocalName = LocalName::pack_inline(1667592960u64, 3u8);
+pub const ATOM_LOCALNAME__73_65_63_68: LocalName = LocalName::pack_inline(448344191744u64, 4u8);
+pub const ATOM_LOCALNAME__73_65_63_74_69_6F_6E: LocalName =
+    LocalName::pack_inline(7957695015191671552u64, 7u8);
+pub const ATOM_LOCALNAME__73_65_65_64: LocalName = LocalName::pack_inline(431197876992u64, 4u8);
+pub const ATOM_LOCALNAME__73_65_6C_65_63_74: LocalName =
+    LocalName::pack_inline(32760384559870720u64, 6u8);
+pub const ATOM_LOCALNAME__73_65_70: LocalName = LocalName::pack_inline(1885696768u64, 3u8);
+pub const ATOM_LOCALNAME__73_65_74: LocalName = LocalName::pack_inline(1952805632u64, 3u8);
+pub const ATOM_LOCALNAME__73_65_74_64_69_66_66: LocalName =
+    LocalName::pack_inline(7378700919663588096u64, 7u8);
+pub const ATOM_LOCALNAME__73_68_61_70_65: LocalName =
+    LocalName::pack_inline(111533344977664u64, 5u8);
+pub const ATOM_LOCALNAME__73_68_6F_77: LocalName = LocalName::pack_inline(512970224384u64, 4u8);
+pub const ATOM_LOCALNAME__73_69_64_65: LocalName = LocalName::pack_inline(435476329216u64, 4u8);
+pub const ATOM_LOCALNAME__73_69_6E: LocalName = LocalName::pack_inline(1852404480u64, 3u8);
+pub const ATOM_LOCALNAME__73_69_6E_68: LocalName = LocalName::pack_inline(448529003264u64, 4u8);
+pub const ATOM_LOCALNAME__73_69_7A_65: LocalName = LocalName::pack_inline(435845427968u64, 4u8);
+pub const ATOM_LOCALNAME__73_69_7A_65_73: LocalName =
+    LocalName::pack_inline(126879682622208u64, 5u8);
+pub const ATOM_LOCALNAME__73_6C_6F_70_65: LocalName =
+    LocalName::pack_inline(111533580120832u64, 5u8);
+pub const ATOM_LOCALNAME__73_6C_6F_74: LocalName = LocalName::pack_inline(500085584640u64, 4u8);
+pub const ATOM_LOCALNAME__73_6D_61_6C_6C: LocalName =
+    LocalName::pack_inline(119212746830592u64, 5u8);
+pub const ATOM_LOCALNAME__73_6F_75_72_63_65: LocalName =
+    LocalName::pack_inline(28538315895436032u64, 6u8);
+pub const ATOM_LOCALNAME__73_70_61_63_65: LocalName =
+    LocalName::pack_inline(111477510927104u64, 5u8);
+pub const ATOM_LOCALNAME__73_70_61_63_65_72: LocalName =
+    LocalName::pack_inline(32199624855941888u64, 6u8);
+pub const ATOM_LOCALNAME__73_70_61_63_69_6E_67: LocalName =
+    LocalName::pack_inline(7453010308902187776u64, 7u8);
+pub const ATOM_LOCALNAME__73_70_61_6E: LocalName = LocalName::pack_inline(474081161984u64, 4u8);
+pub const ATOM_LOCALNAME__73_70_65_65_64: LocalName =
+    LocalName::pack_inline(110386656342784u64, 5u8);
+pub const ATOM_LOCALNAME__73_72_63: LocalName = LocalName::pack_inline(1668444928u64, 3u8);
+pub const ATOM_LOCALNAME__73_72_63_64_6F_63: LocalName =
+    LocalName::pack_inline(27988499650212608u64, 6u8);
+pub const ATOM_LOCALNAME__73_72_63_6C_61_6E_67: LocalName =
+    LocalName::pack_inline(7453001551497556736u64, 7u8);
+pub const ATOM_LOCALNAME__73_72_63_73_65_74: LocalName =
+    LocalName::pack_inline(32762643562525440u64, 6u8);
+pub const ATOM_LOCALNAME__73_74_61_6E_64_62_79: LocalName =
+    LocalName::pack_inline(8746663851551126272u64, 7u8);
+pub const ATOM_LOCALNAME__73_74_61_72_74: LocalName =
+    LocalName::pack_inline(128034610115328u64, 5u8);
+pub const ATOM_LOCALNAME__73_74_65_6D_68: LocalName =
+    LocalName::pack_inline(114819062854400u64, 5u8);
+pub const ATOM_LOCALNAME__73_74_65_6D_76: LocalName =
+    LocalName::pack_inline(130212225643264u64, 5u8);
+pub const ATOM_LOCALNAME__73_74_65_70: LocalName = LocalName::pack_inline(482738467584u64, 4u8);
+pub const ATOM_LOCALNAME__73_74_6F_70: LocalName = LocalName::pack_inline(482906239744u64, 4u8);
+pub const ATOM_LOCALNAME__73_74_72_69_6B_65: LocalName =
+    LocalName::pack_inline(28547073283748608u64, 6u8);
+pub const ATOM_LOCALNAME__73_74_72_69_6E_67: LocalName =
+    LocalName::pack_inline(29113321772053248u64, 6u8);
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65: LocalName =
+    LocalName::pack_inline(28547099053552384u64, 6u8);
+pub const ATOM_LOCALNAME__73_74_72_6F_6E_67: LocalName =
+    LocalName::pack_inline(29113347541857024u64, 6u8);
+pub const ATOM_LOCALNAME__73_74_79_6C_65: LocalName =
+    LocalName::pack_inline(111516568548096u64, 5u8);
+pub const ATOM_LOCALNAME__73_75_62: LocalName = LocalName::pack_inline(1651864320u64, 3u8);
+pub const ATOM_LOCALNAME__73_75_62_73_65_74: LocalName =
+    LocalName::pack_inline(32762643545944832u64, 6u8);
+pub const ATOM_LOCALNAME__73_75_6D: LocalName = LocalName::pack_inline(1836413696u64, 3u8);
+pub const ATOM_LOCALNAME__73_75_6D_6D_61_72_79: LocalName =
+    LocalName::pack_inline(8751164148550038272u64, 7u8);
+pub const ATOM_LOCALNAME__73_75_70: LocalName = LocalName::pack_inline(1886745344u64, 3u8);
+pub const ATOM_LOCALNAME__73_76_67: LocalName = LocalName::pack_inline(1735815936u64, 3u8);
+pub const ATOM_LOCALNAME__73_77_69_74_63_68: LocalName =
+    LocalName::pack_inline(29382749214700288u64, 6u8);
+pub const ATOM_LOCALNAME__73_79_6D_62_6F_6C: LocalName =
+    LocalName::pack_inline(30521766018904832u64, 6u8);
+pub const ATOM_LOCALNAME__74_61_62_6C_65: LocalName =
+    LocalName::pack_inline(111516181427200u64, 5u8);
+pub const ATOM_LOCALNAME__74_61_6E: LocalName = LocalName::pack_inline(1851880448u64, 3u8);
+pub const ATOM_LOCALNAME__74_61_6E_68: LocalName = LocalName::pack_inline(448528479232u64, 4u8);
+pub const ATOM_LOCALNAME__74_61_72_67_65_74: LocalName =
+    LocalName::pack_inline(32762592273462272u64, 6u8);
+pub const ATOM_LOCALNAME__74_61_72_67_65_74_58: LocalName =
+    LocalName::pack_inline(6373830867611120640u64, 7u8);
+pub const ATOM_LOCALNAME__74_61_72_67_65_74_59: LocalName =
+    LocalName::pack_inline(6445888461649048576u64, 7u8);
+pub const ATOM_LOCALNAME__74_61_72_67_65_74_78: LocalName =
+    LocalName::pack_inline(8679673876824814592u64, 7u8);
+pub const ATOM_LOCALNAME__74_61_72_67_65_74_79: LocalName =
+    LocalName::pack_inline(8751731470862742528u64, 7u8);
+pub const ATOM_LOCALNAME__74_62_6F_64_79: LocalName =
+    LocalName::pack_inline(133472272413696u64, 5u8);
+pub const ATOM_LOCALNAME__74_62_72_65_61_6B: LocalName =
+    LocalName::pack_inline(30224910846686208u64, 6u8);
+pub const ATOM_LOCALNAME__74_64: LocalName = LocalName::pack_inline(6583296u64, 2u8);
+pub const ATOM_LOCALNAME__74_65_6E_64_73_74_6F: LocalName =
+    LocalName::pack_inline(8031170910694503424u64, 7u8);
+pub const ATOM_LOCALNAME__74_65_78_74: LocalName = LocalName::pack_inline(500236121088u64, 4u8);
+pub const ATOM_LOCALNAME__74_66_6F_6F_74: LocalName =
+    LocalName::pack_inline(128021959177216u64, 5u8);
+pub const ATOM_LOCALNAME__74_68: LocalName = LocalName::pack_inline(6845440u64, 2u8);
+pub const ATOM_LOCALNAME__74_68_65_61_64: LocalName =
+    LocalName::pack_inline(110369475949568u64, 5u8);
+pub const ATOM_LOCALNAME__74_69_6D_65: LocalName = LocalName::pack_inline(435627324416u64, 4u8);
+pub const ATOM_LOCALNAME__74_69_6D_65_73: LocalName =
+    LocalName::pack_inline(126879464518656u64, 5u8);
+pub const ATOM_LOCALNAME__74_69_74_6C_65: LocalName =
+    LocalName::pack_inline(111516483941376u64, 5u8);
+pub const ATOM_LOCALNAME__74_6F: LocalName = LocalName::pack_inline(7304192u64, 2u8);
+pub const ATOM_LOCALNAME__74_6F_67_67_6C_65: LocalName =
+    LocalName::pack_inline(28548164020564992u64, 6u8);
+pub const ATOM_LOCALNAME__74_72: LocalName = LocalName::pack_inline(7500800u64, 2u8);
+pub const ATOM_LOCALNAME__74_72_61_63_6B: LocalName =
+    LocalName::pack_inline(118074580825088u64, 5u8);
+pub const ATOM_LOCALNAME__74_72_65_66: LocalName = LocalName::pack_inline(439788663808u64, 4u8);
+pub const ATOM_LOCALNAME__74_72_75_65: LocalName = LocalName::pack_inline(435762131968u64, 4u8);
+pub const ATOM_LOCALNAME__74_73_70_61_6E: LocalName =
+    LocalName::pack_inline(121364777497600u64, 5u8);
+pub const ATOM_LOCALNAME__74_74: LocalName = LocalName::pack_inline(7631872u64, 2u8);
+pub const ATOM_LOCALNAME__74_79_70_65: LocalName = LocalName::pack_inline(435678704640u64, 4u8);
+pub const ATOM_LOCALNAME__75: LocalName = LocalName::pack_inline(29952u64, 1u8);
+pub const ATOM_LOCALNAME__75_31: LocalName = LocalName::pack_inline(3241216u64, 2u8);
+pub const ATOM_LOCALNAME__75_32: LocalName = LocalName::pack_inline(3306752u64, 2u8);
+pub const ATOM_LOCALNAME__75_6C: LocalName = LocalName::pack_inline(7107840u64, 2u8);
+pub const ATOM_LOCALNAME__75_6E_69_63_6F_64_65: LocalName =
+    LocalName::pack_inline(7306086968263079168u64, 7u8);
+pub const ATOM_LOCALNAME__75_6E_69_6F_6E: LocalName =
+    LocalName::pack_inline(121424789271808u64, 5u8);
+pub const ATOM_LOCALNAME__75_70_6C_69_6D_69_74: LocalName =
+    LocalName::pack_inline(8388356080512562432u64, 7u8);
+pub const ATOM_LOCALNAME__75_73_65: LocalName = LocalName::pack_inline(1702065408u64, 3u8);
+pub const ATOM_LOCALNAME__75_73_65_6D_61_70: LocalName =
+    LocalName::pack_inline(31632319872988416u64, 6u8);
+pub const ATOM_LOCALNAME__76_61_6C_69_67_6E: LocalName =
+    LocalName::pack_inline(31075949925725696u64, 6u8);
+pub const ATOM_LOCALNAME__76_61_6C_75_65: LocalName =
+    LocalName::pack_inline(111555003905536u64, 5u8);
+pub const ATOM_LOCALNAME__76_61_6C_75_65_73: LocalName =
+    LocalName::pack_inline(32481177325630976u64, 6u8);
+pub const ATOM_LOCALNAME__76_61_72: LocalName = LocalName::pack_inline(1918989824u64, 3u8);
+pub const ATOM_LOCALNAME__76_65_63_74_6F_72: LocalName =
+    LocalName::pack_inline(32210693019497984u64, 6u8);
+pub const ATOM_LOCALNAME__76_65_72_73_69_6F_6E: LocalName =
+    LocalName::pack_inline(7957695011148363264u64, 7u8);
+pub const ATOM_LOCALNAME__76_69_64_65_6F: LocalName =
+    LocalName::pack_inline(122481267013120u64, 5u8);
+pub const ATOM_LOCALNAME__76_69_65_77: LocalName = LocalName::pack_inline(512802518528u64, 4u8);
+pub const ATOM_LOCALNAME__76_69_65_77_42_6F_78: LocalName =
+    LocalName::pack_inline(8678228087536186880u64, 7u8);
+pub const ATOM_LOCALNAME__76_69_65_77_62_6F_78: LocalName =
+    LocalName::pack_inline(8678263271908275712u64, 7u8);
+pub const ATOM_LOCALNAME__76_6B_65_72_6E: LocalName =
+    LocalName::pack_inline(121437606868480u64, 5u8);
+pub const ATOM_LOCALNAME__76_6C_69_6E_6B: LocalName =
+    LocalName::pack_inline(118121959290368u64, 5u8);
+pub const ATOM_LOCALNAME__76_73_70_61_63_65: LocalName =
+    LocalName::pack_inline(28538242797368832u64, 6u8);
+pub const ATOM_LOCALNAME__77_62_72: LocalName = LocalName::pack_inline(1919055616u64, 3u8);
+pub const ATOM_LOCALNAME__77_68_65_6E: LocalName = LocalName::pack_inline(474147747584u64, 4u8);
+pub const ATOM_LOCALNAME__77_69_64_74_68: LocalName =
+    LocalName::pack_inline(114849110128384u64, 5u8);
+pub const ATOM_LOCALNAME__77_69_64_74_68_73: LocalName =
+    LocalName::pack_inline(32484471431853824u64, 6u8);
+pub const ATOM_LOCALNAME__77_72_61_70: LocalName = LocalName::pack_inline(482671228672u64, 4u8);
+pub const ATOM_LOCALNAME__78: LocalName = LocalName::pack_inline(30720u64, 1u8);
+pub const ATOM_LOCALNAME__78_31: LocalName = LocalName::pack_inline(3241984u64, 2u8);
+pub const ATOM_LOCALNAME__78_32: LocalName = LocalName::pack_inline(3307520u64, 2u8);
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B: LocalName =
+    LocalName::pack_inline(118121959290880u64, 5u8);
+pub const ATOM_LOCALNAME__78_6D_6C_6E_73: LocalName =
+    LocalName::pack_inline(126918102710272u64, 5u8);
+pub const ATOM_LOCALNAME__78_6D_70: LocalName = LocalName::pack_inline(1886222336u64, 3u8);
+pub const ATOM_LOCALNAME__78_6F_72: LocalName = LocalName::pack_inline(1919907840u64, 3u8);
+pub const ATOM_LOCALNAME__78_72_65_66: LocalName = LocalName::pack_inline(439788664832u64, 4u8);
+pub const ATOM_LOCALNAME__79: LocalName = LocalName::pack_inline(30976u64, 1u8);
+pub const ATOM_LOCALNAME__79_31: LocalName = LocalName::pack_inline(3242240u64, 2u8);
+pub const ATOM_LOCALNAME__79_32: LocalName = LocalName::pack_inline(3307776u64, 2u8);
+pub const ATOM_LOCALNAME__7A: LocalName = LocalName::pack_inline(31232u64, 1u8);
+#[doc = "Takes a local name as a string and returns its key in the string cache."]
+#[macro_export]
+macro_rules ! local_name { ("equivalent") => { $ crate :: ATOM_LOCALNAME__65_71_75_69_76_61_6C_65_6E_74 } ; ("pointsAtY") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_59 } ; ("stretchy") => { $ crate :: ATOM_LOCALNAME__73_74_72_65_74_63_68_79 } ; ("glyphRef") => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68_52_65_66 } ; ("naturalnumbers") => { $ crate :: ATOM_LOCALNAME__6E_61_74_75_72_61_6C_6E_75_6D_62_65_72_73 } ; ("keyPoints") => { $ crate :: ATOM_LOCALNAME__6B_65_79_50_6F_69_6E_74_73 } ; ("solidColor") => { $ crate :: ATOM_LOCALNAME__73_6F_6C_69_64_43_6F_6C_6F_72 } ; ("pathlength") => { $ crate :: ATOM_LOCALNAME__70_61_74_68_6C_65_6E_67_74_68 } ; ("altGlyph") => { $ crate :: ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68 } ; ("contentScriptType") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_53_63_72_69_70_74_54_79_70_65 } ; ("unselectable") => { $ crate :: ATOM_LOCALNAME__75_6E_73_65_6C_65_63_74_61_62_6C_65 } ; ("solid-color") => { $ crate :: ATOM_LOCALNAME__73_6F_6C_69_64_2D_63_6F_6C_6F_72 } ; ("ondragstart") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_73_74_61_72_74 } ; ("xChannelSelector") => { $ crate :: ATOM_LOCALNAME__78_43_68_61_6E_6E_65_6C_53_65_6C_65_63_74_6F_72 } ; ("onrowexit") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_6F_77_65_78_69_74 } ; ("yChannelSelector") => { $ crate :: ATOM_LOCALNAME__79_43_68_61_6E_6E_65_6C_53_65_6C_65_63_74_6F_72 } ; ("oncanplay") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_61_6E_70_6C_61_79 } ; ("feComponentTransfer") => { $ crate :: ATOM_LOCALNAME__66_65_43_6F_6D_70_6F_6E_65_6E_74_54_72_61_6E_73_66_65_72 } ; ("line-height") => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65_2D_68_65_69_67_68_74 } ; ("diffuseConstant") => { $ crate :: ATOM_LOCALNAME__64_69_66_66_75_73_65_43_6F_6E_73_74_61_6E_74 } ; ("hatchContentUnits") => { $ crate :: ATOM_LOCALNAME__68_61_74_63_68_43_6F_6E_74_65_6E_74_55_6E_69_74_73 } ; ("underline-position") => { $ crate :: ATOM_LOCALNAME__75_6E_64_65_72_6C_69_6E_65_2D_70_6F_73_69_74_69_6F_6E } ; ("spreadmethod") => { $ crate :: ATOM_LOCALNAME__73_70_72_65_61_64_6D_65_74_68_6F_64 } ; ("strikethrough-thickness") => { $ crate :: ATOM_LOCALNAME__73_74_72_69_6B_65_74_68_72_6F_75_67_68_2D_74_68_69_63_6B_6E_65_73_73 } ; ("filterUnits") => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72_55_6E_69_74_73 } ; ("font-face-uri") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_75_72_69 } ; ("requiredExtensions") => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64_45_78_74_65_6E_73_69_6F_6E_73 } ; ("textpath") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_70_61_74_68 } ; ("gradientUnits") => { $ crate :: ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_55_6E_69_74_73 } ; ("equalrows") => { $ crate :: ATOM_LOCALNAME__65_71_75_61_6C_72_6F_77_73 } ; ("rationals") => { $ crate :: ATOM_LOCALNAME__72_61_74_69_6F_6E_61_6C_73 } ; ("displaystyle") => { $ crate :: ATOM_LOCALNAME__64_69_73_70_6C_61_79_73_74_79_6C_65 } ; ("fill-rule") => { $ crate :: ATOM_LOCALNAME__66_69_6C_6C_2D_72_75_6C_65 } ; ("baseProfile") => { $ crate :: ATOM_LOCALNAME__62_61_73_65_50_72_6F_66_69_6C_65 } ; ("aria-setsize") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_73_65_74_73_69_7A_65 } ; ("pointsAtZ") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_5A } ; ("onunload") => { $ crate :: ATOM_LOCALNAME__6F_6E_75_6E_6C_6F_61_64 } ; ("encoding") => { $ crate :: ATOM_LOCALNAME__65_6E_63_6F_64_69_6E_67 } ; ("vert-origin-x") => { $ crate :: ATOM_LOCALNAME__76_65_72_74_2D_6F_72_69_67_69_6E_2D_78 } ; ("irrelevant") => { $ crate :: ATOM_LOCALNAME__69_72_72_65_6C_65_76_61_6E_74 } ; ("flood-color") => { $ crate :: ATOM_LOCALNAME__66_6C_6F_6F_64_2D_63_6F_6C_6F_72 } ; ("mozbrowser") => { $ crate :: ATOM_LOCALNAME__6D_6F_7A_62_72_6F_77_73_65_72 } ; ("aria-grab") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_67_72_61_62 } ; ("oncanplaythrough") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_61_6E_70_6C_61_79_74_68_72_6F_75_67_68 } ; ("font-face-format") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_66_6F_72_6D_61_74 } ; ("clipPathUnits") => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_50_61_74_68_55_6E_69_74_73 } ; ("horiz-origin-y") => { $ crate :: ATOM_LOCALNAME__68_6F_72_69_7A_2D_6F_72_69_67_69_6E_2D_79 } ; ("radiogroup") => { $ crate :: ATOM_LOCALNAME__72_61_64_69_6F_67_72_6F_75_70 } ; ("font-size-adjust") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_73_69_7A_65_2D_61_64_6A_75_73_74 } ; ("numoctaves") => { $ crate :: ATOM_LOCALNAME__6E_75_6D_6F_63_74_61_76_65_73 } ; ("glyph-orientation-vertical") => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68_2D_6F_72_69_65_6E_74_61_74_69_6F_6E_2D_76_65_72_74_69_63_61_6C } ; ("lineargradient") => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65_61_72_67_72_61_64_69_65_6E_74 } ; ("imaginaryi") => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_69_6E_61_72_79_69 } ; ("itemscope") => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_73_63_6F_70_65 } ; ("clip-rule") => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_2D_72_75_6C_65 } ; ("feGaussianBlur") => { $ crate :: ATOM_LOCALNAME__66_65_47_61_75_73_73_69_61_6E_42_6C_75_72 } ; ("fecolormatrix") => { $ crate :: ATOM_LOCALNAME__66_65_63_6F_6C_6F_72_6D_61_74_72_69_78 } ; ("aria-secret") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_73_65_63_72_65_74 } ; ("aria-live") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6C_69_76_65 } ; ("aria-colindextext") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_69_6E_64_65_78_74_65_78_74 } ; ("mathematical") => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_65_6D_61_74_69_63_61_6C } ; ("formaction") => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_61_63_74_69_6F_6E } ; ("shadowrootclonable") => { $ crate :: ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_63_6C_6F_6E_61_62_6C_65 } ; ("columnlines") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_6C_69_6E_65_73 } ; ("primitiveunits") => { $ crate :: ATOM_LOCALNAME__70_72_69_6D_69_74_69_76_65_75_6E_69_74_73 } ; ("stroke-miterlimit") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6D_69_74_65_72_6C_69_6D_69_74 } ; ("reversed") => { $ crate :: ATOM_LOCALNAME__72_65_76_65_72_73_65_64 } ; ("divergence") => { $ crate :: ATOM_LOCALNAME__64_69_76_65_72_67_65_6E_63_65 } ; ("aria-pressed") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_70_72_65_73_73_65_64 } ; ("onerrorupdate") => { $ crate :: ATOM_LOCALNAME__6F_6E_65_72_72_6F_72_75_70_64_61_74_65 } ; ("keyTimes") => { $ crate :: ATOM_LOCALNAME__6B_65_79_54_69_6D_65_73 } ; ("ondragover") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_6F_76_65_72 } ; ("noscript") => { $ crate :: ATOM_LOCALNAME__6E_6F_73_63_72_69_70_74 } ; ("aria-haspopup") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_68_61_73_70_6F_70_75_70 } ; ("viewTarget") => { $ crate :: ATOM_LOCALNAME__76_69_65_77_54_61_72_67_65_74 } ; ("xmlns:xlink") => { $ crate :: ATOM_LOCALNAME__78_6D_6C_6E_73_3A_78_6C_69_6E_6B } ; ("repeat-max") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_2D_6D_61_78 } ; ("onfilterchange") => { $ crate :: ATOM_LOCALNAME__6F_6E_66_69_6C_74_65_72_63_68_61_6E_67_65 } ; ("patternTransform") => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_54_72_61_6E_73_66_6F_72_6D } ; ("fontstyle") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_73_74_79_6C_65 } ; ("shadowrootserializable") => { $ crate :: ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_73_65_72_69_61_6C_69_7A_61_62_6C_65 } ; ("kernelUnitLength") => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_65_6C_55_6E_69_74_4C_65_6E_67_74_68 } ; ("requiredfeatures") => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64_66_65_61_74_75_72_65_73 } ; ("x-height") => { $ crate :: ATOM_LOCALNAME__78_2D_68_65_69_67_68_74 } ; ("aria-activedescendant") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_61_63_74_69_76_65_64_65_73_63_65_6E_64_61_6E_74 } ; ("xchannelselector") => { $ crate :: ATOM_LOCALNAME__78_63_68_61_6E_6E_65_6C_73_65_6C_65_63_74_6F_72 } ; ("markerWidth") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_57_69_64_74_68 } ; ("hidefocus") => { $ crate :: ATOM_LOCALNAME__68_69_64_65_66_6F_63_75_73 } ; ("ondataavailable") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_61_74_61_61_76_61_69_6C_61_62_6C_65 } ; ("aria-invalid") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_69_6E_76_61_6C_69_64 } ; ("glyphref") => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68_72_65_66 } ; ("patterntransform") => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_74_72_61_6E_73_66_6F_72_6D } ; ("ondatasetchanged") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_61_74_61_73_65_74_63_68_61_6E_67_65_64 } ; ("rowspacing") => { $ crate :: ATOM_LOCALNAME__72_6F_77_73_70_61_63_69_6E_67 } ; ("aria-expanded") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_65_78_70_61_6E_64_65_64 } ; ("onmouseover") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6F_76_65_72 } ; ("aria-datatype") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_64_61_74_61_74_79_70_65 } ; ("notprsubset") => { $ crate :: ATOM_LOCALNAME__6E_6F_74_70_72_73_75_62_73_65_74 } ; ("xlink:arcrole") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B_3A_61_72_63_72_6F_6C_65 } ; ("onbounce") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_6F_75_6E_63_65 } ; ("scriptsizemultiplier") => { $ crate :: ATOM_LOCALNAME__73_63_72_69_70_74_73_69_7A_65_6D_75_6C_74_69_70_6C_69_65_72 } ; ("codebase") => { $ crate :: ATOM_LOCALNAME__63_6F_64_65_62_61_73_65 } ; ("stop-color") => { $ crate :: ATOM_LOCALNAME__73_74_6F_70_2D_63_6F_6C_6F_72 } ; ("exponent") => { $ crate :: ATOM_LOCALNAME__65_78_70_6F_6E_65_6E_74 } ; ("columnspan") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_73_70_61_6E } ; ("text-anchor") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_2D_61_6E_63_68_6F_72 } ; ("v-hanging") => { $ crate :: ATOM_LOCALNAME__76_2D_68_61_6E_67_69_6E_67 } ; ("filterunits") => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72_75_6E_69_74_73 } ; ("factorof") => { $ crate :: ATOM_LOCALNAME__66_61_63_74_6F_72_6F_66 } ; ("onbeforeactivate") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_61_63_74_69_76_61_74_65 } ; ("keysplines") => { $ crate :: ATOM_LOCALNAME__6B_65_79_73_70_6C_69_6E_65_73 } ; ("baseline-shift") => { $ crate :: ATOM_LOCALNAME__62_61_73_65_6C_69_6E_65_2D_73_68_69_66_74 } ; ("aria-valuemin") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_6D_69_6E } ; ("xml:base") => { $ crate :: ATOM_LOCALNAME__78_6D_6C_3A_62_61_73_65 } ; ("ondragenter") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_65_6E_74_65_72 } ; ("attributetype") => { $ crate :: ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_74_79_70_65 } ; ("feoffset") => { $ crate :: ATOM_LOCALNAME__66_65_6F_66_66_73_65_74 } ; ("letter-spacing") => { $ crate :: ATOM_LOCALNAME__6C_65_74_74_65_72_2D_73_70_61_63_69_6E_67 } ; ("baseline") => { $ crate :: ATOM_LOCALNAME__62_61_73_65_6C_69_6E_65 } ; ("oncellchange") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_65_6C_6C_63_68_61_6E_67_65 } ; ("missing-glyph") => { $ crate :: ATOM_LOCALNAME__6D_69_73_73_69_6E_67_2D_67_6C_79_70_68 } ; ("alignment-baseline") => { $ crate :: ATOM_LOCALNAME__61_6C_69_67_6E_6D_65_6E_74_2D_62_61_73_65_6C_69_6E_65 } ; ("radialgradient") => { $ crate :: ATOM_LOCALNAME__72_61_64_69_61_6C_67_72_61_64_69_65_6E_74 } ; ("lighting-color") => { $ crate :: ATOM_LOCALNAME__6C_69_67_68_74_69_6E_67_2D_63_6F_6C_6F_72 } ; ("maskcontentunits") => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B_63_6F_6E_74_65_6E_74_75_6E_69_74_73 } ; ("startoffset") => { $ crate :: ATOM_LOCALNAME__73_74_61_72_74_6F_66_66_73_65_74 } ; ("onloadedmetadata") => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_6F_61_64_65_64_6D_65_74_61_64_61_74_61 } ; ("controls") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_72_6F_6C_73 } ; ("shadowrootmode") => { $ crate :: ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_6D_6F_64_65 } ; ("shape-rendering") => { $ crate :: ATOM_LOCALNAME__73_68_61_70_65_2D_72_65_6E_64_65_72_69_6E_67 } ; ("annotation") => { $ crate :: ATOM_LOCALNAME__61_6E_6E_6F_74_61_74_69_6F_6E } ; ("fegaussianblur") => { $ crate :: ATOM_LOCALNAME__66_65_67_61_75_73_73_69_61_6E_62_6C_75_72 } ; ("stddeviation") => { $ crate :: ATOM_LOCALNAME__73_74_64_64_65_76_69_61_74_69_6F_6E } ; ("pointer-events") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_65_72_2D_65_76_65_6E_74_73 } ; ("itemtype") => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_74_79_70_65 } ; ("repeatCount") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_43_6F_75_6E_74 } ; ("otherwise") => { $ crate :: ATOM_LOCALNAME__6F_74_68_65_72_77_69_73_65 } ; ("ondragdrop") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_64_72_6F_70 } ; ("cellpadding") => { $ crate :: ATOM_LOCALNAME__63_65_6C_6C_70_61_64_64_69_6E_67 } ; ("clipPath") => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_50_61_74_68 } ; ("prsubset") => { $ crate :: ATOM_LOCALNAME__70_72_73_75_62_73_65_74 } ; ("plaintext") => { $ crate :: ATOM_LOCALNAME__70_6C_61_69_6E_74_65_78_74 } ; ("mathvariant") => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_76_61_72_69_61_6E_74 } ; ("onafterupdate") => { $ crate :: ATOM_LOCALNAME__6F_6E_61_66_74_65_72_75_70_64_61_74_65 } ; ("marker-start") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_2D_73_74_61_72_74 } ; ("stroke-width") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_77_69_64_74_68 } ; ("aria-braillelabel") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_62_72_61_69_6C_6C_65_6C_61_62_65_6C } ; ("underline-thickness") => { $ crate :: ATOM_LOCALNAME__75_6E_64_65_72_6C_69_6E_65_2D_74_68_69_63_6B_6E_65_73_73 } ; ("ondragleave") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_6C_65_61_76_65 } ; ("markerwidth") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_77_69_64_74_68 } ; ("vector-effect") => { $ crate :: ATOM_LOCALNAME__76_65_63_74_6F_72_2D_65_66_66_65_63_74 } ; ("onafterprint") => { $ crate :: ATOM_LOCALNAME__6F_6E_61_66_74_65_72_70_72_69_6E_74 } ; ("repeatDur") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_44_75_72 } ; ("formenctype") => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_65_6E_63_74_79_70_65 } ; ("innertext") => { $ crate :: ATOM_LOCALNAME__69_6E_6E_65_72_74_65_78_74 } ; ("vectorproduct") => { $ crate :: ATOM_LOCALNAME__76_65_63_74_6F_72_70_72_6F_64_75_63_74 } ; ("onpageswap") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_67_65_73_77_61_70 } ; ("aria-labelledby") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6C_61_62_65_6C_6C_65_64_62_79 } ; ("marginwidth") => { $ crate :: ATOM_LOCALNAME__6D_61_72_67_69_6E_77_69_64_74_68 } ; ("basefrequency") => { $ crate :: ATOM_LOCALNAME__62_61_73_65_66_72_65_71_75_65_6E_63_79 } ; ("fespecularlighting") => { $ crate :: ATOM_LOCALNAME__66_65_73_70_65_63_75_6C_61_72_6C_69_67_68_74_69_6E_67 } ; ("aria-channel") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_68_61_6E_6E_65_6C } ; ("alignmentscope") => { $ crate :: ATOM_LOCALNAME__61_6C_69_67_6E_6D_65_6E_74_73_63_6F_70_65 } ; ("markerUnits") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_55_6E_69_74_73 } ; ("onbeforeprint") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_70_72_69_6E_74 } ; ("baseFrequency") => { $ crate :: ATOM_LOCALNAME__62_61_73_65_46_72_65_71_75_65_6E_63_79 } ; ("xml:lang") => { $ crate :: ATOM_LOCALNAME__78_6D_6C_3A_6C_61_6E_67 } ; ("limitingConeAngle") => { $ crate :: ATOM_LOCALNAME__6C_69_6D_69_74_69_6E_67_43_6F_6E_65_41_6E_67_6C_65 } ; ("edgeMode") => { $ crate :: ATOM_LOCALNAME__65_64_67_65_4D_6F_64_65 } ; ("animateMotion") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_4D_6F_74_69_6F_6E } ; ("hatchUnits") => { $ crate :: ATOM_LOCALNAME__68_61_74_63_68_55_6E_69_74_73 } ; ("controllerchange") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_72_6F_6C_6C_65_72_63_68_61_6E_67_65 } ; ("lengthAdjust") => { $ crate :: ATOM_LOCALNAME__6C_65_6E_67_74_68_41_64_6A_75_73_74 } ; ("pointsAtX") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_58 } ; ("glyph-name") => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68_2D_6E_61_6D_65 } ; ("required") => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64 } ; ("eulergamma") => { $ crate :: ATOM_LOCALNAME__65_75_6C_65_72_67_61_6D_6D_61 } ; ("horiz-adv-x") => { $ crate :: ATOM_LOCALNAME__68_6F_72_69_7A_2D_61_64_76_2D_78 } ; ("datalist") => { $ crate :: ATOM_LOCALNAME__64_61_74_61_6C_69_73_74 } ; ("preserveAspectRatio") => { $ crate :: ATOM_LOCALNAME__70_72_65_73_65_72_76_65_41_73_70_65_63_74_52_61_74_69_6F } ; ("mathcolor") => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_63_6F_6C_6F_72 } ; ("mmultiscripts") => { $ crate :: ATOM_LOCALNAME__6D_6D_75_6C_74_69_73_63_72_69_70_74_73 } ; ("aria-owns") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6F_77_6E_73 } ; ("fespotlight") => { $ crate :: ATOM_LOCALNAME__66_65_73_70_6F_74_6C_69_67_68_74 } ; ("blockquote") => { $ crate :: ATOM_LOCALNAME__62_6C_6F_63_6B_71_75_6F_74_65 } ; ("aria-modal") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6D_6F_64_61_6C } ; ("onseeking") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_65_65_6B_69_6E_67 } ; ("veryverythickmathspace") => { $ crate :: ATOM_LOCALNAME__76_65_72_79_76_65_72_79_74_68_69_63_6B_6D_61_74_68_73_70_61_63_65 } ; ("tableValues") => { $ crate :: ATOM_LOCALNAME__74_61_62_6C_65_56_61_6C_75_65_73 } ; ("animatemotion") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_6D_6F_74_69_6F_6E } ; ("v-alphabetic") => { $ crate :: ATOM_LOCALNAME__76_2D_61_6C_70_68_61_62_65_74_69_63 } ; ("exponentiale") => { $ crate :: ATOM_LOCALNAME__65_78_70_6F_6E_65_6E_74_69_61_6C_65 } ; ("shadowrootdelegatesfocus") => { $ crate :: ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_64_65_6C_65_67_61_74_65_73_66_6F_63_75_73 } ; ("marginheight") => { $ crate :: ATOM_LOCALNAME__6D_61_72_67_69_6E_68_65_69_67_68_74 } ; ("ondeactivate") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_65_61_63_74_69_76_61_74_65 } ; ("arabic-form") => { $ crate :: ATOM_LOCALNAME__61_72_61_62_69_63_2D_66_6F_72_6D } ; ("aria-valuetext") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_74_65_78_74 } ; ("contenteditable") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_65_64_69_74_61_62_6C_65 } ; ("hatchpath") => { $ crate :: ATOM_LOCALNAME__68_61_74_63_68_70_61_74_68 } ; ("onfinish") => { $ crate :: ATOM_LOCALNAME__6F_6E_66_69_6E_69_73_68 } ; ("spellcheck") => { $ crate :: ATOM_LOCALNAME__73_70_65_6C_6C_63_68_65_63_6B } ; ("onrowsinserted") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_6F_77_73_69_6E_73_65_72_74_65_64 } ; ("onrowenter") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_6F_77_65_6E_74_65_72 } ; ("onlanguagechange") => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_61_6E_67_75_61_67_65_63_68_61_6E_67_65 } ; ("clip-path") => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_2D_70_61_74_68 } ; ("aria-flowto") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_66_6C_6F_77_74_6F } ; ("formnovalidate") => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_6E_6F_76_61_6C_69_64_61_74_65 } ; ("manifest") => { $ crate :: ATOM_LOCALNAME__6D_61_6E_69_66_65_73_74 } ; ("aria-multiline") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6D_75_6C_74_69_6C_69_6E_65 } ; ("foreignObject") => { $ crate :: ATOM_LOCALNAME__66_6F_72_65_69_67_6E_4F_62_6A_65_63_74 } ; ("feConvolveMatrix") => { $ crate :: ATOM_LOCALNAME__66_65_43_6F_6E_76_6F_6C_76_65_4D_61_74_72_69_78 } ; ("onmouseenter") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_65_6E_74_65_72 } ; ("animateTransform") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_54_72_61_6E_73_66_6F_72_6D } ; ("feMergeNode") => { $ crate :: ATOM_LOCALNAME__66_65_4D_65_72_67_65_4E_6F_64_65 } ; ("attributename") => { $ crate :: ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_6E_61_6D_65 } ; ("onstorage") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_74_6F_72_61_67_65 } ; ("aria-sort") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_73_6F_72_74 } ; ("momentabout") => { $ crate :: ATOM_LOCALNAME__6D_6F_6D_65_6E_74_61_62_6F_75_74 } ; ("mprescripts") => { $ crate :: ATOM_LOCALNAME__6D_70_72_65_73_63_72_69_70_74_73 } ; ("xlink:actuate") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B_3A_61_63_74_75_61_74_65 } ; ("aria-disabled") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_64_69_73_61_62_6C_65_64 } ; ("aria-brailleroledescription") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_62_72_61_69_6C_6C_65_72_6F_6C_65_64_65_73_63_72_69_70_74_69_6F_6E } ; ("basefont") => { $ crate :: ATOM_LOCALNAME__62_61_73_65_66_6F_6E_74 } ; ("solidcolor") => { $ crate :: ATOM_LOCALNAME__73_6F_6C_69_64_63_6F_6C_6F_72 } ; ("onmousewheel") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_77_68_65_65_6C } ; ("gradientunits") => { $ crate :: ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_75_6E_69_74_73 } ; ("datatemplate") => { $ crate :: ATOM_LOCALNAME__64_61_74_61_74_65_6D_70_6C_61_74_65 } ; ("maskUnits") => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B_55_6E_69_74_73 } ; ("preservealpha") => { $ crate :: ATOM_LOCALNAME__70_72_65_73_65_72_76_65_61_6C_70_68_61 } ; ("textlength") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_6C_65_6E_67_74_68 } ; ("preserveAlpha") => { $ crate :: ATOM_LOCALNAME__70_72_65_73_65_72_76_65_41_6C_70_68_61 } ; ("background") => { $ crate :: ATOM_LOCALNAME__62_61_63_6B_67_72_6F_75_6E_64 } ; ("diffuseconstant") => { $ crate :: ATOM_LOCALNAME__64_69_66_66_75_73_65_63_6F_6E_73_74_61_6E_74 } ; ("notation") => { $ crate :: ATOM_LOCALNAME__6E_6F_74_61_74_69_6F_6E } ; ("vert-origin-y") => { $ crate :: ATOM_LOCALNAME__76_65_72_74_2D_6F_72_69_67_69_6E_2D_79 } ; ("overline-position") => { $ crate :: ATOM_LOCALNAME__6F_76_65_72_6C_69_6E_65_2D_70_6F_73_69_74_69_6F_6E } ; ("enable-background") => { $ crate :: ATOM_LOCALNAME__65_6E_61_62_6C_65_2D_62_61_63_6B_67_72_6F_75_6E_64 } ; ("oncommand") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6F_6D_6D_61_6E_64 } ; ("rowalign") => { $ crate :: ATOM_LOCALNAME__72_6F_77_61_6C_69_67_6E } ; ("actiontype") => { $ crate :: ATOM_LOCALNAME__61_63_74_69_6F_6E_74_79_70_65 } ; ("stroke-dasharray") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_64_61_73_68_61_72_72_61_79 } ; ("aria-details") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_64_65_74_61_69_6C_73 } ; ("tablevalues") => { $ crate :: ATOM_LOCALNAME__74_61_62_6C_65_76_61_6C_75_65_73 } ; ("autosubmit") => { $ crate :: ATOM_LOCALNAME__61_75_74_6F_73_75_62_6D_69_74 } ; ("onbeforeunload") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_75_6E_6C_6F_61_64 } ; ("factorial") => { $ crate :: ATOM_LOCALNAME__66_61_63_74_6F_72_69_61_6C } ; ("stitchtiles") => { $ crate :: ATOM_LOCALNAME__73_74_69_74_63_68_74_69_6C_65_73 } ; ("groupalign") => { $ crate :: ATOM_LOCALNAME__67_72_6F_75_70_61_6C_69_67_6E } ; ("oncontextmenu") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6F_6E_74_65_78_74_6D_65_6E_75 } ; ("repeat-start") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_2D_73_74_61_72_74 } ; ("accentunder") => { $ crate :: ATOM_LOCALNAME__61_63_63_65_6E_74_75_6E_64_65_72 } ; ("additive") => { $ crate :: ATOM_LOCALNAME__61_64_64_69_74_69_76_65 } ; ("fediffuselighting") => { $ crate :: ATOM_LOCALNAME__66_65_64_69_66_66_75_73_65_6C_69_67_68_74_69_6E_67 } ; ("fedistantlight") => { $ crate :: ATOM_LOCALNAME__66_65_64_69_73_74_61_6E_74_6C_69_67_68_74 } ; ("aria-description") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_64_65_73_63_72_69_70_74_69_6F_6E } ; ("decoding") => { $ crate :: ATOM_LOCALNAME__64_65_63_6F_64_69_6E_67 } ; ("fecomponenttransfer") => { $ crate :: ATOM_LOCALNAME__66_65_63_6F_6D_70_6F_6E_65_6E_74_74_72_61_6E_73_66_65_72 } ; ("progress") => { $ crate :: ATOM_LOCALNAME__70_72_6F_67_72_65_73_73 } ; ("formmethod") => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_6D_65_74_68_6F_64 } ; ("specularExponent") => { $ crate :: ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_45_78_70_6F_6E_65_6E_74 } ; ("specularConstant") => { $ crate :: ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_43_6F_6E_73_74_61_6E_74 } ; ("altglyphdef") => { $ crate :: ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68_64_65_66 } ; ("draggable") => { $ crate :: ATOM_LOCALNAME__64_72_61_67_67_61_62_6C_65 } ; ("onoffline") => { $ crate :: ATOM_LOCALNAME__6F_6E_6F_66_66_6C_69_6E_65 } ; ("onbefordeactivate") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_64_65_61_63_74_69_76_61_74_65 } ; ("marker-end") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_2D_65_6E_64 } ; ("onpropertychange") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_72_6F_70_65_72_74_79_63_68_61_6E_67_65 } ; ("thinmathspace") => { $ crate :: ATOM_LOCALNAME__74_68_69_6E_6D_61_74_68_73_70_61_63_65 } ; ("onbeforepaste") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_70_61_73_74_65 } ; ("zoomAndPan") => { $ crate :: ATOM_LOCALNAME__7A_6F_6F_6D_41_6E_64_50_61_6E } ; ("attributeName") => { $ crate :: ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_4E_61_6D_65 } ; ("onbeforecut") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_63_75_74 } ; ("surfacescale") => { $ crate :: ATOM_LOCALNAME__73_75_72_66_61_63_65_73_63_61_6C_65 } ; ("feDistantLight") => { $ crate :: ATOM_LOCALNAME__66_65_44_69_73_74_61_6E_74_4C_69_67_68_74 } ; ("variance") => { $ crate :: ATOM_LOCALNAME__76_61_72_69_61_6E_63_65 } ; ("textarea") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_61_72_65_61 } ; ("aria-checked") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_68_65_63_6B_65_64 } ; ("statechange") => { $ crate :: ATOM_LOCALNAME__73_74_61_74_65_63_68_61_6E_67_65 } ; ("transform-origin") => { $ crate :: ATOM_LOCALNAME__74_72_61_6E_73_66_6F_72_6D_2D_6F_72_69_67_69_6E } ; ("edgemode") => { $ crate :: ATOM_LOCALNAME__65_64_67_65_6D_6F_64_65 } ; ("kernelMatrix") => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_65_6C_4D_61_74_72_69_78 } ; ("mphantom") => { $ crate :: ATOM_LOCALNAME__6D_70_68_61_6E_74_6F_6D } ; ("color-interpolation-filters") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_6F_72_2D_69_6E_74_65_72_70_6F_6C_61_74_69_6F_6E_2D_66_69_6C_74_65_72_73 } ; ("aria-colcount") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_63_6F_75_6E_74 } ; ("cap-height") => { $ crate :: ATOM_LOCALNAME__63_61_70_2D_68_65_69_67_68_74 } ; ("altglyph") => { $ crate :: ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68 } ; ("novalidate") => { $ crate :: ATOM_LOCALNAME__6E_6F_76_61_6C_69_64_61_74_65 } ; ("aria-posinset") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_70_6F_73_69_6E_73_65_74 } ; ("semantics") => { $ crate :: ATOM_LOCALNAME__73_65_6D_61_6E_74_69_63_73 } ; ("gradientTransform") => { $ crate :: ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_54_72_61_6E_73_66_6F_72_6D } ; ("definitionURL") => { $ crate :: ATOM_LOCALNAME__64_65_66_69_6E_69_74_69_6F_6E_55_52_4C } ; ("animatetransform") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_74_72_61_6E_73_66_6F_72_6D } ; ("keytimes") => { $ crate :: ATOM_LOCALNAME__6B_65_79_74_69_6D_65_73 } ; ("ondragexit") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_65_78_69_74 } ; ("separator") => { $ crate :: ATOM_LOCALNAME__73_65_70_61_72_61_74_6F_72 } ; ("onsuspend") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_75_73_70_65_6E_64 } ; ("onmousemove") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6D_6F_76_65 } ; ("innerText") => { $ crate :: ATOM_LOCALNAME__69_6E_6E_65_72_54_65_78_74 } ; ("feSpotLight") => { $ crate :: ATOM_LOCALNAME__66_65_53_70_6F_74_4C_69_67_68_74 } ; ("onmessageerror") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_65_73_73_61_67_65_65_72_72_6F_72 } ; ("elevation") => { $ crate :: ATOM_LOCALNAME__65_6C_65_76_61_74_69_6F_6E } ; ("markerheight") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_68_65_69_67_68_74 } ; ("feColorMatrix") => { $ crate :: ATOM_LOCALNAME__66_65_43_6F_6C_6F_72_4D_61_74_72_69_78 } ; ("animation") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_69_6F_6E } ; ("prefetch") => { $ crate :: ATOM_LOCALNAME__70_72_65_66_65_74_63_68 } ; ("lengthadjust") => { $ crate :: ATOM_LOCALNAME__6C_65_6E_67_74_68_61_64_6A_75_73_74 } ; ("equalcolumns") => { $ crate :: ATOM_LOCALNAME__65_71_75_61_6C_63_6F_6C_75_6D_6E_73 } ; ("partialdiff") => { $ crate :: ATOM_LOCALNAME__70_61_72_74_69_61_6C_64_69_66_66 } ; ("integrity") => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_67_72_69_74_79 } ; ("outerproduct") => { $ crate :: ATOM_LOCALNAME__6F_75_74_65_72_70_72_6F_64_75_63_74 } ; ("popovertarget") => { $ crate :: ATOM_LOCALNAME__70_6F_70_6F_76_65_72_74_61_72_67_65_74 } ; ("symmetric") => { $ crate :: ATOM_LOCALNAME__73_79_6D_6D_65_74_72_69_63 } ; ("alphabetic") => { $ crate :: ATOM_LOCALNAME__61_6C_70_68_61_62_65_74_69_63 } ; ("translate") => { $ crate :: ATOM_LOCALNAME__74_72_61_6E_73_6C_61_74_65 } ; ("operator") => { $ crate :: ATOM_LOCALNAME__6F_70_65_72_61_74_6F_72 } ; ("formtarget") => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_74_61_72_67_65_74 } ; ("autofocus") => { $ crate :: ATOM_LOCALNAME__61_75_74_6F_66_6F_63_75_73 } ; ("zoomandpan") => { $ crate :: ATOM_LOCALNAME__7A_6F_6F_6D_61_6E_64_70_61_6E } ; ("direction") => { $ crate :: ATOM_LOCALNAME__64_69_72_65_63_74_69_6F_6E } ; ("feDropShadow") => { $ crate :: ATOM_LOCALNAME__66_65_44_72_6F_70_53_68_61_64_6F_77 } ; ("columnwidth") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_77_69_64_74_68 } ; ("onmousedown") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_64_6F_77_6E } ; ("animatecolor") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_63_6F_6C_6F_72 } ; ("aria-rowcount") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_63_6F_75_6E_74 } ; ("oncuechange") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_75_65_63_68_61_6E_67_65 } ; ("onresize") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_65_73_69_7A_65 } ; ("frameborder") => { $ crate :: ATOM_LOCALNAME__66_72_61_6D_65_62_6F_72_64_65_72 } ; ("download") => { $ crate :: ATOM_LOCALNAME__64_6F_77_6E_6C_6F_61_64 } ; ("v-mathematical") => { $ crate :: ATOM_LOCALNAME__76_2D_6D_61_74_68_65_6D_61_74_69_63_61_6C } ; ("preserveaspectratio") => { $ crate :: ATOM_LOCALNAME__70_72_65_73_65_72_76_65_61_73_70_65_63_74_72_61_74_69_6F } ; ("ideographic") => { $ crate :: ATOM_LOCALNAME__69_64_65_6F_67_72_61_70_68_69_63 } ; ("aria-valuenow") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_6E_6F_77 } ; ("requiredFeatures") => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64_46_65_61_74_75_72_65_73 } ; ("frameset") => { $ crate :: ATOM_LOCALNAME__66_72_61_6D_65_73_65_74 } ; ("mathbackground") => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_62_61_63_6B_67_72_6F_75_6E_64 } ; ("noresize") => { $ crate :: ATOM_LOCALNAME__6E_6F_72_65_73_69_7A_65 } ; ("clippathunits") => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_70_61_74_68_75_6E_69_74_73 } ; ("polyline") => { $ crate :: ATOM_LOCALNAME__70_6F_6C_79_6C_69_6E_65 } ; ("metadata") => { $ crate :: ATOM_LOCALNAME__6D_65_74_61_64_61_74_61 } ; ("quotient") => { $ crate :: ATOM_LOCALNAME__71_75_6F_74_69_65_6E_74 } ; ("notanumber") => { $ crate :: ATOM_LOCALNAME__6E_6F_74_61_6E_75_6D_62_65_72 } ; ("femorphology") => { $ crate :: ATOM_LOCALNAME__66_65_6D_6F_72_70_68_6F_6C_6F_67_79 } ; ("aria-orientation") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6F_72_69_65_6E_74_61_74_69_6F_6E } ; ("aria-multiselectable") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6D_75_6C_74_69_73_65_6C_65_63_74_61_62_6C_65 } ; ("infinity") => { $ crate :: ATOM_LOCALNAME__69_6E_66_69_6E_69_74_79 } ; ("startOffset") => { $ crate :: ATOM_LOCALNAME__73_74_61_72_74_4F_66_66_73_65_74 } ; ("linearGradient") => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65_61_72_47_72_61_64_69_65_6E_74 } ; ("menuitem") => { $ crate :: ATOM_LOCALNAME__6D_65_6E_75_69_74_65_6D } ; ("onbeforeupdate") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_75_70_64_61_74_65 } ; ("fePointLight") => { $ crate :: ATOM_LOCALNAME__66_65_50_6F_69_6E_74_4C_69_67_68_74 } ; ("font-face") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65 } ; ("colorspace") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_6F_72_73_70_61_63_65 } ; ("aria-rowindex") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_69_6E_64_65_78 } ; ("aria-busy") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_62_75_73_79 } ; ("complexes") => { $ crate :: ATOM_LOCALNAME__63_6F_6D_70_6C_65_78_65_73 } ; ("datetime") => { $ crate :: ATOM_LOCALNAME__64_61_74_65_74_69_6D_65 } ; ("fill-opacity") => { $ crate :: ATOM_LOCALNAME__66_69_6C_6C_2D_6F_70_61_63_69_74_79 } ; ("horiz-origin-x") => { $ crate :: ATOM_LOCALNAME__68_6F_72_69_7A_2D_6F_72_69_67_69_6E_2D_78 } ; ("font-face-name") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_6E_61_6D_65 } ; ("columnalign") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_61_6C_69_67_6E } ; ("strikethrough-position") => { $ crate :: ATOM_LOCALNAME__73_74_72_69_6B_65_74_68_72_6F_75_67_68_2D_70_6F_73_69_74_69_6F_6E } ; ("onformchange") => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_72_6D_63_68_61_6E_67_65 } ; ("closedby") => { $ crate :: ATOM_LOCALNAME__63_6C_6F_73_65_64_62_79 } ; ("spreadMethod") => { $ crate :: ATOM_LOCALNAME__73_70_72_65_61_64_4D_65_74_68_6F_64 } ; ("transpose") => { $ crate :: ATOM_LOCALNAME__74_72_61_6E_73_70_6F_73_65 } ; ("aria-dropeffect") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_64_72_6F_70_65_66_66_65_63_74 } ; ("imaginary") => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_69_6E_61_72_79 } ; ("scrolling") => { $ crate :: ATOM_LOCALNAME__73_63_72_6F_6C_6C_69_6E_67 } ; ("onscroll") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_63_72_6F_6C_6C } ; ("onpagehide") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_67_65_68_69_64_65 } ; ("onmovestart") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_76_65_73_74_61_72_74 } ; ("fedropshadow") => { $ crate :: ATOM_LOCALNAME__66_65_64_72_6F_70_73_68_61_64_6F_77 } ; ("munderover") => { $ crate :: ATOM_LOCALNAME__6D_75_6E_64_65_72_6F_76_65_72 } ; ("selection") => { $ crate :: ATOM_LOCALNAME__73_65_6C_65_63_74_69_6F_6E } ; ("glyph-orientation-horizontal") => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68_2D_6F_72_69_65_6E_74_61_74_69_6F_6E_2D_68_6F_72_69_7A_6F_6E_74_61_6C } ; ("itemprop") => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_70_72_6F_70 } ; ("font-style") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_73_74_79_6C_65 } ; ("ontoggle") => { $ crate :: ATOM_LOCALNAME__6F_6E_74_6F_67_67_6C_65 } ; ("selected") => { $ crate :: ATOM_LOCALNAME__73_65_6C_65_63_74_65_64 } ; ("http-equiv") => { $ crate :: ATOM_LOCALNAME__68_74_74_70_2D_65_71_75_69_76 } ; ("maskunits") => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B_75_6E_69_74_73 } ; ("dataformatas") => { $ crate :: ATOM_LOCALNAME__64_61_74_61_66_6F_72_6D_61_74_61_73 } ; ("onsubmit") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_75_62_6D_69_74 } ; ("fetchpriority") => { $ crate :: ATOM_LOCALNAME__66_65_74_63_68_70_72_69_6F_72_69_74_79 } ; ("verythinmathspace") => { $ crate :: ATOM_LOCALNAME__76_65_72_79_74_68_69_6E_6D_61_74_68_73_70_61_63_65 } ; ("mathsize") => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_73_69_7A_65 } ; ("fedisplacementmap") => { $ crate :: ATOM_LOCALNAME__66_65_64_69_73_70_6C_61_63_65_6D_65_6E_74_6D_61_70 } ; ("calcMode") => { $ crate :: ATOM_LOCALNAME__63_61_6C_63_4D_6F_64_65 } ; ("separators") => { $ crate :: ATOM_LOCALNAME__73_65_70_61_72_61_74_6F_72_73 } ; ("flood-opacity") => { $ crate :: ATOM_LOCALNAME__66_6C_6F_6F_64_2D_6F_70_61_63_69_74_79 } ; ("overline-thickness") => { $ crate :: ATOM_LOCALNAME__6F_76_65_72_6C_69_6E_65_2D_74_68_69_63_6B_6E_65_73_73 } ; ("onreadystatechange") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_65_61_64_79_73_74_61_74_65_63_68_61_6E_67_65 } ; ("columnspacing") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_73_70_61_63_69_6E_67 } ; ("specification") => { $ crate :: ATOM_LOCALNAME__73_70_65_63_69_66_69_63_61_74_69_6F_6E } ; ("aria-rowindextext") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_69_6E_64_65_78_74_65_78_74 } ; ("specularconstant") => { $ crate :: ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_63_6F_6E_73_74_61_6E_74 } ; ("cellspacing") => { $ crate :: ATOM_LOCALNAME__63_65_6C_6C_73_70_61_63_69_6E_67 } ; ("baseprofile") => { $ crate :: ATOM_LOCALNAME__62_61_73_65_70_72_6F_66_69_6C_65 } ; ("requiredextensions") => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64_65_78_74_65_6E_73_69_6F_6E_73 } ; ("attributeType") => { $ crate :: ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_54_79_70_65 } ; ("veryverythinmathspace") => { $ crate :: ATOM_LOCALNAME__76_65_72_79_76_65_72_79_74_68_69_6E_6D_61_74_68_73_70_61_63_65 } ; ("onbeforetoggle") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_74_6F_67_67_6C_65 } ; ("unicode-bidi") => { $ crate :: ATOM_LOCALNAME__75_6E_69_63_6F_64_65_2D_62_69_64_69 } ; ("hatchunits") => { $ crate :: ATOM_LOCALNAME__68_61_74_63_68_75_6E_69_74_73 } ; ("repeat-min") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_2D_6D_69_6E } ; ("nomodule") => { $ crate :: ATOM_LOCALNAME__6E_6F_6D_6F_64_75_6C_65 } ; ("thickmathspace") => { $ crate :: ATOM_LOCALNAME__74_68_69_63_6B_6D_61_74_68_73_70_61_63_65 } ; ("contentStyleType") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_53_74_79_6C_65_54_79_70_65 } ; ("figcaption") => { $ crate :: ATOM_LOCALNAME__66_69_67_63_61_70_74_69_6F_6E } ; ("onforminput") => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_72_6D_69_6E_70_75_74 } ; ("feturbulence") => { $ crate :: ATOM_LOCALNAME__66_65_74_75_72_62_75_6C_65_6E_63_65 } ; ("readonly") => { $ crate :: ATOM_LOCALNAME__72_65_61_64_6F_6E_6C_79 } ; ("onvolumechange") => { $ crate :: ATOM_LOCALNAME__6F_6E_76_6F_6C_75_6D_65_63_68_61_6E_67_65 } ; ("aria-grabbed") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_67_72_61_62_62_65_64 } ; ("font-variant") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_76_61_72_69_61_6E_74 } ; ("stdDeviation") => { $ crate :: ATOM_LOCALNAME__73_74_64_44_65_76_69_61_74_69_6F_6E } ; ("onbeforecopy") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_63_6F_70_79 } ; ("ondatasetcomplete") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_61_74_61_73_65_74_63_6F_6D_70_6C_65_74_65 } ; ("timelinebegin") => { $ crate :: ATOM_LOCALNAME__74_69_6D_65_6C_69_6E_65_62_65_67_69_6E } ; ("domainofapplication") => { $ crate :: ATOM_LOCALNAME__64_6F_6D_61_69_6E_6F_66_61_70_70_6C_69_63_61_74_69_6F_6E } ; ("onunhandledrejection") => { $ crate :: ATOM_LOCALNAME__6F_6E_75_6E_68_61_6E_64_6C_65_64_72_65_6A_65_63_74_69_6F_6E } ; ("occurrence") => { $ crate :: ATOM_LOCALNAME__6F_63_63_75_72_72_65_6E_63_65 } ; ("annotation-xml") => { $ crate :: ATOM_LOCALNAME__61_6E_6E_6F_74_61_74_69_6F_6E_2D_78_6D_6C } ; ("aria-label") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6C_61_62_65_6C } ; ("conjugate") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_6A_75_67_61_74_65 } ; ("patternUnits") => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_55_6E_69_74_73 } ; ("aria-level") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6C_65_76_65_6C } ; ("aria-errormessage") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_65_72_72_6F_72_6D_65_73_73_61_67_65 } ; ("onmoveend") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_76_65_65_6E_64 } ; ("feDiffuseLighting") => { $ crate :: ATOM_LOCALNAME__66_65_44_69_66_66_75_73_65_4C_69_67_68_74_69_6E_67 } ; ("aria-autocomplete") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_61_75_74_6F_63_6F_6D_70_6C_65_74_65 } ; ("calcmode") => { $ crate :: ATOM_LOCALNAME__63_61_6C_63_6D_6F_64_65 } ; ("onselectstart") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_65_6C_65_63_74_73_74_61_72_74 } ; ("aria-rowspan") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_73_70_61_6E } ; ("codetype") => { $ crate :: ATOM_LOCALNAME__63_6F_64_65_74_79_70_65 } ; ("hatchcontentunits") => { $ crate :: ATOM_LOCALNAME__68_61_74_63_68_63_6F_6E_74_65_6E_74_75_6E_69_74_73 } ; ("intersect") => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_72_73_65_63_74 } ; ("fontweight") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_77_65_69_67_68_74 } ; ("onmouseup") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_75_70 } ; ("vert-adv-y") => { $ crate :: ATOM_LOCALNAME__76_65_72_74_2D_61_64_76_2D_79 } ; ("noframes") => { $ crate :: ATOM_LOCALNAME__6E_6F_66_72_61_6D_65_73 } ; ("colgroup") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_67_72_6F_75_70 } ; ("font-face-src") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_73_72_63 } ; ("onmessage") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_65_73_73_61_67_65 } ; ("kernelmatrix") => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_65_6C_6D_61_74_72_69_78 } ; ("filterRes") => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72_52_65_73 } ; ("xlink:title") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B_3A_74_69_74_6C_65 } ; ("rendering-intent") => { $ crate :: ATOM_LOCALNAME__72_65_6E_64_65_72_69_6E_67_2D_69_6E_74_65_6E_74 } ; ("surfaceScale") => { $ crate :: ATOM_LOCALNAME__73_75_72_66_61_63_65_53_63_61_6C_65 } ; ("feOffset") => { $ crate :: ATOM_LOCALNAME__66_65_4F_66_66_73_65_74 } ; ("contextmenu") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_78_74_6D_65_6E_75 } ; ("intercept") => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_72_63_65_70_74 } ; ("units-per-em") => { $ crate :: ATOM_LOCALNAME__75_6E_69_74_73_2D_70_65_72_2D_65_6D } ; ("aria-relevant") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_65_6C_65_76_61_6E_74 } ; ("contentscripttype") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_73_63_72_69_70_74_74_79_70_65 } ; ("aria-keyshortcuts") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6B_65_79_73_68_6F_72_74_63_75_74_73 } ; ("onloadstart") => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_6F_61_64_73_74_61_72_74 } ; ("maligngroup") => { $ crate :: ATOM_LOCALNAME__6D_61_6C_69_67_6E_67_72_6F_75_70 } ; ("minlength") => { $ crate :: ATOM_LOCALNAME__6D_69_6E_6C_65_6E_67_74_68 } ; ("ontimeupdate") => { $ crate :: ATOM_LOCALNAME__6F_6E_74_69_6D_65_75_70_64_61_74_65 } ; ("altGlyphDef") => { $ crate :: ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68_44_65_66 } ; ("commandfor") => { $ crate :: ATOM_LOCALNAME__63_6F_6D_6D_61_6E_64_66_6F_72 } ; ("ondurationchange") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_75_72_61_74_69_6F_6E_63_68_61_6E_67_65 } ; ("popovertargetaction") => { $ crate :: ATOM_LOCALNAME__70_6F_70_6F_76_65_72_74_61_72_67_65_74_61_63_74_69_6F_6E } ; ("fecomposite") => { $ crate :: ATOM_LOCALNAME__66_65_63_6F_6D_70_6F_73_69_74_65 } ; ("feDisplacementMap") => { $ crate :: ATOM_LOCALNAME__66_65_44_69_73_70_6C_61_63_65_6D_65_6E_74_4D_61_70 } ; ("scriptminsize") => { $ crate :: ATOM_LOCALNAME__73_63_72_69_70_74_6D_69_6E_73_69_7A_65 } ; ("stitchTiles") => { $ crate :: ATOM_LOCALNAME__73_74_69_74_63_68_54_69_6C_65_73 } ; ("exportparts") => { $ crate :: ATOM_LOCALNAME__65_78_70_6F_72_74_70_61_72_74_73 } ; ("stroke-opacity") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6F_70_61_63_69_74_79 } ; ("oncontrolselect") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6F_6E_74_72_6F_6C_73_65_6C_65_63_74 } ; ("tabindex") => { $ crate :: ATOM_LOCALNAME__74_61_62_69_6E_64_65_78 } ; ("pathLength") => { $ crate :: ATOM_LOCALNAME__70_61_74_68_4C_65_6E_67_74_68 } ; ("ychannelselector") => { $ crate :: ATOM_LOCALNAME__79_63_68_61_6E_6E_65_6C_73_65_6C_65_63_74_6F_72 } ; ("stroke-linejoin") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6C_69_6E_65_6A_6F_69_6E } ; ("text-decoration") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_2D_64_65_63_6F_72_61_74_69_6F_6E } ; ("foreignobject") => { $ crate :: ATOM_LOCALNAME__66_6F_72_65_69_67_6E_6F_62_6A_65_63_74 } ; ("onlosecapture") => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_6F_73_65_63_61_70_74_75_72_65 } ; ("onwaiting") => { $ crate :: ATOM_LOCALNAME__6F_6E_77_61_69_74_69_6E_67 } ; ("feSpecularLighting") => { $ crate :: ATOM_LOCALNAME__66_65_53_70_65_63_75_6C_61_72_4C_69_67_68_74_69_6E_67 } ; ("color-profile") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_6F_72_2D_70_72_6F_66_69_6C_65 } ; ("ondblclick") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_62_6C_63_6C_69_63_6B } ; ("font-stretch") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_73_74_72_65_74_63_68 } ; ("allowfullscreen") => { $ crate :: ATOM_LOCALNAME__61_6C_6C_6F_77_66_75_6C_6C_73_63_72_65_65_6E } ; ("autoplay") => { $ crate :: ATOM_LOCALNAME__61_75_74_6F_70_6C_61_79 } ; ("aria-placeholder") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_70_6C_61_63_65_68_6F_6C_64_65_72 } ; ("patternunits") => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_75_6E_69_74_73 } ; ("onactivate") => { $ crate :: ATOM_LOCALNAME__6F_6E_61_63_74_69_76_61_74_65 } ; ("maskContentUnits") => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B_43_6F_6E_74_65_6E_74_55_6E_69_74_73 } ; ("specularexponent") => { $ crate :: ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_65_78_70_6F_6E_65_6E_74 } ; ("solid-opacity") => { $ crate :: ATOM_LOCALNAME__73_6F_6C_69_64_2D_6F_70_61_63_69_74_79 } ; ("aria-readonly") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_65_61_64_6F_6E_6C_79 } ; ("orientation") => { $ crate :: ATOM_LOCALNAME__6F_72_69_65_6E_74_61_74_69_6F_6E } ; ("kernelunitlength") => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_65_6C_75_6E_69_74_6C_65_6E_67_74_68 } ; ("notsubset") => { $ crate :: ATOM_LOCALNAME__6E_6F_74_73_75_62_73_65_74 } ; ("onkeypress") => { $ crate :: ATOM_LOCALNAME__6F_6E_6B_65_79_70_72_65_73_73 } ; ("superscriptshift") => { $ crate :: ATOM_LOCALNAME__73_75_70_65_72_73_63_72_69_70_74_73_68_69_66_74 } ; ("onplaying") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_6C_61_79_69_6E_67 } ; ("crossorigin") => { $ crate :: ATOM_LOCALNAME__63_72_6F_73_73_6F_72_69_67_69_6E } ; ("accesskey") => { $ crate :: ATOM_LOCALNAME__61_63_63_65_73_73_6B_65_79 } ; ("onmouseout") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6F_75_74 } ; ("definitionurl") => { $ crate :: ATOM_LOCALNAME__64_65_66_69_6E_69_74_69_6F_6E_75_72_6C } ; ("onbeforeeditfocus") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_65_64_69_74_66_6F_63_75_73 } ; ("filterres") => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72_72_65_73 } ; ("transform") => { $ crate :: ATOM_LOCALNAME__74_72_61_6E_73_66_6F_72_6D } ; ("fepointlight") => { $ crate :: ATOM_LOCALNAME__66_65_70_6F_69_6E_74_6C_69_67_68_74 } ; ("pointsatx") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_78 } ; ("property") => { $ crate :: ATOM_LOCALNAME__70_72_6F_70_65_72_74_79 } ; ("template") => { $ crate :: ATOM_LOCALNAME__74_65_6D_70_6C_61_74_65 } ; ("bevelled") => { $ crate :: ATOM_LOCALNAME__62_65_76_65_6C_6C_65_64 } ; ("onfocusout") => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_63_75_73_6F_75_74 } ; ("systemLanguage") => { $ crate :: ATOM_LOCALNAME__73_79_73_74_65_6D_4C_61_6E_67_75_61_67_65 } ; ("marker-mid") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_2D_6D_69_64 } ; ("referrerpolicy") => { $ crate :: ATOM_LOCALNAME__72_65_66_65_72_72_65_72_70_6F_6C_69_63_79 } ; ("onrowsdelete") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_6F_77_73_64_65_6C_65_74_65 } ; ("radialGradient") => { $ crate :: ATOM_LOCALNAME__72_61_64_69_61_6C_47_72_61_64_69_65_6E_74 } ; ("language") => { $ crate :: ATOM_LOCALNAME__6C_61_6E_67_75_61_67_65 } ; ("maxlength") => { $ crate :: ATOM_LOCALNAME__6D_61_78_6C_65_6E_67_74_68 } ; ("codomain") => { $ crate :: ATOM_LOCALNAME__63_6F_64_6F_6D_61_69_6E } ; ("aria-valuemax") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_6D_61_78 } ; ("keypoints") => { $ crate :: ATOM_LOCALNAME__6B_65_79_70_6F_69_6E_74_73 } ; ("aria-current") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_75_72_72_65_6E_74 } ; ("xlink:type") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B_3A_74_79_70_65 } ; ("font-size") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_73_69_7A_65 } ; ("onpagereveal") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_67_65_72_65_76_65_61_6C } ; ("onhashchange") => { $ crate :: ATOM_LOCALNAME__6F_6E_68_61_73_68_63_68_61_6E_67_65 } ; ("interval") => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_72_76_61_6C } ; ("externalresourcesrequired") => { $ crate :: ATOM_LOCALNAME__65_78_74_65_72_6E_61_6C_72_65_73_6F_75_72_63_65_73_72_65_71_75_69_72_65_64 } ; ("mlabeledtr") => { $ crate :: ATOM_LOCALNAME__6D_6C_61_62_65_6C_65_64_74_72 } ; ("accept-charset") => { $ crate :: ATOM_LOCALNAME__61_63_63_65_70_74_2D_63_68_61_72_73_65_74 } ; ("onkeydown") => { $ crate :: ATOM_LOCALNAME__6F_6E_6B_65_79_64_6F_77_6E } ; ("markerHeight") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_48_65_69_67_68_74 } ; ("unicode-range") => { $ crate :: ATOM_LOCALNAME__75_6E_69_63_6F_64_65_2D_72_61_6E_67_65 } ; ("onprogress") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_72_6F_67_72_65_73_73 } ; ("definition-src") => { $ crate :: ATOM_LOCALNAME__64_65_66_69_6E_69_74_69_6F_6E_2D_73_72_63 } ; ("valuetype") => { $ crate :: ATOM_LOCALNAME__76_61_6C_75_65_74_79_70_65 } ; ("textContent") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_43_6F_6E_74_65_6E_74 } ; ("placeholder") => { $ crate :: ATOM_LOCALNAME__70_6C_61_63_65_68_6F_6C_64_65_72 } ; ("lowlimit") => { $ crate :: ATOM_LOCALNAME__6C_6F_77_6C_69_6D_69_74 } ; ("emptyset") => { $ crate :: ATOM_LOCALNAME__65_6D_70_74_79_73_65_74 } ; ("subscriptshift") => { $ crate :: ATOM_LOCALNAME__73_75_62_73_63_72_69_70_74_73_68_69_66_74 } ; ("font-family") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_6D_69_6C_79 } ; ("xlink:role") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B_3A_72_6F_6C_65 } ; ("condition") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_64_69_74_69_6F_6E } ; ("visibility") => { $ crate :: ATOM_LOCALNAME__76_69_73_69_62_69_6C_69_74_79 } ; ("aria-describedby") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_64_65_73_63_72_69_62_65_64_62_79 } ; ("aria-selected") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_73_65_6C_65_63_74_65_64 } ; ("externalResourcesRequired") => { $ crate :: ATOM_LOCALNAME__65_78_74_65_72_6E_61_6C_52_65_73_6F_75_72_63_65_73_52_65_71_75_69_72_65_64 } ; ("aria-colspan") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_73_70_61_6E } ; ("seamless") => { $ crate :: ATOM_LOCALNAME__73_65_61_6D_6C_65_73_73 } ; ("multiple") => { $ crate :: ATOM_LOCALNAME__6D_75_6C_74_69_70_6C_65 } ; ("onrepeat") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_65_70_65_61_74 } ; ("aria-templateid") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_74_65_6D_70_6C_61_74_65_69_64 } ; ("pointsaty") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_79 } ; ("integers") => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_67_65_72_73 } ; ("fontfamily") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_66_61_6D_69_6C_79 } ; ("disabled") => { $ crate :: ATOM_LOCALNAME__64_69_73_61_62_6C_65_64 } ; ("xlink:href") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B_3A_68_72_65_66 } ; ("onchange") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_68_61_6E_67_65 } ; ("feMorphology") => { $ crate :: ATOM_LOCALNAME__66_65_4D_6F_72_70_68_6F_6C_6F_67_79 } ; ("autocomplete") => { $ crate :: ATOM_LOCALNAME__61_75_74_6F_63_6F_6D_70_6C_65_74_65 } ; ("limitingconeangle") => { $ crate :: ATOM_LOCALNAME__6C_69_6D_69_74_69_6E_67_63_6F_6E_65_61_6E_67_6C_65 } ; ("stroke-linecap") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6C_69_6E_65_63_61_70 } ; ("font-weight") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_77_65_69_67_68_74 } ; ("writing-mode") => { $ crate :: ATOM_LOCALNAME__77_72_69_74_69_6E_67_2D_6D_6F_64_65 } ; ("aria-atomic") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_61_74_6F_6D_69_63 } ; ("femergenode") => { $ crate :: ATOM_LOCALNAME__66_65_6D_65_72_67_65_6E_6F_64_65 } ; ("malignmark") => { $ crate :: ATOM_LOCALNAME__6D_61_6C_69_67_6E_6D_61_72_6B } ; ("onstalled") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_74_61_6C_6C_65_64 } ; ("oninvalid") => { $ crate :: ATOM_LOCALNAME__6F_6E_69_6E_76_61_6C_69_64 } ; ("scalarproduct") => { $ crate :: ATOM_LOCALNAME__73_63_61_6C_61_72_70_72_6F_64_75_63_74 } ; ("paint-order") => { $ crate :: ATOM_LOCALNAME__70_61_69_6E_74_2D_6F_72_64_65_72 } ; ("cartesianproduct") => { $ crate :: ATOM_LOCALNAME__63_61_72_74_65_73_69_61_6E_70_72_6F_64_75_63_74 } ; ("textLength") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_4C_65_6E_67_74_68 } ; ("xml:space") => { $ crate :: ATOM_LOCALNAME__78_6D_6C_3A_73_70_61_63_65 } ; ("linebreak") => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65_62_72_65_61_6B } ; ("pointsatz") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_7A } ; ("stroke-dashoffset") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_64_61_73_68_6F_66_66_73_65_74 } ; ("ondragend") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_65_6E_64 } ; ("text-rendering") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_2D_72_65_6E_64_65_72_69_6E_67 } ; ("longdesc") => { $ crate :: ATOM_LOCALNAME__6C_6F_6E_67_64_65_73_63 } ; ("matrixrow") => { $ crate :: ATOM_LOCALNAME__6D_61_74_72_69_78_72_6F_77 } ; ("altGlyphItem") => { $ crate :: ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68_49_74_65_6D } ; ("textPath") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_50_61_74_68 } ; ("panose-1") => { $ crate :: ATOM_LOCALNAME__70_61_6E_6F_73_65_2D_31 } ; ("piecewise") => { $ crate :: ATOM_LOCALNAME__70_69_65_63_65_77_69_73_65 } ; ("onloadeddata") => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_6F_61_64_65_64_64_61_74_61 } ; ("primitiveUnits") => { $ crate :: ATOM_LOCALNAME__70_72_69_6D_69_74_69_76_65_55_6E_69_74_73 } ; ("word-spacing") => { $ crate :: ATOM_LOCALNAME__77_6F_72_64_2D_73_70_61_63_69_6E_67 } ; ("aria-colindex") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_69_6E_64_65_78 } ; ("listener") => { $ crate :: ATOM_LOCALNAME__6C_69_73_74_65_6E_65_72 } ; ("aria-hidden") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_68_69_64_64_65_6E } ; ("framespacing") => { $ crate :: ATOM_LOCALNAME__66_72_61_6D_65_73_70_61_63_69_6E_67 } ; ("inputmode") => { $ crate :: ATOM_LOCALNAME__69_6E_70_75_74_6D_6F_64_65 } ; ("gradienttransform") => { $ crate :: ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_74_72_61_6E_73_66_6F_72_6D } ; ("systemlanguage") => { $ crate :: ATOM_LOCALNAME__73_79_73_74_65_6D_6C_61_6E_67_75_61_67_65 } ; ("feTurbulence") => { $ crate :: ATOM_LOCALNAME__66_65_54_75_72_62_75_6C_65_6E_63_65 } ; ("repeatdur") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_64_75_72 } ; ("hreflang") => { $ crate :: ATOM_LOCALNAME__68_72_65_66_6C_61_6E_67 } ; ("fontsize") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_73_69_7A_65 } ; ("overflow") => { $ crate :: ATOM_LOCALNAME__6F_76_65_72_66_6C_6F_77 } ; ("playbackorder") => { $ crate :: ATOM_LOCALNAME__70_6C_61_79_62_61_63_6B_6F_72_64_65_72 } ; ("repeatcount") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_63_6F_75_6E_74 } ; ("color-interpolation") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_6F_72_2D_69_6E_74_65_72_70_6F_6C_61_74_69_6F_6E } ; ("laplacian") => { $ crate :: ATOM_LOCALNAME__6C_61_70_6C_61_63_69_61_6E } ; ("patterncontentunits") => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_63_6F_6E_74_65_6E_74_75_6E_69_74_73 } ; ("amplitude") => { $ crate :: ATOM_LOCALNAME__61_6D_70_6C_69_74_75_64_65 } ; ("ononline") => { $ crate :: ATOM_LOCALNAME__6F_6E_6F_6E_6C_69_6E_65 } ; ("clippath") => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_70_61_74_68 } ; ("accent-height") => { $ crate :: ATOM_LOCALNAME__61_63_63_65_6E_74_2D_68_65_69_67_68_74 } ; ("mask-type") => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B_2D_74_79_70_65 } ; ("color-rendering") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_6F_72_2D_72_65_6E_64_65_72_69_6E_67 } ; ("multicol") => { $ crate :: ATOM_LOCALNAME__6D_75_6C_74_69_63_6F_6C } ; ("aria-required") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_65_71_75_69_72_65_64 } ; ("oncancel") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_61_6E_63_65_6C } ; ("numOctaves") => { $ crate :: ATOM_LOCALNAME__6E_75_6D_4F_63_74_61_76_65_73 } ; ("scrolldelay") => { $ crate :: ATOM_LOCALNAME__73_63_72_6F_6C_6C_64_65_6C_61_79 } ; ("onemptied") => { $ crate :: ATOM_LOCALNAME__6F_6E_65_6D_70_74_69_65_64 } ; ("patternContentUnits") => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_43_6F_6E_74_65_6E_74_55_6E_69_74_73 } ; ("imagesrcset") => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_65_73_72_63_73_65_74 } ; ("altglyphitem") => { $ crate :: ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68_69_74_65_6D } ; ("onselect") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_65_6C_65_63_74 } ; ("animateColor") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_43_6F_6C_6F_72 } ; ("dominant-baseline") => { $ crate :: ATOM_LOCALNAME__64_6F_6D_69_6E_61_6E_74_2D_62_61_73_65_6C_69_6E_65 } ; ("selectedcontent") => { $ crate :: ATOM_LOCALNAME__73_65_6C_65_63_74_65_64_63_6F_6E_74_65_6E_74 } ; ("viewtarget") => { $ crate :: ATOM_LOCALNAME__76_69_65_77_74_61_72_67_65_74 } ; ("xlink:show") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B_3A_73_68_6F_77 } ; ("textcontent") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_63_6F_6E_74_65_6E_74 } ; ("onseeked") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_65_65_6B_65_64 } ; ("mediummathspace") => { $ crate :: ATOM_LOCALNAME__6D_65_64_69_75_6D_6D_61_74_68_73_70_61_63_65 } ; ("accumulate") => { $ crate :: ATOM_LOCALNAME__61_63_63_75_6D_75_6C_61_74_65 } ; ("onmouseleave") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6C_65_61_76_65 } ; ("aria-controls") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6E_74_72_6F_6C_73 } ; ("contentstyletype") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_73_74_79_6C_65_74_79_70_65 } ; ("rowlines") => { $ crate :: ATOM_LOCALNAME__72_6F_77_6C_69_6E_65_73 } ; ("stop-opacity") => { $ crate :: ATOM_LOCALNAME__73_74_6F_70_2D_6F_70_61_63_69_74_79 } ; ("v-ideographic") => { $ crate :: ATOM_LOCALNAME__76_2D_69_64_65_6F_67_72_61_70_68_69_63 } ; ("menclose") => { $ crate :: ATOM_LOCALNAME__6D_65_6E_63_6C_6F_73_65 } ; ("imagesizes") => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_65_73_69_7A_65_73 } ; ("repeat-template") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_2D_74_65_6D_70_6C_61_74_65 } ; ("feconvolvematrix") => { $ crate :: ATOM_LOCALNAME__66_65_63_6F_6E_76_6F_6C_76_65_6D_61_74_72_69_78 } ; ("selector") => { $ crate :: ATOM_LOCALNAME__73_65_6C_65_63_74_6F_72 } ; ("image-rendering") => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_65_2D_72_65_6E_64_65_72_69_6E_67 } ; ("fieldset") => { $ crate :: ATOM_LOCALNAME__66_69_65_6C_64_73_65_74 } ; ("feComposite") => { $ crate :: ATOM_LOCALNAME__66_65_43_6F_6D_70_6F_73_69_74_65 } ; ("keySplines") => { $ crate :: ATOM_LOCALNAME__6B_65_79_53_70_6C_69_6E_65_73 } ; ("aria-roledescription") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_6F_6C_65_64_65_73_63_72_69_70_74_69_6F_6E } ; ("verythickmathspace") => { $ crate :: ATOM_LOCALNAME__76_65_72_79_74_68_69_63_6B_6D_61_74_68_73_70_61_63_65 } ; ("blocking") => { $ crate :: ATOM_LOCALNAME__62_6C_6F_63_6B_69_6E_67 } ; ("shadowrootslotassignment") => { $ crate :: ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_73_6C_6F_74_61_73_73_69_67_6E_6D_65_6E_74 } ; ("movablelimits") => { $ crate :: ATOM_LOCALNAME__6D_6F_76_61_62_6C_65_6C_69_6D_69_74_73 } ; ("scriptlevel") => { $ crate :: ATOM_LOCALNAME__73_63_72_69_70_74_6C_65_76_65_6C } ; ("onpageshow") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_67_65_73_68_6F_77 } ; ("onpopstate") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_6F_70_73_74_61_74_65 } ; ("onfocusin") => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_63_75_73_69_6E } ; ("onratechange") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_61_74_65_63_68_61_6E_67_65 } ; ("determinant") => { $ crate :: ATOM_LOCALNAME__64_65_74_65_72_6D_69_6E_61_6E_74 } ; ("optgroup") => { $ crate :: ATOM_LOCALNAME__6F_70_74_67_72_6F_75_70 } ; ("linethickness") => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65_74_68_69_63_6B_6E_65_73_73 } ; ("onrejectionhandled") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_65_6A_65_63_74_69_6F_6E_68_61_6E_64_6C_65_64 } ; ("markerunits") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_75_6E_69_74_73 } ; ("") => { $ crate :: ATOM_LOCALNAME_ } ; ("*") => { $ crate :: ATOM_LOCALNAME__2A } ; ("a") => { $ crate :: ATOM_LOCALNAME__61 } ; ("abbr") => { $ crate :: ATOM_LOCALNAME__61_62_62_72 } ; ("abs") => { $ crate :: ATOM_LOCALNAME__61_62_73 } ; ("accent") => { $ crate :: ATOM_LOCALNAME__61_63_63_65_6E_74 } ; ("accept") => { $ crate :: ATOM_LOCALNAME__61_63_63_65_70_74 } ; ("acronym") => { $ crate :: ATOM_LOCALNAME__61_63_72_6F_6E_79_6D } ; ("action") => { $ crate :: ATOM_LOCALNAME__61_63_74_69_6F_6E } ; ("active") => { $ crate :: ATOM_LOCALNAME__61_63_74_69_76_65 } ; ("actuate") => { $ crate :: ATOM_LOCALNAME__61_63_74_75_61_74_65 } ; ("address") => { $ crate :: ATOM_LOCALNAME__61_64_64_72_65_73_73 } ; ("align") => { $ crate :: ATOM_LOCALNAME__61_6C_69_67_6E } ; ("alink") => { $ crate :: ATOM_LOCALNAME__61_6C_69_6E_6B } ; ("allow") => { $ crate :: ATOM_LOCALNAME__61_6C_6C_6F_77 } ; ("alpha") => { $ crate :: ATOM_LOCALNAME__61_6C_70_68_61 } ; ("alt") => { $ crate :: ATOM_LOCALNAME__61_6C_74 } ; ("altimg") => { $ crate :: ATOM_LOCALNAME__61_6C_74_69_6D_67 } ; ("alttext") => { $ crate :: ATOM_LOCALNAME__61_6C_74_74_65_78_74 } ; ("and") => { $ crate :: ATOM_LOCALNAME__61_6E_64 } ; ("animate") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65 } ; ("applet") => { $ crate :: ATOM_LOCALNAME__61_70_70_6C_65_74 } ; ("apply") => { $ crate :: ATOM_LOCALNAME__61_70_70_6C_79 } ; ("approx") => { $ crate :: ATOM_LOCALNAME__61_70_70_72_6F_78 } ; ("arccos") => { $ crate :: ATOM_LOCALNAME__61_72_63_63_6F_73 } ; ("arccosh") => { $ crate :: ATOM_LOCALNAME__61_72_63_63_6F_73_68 } ; ("arccot") => { $ crate :: ATOM_LOCALNAME__61_72_63_63_6F_74 } ; ("arccoth") => { $ crate :: ATOM_LOCALNAME__61_72_63_63_6F_74_68 } ; ("arccsc") => { $ crate :: ATOM_LOCALNAME__61_72_63_63_73_63 } ; ("arccsch") => { $ crate :: ATOM_LOCALNAME__61_72_63_63_73_63_68 } ; ("archive") => { $ crate :: ATOM_LOCALNAME__61_72_63_68_69_76_65 } ; ("arcrole") => { $ crate :: ATOM_LOCALNAME__61_72_63_72_6F_6C_65 } ; ("arcsec") => { $ crate :: ATOM_LOCALNAME__61_72_63_73_65_63 } ; ("arcsech") => { $ crate :: ATOM_LOCALNAME__61_72_63_73_65_63_68 } ; ("arcsin") => { $ crate :: ATOM_LOCALNAME__61_72_63_73_69_6E } ; ("arcsinh") => { $ crate :: ATOM_LOCALNAME__61_72_63_73_69_6E_68 } ; ("arctan") => { $ crate :: ATOM_LOCALNAME__61_72_63_74_61_6E } ; ("arctanh") => { $ crate :: ATOM_LOCALNAME__61_72_63_74_61_6E_68 } ; ("area") => { $ crate :: ATOM_LOCALNAME__61_72_65_61 } ; ("arg") => { $ crate :: ATOM_LOCALNAME__61_72_67 } ; ("article") => { $ crate :: ATOM_LOCALNAME__61_72_74_69_63_6C_65 } ; ("as") => { $ crate :: ATOM_LOCALNAME__61_73 } ; ("ascent") => { $ crate :: ATOM_LOCALNAME__61_73_63_65_6E_74 } ; ("aside") => { $ crate :: ATOM_LOCALNAME__61_73_69_64_65 } ; ("async") => { $ crate :: ATOM_LOCALNAME__61_73_79_6E_63 } ; ("audio") => { $ crate :: ATOM_LOCALNAME__61_75_64_69_6F } ; ("axis") => { $ crate :: ATOM_LOCALNAME__61_78_69_73 } ; ("azimuth") => { $ crate :: ATOM_LOCALNAME__61_7A_69_6D_75_74_68 } ; ("b") => { $ crate :: ATOM_LOCALNAME__62 } ; ("base") => { $ crate :: ATOM_LOCALNAME__62_61_73_65 } ; ("bbox") => { $ crate :: ATOM_LOCALNAME__62_62_6F_78 } ; ("bdi") => { $ crate :: ATOM_LOCALNAME__62_64_69 } ; ("bdo") => { $ crate :: ATOM_LOCALNAME__62_64_6F } ; ("begin") => { $ crate :: ATOM_LOCALNAME__62_65_67_69_6E } ; ("bgcolor") => { $ crate :: ATOM_LOCALNAME__62_67_63_6F_6C_6F_72 } ; ("bgsound") => { $ crate :: ATOM_LOCALNAME__62_67_73_6F_75_6E_64 } ; ("bias") => { $ crate :: ATOM_LOCALNAME__62_69_61_73 } ; ("big") => { $ crate :: ATOM_LOCALNAME__62_69_67 } ; ("blink") => { $ crate :: ATOM_LOCALNAME__62_6C_69_6E_6B } ; ("body") => { $ crate :: ATOM_LOCALNAME__62_6F_64_79 } ; ("border") => { $ crate :: ATOM_LOCALNAME__62_6F_72_64_65_72 } ; ("br") => { $ crate :: ATOM_LOCALNAME__62_72 } ; ("button") => { $ crate :: ATOM_LOCALNAME__62_75_74_74_6F_6E } ; ("bvar") => { $ crate :: ATOM_LOCALNAME__62_76_61_72 } ; ("by") => { $ crate :: ATOM_LOCALNAME__62_79 } ; ("canvas") => { $ crate :: ATOM_LOCALNAME__63_61_6E_76_61_73 } ; ("caption") => { $ crate :: ATOM_LOCALNAME__63_61_70_74_69_6F_6E } ; ("card") => { $ crate :: ATOM_LOCALNAME__63_61_72_64 } ; ("ceiling") => { $ crate :: ATOM_LOCALNAME__63_65_69_6C_69_6E_67 } ; ("center") => { $ crate :: ATOM_LOCALNAME__63_65_6E_74_65_72 } ; ("char") => { $ crate :: ATOM_LOCALNAME__63_68_61_72 } ; ("charoff") => { $ crate :: ATOM_LOCALNAME__63_68_61_72_6F_66_66 } ; ("charset") => { $ crate :: ATOM_LOCALNAME__63_68_61_72_73_65_74 } ; ("checked") => { $ crate :: ATOM_LOCALNAME__63_68_65_63_6B_65_64 } ; ("ci") => { $ crate :: ATOM_LOCALNAME__63_69 } ; ("circle") => { $ crate :: ATOM_LOCALNAME__63_69_72_63_6C_65 } ; ("cite") => { $ crate :: ATOM_LOCALNAME__63_69_74_65 } ; ("class") => { $ crate :: ATOM_LOCALNAME__63_6C_61_73_73 } ; ("classid") => { $ crate :: ATOM_LOCALNAME__63_6C_61_73_73_69_64 } ; ("clear") => { $ crate :: ATOM_LOCALNAME__63_6C_65_61_72 } ; ("clip") => { $ crate :: ATOM_LOCALNAME__63_6C_69_70 } ; ("close") => { $ crate :: ATOM_LOCALNAME__63_6C_6F_73_65 } ; ("closure") => { $ crate :: ATOM_LOCALNAME__63_6C_6F_73_75_72_65 } ; ("cn") => { $ crate :: ATOM_LOCALNAME__63_6E } ; ("code") => { $ crate :: ATOM_LOCALNAME__63_6F_64_65 } ; ("col") => { $ crate :: ATOM_LOCALNAME__63_6F_6C } ; ("color") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_6F_72 } ; ("cols") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_73 } ; ("colspan") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_73_70_61_6E } ; ("command") => { $ crate :: ATOM_LOCALNAME__63_6F_6D_6D_61_6E_64 } ; ("compact") => { $ crate :: ATOM_LOCALNAME__63_6F_6D_70_61_63_74 } ; ("compose") => { $ crate :: ATOM_LOCALNAME__63_6F_6D_70_6F_73_65 } ; ("content") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74 } ; ("coords") => { $ crate :: ATOM_LOCALNAME__63_6F_6F_72_64_73 } ; ("cos") => { $ crate :: ATOM_LOCALNAME__63_6F_73 } ; ("cosh") => { $ crate :: ATOM_LOCALNAME__63_6F_73_68 } ; ("cot") => { $ crate :: ATOM_LOCALNAME__63_6F_74 } ; ("coth") => { $ crate :: ATOM_LOCALNAME__63_6F_74_68 } ; ("csc") => { $ crate :: ATOM_LOCALNAME__63_73_63 } ; ("csch") => { $ crate :: ATOM_LOCALNAME__63_73_63_68 } ; ("csymbol") => { $ crate :: ATOM_LOCALNAME__63_73_79_6D_62_6F_6C } ; ("curl") => { $ crate :: ATOM_LOCALNAME__63_75_72_6C } ; ("cursor") => { $ crate :: ATOM_LOCALNAME__63_75_72_73_6F_72 } ; ("cx") => { $ crate :: ATOM_LOCALNAME__63_78 } ; ("cy") => { $ crate :: ATOM_LOCALNAME__63_79 } ; ("d") => { $ crate :: ATOM_LOCALNAME__64 } ; ("data") => { $ crate :: ATOM_LOCALNAME__64_61_74_61 } ; ("datafld") => { $ crate :: ATOM_LOCALNAME__64_61_74_61_66_6C_64 } ; ("datasrc") => { $ crate :: ATOM_LOCALNAME__64_61_74_61_73_72_63 } ; ("dd") => { $ crate :: ATOM_LOCALNAME__64_64 } ; ("declare") => { $ crate :: ATOM_LOCALNAME__64_65_63_6C_61_72_65 } ; ("default") => { $ crate :: ATOM_LOCALNAME__64_65_66_61_75_6C_74 } ; ("defer") => { $ crate :: ATOM_LOCALNAME__64_65_66_65_72 } ; ("defs") => { $ crate :: ATOM_LOCALNAME__64_65_66_73 } ; ("degree") => { $ crate :: ATOM_LOCALNAME__64_65_67_72_65_65 } ; ("del") => { $ crate :: ATOM_LOCALNAME__64_65_6C } ; ("depth") => { $ crate :: ATOM_LOCALNAME__64_65_70_74_68 } ; ("desc") => { $ crate :: ATOM_LOCALNAME__64_65_73_63 } ; ("descent") => { $ crate :: ATOM_LOCALNAME__64_65_73_63_65_6E_74 } ; ("details") => { $ crate :: ATOM_LOCALNAME__64_65_74_61_69_6C_73 } ; ("dfn") => { $ crate :: ATOM_LOCALNAME__64_66_6E } ; ("dialog") => { $ crate :: ATOM_LOCALNAME__64_69_61_6C_6F_67 } ; ("diff") => { $ crate :: ATOM_LOCALNAME__64_69_66_66 } ; ("dir") => { $ crate :: ATOM_LOCALNAME__64_69_72 } ; ("dirname") => { $ crate :: ATOM_LOCALNAME__64_69_72_6E_61_6D_65 } ; ("discard") => { $ crate :: ATOM_LOCALNAME__64_69_73_63_61_72_64 } ; ("display") => { $ crate :: ATOM_LOCALNAME__64_69_73_70_6C_61_79 } ; ("div") => { $ crate :: ATOM_LOCALNAME__64_69_76 } ; ("divide") => { $ crate :: ATOM_LOCALNAME__64_69_76_69_64_65 } ; ("divisor") => { $ crate :: ATOM_LOCALNAME__64_69_76_69_73_6F_72 } ; ("dl") => { $ crate :: ATOM_LOCALNAME__64_6C } ; ("domain") => { $ crate :: ATOM_LOCALNAME__64_6F_6D_61_69_6E } ; ("dt") => { $ crate :: ATOM_LOCALNAME__64_74 } ; ("dur") => { $ crate :: ATOM_LOCALNAME__64_75_72 } ; ("dx") => { $ crate :: ATOM_LOCALNAME__64_78 } ; ("dy") => { $ crate :: ATOM_LOCALNAME__64_79 } ; ("edge") => { $ crate :: ATOM_LOCALNAME__65_64_67_65 } ; ("ellipse") => { $ crate :: ATOM_LOCALNAME__65_6C_6C_69_70_73_65 } ; ("em") => { $ crate :: ATOM_LOCALNAME__65_6D } ; ("embed") => { $ crate :: ATOM_LOCALNAME__65_6D_62_65_64 } ; ("enctype") => { $ crate :: ATOM_LOCALNAME__65_6E_63_74_79_70_65 } ; ("end") => { $ crate :: ATOM_LOCALNAME__65_6E_64 } ; ("eq") => { $ crate :: ATOM_LOCALNAME__65_71 } ; ("event") => { $ crate :: ATOM_LOCALNAME__65_76_65_6E_74 } ; ("exists") => { $ crate :: ATOM_LOCALNAME__65_78_69_73_74_73 } ; ("exp") => { $ crate :: ATOM_LOCALNAME__65_78_70 } ; ("face") => { $ crate :: ATOM_LOCALNAME__66_61_63_65 } ; ("false") => { $ crate :: ATOM_LOCALNAME__66_61_6C_73_65 } ; ("feBlend") => { $ crate :: ATOM_LOCALNAME__66_65_42_6C_65_6E_64 } ; ("feFlood") => { $ crate :: ATOM_LOCALNAME__66_65_46_6C_6F_6F_64 } ; ("feFuncA") => { $ crate :: ATOM_LOCALNAME__66_65_46_75_6E_63_41 } ; ("feFuncB") => { $ crate :: ATOM_LOCALNAME__66_65_46_75_6E_63_42 } ; ("feFuncG") => { $ crate :: ATOM_LOCALNAME__66_65_46_75_6E_63_47 } ; ("feFuncR") => { $ crate :: ATOM_LOCALNAME__66_65_46_75_6E_63_52 } ; ("feImage") => { $ crate :: ATOM_LOCALNAME__66_65_49_6D_61_67_65 } ; ("feMerge") => { $ crate :: ATOM_LOCALNAME__66_65_4D_65_72_67_65 } ; ("feTile") => { $ crate :: ATOM_LOCALNAME__66_65_54_69_6C_65 } ; ("feblend") => { $ crate :: ATOM_LOCALNAME__66_65_62_6C_65_6E_64 } ; ("feflood") => { $ crate :: ATOM_LOCALNAME__66_65_66_6C_6F_6F_64 } ; ("fefunca") => { $ crate :: ATOM_LOCALNAME__66_65_66_75_6E_63_61 } ; ("fefuncb") => { $ crate :: ATOM_LOCALNAME__66_65_66_75_6E_63_62 } ; ("fefuncg") => { $ crate :: ATOM_LOCALNAME__66_65_66_75_6E_63_67 } ; ("fefuncr") => { $ crate :: ATOM_LOCALNAME__66_65_66_75_6E_63_72 } ; ("feimage") => { $ crate :: ATOM_LOCALNAME__66_65_69_6D_61_67_65 } ; ("femerge") => { $ crate :: ATOM_LOCALNAME__66_65_6D_65_72_67_65 } ; ("fence") => { $ crate :: ATOM_LOCALNAME__66_65_6E_63_65 } ; ("fetch") => { $ crate :: ATOM_LOCALNAME__66_65_74_63_68 } ; ("fetile") => { $ crate :: ATOM_LOCALNAME__66_65_74_69_6C_65 } ; ("figure") => { $ crate :: ATOM_LOCALNAME__66_69_67_75_72_65 } ; ("fill") => { $ crate :: ATOM_LOCALNAME__66_69_6C_6C } ; ("filter") => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72 } ; ("floor") => { $ crate :: ATOM_LOCALNAME__66_6C_6F_6F_72 } ; ("fn") => { $ crate :: ATOM_LOCALNAME__66_6E } ; ("font") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74 } ; ("footer") => { $ crate :: ATOM_LOCALNAME__66_6F_6F_74_65_72 } ; ("for") => { $ crate :: ATOM_LOCALNAME__66_6F_72 } ; ("forall") => { $ crate :: ATOM_LOCALNAME__66_6F_72_61_6C_6C } ; ("form") => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D } ; ("format") => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_61_74 } ; ("fr") => { $ crate :: ATOM_LOCALNAME__66_72 } ; ("frame") => { $ crate :: ATOM_LOCALNAME__66_72_61_6D_65 } ; ("from") => { $ crate :: ATOM_LOCALNAME__66_72_6F_6D } ; ("fx") => { $ crate :: ATOM_LOCALNAME__66_78 } ; ("fy") => { $ crate :: ATOM_LOCALNAME__66_79 } ; ("g") => { $ crate :: ATOM_LOCALNAME__67 } ; ("g1") => { $ crate :: ATOM_LOCALNAME__67_31 } ; ("g2") => { $ crate :: ATOM_LOCALNAME__67_32 } ; ("gcd") => { $ crate :: ATOM_LOCALNAME__67_63_64 } ; ("geq") => { $ crate :: ATOM_LOCALNAME__67_65_71 } ; ("glyph") => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68 } ; ("grad") => { $ crate :: ATOM_LOCALNAME__67_72_61_64 } ; ("gt") => { $ crate :: ATOM_LOCALNAME__67_74 } ; ("h1") => { $ crate :: ATOM_LOCALNAME__68_31 } ; ("h2") => { $ crate :: ATOM_LOCALNAME__68_32 } ; ("h3") => { $ crate :: ATOM_LOCALNAME__68_33 } ; ("h4") => { $ crate :: ATOM_LOCALNAME__68_34 } ; ("h5") => { $ crate :: ATOM_LOCALNAME__68_35 } ; ("h6") => { $ crate :: ATOM_LOCALNAME__68_36 } ; ("handler") => { $ crate :: ATOM_LOCALNAME__68_61_6E_64_6C_65_72 } ; ("hanging") => { $ crate :: ATOM_LOCALNAME__68_61_6E_67_69_6E_67 } ; ("hatch") => { $ crate :: ATOM_LOCALNAME__68_61_74_63_68 } ; ("head") => { $ crate :: ATOM_LOCALNAME__68_65_61_64 } ; ("header") => { $ crate :: ATOM_LOCALNAME__68_65_61_64_65_72 } ; ("headers") => { $ crate :: ATOM_LOCALNAME__68_65_61_64_65_72_73 } ; ("height") => { $ crate :: ATOM_LOCALNAME__68_65_69_67_68_74 } ; ("hgroup") => { $ crate :: ATOM_LOCALNAME__68_67_72_6F_75_70 } ; ("hidden") => { $ crate :: ATOM_LOCALNAME__68_69_64_64_65_6E } ; ("high") => { $ crate :: ATOM_LOCALNAME__68_69_67_68 } ; ("hkern") => { $ crate :: ATOM_LOCALNAME__68_6B_65_72_6E } ; ("hr") => { $ crate :: ATOM_LOCALNAME__68_72 } ; ("href") => { $ crate :: ATOM_LOCALNAME__68_72_65_66 } ; ("hspace") => { $ crate :: ATOM_LOCALNAME__68_73_70_61_63_65 } ; ("html") => { $ crate :: ATOM_LOCALNAME__68_74_6D_6C } ; ("i") => { $ crate :: ATOM_LOCALNAME__69 } ; ("icon") => { $ crate :: ATOM_LOCALNAME__69_63_6F_6E } ; ("id") => { $ crate :: ATOM_LOCALNAME__69_64 } ; ("ident") => { $ crate :: ATOM_LOCALNAME__69_64_65_6E_74 } ; ("iframe") => { $ crate :: ATOM_LOCALNAME__69_66_72_61_6D_65 } ; ("image") => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_65 } ; ("img") => { $ crate :: ATOM_LOCALNAME__69_6D_67 } ; ("implies") => { $ crate :: ATOM_LOCALNAME__69_6D_70_6C_69_65_73 } ; ("in") => { $ crate :: ATOM_LOCALNAME__69_6E } ; ("in2") => { $ crate :: ATOM_LOCALNAME__69_6E_32 } ; ("index") => { $ crate :: ATOM_LOCALNAME__69_6E_64_65_78 } ; ("inert") => { $ crate :: ATOM_LOCALNAME__69_6E_65_72_74 } ; ("input") => { $ crate :: ATOM_LOCALNAME__69_6E_70_75_74 } ; ("ins") => { $ crate :: ATOM_LOCALNAME__69_6E_73 } ; ("int") => { $ crate :: ATOM_LOCALNAME__69_6E_74 } ; ("inverse") => { $ crate :: ATOM_LOCALNAME__69_6E_76_65_72_73_65 } ; ("isindex") => { $ crate :: ATOM_LOCALNAME__69_73_69_6E_64_65_78 } ; ("ismap") => { $ crate :: ATOM_LOCALNAME__69_73_6D_61_70 } ; ("itemid") => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_69_64 } ; ("itemref") => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_72_65_66 } ; ("k") => { $ crate :: ATOM_LOCALNAME__6B } ; ("k1") => { $ crate :: ATOM_LOCALNAME__6B_31 } ; ("k2") => { $ crate :: ATOM_LOCALNAME__6B_32 } ; ("k3") => { $ crate :: ATOM_LOCALNAME__6B_33 } ; ("k4") => { $ crate :: ATOM_LOCALNAME__6B_34 } ; ("kbd") => { $ crate :: ATOM_LOCALNAME__6B_62_64 } ; ("kerning") => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_69_6E_67 } ; ("keygen") => { $ crate :: ATOM_LOCALNAME__6B_65_79_67_65_6E } ; ("kind") => { $ crate :: ATOM_LOCALNAME__6B_69_6E_64 } ; ("label") => { $ crate :: ATOM_LOCALNAME__6C_61_62_65_6C } ; ("lambda") => { $ crate :: ATOM_LOCALNAME__6C_61_6D_62_64_61 } ; ("lang") => { $ crate :: ATOM_LOCALNAME__6C_61_6E_67 } ; ("largeop") => { $ crate :: ATOM_LOCALNAME__6C_61_72_67_65_6F_70 } ; ("lcm") => { $ crate :: ATOM_LOCALNAME__6C_63_6D } ; ("legend") => { $ crate :: ATOM_LOCALNAME__6C_65_67_65_6E_64 } ; ("leq") => { $ crate :: ATOM_LOCALNAME__6C_65_71 } ; ("li") => { $ crate :: ATOM_LOCALNAME__6C_69 } ; ("limit") => { $ crate :: ATOM_LOCALNAME__6C_69_6D_69_74 } ; ("line") => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65 } ; ("link") => { $ crate :: ATOM_LOCALNAME__6C_69_6E_6B } ; ("list") => { $ crate :: ATOM_LOCALNAME__6C_69_73_74 } ; ("listing") => { $ crate :: ATOM_LOCALNAME__6C_69_73_74_69_6E_67 } ; ("ln") => { $ crate :: ATOM_LOCALNAME__6C_6E } ; ("loading") => { $ crate :: ATOM_LOCALNAME__6C_6F_61_64_69_6E_67 } ; ("local") => { $ crate :: ATOM_LOCALNAME__6C_6F_63_61_6C } ; ("log") => { $ crate :: ATOM_LOCALNAME__6C_6F_67 } ; ("logbase") => { $ crate :: ATOM_LOCALNAME__6C_6F_67_62_61_73_65 } ; ("loop") => { $ crate :: ATOM_LOCALNAME__6C_6F_6F_70 } ; ("low") => { $ crate :: ATOM_LOCALNAME__6C_6F_77 } ; ("lowsrc") => { $ crate :: ATOM_LOCALNAME__6C_6F_77_73_72_63 } ; ("lquote") => { $ crate :: ATOM_LOCALNAME__6C_71_75_6F_74_65 } ; ("lspace") => { $ crate :: ATOM_LOCALNAME__6C_73_70_61_63_65 } ; ("lt") => { $ crate :: ATOM_LOCALNAME__6C_74 } ; ("macros") => { $ crate :: ATOM_LOCALNAME__6D_61_63_72_6F_73 } ; ("maction") => { $ crate :: ATOM_LOCALNAME__6D_61_63_74_69_6F_6E } ; ("main") => { $ crate :: ATOM_LOCALNAME__6D_61_69_6E } ; ("map") => { $ crate :: ATOM_LOCALNAME__6D_61_70 } ; ("mark") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B } ; ("marker") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72 } ; ("marquee") => { $ crate :: ATOM_LOCALNAME__6D_61_72_71_75_65_65 } ; ("mask") => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B } ; ("math") => { $ crate :: ATOM_LOCALNAME__6D_61_74_68 } ; ("matrix") => { $ crate :: ATOM_LOCALNAME__6D_61_74_72_69_78 } ; ("max") => { $ crate :: ATOM_LOCALNAME__6D_61_78 } ; ("maxsize") => { $ crate :: ATOM_LOCALNAME__6D_61_78_73_69_7A_65 } ; ("mean") => { $ crate :: ATOM_LOCALNAME__6D_65_61_6E } ; ("media") => { $ crate :: ATOM_LOCALNAME__6D_65_64_69_61 } ; ("median") => { $ crate :: ATOM_LOCALNAME__6D_65_64_69_61_6E } ; ("menu") => { $ crate :: ATOM_LOCALNAME__6D_65_6E_75 } ; ("merror") => { $ crate :: ATOM_LOCALNAME__6D_65_72_72_6F_72 } ; ("meta") => { $ crate :: ATOM_LOCALNAME__6D_65_74_61 } ; ("meter") => { $ crate :: ATOM_LOCALNAME__6D_65_74_65_72 } ; ("method") => { $ crate :: ATOM_LOCALNAME__6D_65_74_68_6F_64 } ; ("mfenced") => { $ crate :: ATOM_LOCALNAME__6D_66_65_6E_63_65_64 } ; ("mfrac") => { $ crate :: ATOM_LOCALNAME__6D_66_72_61_63 } ; ("mglyph") => { $ crate :: ATOM_LOCALNAME__6D_67_6C_79_70_68 } ; ("mi") => { $ crate :: ATOM_LOCALNAME__6D_69 } ; ("min") => { $ crate :: ATOM_LOCALNAME__6D_69_6E } ; ("minsize") => { $ crate :: ATOM_LOCALNAME__6D_69_6E_73_69_7A_65 } ; ("minus") => { $ crate :: ATOM_LOCALNAME__6D_69_6E_75_73 } ; ("mn") => { $ crate :: ATOM_LOCALNAME__6D_6E } ; ("mo") => { $ crate :: ATOM_LOCALNAME__6D_6F } ; ("mode") => { $ crate :: ATOM_LOCALNAME__6D_6F_64_65 } ; ("moment") => { $ crate :: ATOM_LOCALNAME__6D_6F_6D_65_6E_74 } ; ("mover") => { $ crate :: ATOM_LOCALNAME__6D_6F_76_65_72 } ; ("mpadded") => { $ crate :: ATOM_LOCALNAME__6D_70_61_64_64_65_64 } ; ("mpath") => { $ crate :: ATOM_LOCALNAME__6D_70_61_74_68 } ; ("mroot") => { $ crate :: ATOM_LOCALNAME__6D_72_6F_6F_74 } ; ("mrow") => { $ crate :: ATOM_LOCALNAME__6D_72_6F_77 } ; ("ms") => { $ crate :: ATOM_LOCALNAME__6D_73 } ; ("mspace") => { $ crate :: ATOM_LOCALNAME__6D_73_70_61_63_65 } ; ("msqrt") => { $ crate :: ATOM_LOCALNAME__6D_73_71_72_74 } ; ("mstyle") => { $ crate :: ATOM_LOCALNAME__6D_73_74_79_6C_65 } ; ("msub") => { $ crate :: ATOM_LOCALNAME__6D_73_75_62 } ; ("msubsup") => { $ crate :: ATOM_LOCALNAME__6D_73_75_62_73_75_70 } ; ("msup") => { $ crate :: ATOM_LOCALNAME__6D_73_75_70 } ; ("mtable") => { $ crate :: ATOM_LOCALNAME__6D_74_61_62_6C_65 } ; ("mtd") => { $ crate :: ATOM_LOCALNAME__6D_74_64 } ; ("mtext") => { $ crate :: ATOM_LOCALNAME__6D_74_65_78_74 } ; ("mtr") => { $ crate :: ATOM_LOCALNAME__6D_74_72 } ; ("munder") => { $ crate :: ATOM_LOCALNAME__6D_75_6E_64_65_72 } ; ("muted") => { $ crate :: ATOM_LOCALNAME__6D_75_74_65_64 } ; ("name") => { $ crate :: ATOM_LOCALNAME__6E_61_6D_65 } ; ("nargs") => { $ crate :: ATOM_LOCALNAME__6E_61_72_67_73 } ; ("nav") => { $ crate :: ATOM_LOCALNAME__6E_61_76 } ; ("neq") => { $ crate :: ATOM_LOCALNAME__6E_65_71 } ; ("nest") => { $ crate :: ATOM_LOCALNAME__6E_65_73_74 } ; ("nextid") => { $ crate :: ATOM_LOCALNAME__6E_65_78_74_69_64 } ; ("nobr") => { $ crate :: ATOM_LOCALNAME__6E_6F_62_72 } ; ("noembed") => { $ crate :: ATOM_LOCALNAME__6E_6F_65_6D_62_65_64 } ; ("nohref") => { $ crate :: ATOM_LOCALNAME__6E_6F_68_72_65_66 } ; ("nonce") => { $ crate :: ATOM_LOCALNAME__6E_6F_6E_63_65 } ; ("none") => { $ crate :: ATOM_LOCALNAME__6E_6F_6E_65 } ; ("noshade") => { $ crate :: ATOM_LOCALNAME__6E_6F_73_68_61_64_65 } ; ("not") => { $ crate :: ATOM_LOCALNAME__6E_6F_74 } ; ("notin") => { $ crate :: ATOM_LOCALNAME__6E_6F_74_69_6E } ; ("nowrap") => { $ crate :: ATOM_LOCALNAME__6E_6F_77_72_61_70 } ; ("object") => { $ crate :: ATOM_LOCALNAME__6F_62_6A_65_63_74 } ; ("offset") => { $ crate :: ATOM_LOCALNAME__6F_66_66_73_65_74 } ; ("ol") => { $ crate :: ATOM_LOCALNAME__6F_6C } ; ("onabort") => { $ crate :: ATOM_LOCALNAME__6F_6E_61_62_6F_72_74 } ; ("onbegin") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_67_69_6E } ; ("onblur") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_6C_75_72 } ; ("onclick") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6C_69_63_6B } ; ("onclose") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6C_6F_73_65 } ; ("oncopy") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6F_70_79 } ; ("oncut") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_75_74 } ; ("ondrag") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67 } ; ("ondrop") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_6F_70 } ; ("onend") => { $ crate :: ATOM_LOCALNAME__6F_6E_65_6E_64 } ; ("onended") => { $ crate :: ATOM_LOCALNAME__6F_6E_65_6E_64_65_64 } ; ("onerror") => { $ crate :: ATOM_LOCALNAME__6F_6E_65_72_72_6F_72 } ; ("onfocus") => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_63_75_73 } ; ("onhelp") => { $ crate :: ATOM_LOCALNAME__6F_6E_68_65_6C_70 } ; ("oninput") => { $ crate :: ATOM_LOCALNAME__6F_6E_69_6E_70_75_74 } ; ("onkeyup") => { $ crate :: ATOM_LOCALNAME__6F_6E_6B_65_79_75_70 } ; ("onload") => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_6F_61_64 } ; ("onmove") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_76_65 } ; ("onpaste") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_73_74_65 } ; ("onpause") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_75_73_65 } ; ("onplay") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_6C_61_79 } ; ("onreset") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_65_73_65_74 } ; ("onshow") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_68_6F_77 } ; ("onstart") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_74_61_72_74 } ; ("onstop") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_74_6F_70 } ; ("onwheel") => { $ crate :: ATOM_LOCALNAME__6F_6E_77_68_65_65_6C } ; ("onzoom") => { $ crate :: ATOM_LOCALNAME__6F_6E_7A_6F_6F_6D } ; ("opacity") => { $ crate :: ATOM_LOCALNAME__6F_70_61_63_69_74_79 } ; ("open") => { $ crate :: ATOM_LOCALNAME__6F_70_65_6E } ; ("optimum") => { $ crate :: ATOM_LOCALNAME__6F_70_74_69_6D_75_6D } ; ("option") => { $ crate :: ATOM_LOCALNAME__6F_70_74_69_6F_6E } ; ("or") => { $ crate :: ATOM_LOCALNAME__6F_72 } ; ("order") => { $ crate :: ATOM_LOCALNAME__6F_72_64_65_72 } ; ("orient") => { $ crate :: ATOM_LOCALNAME__6F_72_69_65_6E_74 } ; ("origin") => { $ crate :: ATOM_LOCALNAME__6F_72_69_67_69_6E } ; ("other") => { $ crate :: ATOM_LOCALNAME__6F_74_68_65_72 } ; ("output") => { $ crate :: ATOM_LOCALNAME__6F_75_74_70_75_74 } ; ("p") => { $ crate :: ATOM_LOCALNAME__70 } ; ("param") => { $ crate :: ATOM_LOCALNAME__70_61_72_61_6D } ; ("parse") => { $ crate :: ATOM_LOCALNAME__70_61_72_73_65 } ; ("part") => { $ crate :: ATOM_LOCALNAME__70_61_72_74 } ; ("path") => { $ crate :: ATOM_LOCALNAME__70_61_74_68 } ; ("pattern") => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E } ; ("pi") => { $ crate :: ATOM_LOCALNAME__70_69 } ; ("picture") => { $ crate :: ATOM_LOCALNAME__70_69_63_74_75_72_65 } ; ("piece") => { $ crate :: ATOM_LOCALNAME__70_69_65_63_65 } ; ("ping") => { $ crate :: ATOM_LOCALNAME__70_69_6E_67 } ; ("pitch") => { $ crate :: ATOM_LOCALNAME__70_69_74_63_68 } ; ("plus") => { $ crate :: ATOM_LOCALNAME__70_6C_75_73 } ; ("points") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73 } ; ("polygon") => { $ crate :: ATOM_LOCALNAME__70_6F_6C_79_67_6F_6E } ; ("popover") => { $ crate :: ATOM_LOCALNAME__70_6F_70_6F_76_65_72 } ; ("poster") => { $ crate :: ATOM_LOCALNAME__70_6F_73_74_65_72 } ; ("power") => { $ crate :: ATOM_LOCALNAME__70_6F_77_65_72 } ; ("pre") => { $ crate :: ATOM_LOCALNAME__70_72_65 } ; ("preload") => { $ crate :: ATOM_LOCALNAME__70_72_65_6C_6F_61_64 } ; ("primes") => { $ crate :: ATOM_LOCALNAME__70_72_69_6D_65_73 } ; ("product") => { $ crate :: ATOM_LOCALNAME__70_72_6F_64_75_63_74 } ; ("profile") => { $ crate :: ATOM_LOCALNAME__70_72_6F_66_69_6C_65 } ; ("prompt") => { $ crate :: ATOM_LOCALNAME__70_72_6F_6D_70_74 } ; ("q") => { $ crate :: ATOM_LOCALNAME__71 } ; ("r") => { $ crate :: ATOM_LOCALNAME__72 } ; ("radius") => { $ crate :: ATOM_LOCALNAME__72_61_64_69_75_73 } ; ("rb") => { $ crate :: ATOM_LOCALNAME__72_62 } ; ("real") => { $ crate :: ATOM_LOCALNAME__72_65_61_6C } ; ("reals") => { $ crate :: ATOM_LOCALNAME__72_65_61_6C_73 } ; ("rect") => { $ crate :: ATOM_LOCALNAME__72_65_63_74 } ; ("refX") => { $ crate :: ATOM_LOCALNAME__72_65_66_58 } ; ("refY") => { $ crate :: ATOM_LOCALNAME__72_65_66_59 } ; ("refx") => { $ crate :: ATOM_LOCALNAME__72_65_66_78 } ; ("refy") => { $ crate :: ATOM_LOCALNAME__72_65_66_79 } ; ("rel") => { $ crate :: ATOM_LOCALNAME__72_65_6C } ; ("reln") => { $ crate :: ATOM_LOCALNAME__72_65_6C_6E } ; ("rem") => { $ crate :: ATOM_LOCALNAME__72_65_6D } ; ("repeat") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74 } ; ("replace") => { $ crate :: ATOM_LOCALNAME__72_65_70_6C_61_63_65 } ; ("restart") => { $ crate :: ATOM_LOCALNAME__72_65_73_74_61_72_74 } ; ("result") => { $ crate :: ATOM_LOCALNAME__72_65_73_75_6C_74 } ; ("rev") => { $ crate :: ATOM_LOCALNAME__72_65_76 } ; ("role") => { $ crate :: ATOM_LOCALNAME__72_6F_6C_65 } ; ("root") => { $ crate :: ATOM_LOCALNAME__72_6F_6F_74 } ; ("rotate") => { $ crate :: ATOM_LOCALNAME__72_6F_74_61_74_65 } ; ("rows") => { $ crate :: ATOM_LOCALNAME__72_6F_77_73 } ; ("rowspan") => { $ crate :: ATOM_LOCALNAME__72_6F_77_73_70_61_6E } ; ("rp") => { $ crate :: ATOM_LOCALNAME__72_70 } ; ("rquote") => { $ crate :: ATOM_LOCALNAME__72_71_75_6F_74_65 } ; ("rspace") => { $ crate :: ATOM_LOCALNAME__72_73_70_61_63_65 } ; ("rt") => { $ crate :: ATOM_LOCALNAME__72_74 } ; ("rtc") => { $ crate :: ATOM_LOCALNAME__72_74_63 } ; ("ruby") => { $ crate :: ATOM_LOCALNAME__72_75_62_79 } ; ("rule") => { $ crate :: ATOM_LOCALNAME__72_75_6C_65 } ; ("rules") => { $ crate :: ATOM_LOCALNAME__72_75_6C_65_73 } ; ("rx") => { $ crate :: ATOM_LOCALNAME__72_78 } ; ("ry") => { $ crate :: ATOM_LOCALNAME__72_79 } ; ("s") => { $ crate :: ATOM_LOCALNAME__73 } ; ("samp") => { $ crate :: ATOM_LOCALNAME__73_61_6D_70 } ; ("sandbox") => { $ crate :: ATOM_LOCALNAME__73_61_6E_64_62_6F_78 } ; ("scale") => { $ crate :: ATOM_LOCALNAME__73_63_61_6C_65 } ; ("scheme") => { $ crate :: ATOM_LOCALNAME__73_63_68_65_6D_65 } ; ("scope") => { $ crate :: ATOM_LOCALNAME__73_63_6F_70_65 } ; ("scoped") => { $ crate :: ATOM_LOCALNAME__73_63_6F_70_65_64 } ; ("script") => { $ crate :: ATOM_LOCALNAME__73_63_72_69_70_74 } ; ("sdev") => { $ crate :: ATOM_LOCALNAME__73_64_65_76 } ; ("search") => { $ crate :: ATOM_LOCALNAME__73_65_61_72_63_68 } ; ("sec") => { $ crate :: ATOM_LOCALNAME__73_65_63 } ; ("sech") => { $ crate :: ATOM_LOCALNAME__73_65_63_68 } ; ("section") => { $ crate :: ATOM_LOCALNAME__73_65_63_74_69_6F_6E } ; ("seed") => { $ crate :: ATOM_LOCALNAME__73_65_65_64 } ; ("select") => { $ crate :: ATOM_LOCALNAME__73_65_6C_65_63_74 } ; ("sep") => { $ crate :: ATOM_LOCALNAME__73_65_70 } ; ("set") => { $ crate :: ATOM_LOCALNAME__73_65_74 } ; ("setdiff") => { $ crate :: ATOM_LOCALNAME__73_65_74_64_69_66_66 } ; ("shape") => { $ crate :: ATOM_LOCALNAME__73_68_61_70_65 } ; ("show") => { $ crate :: ATOM_LOCALNAME__73_68_6F_77 } ; ("side") => { $ crate :: ATOM_LOCALNAME__73_69_64_65 } ; ("sin") => { $ crate :: ATOM_LOCALNAME__73_69_6E } ; ("sinh") => { $ crate :: ATOM_LOCALNAME__73_69_6E_68 } ; ("size") => { $ crate :: ATOM_LOCALNAME__73_69_7A_65 } ; ("sizes") => { $ crate :: ATOM_LOCALNAME__73_69_7A_65_73 } ; ("slope") => { $ crate :: ATOM_LOCALNAME__73_6C_6F_70_65 } ; ("slot") => { $ crate :: ATOM_LOCALNAME__73_6C_6F_74 } ; ("small") => { $ crate :: ATOM_LOCALNAME__73_6D_61_6C_6C } ; ("source") => { $ crate :: ATOM_LOCALNAME__73_6F_75_72_63_65 } ; ("space") => { $ crate :: ATOM_LOCALNAME__73_70_61_63_65 } ; ("spacer") => { $ crate :: ATOM_LOCALNAME__73_70_61_63_65_72 } ; ("spacing") => { $ crate :: ATOM_LOCALNAME__73_70_61_63_69_6E_67 } ; ("span") => { $ crate :: ATOM_LOCALNAME__73_70_61_6E } ; ("speed") => { $ crate :: ATOM_LOCALNAME__73_70_65_65_64 } ; ("src") => { $ crate :: ATOM_LOCALNAME__73_72_63 } ; ("srcdoc") => { $ crate :: ATOM_LOCALNAME__73_72_63_64_6F_63 } ; ("srclang") => { $ crate :: ATOM_LOCALNAME__73_72_63_6C_61_6E_67 } ; ("srcset") => { $ crate :: ATOM_LOCALNAME__73_72_63_73_65_74 } ; ("standby") => { $ crate :: ATOM_LOCALNAME__73_74_61_6E_64_62_79 } ; ("start") => { $ crate :: ATOM_LOCALNAME__73_74_61_72_74 } ; ("stemh") => { $ crate :: ATOM_LOCALNAME__73_74_65_6D_68 } ; ("stemv") => { $ crate :: ATOM_LOCALNAME__73_74_65_6D_76 } ; ("step") => { $ crate :: ATOM_LOCALNAME__73_74_65_70 } ; ("stop") => { $ crate :: ATOM_LOCALNAME__73_74_6F_70 } ; ("strike") => { $ crate :: ATOM_LOCALNAME__73_74_72_69_6B_65 } ; ("string") => { $ crate :: ATOM_LOCALNAME__73_74_72_69_6E_67 } ; ("stroke") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65 } ; ("strong") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6E_67 } ; ("style") => { $ crate :: ATOM_LOCALNAME__73_74_79_6C_65 } ; ("sub") => { $ crate :: ATOM_LOCALNAME__73_75_62 } ; ("subset") => { $ crate :: ATOM_LOCALNAME__73_75_62_73_65_74 } ; ("sum") => { $ crate :: ATOM_LOCALNAME__73_75_6D } ; ("summary") => { $ crate :: ATOM_LOCALNAME__73_75_6D_6D_61_72_79 } ; ("sup") => { $ crate :: ATOM_LOCALNAME__73_75_70 } ; ("svg") => { $ crate :: ATOM_LOCALNAME__73_76_67 } ; ("switch") => { $ crate :: ATOM_LOCALNAME__73_77_69_74_63_68 } ; ("symbol") => { $ crate :: ATOM_LOCALNAME__73_79_6D_62_6F_6C } ; ("table") => { $ crate :: ATOM_LOCALNAME__74_61_62_6C_65 } ; ("tan") => { $ crate :: ATOM_LOCALNAME__74_61_6E } ; ("tanh") => { $ crate :: ATOM_LOCALNAME__74_61_6E_68 } ; ("target") => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74 } ; ("targetX") => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74_58 } ; ("targetY") => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74_59 } ; ("targetx") => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74_78 } ; ("targety") => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74_79 } ; ("tbody") => { $ crate :: ATOM_LOCALNAME__74_62_6F_64_79 } ; ("tbreak") => { $ crate :: ATOM_LOCALNAME__74_62_72_65_61_6B } ; ("td") => { $ crate :: ATOM_LOCALNAME__74_64 } ; ("tendsto") => { $ crate :: ATOM_LOCALNAME__74_65_6E_64_73_74_6F } ; ("text") => { $ crate :: ATOM_LOCALNAME__74_65_78_74 } ; ("tfoot") => { $ crate :: ATOM_LOCALNAME__74_66_6F_6F_74 } ; ("th") => { $ crate :: ATOM_LOCALNAME__74_68 } ; ("thead") => { $ crate :: ATOM_LOCALNAME__74_68_65_61_64 } ; ("time") => { $ crate :: ATOM_LOCALNAME__74_69_6D_65 } ; ("times") => { $ crate :: ATOM_LOCALNAME__74_69_6D_65_73 } ; ("title") => { $ crate :: ATOM_LOCALNAME__74_69_74_6C_65 } ; ("to") => { $ crate :: ATOM_LOCALNAME__74_6F } ; ("toggle") => { $ crate :: ATOM_LOCALNAME__74_6F_67_67_6C_65 } ; ("tr") => { $ crate :: ATOM_LOCALNAME__74_72 } ; ("track") => { $ crate :: ATOM_LOCALNAME__74_72_61_63_6B } ; ("tref") => { $ crate :: ATOM_LOCALNAME__74_72_65_66 } ; ("true") => { $ crate :: ATOM_LOCALNAME__74_72_75_65 } ; ("tspan") => { $ crate :: ATOM_LOCALNAME__74_73_70_61_6E } ; ("tt") => { $ crate :: ATOM_LOCALNAME__74_74 } ; ("type") => { $ crate :: ATOM_LOCALNAME__74_79_70_65 } ; ("u") => { $ crate :: ATOM_LOCALNAME__75 } ; ("u1") => { $ crate :: ATOM_LOCALNAME__75_31 } ; ("u2") => { $ crate :: ATOM_LOCALNAME__75_32 } ; ("ul") => { $ crate :: ATOM_LOCALNAME__75_6C } ; ("unicode") => { $ crate :: ATOM_LOCALNAME__75_6E_69_63_6F_64_65 } ; ("union") => { $ crate :: ATOM_LOCALNAME__75_6E_69_6F_6E } ; ("uplimit") => { $ crate :: ATOM_LOCALNAME__75_70_6C_69_6D_69_74 } ; ("use") => { $ crate :: ATOM_LOCALNAME__75_73_65 } ; ("usemap") => { $ crate :: ATOM_LOCALNAME__75_73_65_6D_61_70 } ; ("valign") => { $ crate :: ATOM_LOCALNAME__76_61_6C_69_67_6E } ; ("value") => { $ crate :: ATOM_LOCALNAME__76_61_6C_75_65 } ; ("values") => { $ crate :: ATOM_LOCALNAME__76_61_6C_75_65_73 } ; ("var") => { $ crate :: ATOM_LOCALNAME__76_61_72 } ; ("vector") => { $ crate :: ATOM_LOCALNAME__76_65_63_74_6F_72 } ; ("version") => { $ crate :: ATOM_LOCALNAME__76_65_72_73_69_6F_6E } ; ("video") => { $ crate :: ATOM_LOCALNAME__76_69_64_65_6F } ; ("view") => { $ crate :: ATOM_LOCALNAME__76_69_65_77 } ; ("viewBox") => { $ crate :: ATOM_LOCALNAME__76_69_65_77_42_6F_78 } ; ("viewbox") => { $ crate :: ATOM_LOCALNAME__76_69_65_77_62_6F_78 } ; ("vkern") => { $ crate :: ATOM_LOCALNAME__76_6B_65_72_6E } ; ("vlink") => { $ crate :: ATOM_LOCALNAME__76_6C_69_6E_6B } ; ("vspace") => { $ crate :: ATOM_LOCALNAME__76_73_70_61_63_65 } ; ("wbr") => { $ crate :: ATOM_LOCALNAME__77_62_72 } ; ("when") => { $ crate :: ATOM_LOCALNAME__77_68_65_6E } ; ("width") => { $ crate :: ATOM_LOCALNAME__77_69_64_74_68 } ; ("widths") => { $ crate :: ATOM_LOCALNAME__77_69_64_74_68_73 } ; ("wrap") => { $ crate :: ATOM_LOCALNAME__77_72_61_70 } ; ("x") => { $ crate :: ATOM_LOCALNAME__78 } ; ("x1") => { $ crate :: ATOM_LOCALNAME__78_31 } ; ("x2") => { $ crate :: ATOM_LOCALNAME__78_32 } ; ("xlink") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B } ; ("xmlns") => { $ crate :: ATOM_LOCALNAME__78_6D_6C_6E_73 } ; ("xmp") => { $ crate :: ATOM_LOCALNAME__78_6D_70 } ; ("xor") => { $ crate :: ATOM_LOCALNAME__78_6F_72 } ; ("xref") => { $ crate :: ATOM_LOCALNAME__78_72_65_66 } ; ("y") => { $ crate :: ATOM_LOCALNAME__79 } ; ("y1") => { $ crate :: ATOM_LOCALNAME__79_31 } ; ("y2") => { $ crate :: ATOM_LOCALNAME__79_32 } ; ("z") => { $ crate :: ATOM_LOCALNAME__7A } ; (equivalent) => { $ crate :: ATOM_LOCALNAME__65_71_75_69_76_61_6C_65_6E_74 } ; (pointsAtY) => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_59 } ; (stretchy) => { $ crate :: ATOM_LOCALNAME__73_74_72_65_74_63_68_79 } ; (glyphRef) => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68_52_65_66 } ; (naturalnumbers) => { $ crate :: ATOM_LOCALNAME__6E_61_74_75_72_61_6C_6E_75_6D_62_65_72_73 } ; (keyPoints) => { $ crate :: ATOM_LOCALNAME__6B_65_79_50_6F_69_6E_74_73 } ; (solidColor) => { $ crate :: ATOM_LOCALNAME__73_6F_6C_69_64_43_6F_6C_6F_72 } ; (pathlength) => { $ crate :: ATOM_LOCALNAME__70_61_74_68_6C_65_6E_67_74_68 } ; (altGlyph) => { $ crate :: ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68 } ; (contentScriptType) => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_53_63_72_69_70_74_54_79_70_65 } ; (unselectable) => { $ crate :: ATOM_LOCALNAME__75_6E_73_65_6C_65_63_74_61_62_6C_65 } ; (ondragstart) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_73_74_61_72_74 } ; (xChannelSelector) => { $ crate :: ATOM_LOCALNAME__78_43_68_61_6E_6E_65_6C_53_65_6C_65_63_74_6F_72 } ; (onrowexit) => { $ crate :: ATOM_LOCALNAME__6F_6E_72_6F_77_65_78_69_74 } ; (yChannelSelector) => { $ crate :: ATOM_LOCALNAME__79_43_68_61_6E_6E_65_6C_53_65_6C_65_63_74_6F_72 } ; (oncanplay) => { $ crate :: ATOM_LOCALNAME__6F_6E_63_61_6E_70_6C_61_79 } ; (feComponentTransfer) => { $ crate :: ATOM_LOCALNAME__66_65_43_6F_6D_70_6F_6E_65_6E_74_54_72_61_6E_73_66_65_72 } ; (diffuseConstant) => { $ crate :: ATOM_LOCALNAME__64_69_66_66_75_73_65_43_6F_6E_73_74_61_6E_74 } ; (hatchContentUnits) => { $ crate :: ATOM_LOCALNAME__68_61_74_63_68_43_6F_6E_74_65_6E_74_55_6E_69_74_73 } ; (spreadmethod) => { $ crate :: ATOM_LOCALNAME__73_70_72_65_61_64_6D_65_74_68_6F_64 } ; (filterUnits) => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72_55_6E_69_74_73 } ; (requiredExtensions) => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64_45_78_74_65_6E_73_69_6F_6E_73 } ; (textpath) => { $ crate :: ATOM_LOCALNAME__74_65_78_74_70_61_74_68 } ; (gradientUnits) => { $ crate :: ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_55_6E_69_74_73 } ; (equalrows) => { $ crate :: ATOM_LOCALNAME__65_71_75_61_6C_72_6F_77_73 } ; (rationals) => { $ crate :: ATOM_LOCALNAME__72_61_74_69_6F_6E_61_6C_73 } ; (displaystyle) => { $ crate :: ATOM_LOCALNAME__64_69_73_70_6C_61_79_73_74_79_6C_65 } ; (baseProfile) => { $ crate :: ATOM_LOCALNAME__62_61_73_65_50_72_6F_66_69_6C_65 } ; (pointsAtZ) => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_5A } ; (onunload) => { $ crate :: ATOM_LOCALNAME__6F_6E_75_6E_6C_6F_61_64 } ; (encoding) => { $ crate :: ATOM_LOCALNAME__65_6E_63_6F_64_69_6E_67 } ; (irrelevant) => { $ crate :: ATOM_LOCALNAME__69_72_72_65_6C_65_76_61_6E_74 } ; (mozbrowser) => { $ crate :: ATOM_LOCALNAME__6D_6F_7A_62_72_6F_77_73_65_72 } ; (oncanplaythrough) => { $ crate :: ATOM_LOCALNAME__6F_6E_63_61_6E_70_6C_61_79_74_68_72_6F_75_67_68 } ; (clipPathUnits) => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_50_61_74_68_55_6E_69_74_73 } ; (radiogroup) => { $ crate :: ATOM_LOCALNAME__72_61_64_69_6F_67_72_6F_75_70 } ; (numoctaves) => { $ crate :: ATOM_LOCALNAME__6E_75_6D_6F_63_74_61_76_65_73 } ; (lineargradient) => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65_61_72_67_72_61_64_69_65_6E_74 } ; (imaginaryi) => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_69_6E_61_72_79_69 } ; (itemscope) => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_73_63_6F_70_65 } ; (feGaussianBlur) => { $ crate :: ATOM_LOCALNAME__66_65_47_61_75_73_73_69_61_6E_42_6C_75_72 } ; (fecolormatrix) => { $ crate :: ATOM_LOCALNAME__66_65_63_6F_6C_6F_72_6D_61_74_72_69_78 } ; (mathematical) => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_65_6D_61_74_69_63_61_6C } ; (formaction) => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_61_63_74_69_6F_6E } ; (shadowrootclonable) => { $ crate :: ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_63_6C_6F_6E_61_62_6C_65 } ; (columnlines) => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_6C_69_6E_65_73 } ; (primitiveunits) => { $ crate :: ATOM_LOCALNAME__70_72_69_6D_69_74_69_76_65_75_6E_69_74_73 } ; (reversed) => { $ crate :: ATOM_LOCALNAME__72_65_76_65_72_73_65_64 } ; (divergence) => { $ crate :: ATOM_LOCALNAME__64_69_76_65_72_67_65_6E_63_65 } ; (onerrorupdate) => { $ crate :: ATOM_LOCALNAME__6F_6E_65_72_72_6F_72_75_70_64_61_74_65 } ; (keyTimes) => { $ crate :: ATOM_LOCALNAME__6B_65_79_54_69_6D_65_73 } ; (ondragover) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_6F_76_65_72 } ; (noscript) => { $ crate :: ATOM_LOCALNAME__6E_6F_73_63_72_69_70_74 } ; (viewTarget) => { $ crate :: ATOM_LOCALNAME__76_69_65_77_54_61_72_67_65_74 } ; (onfilterchange) => { $ crate :: ATOM_LOCALNAME__6F_6E_66_69_6C_74_65_72_63_68_61_6E_67_65 } ; (patternTransform) => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_54_72_61_6E_73_66_6F_72_6D } ; (fontstyle) => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_73_74_79_6C_65 } ; (shadowrootserializable) => { $ crate :: ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_73_65_72_69_61_6C_69_7A_61_62_6C_65 } ; (kernelUnitLength) => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_65_6C_55_6E_69_74_4C_65_6E_67_74_68 } ; (requiredfeatures) => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64_66_65_61_74_75_72_65_73 } ; (xchannelselector) => { $ crate :: ATOM_LOCALNAME__78_63_68_61_6E_6E_65_6C_73_65_6C_65_63_74_6F_72 } ; (markerWidth) => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_57_69_64_74_68 } ; (hidefocus) => { $ crate :: ATOM_LOCALNAME__68_69_64_65_66_6F_63_75_73 } ; (ondataavailable) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_61_74_61_61_76_61_69_6C_61_62_6C_65 } ; (glyphref) => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68_72_65_66 } ; (patterntransform) => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_74_72_61_6E_73_66_6F_72_6D } ; (ondatasetchanged) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_61_74_61_73_65_74_63_68_61_6E_67_65_64 } ; (rowspacing) => { $ crate :: ATOM_LOCALNAME__72_6F_77_73_70_61_63_69_6E_67 } ; (onmouseover) => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6F_76_65_72 } ; (notprsubset) => { $ crate :: ATOM_LOCALNAME__6E_6F_74_70_72_73_75_62_73_65_74 } ; (onbounce) => { $ crate :: ATOM_LOCALNAME__6F_6E_62_6F_75_6E_63_65 } ; (scriptsizemultiplier) => { $ crate :: ATOM_LOCALNAME__73_63_72_69_70_74_73_69_7A_65_6D_75_6C_74_69_70_6C_69_65_72 } ; (codebase) => { $ crate :: ATOM_LOCALNAME__63_6F_64_65_62_61_73_65 } ; (exponent) => { $ crate :: ATOM_LOCALNAME__65_78_70_6F_6E_65_6E_74 } ; (columnspan) => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_73_70_61_6E } ; (filterunits) => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72_75_6E_69_74_73 } ; (factorof) => { $ crate :: ATOM_LOCALNAME__66_61_63_74_6F_72_6F_66 } ; (onbeforeactivate) => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_61_63_74_69_76_61_74_65 } ; (keysplines) => { $ crate :: ATOM_LOCALNAME__6B_65_79_73_70_6C_69_6E_65_73 } ; (ondragenter) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_65_6E_74_65_72 } ; (attributetype) => { $ crate :: ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_74_79_70_65 } ; (feoffset) => { $ crate :: ATOM_LOCALNAME__66_65_6F_66_66_73_65_74 } ; (baseline) => { $ crate :: ATOM_LOCALNAME__62_61_73_65_6C_69_6E_65 } ; (oncellchange) => { $ crate :: ATOM_LOCALNAME__6F_6E_63_65_6C_6C_63_68_61_6E_67_65 } ; (radialgradient) => { $ crate :: ATOM_LOCALNAME__72_61_64_69_61_6C_67_72_61_64_69_65_6E_74 } ; (maskcontentunits) => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B_63_6F_6E_74_65_6E_74_75_6E_69_74_73 } ; (startoffset) => { $ crate :: ATOM_LOCALNAME__73_74_61_72_74_6F_66_66_73_65_74 } ; (onloadedmetadata) => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_6F_61_64_65_64_6D_65_74_61_64_61_74_61 } ; (controls) => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_72_6F_6C_73 } ; (shadowrootmode) => { $ crate :: ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_6D_6F_64_65 } ; (annotation) => { $ crate :: ATOM_LOCALNAME__61_6E_6E_6F_74_61_74_69_6F_6E } ; (fegaussianblur) => { $ crate :: ATOM_LOCALNAME__66_65_67_61_75_73_73_69_61_6E_62_6C_75_72 } ; (stddeviation) => { $ crate :: ATOM_LOCALNAME__73_74_64_64_65_76_69_61_74_69_6F_6E } ; (itemtype) => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_74_79_70_65 } ; (repeatCount) => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_43_6F_75_6E_74 } ; (otherwise) => { $ crate :: ATOM_LOCALNAME__6F_74_68_65_72_77_69_73_65 } ; (ondragdrop) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_64_72_6F_70 } ; (cellpadding) => { $ crate :: ATOM_LOCALNAME__63_65_6C_6C_70_61_64_64_69_6E_67 } ; (clipPath) => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_50_61_74_68 } ; (prsubset) => { $ crate :: ATOM_LOCALNAME__70_72_73_75_62_73_65_74 } ; (plaintext) => { $ crate :: ATOM_LOCALNAME__70_6C_61_69_6E_74_65_78_74 } ; (mathvariant) => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_76_61_72_69_61_6E_74 } ; (onafterupdate) => { $ crate :: ATOM_LOCALNAME__6F_6E_61_66_74_65_72_75_70_64_61_74_65 } ; (ondragleave) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_6C_65_61_76_65 } ; (markerwidth) => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_77_69_64_74_68 } ; (onafterprint) => { $ crate :: ATOM_LOCALNAME__6F_6E_61_66_74_65_72_70_72_69_6E_74 } ; (repeatDur) => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_44_75_72 } ; (formenctype) => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_65_6E_63_74_79_70_65 } ; (innertext) => { $ crate :: ATOM_LOCALNAME__69_6E_6E_65_72_74_65_78_74 } ; (vectorproduct) => { $ crate :: ATOM_LOCALNAME__76_65_63_74_6F_72_70_72_6F_64_75_63_74 } ; (onpageswap) => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_67_65_73_77_61_70 } ; (marginwidth) => { $ crate :: ATOM_LOCALNAME__6D_61_72_67_69_6E_77_69_64_74_68 } ; (basefrequency) => { $ crate :: ATOM_LOCALNAME__62_61_73_65_66_72_65_71_75_65_6E_63_79 } ; (fespecularlighting) => { $ crate :: ATOM_LOCALNAME__66_65_73_70_65_63_75_6C_61_72_6C_69_67_68_74_69_6E_67 } ; (alignmentscope) => { $ crate :: ATOM_LOCALNAME__61_6C_69_67_6E_6D_65_6E_74_73_63_6F_70_65 } ; (markerUnits) => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_55_6E_69_74_73 } ; (onbeforeprint) => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_70_72_69_6E_74 } ; (baseFrequency) => { $ crate :: ATOM_LOCALNAME__62_61_73_65_46_72_65_71_75_65_6E_63_79 } ; (limitingConeAngle) => { $ crate :: ATOM_LOCALNAME__6C_69_6D_69_74_69_6E_67_43_6F_6E_65_41_6E_67_6C_65 } ; (edgeMode) => { $ crate :: ATOM_LOCALNAME__65_64_67_65_4D_6F_64_65 } ; (animateMotion) => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_4D_6F_74_69_6F_6E } ; (hatchUnits) => { $ crate :: ATOM_LOCALNAME__68_61_74_63_68_55_6E_69_74_73 } ; (controllerchange) => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_72_6F_6C_6C_65_72_63_68_61_6E_67_65 } ; (lengthAdjust) => { $ crate :: ATOM_LOCALNAME__6C_65_6E_67_74_68_41_64_6A_75_73_74 } ; (pointsAtX) => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_58 } ; (required) => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64 } ; (eulergamma) => { $ crate :: ATOM_LOCALNAME__65_75_6C_65_72_67_61_6D_6D_61 } ; (datalist) => { $ crate :: ATOM_LOCALNAME__64_61_74_61_6C_69_73_74 } ; (preserveAspectRatio) => { $ crate :: ATOM_LOCALNAME__70_72_65_73_65_72_76_65_41_73_70_65_63_74_52_61_74_69_6F } ; (mathcolor) => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_63_6F_6C_6F_72 } ; (mmultiscripts) => { $ crate :: ATOM_LOCALNAME__6D_6D_75_6C_74_69_73_63_72_69_70_74_73 } ; (fespotlight) => { $ crate :: ATOM_LOCALNAME__66_65_73_70_6F_74_6C_69_67_68_74 } ; (blockquote) => { $ crate :: ATOM_LOCALNAME__62_6C_6F_63_6B_71_75_6F_74_65 } ; (onseeking) => { $ crate :: ATOM_LOCALNAME__6F_6E_73_65_65_6B_69_6E_67 } ; (veryverythickmathspace) => { $ crate :: ATOM_LOCALNAME__76_65_72_79_76_65_72_79_74_68_69_63_6B_6D_61_74_68_73_70_61_63_65 } ; (tableValues) => { $ crate :: ATOM_LOCALNAME__74_61_62_6C_65_56_61_6C_75_65_73 } ; (animatemotion) => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_6D_6F_74_69_6F_6E } ; (exponentiale) => { $ crate :: ATOM_LOCALNAME__65_78_70_6F_6E_65_6E_74_69_61_6C_65 } ; (shadowrootdelegatesfocus) => { $ crate :: ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_64_65_6C_65_67_61_74_65_73_66_6F_63_75_73 } ; (marginheight) => { $ crate :: ATOM_LOCALNAME__6D_61_72_67_69_6E_68_65_69_67_68_74 } ; (ondeactivate) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_65_61_63_74_69_76_61_74_65 } ; (contenteditable) => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_65_64_69_74_61_62_6C_65 } ; (hatchpath) => { $ crate :: ATOM_LOCALNAME__68_61_74_63_68_70_61_74_68 } ; (onfinish) => { $ crate :: ATOM_LOCALNAME__6F_6E_66_69_6E_69_73_68 } ; (spellcheck) => { $ crate :: ATOM_LOCALNAME__73_70_65_6C_6C_63_68_65_63_6B } ; (onrowsinserted) => { $ crate :: ATOM_LOCALNAME__6F_6E_72_6F_77_73_69_6E_73_65_72_74_65_64 } ; (onrowenter) => { $ crate :: ATOM_LOCALNAME__6F_6E_72_6F_77_65_6E_74_65_72 } ; (onlanguagechange) => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_61_6E_67_75_61_67_65_63_68_61_6E_67_65 } ; (formnovalidate) => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_6E_6F_76_61_6C_69_64_61_74_65 } ; (manifest) => { $ crate :: ATOM_LOCALNAME__6D_61_6E_69_66_65_73_74 } ; (foreignObject) => { $ crate :: ATOM_LOCALNAME__66_6F_72_65_69_67_6E_4F_62_6A_65_63_74 } ; (feConvolveMatrix) => { $ crate :: ATOM_LOCALNAME__66_65_43_6F_6E_76_6F_6C_76_65_4D_61_74_72_69_78 } ; (onmouseenter) => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_65_6E_74_65_72 } ; (animateTransform) => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_54_72_61_6E_73_66_6F_72_6D } ; (feMergeNode) => { $ crate :: ATOM_LOCALNAME__66_65_4D_65_72_67_65_4E_6F_64_65 } ; (attributename) => { $ crate :: ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_6E_61_6D_65 } ; (onstorage) => { $ crate :: ATOM_LOCALNAME__6F_6E_73_74_6F_72_61_67_65 } ; (momentabout) => { $ crate :: ATOM_LOCALNAME__6D_6F_6D_65_6E_74_61_62_6F_75_74 } ; (mprescripts) => { $ crate :: ATOM_LOCALNAME__6D_70_72_65_73_63_72_69_70_74_73 } ; (basefont) => { $ crate :: ATOM_LOCALNAME__62_61_73_65_66_6F_6E_74 } ; (solidcolor) => { $ crate :: ATOM_LOCALNAME__73_6F_6C_69_64_63_6F_6C_6F_72 } ; (onmousewheel) => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_77_68_65_65_6C } ; (gradientunits) => { $ crate :: ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_75_6E_69_74_73 } ; (datatemplate) => { $ crate :: ATOM_LOCALNAME__64_61_74_61_74_65_6D_70_6C_61_74_65 } ; (maskUnits) => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B_55_6E_69_74_73 } ; (preservealpha) => { $ crate :: ATOM_LOCALNAME__70_72_65_73_65_72_76_65_61_6C_70_68_61 } ; (textlength) => { $ crate :: ATOM_LOCALNAME__74_65_78_74_6C_65_6E_67_74_68 } ; (preserveAlpha) => { $ crate :: ATOM_LOCALNAME__70_72_65_73_65_72_76_65_41_6C_70_68_61 } ; (background) => { $ crate :: ATOM_LOCALNAME__62_61_63_6B_67_72_6F_75_6E_64 } ; (diffuseconstant) => { $ crate :: ATOM_LOCALNAME__64_69_66_66_75_73_65_63_6F_6E_73_74_61_6E_74 } ; (notation) => { $ crate :: ATOM_LOCALNAME__6E_6F_74_61_74_69_6F_6E } ; (oncommand) => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6F_6D_6D_61_6E_64 } ; (rowalign) => { $ crate :: ATOM_LOCALNAME__72_6F_77_61_6C_69_67_6E } ; (actiontype) => { $ crate :: ATOM_LOCALNAME__61_63_74_69_6F_6E_74_79_70_65 } ; (tablevalues) => { $ crate :: ATOM_LOCALNAME__74_61_62_6C_65_76_61_6C_75_65_73 } ; (autosubmit) => { $ crate :: ATOM_LOCALNAME__61_75_74_6F_73_75_62_6D_69_74 } ; (onbeforeunload) => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_75_6E_6C_6F_61_64 } ; (factorial) => { $ crate :: ATOM_LOCALNAME__66_61_63_74_6F_72_69_61_6C } ; (stitchtiles) => { $ crate :: ATOM_LOCALNAME__73_74_69_74_63_68_74_69_6C_65_73 } ; (groupalign) => { $ crate :: ATOM_LOCALNAME__67_72_6F_75_70_61_6C_69_67_6E } ; (oncontextmenu) => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6F_6E_74_65_78_74_6D_65_6E_75 } ; (accentunder) => { $ crate :: ATOM_LOCALNAME__61_63_63_65_6E_74_75_6E_64_65_72 } ; (additive) => { $ crate :: ATOM_LOCALNAME__61_64_64_69_74_69_76_65 } ; (fediffuselighting) => { $ crate :: ATOM_LOCALNAME__66_65_64_69_66_66_75_73_65_6C_69_67_68_74_69_6E_67 } ; (fedistantlight) => { $ crate :: ATOM_LOCALNAME__66_65_64_69_73_74_61_6E_74_6C_69_67_68_74 } ; (decoding) => { $ crate :: ATOM_LOCALNAME__64_65_63_6F_64_69_6E_67 } ; (fecomponenttransfer) => { $ crate :: ATOM_LOCALNAME__66_65_63_6F_6D_70_6F_6E_65_6E_74_74_72_61_6E_73_66_65_72 } ; (progress) => { $ crate :: ATOM_LOCALNAME__70_72_6F_67_72_65_73_73 } ; (formmethod) => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_6D_65_74_68_6F_64 } ; (specularExponent) => { $ crate :: ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_45_78_70_6F_6E_65_6E_74 } ; (specularConstant) => { $ crate :: ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_43_6F_6E_73_74_61_6E_74 } ; (altglyphdef) => { $ crate :: ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68_64_65_66 } ; (draggable) => { $ crate :: ATOM_LOCALNAME__64_72_61_67_67_61_62_6C_65 } ; (onoffline) => { $ crate :: ATOM_LOCALNAME__6F_6E_6F_66_66_6C_69_6E_65 } ; (onbefordeactivate) => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_64_65_61_63_74_69_76_61_74_65 } ; (onpropertychange) => { $ crate :: ATOM_LOCALNAME__6F_6E_70_72_6F_70_65_72_74_79_63_68_61_6E_67_65 } ; (thinmathspace) => { $ crate :: ATOM_LOCALNAME__74_68_69_6E_6D_61_74_68_73_70_61_63_65 } ; (onbeforepaste) => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_70_61_73_74_65 } ; (zoomAndPan) => { $ crate :: ATOM_LOCALNAME__7A_6F_6F_6D_41_6E_64_50_61_6E } ; (attributeName) => { $ crate :: ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_4E_61_6D_65 } ; (onbeforecut) => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_63_75_74 } ; (surfacescale) => { $ crate :: ATOM_LOCALNAME__73_75_72_66_61_63_65_73_63_61_6C_65 } ; (feDistantLight) => { $ crate :: ATOM_LOCALNAME__66_65_44_69_73_74_61_6E_74_4C_69_67_68_74 } ; (variance) => { $ crate :: ATOM_LOCALNAME__76_61_72_69_61_6E_63_65 } ; (textarea) => { $ crate :: ATOM_LOCALNAME__74_65_78_74_61_72_65_61 } ; (statechange) => { $ crate :: ATOM_LOCALNAME__73_74_61_74_65_63_68_61_6E_67_65 } ; (edgemode) => { $ crate :: ATOM_LOCALNAME__65_64_67_65_6D_6F_64_65 } ; (kernelMatrix) => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_65_6C_4D_61_74_72_69_78 } ; (mphantom) => { $ crate :: ATOM_LOCALNAME__6D_70_68_61_6E_74_6F_6D } ; (altglyph) => { $ crate :: ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68 } ; (novalidate) => { $ crate :: ATOM_LOCALNAME__6E_6F_76_61_6C_69_64_61_74_65 } ; (semantics) => { $ crate :: ATOM_LOCALNAME__73_65_6D_61_6E_74_69_63_73 } ; (gradientTransform) => { $ crate :: ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_54_72_61_6E_73_66_6F_72_6D } ; (definitionURL) => { $ crate :: ATOM_LOCALNAME__64_65_66_69_6E_69_74_69_6F_6E_55_52_4C } ; (animatetransform) => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_74_72_61_6E_73_66_6F_72_6D } ; (keytimes) => { $ crate :: ATOM_LOCALNAME__6B_65_79_74_69_6D_65_73 } ; (ondragexit) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_65_78_69_74 } ; (separator) => { $ crate :: ATOM_LOCALNAME__73_65_70_61_72_61_74_6F_72 } ; (onsuspend) => { $ crate :: ATOM_LOCALNAME__6F_6E_73_75_73_70_65_6E_64 } ; (onmousemove) => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6D_6F_76_65 } ; (innerText) => { $ crate :: ATOM_LOCALNAME__69_6E_6E_65_72_54_65_78_74 } ; (feSpotLight) => { $ crate :: ATOM_LOCALNAME__66_65_53_70_6F_74_4C_69_67_68_74 } ; (onmessageerror) => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_65_73_73_61_67_65_65_72_72_6F_72 } ; (elevation) => { $ crate :: ATOM_LOCALNAME__65_6C_65_76_61_74_69_6F_6E } ; (markerheight) => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_68_65_69_67_68_74 } ; (feColorMatrix) => { $ crate :: ATOM_LOCALNAME__66_65_43_6F_6C_6F_72_4D_61_74_72_69_78 } ; (animation) => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_69_6F_6E } ; (prefetch) => { $ crate :: ATOM_LOCALNAME__70_72_65_66_65_74_63_68 } ; (lengthadjust) => { $ crate :: ATOM_LOCALNAME__6C_65_6E_67_74_68_61_64_6A_75_73_74 } ; (equalcolumns) => { $ crate :: ATOM_LOCALNAME__65_71_75_61_6C_63_6F_6C_75_6D_6E_73 } ; (partialdiff) => { $ crate :: ATOM_LOCALNAME__70_61_72_74_69_61_6C_64_69_66_66 } ; (integrity) => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_67_72_69_74_79 } ; (outerproduct) => { $ crate :: ATOM_LOCALNAME__6F_75_74_65_72_70_72_6F_64_75_63_74 } ; (popovertarget) => { $ crate :: ATOM_LOCALNAME__70_6F_70_6F_76_65_72_74_61_72_67_65_74 } ; (symmetric) => { $ crate :: ATOM_LOCALNAME__73_79_6D_6D_65_74_72_69_63 } ; (alphabetic) => { $ crate :: ATOM_LOCALNAME__61_6C_70_68_61_62_65_74_69_63 } ; (translate) => { $ crate :: ATOM_LOCALNAME__74_72_61_6E_73_6C_61_74_65 } ; (operator) => { $ crate :: ATOM_LOCALNAME__6F_70_65_72_61_74_6F_72 } ; (formtarget) => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_74_61_72_67_65_74 } ; (autofocus) => { $ crate :: ATOM_LOCALNAME__61_75_74_6F_66_6F_63_75_73 } ; (zoomandpan) => { $ crate :: ATOM_LOCALNAME__7A_6F_6F_6D_61_6E_64_70_61_6E } ; (direction) => { $ crate :: ATOM_LOCALNAME__64_69_72_65_63_74_69_6F_6E } ; (feDropShadow) => { $ crate :: ATOM_LOCALNAME__66_65_44_72_6F_70_53_68_61_64_6F_77 } ; (columnwidth) => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_77_69_64_74_68 } ; (onmousedown) => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_64_6F_77_6E } ; (animatecolor) => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_63_6F_6C_6F_72 } ; (oncuechange) => { $ crate :: ATOM_LOCALNAME__6F_6E_63_75_65_63_68_61_6E_67_65 } ; (onresize) => { $ crate :: ATOM_LOCALNAME__6F_6E_72_65_73_69_7A_65 } ; (frameborder) => { $ crate :: ATOM_LOCALNAME__66_72_61_6D_65_62_6F_72_64_65_72 } ; (download) => { $ crate :: ATOM_LOCALNAME__64_6F_77_6E_6C_6F_61_64 } ; (preserveaspectratio) => { $ crate :: ATOM_LOCALNAME__70_72_65_73_65_72_76_65_61_73_70_65_63_74_72_61_74_69_6F } ; (ideographic) => { $ crate :: ATOM_LOCALNAME__69_64_65_6F_67_72_61_70_68_69_63 } ; (requiredFeatures) => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64_46_65_61_74_75_72_65_73 } ; (frameset) => { $ crate :: ATOM_LOCALNAME__66_72_61_6D_65_73_65_74 } ; (mathbackground) => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_62_61_63_6B_67_72_6F_75_6E_64 } ; (noresize) => { $ crate :: ATOM_LOCALNAME__6E_6F_72_65_73_69_7A_65 } ; (clippathunits) => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_70_61_74_68_75_6E_69_74_73 } ; (polyline) => { $ crate :: ATOM_LOCALNAME__70_6F_6C_79_6C_69_6E_65 } ; (metadata) => { $ crate :: ATOM_LOCALNAME__6D_65_74_61_64_61_74_61 } ; (quotient) => { $ crate :: ATOM_LOCALNAME__71_75_6F_74_69_65_6E_74 } ; (notanumber) => { $ crate :: ATOM_LOCALNAME__6E_6F_74_61_6E_75_6D_62_65_72 } ; (femorphology) => { $ crate :: ATOM_LOCALNAME__66_65_6D_6F_72_70_68_6F_6C_6F_67_79 } ; (infinity) => { $ crate :: ATOM_LOCALNAME__69_6E_66_69_6E_69_74_79 } ; (startOffset) => { $ crate :: ATOM_LOCALNAME__73_74_61_72_74_4F_66_66_73_65_74 } ; (linearGradient) => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65_61_72_47_72_61_64_69_65_6E_74 } ; (menuitem) => { $ crate :: ATOM_LOCALNAME__6D_65_6E_75_69_74_65_6D } ; (onbeforeupdate) => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_75_70_64_61_74_65 } ; (fePointLight) => { $ crate :: ATOM_LOCALNAME__66_65_50_6F_69_6E_74_4C_69_67_68_74 } ; (colorspace) => { $ crate :: ATOM_LOCALNAME__63_6F_6C_6F_72_73_70_61_63_65 } ; (complexes) => { $ crate :: ATOM_LOCALNAME__63_6F_6D_70_6C_65_78_65_73 } ; (datetime) => { $ crate :: ATOM_LOCALNAME__64_61_74_65_74_69_6D_65 } ; (columnalign) => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_61_6C_69_67_6E } ; (onformchange) => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_72_6D_63_68_61_6E_67_65 } ; (closedby) => { $ crate :: ATOM_LOCALNAME__63_6C_6F_73_65_64_62_79 } ; (spreadMethod) => { $ crate :: ATOM_LOCALNAME__73_70_72_65_61_64_4D_65_74_68_6F_64 } ; (transpose) => { $ crate :: ATOM_LOCALNAME__74_72_61_6E_73_70_6F_73_65 } ; (imaginary) => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_69_6E_61_72_79 } ; (scrolling) => { $ crate :: ATOM_LOCALNAME__73_63_72_6F_6C_6C_69_6E_67 } ; (onscroll) => { $ crate :: ATOM_LOCALNAME__6F_6E_73_63_72_6F_6C_6C } ; (onpagehide) => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_67_65_68_69_64_65 } ; (onmovestart) => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_76_65_73_74_61_72_74 } ; (fedropshadow) => { $ crate :: ATOM_LOCALNAME__66_65_64_72_6F_70_73_68_61_64_6F_77 } ; (munderover) => { $ crate :: ATOM_LOCALNAME__6D_75_6E_64_65_72_6F_76_65_72 } ; (selection) => { $ crate :: ATOM_LOCALNAME__73_65_6C_65_63_74_69_6F_6E } ; (itemprop) => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_70_72_6F_70 } ; (ontoggle) => { $ crate :: ATOM_LOCALNAME__6F_6E_74_6F_67_67_6C_65 } ; (selected) => { $ crate :: ATOM_LOCALNAME__73_65_6C_65_63_74_65_64 } ; (maskunits) => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B_75_6E_69_74_73 } ; (dataformatas) => { $ crate :: ATOM_LOCALNAME__64_61_74_61_66_6F_72_6D_61_74_61_73 } ; (onsubmit) => { $ crate :: ATOM_LOCALNAME__6F_6E_73_75_62_6D_69_74 } ; (fetchpriority) => { $ crate :: ATOM_LOCALNAME__66_65_74_63_68_70_72_69_6F_72_69_74_79 } ; (verythinmathspace) => { $ crate :: ATOM_LOCALNAME__76_65_72_79_74_68_69_6E_6D_61_74_68_73_70_61_63_65 } ; (mathsize) => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_73_69_7A_65 } ; (fedisplacementmap) => { $ crate :: ATOM_LOCALNAME__66_65_64_69_73_70_6C_61_63_65_6D_65_6E_74_6D_61_70 } ; (calcMode) => { $ crate :: ATOM_LOCALNAME__63_61_6C_63_4D_6F_64_65 } ; (separators) => { $ crate :: ATOM_LOCALNAME__73_65_70_61_72_61_74_6F_72_73 } ; (onreadystatechange) => { $ crate :: ATOM_LOCALNAME__6F_6E_72_65_61_64_79_73_74_61_74_65_63_68_61_6E_67_65 } ; (columnspacing) => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_73_70_61_63_69_6E_67 } ; (specification) => { $ crate :: ATOM_LOCALNAME__73_70_65_63_69_66_69_63_61_74_69_6F_6E } ; (specularconstant) => { $ crate :: ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_63_6F_6E_73_74_61_6E_74 } ; (cellspacing) => { $ crate :: ATOM_LOCALNAME__63_65_6C_6C_73_70_61_63_69_6E_67 } ; (baseprofile) => { $ crate :: ATOM_LOCALNAME__62_61_73_65_70_72_6F_66_69_6C_65 } ; (requiredextensions) => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64_65_78_74_65_6E_73_69_6F_6E_73 } ; (attributeType) => { $ crate :: ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_54_79_70_65 } ; (veryverythinmathspace) => { $ crate :: ATOM_LOCALNAME__76_65_72_79_76_65_72_79_74_68_69_6E_6D_61_74_68_73_70_61_63_65 } ; (onbeforetoggle) => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_74_6F_67_67_6C_65 } ; (hatchunits) => { $ crate :: ATOM_LOCALNAME__68_61_74_63_68_75_6E_69_74_73 } ; (nomodule) => { $ crate :: ATOM_LOCALNAME__6E_6F_6D_6F_64_75_6C_65 } ; (thickmathspace) => { $ crate :: ATOM_LOCALNAME__74_68_69_63_6B_6D_61_74_68_73_70_61_63_65 } ; (contentStyleType) => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_53_74_79_6C_65_54_79_70_65 } ; (figcaption) => { $ crate :: ATOM_LOCALNAME__66_69_67_63_61_70_74_69_6F_6E } ; (onforminput) => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_72_6D_69_6E_70_75_74 } ; (feturbulence) => { $ crate :: ATOM_LOCALNAME__66_65_74_75_72_62_75_6C_65_6E_63_65 } ; (readonly) => { $ crate :: ATOM_LOCALNAME__72_65_61_64_6F_6E_6C_79 } ; (onvolumechange) => { $ crate :: ATOM_LOCALNAME__6F_6E_76_6F_6C_75_6D_65_63_68_61_6E_67_65 } ; (stdDeviation) => { $ crate :: ATOM_LOCALNAME__73_74_64_44_65_76_69_61_74_69_6F_6E } ; (onbeforecopy) => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_63_6F_70_79 } ; (ondatasetcomplete) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_61_74_61_73_65_74_63_6F_6D_70_6C_65_74_65 } ; (timelinebegin) => { $ crate :: ATOM_LOCALNAME__74_69_6D_65_6C_69_6E_65_62_65_67_69_6E } ; (domainofapplication) => { $ crate :: ATOM_LOCALNAME__64_6F_6D_61_69_6E_6F_66_61_70_70_6C_69_63_61_74_69_6F_6E } ; (onunhandledrejection) => { $ crate :: ATOM_LOCALNAME__6F_6E_75_6E_68_61_6E_64_6C_65_64_72_65_6A_65_63_74_69_6F_6E } ; (occurrence) => { $ crate :: ATOM_LOCALNAME__6F_63_63_75_72_72_65_6E_63_65 } ; (conjugate) => { $ crate :: ATOM_LOCALNAME__63_6F_6E_6A_75_67_61_74_65 } ; (patternUnits) => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_55_6E_69_74_73 } ; (onmoveend) => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_76_65_65_6E_64 } ; (feDiffuseLighting) => { $ crate :: ATOM_LOCALNAME__66_65_44_69_66_66_75_73_65_4C_69_67_68_74_69_6E_67 } ; (calcmode) => { $ crate :: ATOM_LOCALNAME__63_61_6C_63_6D_6F_64_65 } ; (onselectstart) => { $ crate :: ATOM_LOCALNAME__6F_6E_73_65_6C_65_63_74_73_74_61_72_74 } ; (codetype) => { $ crate :: ATOM_LOCALNAME__63_6F_64_65_74_79_70_65 } ; (hatchcontentunits) => { $ crate :: ATOM_LOCALNAME__68_61_74_63_68_63_6F_6E_74_65_6E_74_75_6E_69_74_73 } ; (intersect) => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_72_73_65_63_74 } ; (fontweight) => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_77_65_69_67_68_74 } ; (onmouseup) => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_75_70 } ; (noframes) => { $ crate :: ATOM_LOCALNAME__6E_6F_66_72_61_6D_65_73 } ; (colgroup) => { $ crate :: ATOM_LOCALNAME__63_6F_6C_67_72_6F_75_70 } ; (onmessage) => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_65_73_73_61_67_65 } ; (kernelmatrix) => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_65_6C_6D_61_74_72_69_78 } ; (filterRes) => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72_52_65_73 } ; (surfaceScale) => { $ crate :: ATOM_LOCALNAME__73_75_72_66_61_63_65_53_63_61_6C_65 } ; (feOffset) => { $ crate :: ATOM_LOCALNAME__66_65_4F_66_66_73_65_74 } ; (contextmenu) => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_78_74_6D_65_6E_75 } ; (intercept) => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_72_63_65_70_74 } ; (contentscripttype) => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_73_63_72_69_70_74_74_79_70_65 } ; (onloadstart) => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_6F_61_64_73_74_61_72_74 } ; (maligngroup) => { $ crate :: ATOM_LOCALNAME__6D_61_6C_69_67_6E_67_72_6F_75_70 } ; (minlength) => { $ crate :: ATOM_LOCALNAME__6D_69_6E_6C_65_6E_67_74_68 } ; (ontimeupdate) => { $ crate :: ATOM_LOCALNAME__6F_6E_74_69_6D_65_75_70_64_61_74_65 } ; (altGlyphDef) => { $ crate :: ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68_44_65_66 } ; (commandfor) => { $ crate :: ATOM_LOCALNAME__63_6F_6D_6D_61_6E_64_66_6F_72 } ; (ondurationchange) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_75_72_61_74_69_6F_6E_63_68_61_6E_67_65 } ; (popovertargetaction) => { $ crate :: ATOM_LOCALNAME__70_6F_70_6F_76_65_72_74_61_72_67_65_74_61_63_74_69_6F_6E } ; (fecomposite) => { $ crate :: ATOM_LOCALNAME__66_65_63_6F_6D_70_6F_73_69_74_65 } ; (feDisplacementMap) => { $ crate :: ATOM_LOCALNAME__66_65_44_69_73_70_6C_61_63_65_6D_65_6E_74_4D_61_70 } ; (scriptminsize) => { $ crate :: ATOM_LOCALNAME__73_63_72_69_70_74_6D_69_6E_73_69_7A_65 } ; (stitchTiles) => { $ crate :: ATOM_LOCALNAME__73_74_69_74_63_68_54_69_6C_65_73 } ; (exportparts) => { $ crate :: ATOM_LOCALNAME__65_78_70_6F_72_74_70_61_72_74_73 } ; (oncontrolselect) => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6F_6E_74_72_6F_6C_73_65_6C_65_63_74 } ; (tabindex) => { $ crate :: ATOM_LOCALNAME__74_61_62_69_6E_64_65_78 } ; (pathLength) => { $ crate :: ATOM_LOCALNAME__70_61_74_68_4C_65_6E_67_74_68 } ; (ychannelselector) => { $ crate :: ATOM_LOCALNAME__79_63_68_61_6E_6E_65_6C_73_65_6C_65_63_74_6F_72 } ; (foreignobject) => { $ crate :: ATOM_LOCALNAME__66_6F_72_65_69_67_6E_6F_62_6A_65_63_74 } ; (onlosecapture) => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_6F_73_65_63_61_70_74_75_72_65 } ; (onwaiting) => { $ crate :: ATOM_LOCALNAME__6F_6E_77_61_69_74_69_6E_67 } ; (feSpecularLighting) => { $ crate :: ATOM_LOCALNAME__66_65_53_70_65_63_75_6C_61_72_4C_69_67_68_74_69_6E_67 } ; (ondblclick) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_62_6C_63_6C_69_63_6B } ; (allowfullscreen) => { $ crate :: ATOM_LOCALNAME__61_6C_6C_6F_77_66_75_6C_6C_73_63_72_65_65_6E } ; (autoplay) => { $ crate :: ATOM_LOCALNAME__61_75_74_6F_70_6C_61_79 } ; (patternunits) => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_75_6E_69_74_73 } ; (onactivate) => { $ crate :: ATOM_LOCALNAME__6F_6E_61_63_74_69_76_61_74_65 } ; (maskContentUnits) => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B_43_6F_6E_74_65_6E_74_55_6E_69_74_73 } ; (specularexponent) => { $ crate :: ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_65_78_70_6F_6E_65_6E_74 } ; (orientation) => { $ crate :: ATOM_LOCALNAME__6F_72_69_65_6E_74_61_74_69_6F_6E } ; (kernelunitlength) => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_65_6C_75_6E_69_74_6C_65_6E_67_74_68 } ; (notsubset) => { $ crate :: ATOM_LOCALNAME__6E_6F_74_73_75_62_73_65_74 } ; (onkeypress) => { $ crate :: ATOM_LOCALNAME__6F_6E_6B_65_79_70_72_65_73_73 } ; (superscriptshift) => { $ crate :: ATOM_LOCALNAME__73_75_70_65_72_73_63_72_69_70_74_73_68_69_66_74 } ; (onplaying) => { $ crate :: ATOM_LOCALNAME__6F_6E_70_6C_61_79_69_6E_67 } ; (crossorigin) => { $ crate :: ATOM_LOCALNAME__63_72_6F_73_73_6F_72_69_67_69_6E } ; (accesskey) => { $ crate :: ATOM_LOCALNAME__61_63_63_65_73_73_6B_65_79 } ; (onmouseout) => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6F_75_74 } ; (definitionurl) => { $ crate :: ATOM_LOCALNAME__64_65_66_69_6E_69_74_69_6F_6E_75_72_6C } ; (onbeforeeditfocus) => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_65_64_69_74_66_6F_63_75_73 } ; (filterres) => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72_72_65_73 } ; (transform) => { $ crate :: ATOM_LOCALNAME__74_72_61_6E_73_66_6F_72_6D } ; (fepointlight) => { $ crate :: ATOM_LOCALNAME__66_65_70_6F_69_6E_74_6C_69_67_68_74 } ; (pointsatx) => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_78 } ; (property) => { $ crate :: ATOM_LOCALNAME__70_72_6F_70_65_72_74_79 } ; (template) => { $ crate :: ATOM_LOCALNAME__74_65_6D_70_6C_61_74_65 } ; (bevelled) => { $ crate :: ATOM_LOCALNAME__62_65_76_65_6C_6C_65_64 } ; (onfocusout) => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_63_75_73_6F_75_74 } ; (systemLanguage) => { $ crate :: ATOM_LOCALNAME__73_79_73_74_65_6D_4C_61_6E_67_75_61_67_65 } ; (referrerpolicy) => { $ crate :: ATOM_LOCALNAME__72_65_66_65_72_72_65_72_70_6F_6C_69_63_79 } ; (onrowsdelete) => { $ crate :: ATOM_LOCALNAME__6F_6E_72_6F_77_73_64_65_6C_65_74_65 } ; (radialGradient) => { $ crate :: ATOM_LOCALNAME__72_61_64_69_61_6C_47_72_61_64_69_65_6E_74 } ; (language) => { $ crate :: ATOM_LOCALNAME__6C_61_6E_67_75_61_67_65 } ; (maxlength) => { $ crate :: ATOM_LOCALNAME__6D_61_78_6C_65_6E_67_74_68 } ; (codomain) => { $ crate :: ATOM_LOCALNAME__63_6F_64_6F_6D_61_69_6E } ; (keypoints) => { $ crate :: ATOM_LOCALNAME__6B_65_79_70_6F_69_6E_74_73 } ; (onpagereveal) => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_67_65_72_65_76_65_61_6C } ; (onhashchange) => { $ crate :: ATOM_LOCALNAME__6F_6E_68_61_73_68_63_68_61_6E_67_65 } ; (interval) => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_72_76_61_6C } ; (externalresourcesrequired) => { $ crate :: ATOM_LOCALNAME__65_78_74_65_72_6E_61_6C_72_65_73_6F_75_72_63_65_73_72_65_71_75_69_72_65_64 } ; (mlabeledtr) => { $ crate :: ATOM_LOCALNAME__6D_6C_61_62_65_6C_65_64_74_72 } ; (onkeydown) => { $ crate :: ATOM_LOCALNAME__6F_6E_6B_65_79_64_6F_77_6E } ; (markerHeight) => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_48_65_69_67_68_74 } ; (onprogress) => { $ crate :: ATOM_LOCALNAME__6F_6E_70_72_6F_67_72_65_73_73 } ; (valuetype) => { $ crate :: ATOM_LOCALNAME__76_61_6C_75_65_74_79_70_65 } ; (textContent) => { $ crate :: ATOM_LOCALNAME__74_65_78_74_43_6F_6E_74_65_6E_74 } ; (placeholder) => { $ crate :: ATOM_LOCALNAME__70_6C_61_63_65_68_6F_6C_64_65_72 } ; (lowlimit) => { $ crate :: ATOM_LOCALNAME__6C_6F_77_6C_69_6D_69_74 } ; (emptyset) => { $ crate :: ATOM_LOCALNAME__65_6D_70_74_79_73_65_74 } ; (subscriptshift) => { $ crate :: ATOM_LOCALNAME__73_75_62_73_63_72_69_70_74_73_68_69_66_74 } ; (condition) => { $ crate :: ATOM_LOCALNAME__63_6F_6E_64_69_74_69_6F_6E } ; (visibility) => { $ crate :: ATOM_LOCALNAME__76_69_73_69_62_69_6C_69_74_79 } ; (externalResourcesRequired) => { $ crate :: ATOM_LOCALNAME__65_78_74_65_72_6E_61_6C_52_65_73_6F_75_72_63_65_73_52_65_71_75_69_72_65_64 } ; (seamless) => { $ crate :: ATOM_LOCALNAME__73_65_61_6D_6C_65_73_73 } ; (multiple) => { $ crate :: ATOM_LOCALNAME__6D_75_6C_74_69_70_6C_65 } ; (onrepeat) => { $ crate :: ATOM_LOCALNAME__6F_6E_72_65_70_65_61_74 } ; (pointsaty) => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_79 } ; (integers) => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_67_65_72_73 } ; (fontfamily) => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_66_61_6D_69_6C_79 } ; (disabled) => { $ crate :: ATOM_LOCALNAME__64_69_73_61_62_6C_65_64 } ; (onchange) => { $ crate :: ATOM_LOCALNAME__6F_6E_63_68_61_6E_67_65 } ; (feMorphology) => { $ crate :: ATOM_LOCALNAME__66_65_4D_6F_72_70_68_6F_6C_6F_67_79 } ; (autocomplete) => { $ crate :: ATOM_LOCALNAME__61_75_74_6F_63_6F_6D_70_6C_65_74_65 } ; (limitingconeangle) => { $ crate :: ATOM_LOCALNAME__6C_69_6D_69_74_69_6E_67_63_6F_6E_65_61_6E_67_6C_65 } ; (femergenode) => { $ crate :: ATOM_LOCALNAME__66_65_6D_65_72_67_65_6E_6F_64_65 } ; (malignmark) => { $ crate :: ATOM_LOCALNAME__6D_61_6C_69_67_6E_6D_61_72_6B } ; (onstalled) => { $ crate :: ATOM_LOCALNAME__6F_6E_73_74_61_6C_6C_65_64 } ; (oninvalid) => { $ crate :: ATOM_LOCALNAME__6F_6E_69_6E_76_61_6C_69_64 } ; (scalarproduct) => { $ crate :: ATOM_LOCALNAME__73_63_61_6C_61_72_70_72_6F_64_75_63_74 } ; (cartesianproduct) => { $ crate :: ATOM_LOCALNAME__63_61_72_74_65_73_69_61_6E_70_72_6F_64_75_63_74 } ; (textLength) => { $ crate :: ATOM_LOCALNAME__74_65_78_74_4C_65_6E_67_74_68 } ; (linebreak) => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65_62_72_65_61_6B } ; (pointsatz) => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_7A } ; (ondragend) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_65_6E_64 } ; (longdesc) => { $ crate :: ATOM_LOCALNAME__6C_6F_6E_67_64_65_73_63 } ; (matrixrow) => { $ crate :: ATOM_LOCALNAME__6D_61_74_72_69_78_72_6F_77 } ; (altGlyphItem) => { $ crate :: ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68_49_74_65_6D } ; (textPath) => { $ crate :: ATOM_LOCALNAME__74_65_78_74_50_61_74_68 } ; (piecewise) => { $ crate :: ATOM_LOCALNAME__70_69_65_63_65_77_69_73_65 } ; (onloadeddata) => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_6F_61_64_65_64_64_61_74_61 } ; (primitiveUnits) => { $ crate :: ATOM_LOCALNAME__70_72_69_6D_69_74_69_76_65_55_6E_69_74_73 } ; (listener) => { $ crate :: ATOM_LOCALNAME__6C_69_73_74_65_6E_65_72 } ; (framespacing) => { $ crate :: ATOM_LOCALNAME__66_72_61_6D_65_73_70_61_63_69_6E_67 } ; (inputmode) => { $ crate :: ATOM_LOCALNAME__69_6E_70_75_74_6D_6F_64_65 } ; (gradienttransform) => { $ crate :: ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_74_72_61_6E_73_66_6F_72_6D } ; (systemlanguage) => { $ crate :: ATOM_LOCALNAME__73_79_73_74_65_6D_6C_61_6E_67_75_61_67_65 } ; (feTurbulence) => { $ crate :: ATOM_LOCALNAME__66_65_54_75_72_62_75_6C_65_6E_63_65 } ; (repeatdur) => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_64_75_72 } ; (hreflang) => { $ crate :: ATOM_LOCALNAME__68_72_65_66_6C_61_6E_67 } ; (fontsize) => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_73_69_7A_65 } ; (overflow) => { $ crate :: ATOM_LOCALNAME__6F_76_65_72_66_6C_6F_77 } ; (playbackorder) => { $ crate :: ATOM_LOCALNAME__70_6C_61_79_62_61_63_6B_6F_72_64_65_72 } ; (repeatcount) => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_63_6F_75_6E_74 } ; (laplacian) => { $ crate :: ATOM_LOCALNAME__6C_61_70_6C_61_63_69_61_6E } ; (patterncontentunits) => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_63_6F_6E_74_65_6E_74_75_6E_69_74_73 } ; (amplitude) => { $ crate :: ATOM_LOCALNAME__61_6D_70_6C_69_74_75_64_65 } ; (ononline) => { $ crate :: ATOM_LOCALNAME__6F_6E_6F_6E_6C_69_6E_65 } ; (clippath) => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_70_61_74_68 } ; (multicol) => { $ crate :: ATOM_LOCALNAME__6D_75_6C_74_69_63_6F_6C } ; (oncancel) => { $ crate :: ATOM_LOCALNAME__6F_6E_63_61_6E_63_65_6C } ; (numOctaves) => { $ crate :: ATOM_LOCALNAME__6E_75_6D_4F_63_74_61_76_65_73 } ; (scrolldelay) => { $ crate :: ATOM_LOCALNAME__73_63_72_6F_6C_6C_64_65_6C_61_79 } ; (onemptied) => { $ crate :: ATOM_LOCALNAME__6F_6E_65_6D_70_74_69_65_64 } ; (patternContentUnits) => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_43_6F_6E_74_65_6E_74_55_6E_69_74_73 } ; (imagesrcset) => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_65_73_72_63_73_65_74 } ; (altglyphitem) => { $ crate :: ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68_69_74_65_6D } ; (onselect) => { $ crate :: ATOM_LOCALNAME__6F_6E_73_65_6C_65_63_74 } ; (animateColor) => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_43_6F_6C_6F_72 } ; (selectedcontent) => { $ crate :: ATOM_LOCALNAME__73_65_6C_65_63_74_65_64_63_6F_6E_74_65_6E_74 } ; (viewtarget) => { $ crate :: ATOM_LOCALNAME__76_69_65_77_74_61_72_67_65_74 } ; (textcontent) => { $ crate :: ATOM_LOCALNAME__74_65_78_74_63_6F_6E_74_65_6E_74 } ; (onseeked) => { $ crate :: ATOM_LOCALNAME__6F_6E_73_65_65_6B_65_64 } ; (mediummathspace) => { $ crate :: ATOM_LOCALNAME__6D_65_64_69_75_6D_6D_61_74_68_73_70_61_63_65 } ; (accumulate) => { $ crate :: ATOM_LOCALNAME__61_63_63_75_6D_75_6C_61_74_65 } ; (onmouseleave) => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6C_65_61_76_65 } ; (contentstyletype) => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_73_74_79_6C_65_74_79_70_65 } ; (rowlines) => { $ crate :: ATOM_LOCALNAME__72_6F_77_6C_69_6E_65_73 } ; (menclose) => { $ crate :: ATOM_LOCALNAME__6D_65_6E_63_6C_6F_73_65 } ; (imagesizes) => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_65_73_69_7A_65_73 } ; (feconvolvematrix) => { $ crate :: ATOM_LOCALNAME__66_65_63_6F_6E_76_6F_6C_76_65_6D_61_74_72_69_78 } ; (selector) => { $ crate :: ATOM_LOCALNAME__73_65_6C_65_63_74_6F_72 } ; (fieldset) => { $ crate :: ATOM_LOCALNAME__66_69_65_6C_64_73_65_74 } ; (feComposite) => { $ crate :: ATOM_LOCALNAME__66_65_43_6F_6D_70_6F_73_69_74_65 } ; (keySplines) => { $ crate :: ATOM_LOCALNAME__6B_65_79_53_70_6C_69_6E_65_73 } ; (verythickmathspace) => { $ crate :: ATOM_LOCALNAME__76_65_72_79_74_68_69_63_6B_6D_61_74_68_73_70_61_63_65 } ; (blocking) => { $ crate :: ATOM_LOCALNAME__62_6C_6F_63_6B_69_6E_67 } ; (shadowrootslotassignment) => { $ crate :: ATOM_LOCALNAME__73_68_61_64_6F_77_72_6F_6F_74_73_6C_6F_74_61_73_73_69_67_6E_6D_65_6E_74 } ; (movablelimits) => { $ crate :: ATOM_LOCALNAME__6D_6F_76_61_62_6C_65_6C_69_6D_69_74_73 } ; (scriptlevel) => { $ crate :: ATOM_LOCALNAME__73_63_72_69_70_74_6C_65_76_65_6C } ; (onpageshow) => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_67_65_73_68_6F_77 } ; (onpopstate) => { $ crate :: ATOM_LOCALNAME__6F_6E_70_6F_70_73_74_61_74_65 } ; (onfocusin) => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_63_75_73_69_6E } ; (onratechange) => { $ crate :: ATOM_LOCALNAME__6F_6E_72_61_74_65_63_68_61_6E_67_65 } ; (determinant) => { $ crate :: ATOM_LOCALNAME__64_65_74_65_72_6D_69_6E_61_6E_74 } ; (optgroup) => { $ crate :: ATOM_LOCALNAME__6F_70_74_67_72_6F_75_70 } ; (linethickness) => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65_74_68_69_63_6B_6E_65_73_73 } ; (onrejectionhandled) => { $ crate :: ATOM_LOCALNAME__6F_6E_72_65_6A_65_63_74_69_6F_6E_68_61_6E_64_6C_65_64 } ; (markerunits) => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_75_6E_69_74_73 } ; (a) => { $ crate :: ATOM_LOCALNAME__61 } ; (abbr) => { $ crate :: ATOM_LOCALNAME__61_62_62_72 } ; (abs) => { $ crate :: ATOM_LOCALNAME__61_62_73 } ; (accent) => { $ crate :: ATOM_LOCALNAME__61_63_63_65_6E_74 } ; (accept) => { $ crate :: ATOM_LOCALNAME__61_63_63_65_70_74 } ; (acronym) => { $ crate :: ATOM_LOCALNAME__61_63_72_6F_6E_79_6D } ; (action) => { $ crate :: ATOM_LOCALNAME__61_63_74_69_6F_6E } ; (active) => { $ crate :: ATOM_LOCALNAME__61_63_74_69_76_65 } ; (actuate) => { $ crate :: ATOM_LOCALNAME__61_63_74_75_61_74_65 } ; (address) => { $ crate :: ATOM_LOCALNAME__61_64_64_72_65_73_73 } ; (align) => { $ crate :: ATOM_LOCALNAME__61_6C_69_67_6E } ; (alink) => { $ crate :: ATOM_LOCALNAME__61_6C_69_6E_6B } ; (allow) => { $ crate :: ATOM_LOCALNAME__61_6C_6C_6F_77 } ; (alpha) => { $ crate :: ATOM_LOCALNAME__61_6C_70_68_61 } ; (alt) => { $ crate :: ATOM_LOCALNAME__61_6C_74 } ; (altimg) => { $ crate :: ATOM_LOCALNAME__61_6C_74_69_6D_67 } ; (alttext) => { $ crate :: ATOM_LOCALNAME__61_6C_74_74_65_78_74 } ; (and) => { $ crate :: ATOM_LOCALNAME__61_6E_64 } ; (animate) => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65 } ; (applet) => { $ crate :: ATOM_LOCALNAME__61_70_70_6C_65_74 } ; (apply) => { $ crate :: ATOM_LOCALNAME__61_70_70_6C_79 } ; (approx) => { $ crate :: ATOM_LOCALNAME__61_70_70_72_6F_78 } ; (arccos) => { $ crate :: ATOM_LOCALNAME__61_72_63_63_6F_73 } ; (arccosh) => { $ crate :: ATOM_LOCALNAME__61_72_63_63_6F_73_68 } ; (arccot) => { $ crate :: ATOM_LOCALNAME__61_72_63_63_6F_74 } ; (arccoth) => { $ crate :: ATOM_LOCALNAME__61_72_63_63_6F_74_68 } ; (arccsc) => { $ crate :: ATOM_LOCALNAME__61_72_63_63_73_63 } ; (arccsch) => { $ crate :: ATOM_LOCALNAME__61_72_63_63_73_63_68 } ; (archive) => { $ crate :: ATOM_LOCALNAME__61_72_63_68_69_76_65 } ; (arcrole) => { $ crate :: ATOM_LOCALNAME__61_72_63_72_6F_6C_65 } ; (arcsec) => { $ crate :: ATOM_LOCALNAME__61_72_63_73_65_63 } ; (arcsech) => { $ crate :: ATOM_LOCALNAME__61_72_63_73_65_63_68 } ; (arcsin) => { $ crate :: ATOM_LOCALNAME__61_72_63_73_69_6E } ; (arcsinh) => { $ crate :: ATOM_LOCALNAME__61_72_63_73_69_6E_68 } ; (arctan) => { $ crate :: ATOM_LOCALNAME__61_72_63_74_61_6E } ; (arctanh) => { $ crate :: ATOM_LOCALNAME__61_72_63_74_61_6E_68 } ; (area) => { $ crate :: ATOM_LOCALNAME__61_72_65_61 } ; (arg) => { $ crate :: ATOM_LOCALNAME__61_72_67 } ; (article) => { $ crate :: ATOM_LOCALNAME__61_72_74_69_63_6C_65 } ; (as) => { $ crate :: ATOM_LOCALNAME__61_73 } ; (ascent) => { $ crate :: ATOM_LOCALNAME__61_73_63_65_6E_74 } ; (aside) => { $ crate :: ATOM_LOCALNAME__61_73_69_64_65 } ; (async) => { $ crate :: ATOM_LOCALNAME__61_73_79_6E_63 } ; (audio) => { $ crate :: ATOM_LOCALNAME__61_75_64_69_6F } ; (axis) => { $ crate :: ATOM_LOCALNAME__61_78_69_73 } ; (azimuth) => { $ crate :: ATOM_LOCALNAME__61_7A_69_6D_75_74_68 } ; (b) => { $ crate :: ATOM_LOCALNAME__62 } ; (base) => { $ crate :: ATOM_LOCALNAME__62_61_73_65 } ; (bbox) => { $ crate :: ATOM_LOCALNAME__62_62_6F_78 } ; (bdi) => { $ crate :: ATOM_LOCALNAME__62_64_69 } ; (bdo) => { $ crate :: ATOM_LOCALNAME__62_64_6F } ; (begin) => { $ crate :: ATOM_LOCALNAME__62_65_67_69_6E } ; (bgcolor) => { $ crate :: ATOM_LOCALNAME__62_67_63_6F_6C_6F_72 } ; (bgsound) => { $ crate :: ATOM_LOCALNAME__62_67_73_6F_75_6E_64 } ; (bias) => { $ crate :: ATOM_LOCALNAME__62_69_61_73 } ; (big) => { $ crate :: ATOM_LOCALNAME__62_69_67 } ; (blink) => { $ crate :: ATOM_LOCALNAME__62_6C_69_6E_6B } ; (body) => { $ crate :: ATOM_LOCALNAME__62_6F_64_79 } ; (border) => { $ crate :: ATOM_LOCALNAME__62_6F_72_64_65_72 } ; (br) => { $ crate :: ATOM_LOCALNAME__62_72 } ; (button) => { $ crate :: ATOM_LOCALNAME__62_75_74_74_6F_6E } ; (bvar) => { $ crate :: ATOM_LOCALNAME__62_76_61_72 } ; (by) => { $ crate :: ATOM_LOCALNAME__62_79 } ; (canvas) => { $ crate :: ATOM_LOCALNAME__63_61_6E_76_61_73 } ; (caption) => { $ crate :: ATOM_LOCALNAME__63_61_70_74_69_6F_6E } ; (card) => { $ crate :: ATOM_LOCALNAME__63_61_72_64 } ; (ceiling) => { $ crate :: ATOM_LOCALNAME__63_65_69_6C_69_6E_67 } ; (center) => { $ crate :: ATOM_LOCALNAME__63_65_6E_74_65_72 } ; (char) => { $ crate :: ATOM_LOCALNAME__63_68_61_72 } ; (charoff) => { $ crate :: ATOM_LOCALNAME__63_68_61_72_6F_66_66 } ; (charset) => { $ crate :: ATOM_LOCALNAME__63_68_61_72_73_65_74 } ; (checked) => { $ crate :: ATOM_LOCALNAME__63_68_65_63_6B_65_64 } ; (ci) => { $ crate :: ATOM_LOCALNAME__63_69 } ; (circle) => { $ crate :: ATOM_LOCALNAME__63_69_72_63_6C_65 } ; (cite) => { $ crate :: ATOM_LOCALNAME__63_69_74_65 } ; (class) => { $ crate :: ATOM_LOCALNAME__63_6C_61_73_73 } ; (classid) => { $ crate :: ATOM_LOCALNAME__63_6C_61_73_73_69_64 } ; (clear) => { $ crate :: ATOM_LOCALNAME__63_6C_65_61_72 } ; (clip) => { $ crate :: ATOM_LOCALNAME__63_6C_69_70 } ; (close) => { $ crate :: ATOM_LOCALNAME__63_6C_6F_73_65 } ; (closure) => { $ crate :: ATOM_LOCALNAME__63_6C_6F_73_75_72_65 } ; (cn) => { $ crate :: ATOM_LOCALNAME__63_6E } ; (code) => { $ crate :: ATOM_LOCALNAME__63_6F_64_65 } ; (col) => { $ crate :: ATOM_LOCALNAME__63_6F_6C } ; (color) => { $ crate :: ATOM_LOCALNAME__63_6F_6C_6F_72 } ; (cols) => { $ crate :: ATOM_LOCALNAME__63_6F_6C_73 } ; (colspan) => { $ crate :: ATOM_LOCALNAME__63_6F_6C_73_70_61_6E } ; (command) => { $ crate :: ATOM_LOCALNAME__63_6F_6D_6D_61_6E_64 } ; (compact) => { $ crate :: ATOM_LOCALNAME__63_6F_6D_70_61_63_74 } ; (compose) => { $ crate :: ATOM_LOCALNAME__63_6F_6D_70_6F_73_65 } ; (content) => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74 } ; (coords) => { $ crate :: ATOM_LOCALNAME__63_6F_6F_72_64_73 } ; (cos) => { $ crate :: ATOM_LOCALNAME__63_6F_73 } ; (cosh) => { $ crate :: ATOM_LOCALNAME__63_6F_73_68 } ; (cot) => { $ crate :: ATOM_LOCALNAME__63_6F_74 } ; (coth) => { $ crate :: ATOM_LOCALNAME__63_6F_74_68 } ; (csc) => { $ crate :: ATOM_LOCALNAME__63_73_63 } ; (csch) => { $ crate :: ATOM_LOCALNAME__63_73_63_68 } ; (csymbol) => { $ crate :: ATOM_LOCALNAME__63_73_79_6D_62_6F_6C } ; (curl) => { $ crate :: ATOM_LOCALNAME__63_75_72_6C } ; (cursor) => { $ crate :: ATOM_LOCALNAME__63_75_72_73_6F_72 } ; (cx) => { $ crate :: ATOM_LOCALNAME__63_78 } ; (cy) => { $ crate :: ATOM_LOCALNAME__63_79 } ; (d) => { $ crate :: ATOM_LOCALNAME__64 } ; (data) => { $ crate :: ATOM_LOCALNAME__64_61_74_61 } ; (datafld) => { $ crate :: ATOM_LOCALNAME__64_61_74_61_66_6C_64 } ; (datasrc) => { $ crate :: ATOM_LOCALNAME__64_61_74_61_73_72_63 } ; (dd) => { $ crate :: ATOM_LOCALNAME__64_64 } ; (declare) => { $ crate :: ATOM_LOCALNAME__64_65_63_6C_61_72_65 } ; (default) => { $ crate :: ATOM_LOCALNAME__64_65_66_61_75_6C_74 } ; (defer) => { $ crate :: ATOM_LOCALNAME__64_65_66_65_72 } ; (defs) => { $ crate :: ATOM_LOCALNAME__64_65_66_73 } ; (degree) => { $ crate :: ATOM_LOCALNAME__64_65_67_72_65_65 } ; (del) => { $ crate :: ATOM_LOCALNAME__64_65_6C } ; (depth) => { $ crate :: ATOM_LOCALNAME__64_65_70_74_68 } ; (desc) => { $ crate :: ATOM_LOCALNAME__64_65_73_63 } ; (descent) => { $ crate :: ATOM_LOCALNAME__64_65_73_63_65_6E_74 } ; (details) => { $ crate :: ATOM_LOCALNAME__64_65_74_61_69_6C_73 } ; (dfn) => { $ crate :: ATOM_LOCALNAME__64_66_6E } ; (dialog) => { $ crate :: ATOM_LOCALNAME__64_69_61_6C_6F_67 } ; (diff) => { $ crate :: ATOM_LOCALNAME__64_69_66_66 } ; (dir) => { $ crate :: ATOM_LOCALNAME__64_69_72 } ; (dirname) => { $ crate :: ATOM_LOCALNAME__64_69_72_6E_61_6D_65 } ; (discard) => { $ crate :: ATOM_LOCALNAME__64_69_73_63_61_72_64 } ; (display) => { $ crate :: ATOM_LOCALNAME__64_69_73_70_6C_61_79 } ; (div) => { $ crate :: ATOM_LOCALNAME__64_69_76 } ; (divide) => { $ crate :: ATOM_LOCALNAME__64_69_76_69_64_65 } ; (divisor) => { $ crate :: ATOM_LOCALNAME__64_69_76_69_73_6F_72 } ; (dl) => { $ crate :: ATOM_LOCALNAME__64_6C } ; (domain) => { $ crate :: ATOM_LOCALNAME__64_6F_6D_61_69_6E } ; (dt) => { $ crate :: ATOM_LOCALNAME__64_74 } ; (dur) => { $ crate :: ATOM_LOCALNAME__64_75_72 } ; (dx) => { $ crate :: ATOM_LOCALNAME__64_78 } ; (dy) => { $ crate :: ATOM_LOCALNAME__64_79 } ; (edge) => { $ crate :: ATOM_LOCALNAME__65_64_67_65 } ; (ellipse) => { $ crate :: ATOM_LOCALNAME__65_6C_6C_69_70_73_65 } ; (em) => { $ crate :: ATOM_LOCALNAME__65_6D } ; (embed) => { $ crate :: ATOM_LOCALNAME__65_6D_62_65_64 } ; (enctype) => { $ crate :: ATOM_LOCALNAME__65_6E_63_74_79_70_65 } ; (end) => { $ crate :: ATOM_LOCALNAME__65_6E_64 } ; (eq) => { $ crate :: ATOM_LOCALNAME__65_71 } ; (event) => { $ crate :: ATOM_LOCALNAME__65_76_65_6E_74 } ; (exists) => { $ crate :: ATOM_LOCALNAME__65_78_69_73_74_73 } ; (exp) => { $ crate :: ATOM_LOCALNAME__65_78_70 } ; (face) => { $ crate :: ATOM_LOCALNAME__66_61_63_65 } ; (false) => { $ crate :: ATOM_LOCALNAME__66_61_6C_73_65 } ; (feBlend) => { $ crate :: ATOM_LOCALNAME__66_65_42_6C_65_6E_64 } ; (feFlood) => { $ crate :: ATOM_LOCALNAME__66_65_46_6C_6F_6F_64 } ; (feFuncA) => { $ crate :: ATOM_LOCALNAME__66_65_46_75_6E_63_41 } ; (feFuncB) => { $ crate :: ATOM_LOCALNAME__66_65_46_75_6E_63_42 } ; (feFuncG) => { $ crate :: ATOM_LOCALNAME__66_65_46_75_6E_63_47 } ; (feFuncR) => { $ crate :: ATOM_LOCALNAME__66_65_46_75_6E_63_52 } ; (feImage) => { $ crate :: ATOM_LOCALNAME__66_65_49_6D_61_67_65 } ; (feMerge) => { $ crate :: ATOM_LOCALNAME__66_65_4D_65_72_67_65 } ; (feTile) => { $ crate :: ATOM_LOCALNAME__66_65_54_69_6C_65 } ; (feblend) => { $ crate :: ATOM_LOCALNAME__66_65_62_6C_65_6E_64 } ; (feflood) => { $ crate :: ATOM_LOCALNAME__66_65_66_6C_6F_6F_64 } ; (fefunca) => { $ crate :: ATOM_LOCALNAME__66_65_66_75_6E_63_61 } ; (fefuncb) => { $ crate :: ATOM_LOCALNAME__66_65_66_75_6E_63_62 } ; (fefuncg) => { $ crate :: ATOM_LOCALNAME__66_65_66_75_6E_63_67 } ; (fefuncr) => { $ crate :: ATOM_LOCALNAME__66_65_66_75_6E_63_72 } ; (feimage) => { $ crate :: ATOM_LOCALNAME__66_65_69_6D_61_67_65 } ; (femerge) => { $ crate :: ATOM_LOCALNAME__66_65_6D_65_72_67_65 } ; (fence) => { $ crate :: ATOM_LOCALNAME__66_65_6E_63_65 } ; (fetch) => { $ crate :: ATOM_LOCALNAME__66_65_74_63_68 } ; (fetile) => { $ crate :: ATOM_LOCALNAME__66_65_74_69_6C_65 } ; (figure) => { $ crate :: ATOM_LOCALNAME__66_69_67_75_72_65 } ; (fill) => { $ crate :: ATOM_LOCALNAME__66_69_6C_6C } ; (filter) => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72 } ; (floor) => { $ crate :: ATOM_LOCALNAME__66_6C_6F_6F_72 } ; (fn) => { $ crate :: ATOM_LOCALNAME__66_6E } ; (font) => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74 } ; (footer) => { $ crate :: ATOM_LOCALNAME__66_6F_6F_74_65_72 } ; (for) => { $ crate :: ATOM_LOCALNAME__66_6F_72 } ; (forall) => { $ crate :: ATOM_LOCALNAME__66_6F_72_61_6C_6C } ; (form) => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D } ; (format) => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_61_74 } ; (fr) => { $ crate :: ATOM_LOCALNAME__66_72 } ; (frame) => { $ crate :: ATOM_LOCALNAME__66_72_61_6D_65 } ; (from) => { $ crate :: ATOM_LOCALNAME__66_72_6F_6D } ; (fx) => { $ crate :: ATOM_LOCALNAME__66_78 } ; (fy) => { $ crate :: ATOM_LOCALNAME__66_79 } ; (g) => { $ crate :: ATOM_LOCALNAME__67 } ; (g1) => { $ crate :: ATOM_LOCALNAME__67_31 } ; (g2) => { $ crate :: ATOM_LOCALNAME__67_32 } ; (gcd) => { $ crate :: ATOM_LOCALNAME__67_63_64 } ; (geq) => { $ crate :: ATOM_LOCALNAME__67_65_71 } ; (glyph) => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68 } ; (grad) => { $ crate :: ATOM_LOCALNAME__67_72_61_64 } ; (gt) => { $ crate :: ATOM_LOCALNAME__67_74 } ; (h1) => { $ crate :: ATOM_LOCALNAME__68_31 } ; (h2) => { $ crate :: ATOM_LOCALNAME__68_32 } ; (h3) => { $ crate :: ATOM_LOCALNAME__68_33 } ; (h4) => { $ crate :: ATOM_LOCALNAME__68_34 } ; (h5) => { $ crate :: ATOM_LOCALNAME__68_35 } ; (h6) => { $ crate :: ATOM_LOCALNAME__68_36 } ; (handler) => { $ crate :: ATOM_LOCALNAME__68_61_6E_64_6C_65_72 } ; (hanging) => { $ crate :: ATOM_LOCALNAME__68_61_6E_67_69_6E_67 } ; (hatch) => { $ crate :: ATOM_LOCALNAME__68_61_74_63_68 } ; (head) => { $ crate :: ATOM_LOCALNAME__68_65_61_64 } ; (header) => { $ crate :: ATOM_LOCALNAME__68_65_61_64_65_72 } ; (headers) => { $ crate :: ATOM_LOCALNAME__68_65_61_64_65_72_73 } ; (height) => { $ crate :: ATOM_LOCALNAME__68_65_69_67_68_74 } ; (hgroup) => { $ crate :: ATOM_LOCALNAME__68_67_72_6F_75_70 } ; (hidden) => { $ crate :: ATOM_LOCALNAME__68_69_64_64_65_6E } ; (high) => { $ crate :: ATOM_LOCALNAME__68_69_67_68 } ; (hkern) => { $ crate :: ATOM_LOCALNAME__68_6B_65_72_6E } ; (hr) => { $ crate :: ATOM_LOCALNAME__68_72 } ; (href) => { $ crate :: ATOM_LOCALNAME__68_72_65_66 } ; (hspace) => { $ crate :: ATOM_LOCALNAME__68_73_70_61_63_65 } ; (html) => { $ crate :: ATOM_LOCALNAME__68_74_6D_6C } ; (i) => { $ crate :: ATOM_LOCALNAME__69 } ; (icon) => { $ crate :: ATOM_LOCALNAME__69_63_6F_6E } ; (id) => { $ crate :: ATOM_LOCALNAME__69_64 } ; (ident) => { $ crate :: ATOM_LOCALNAME__69_64_65_6E_74 } ; (iframe) => { $ crate :: ATOM_LOCALNAME__69_66_72_61_6D_65 } ; (image) => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_65 } ; (img) => { $ crate :: ATOM_LOCALNAME__69_6D_67 } ; (implies) => { $ crate :: ATOM_LOCALNAME__69_6D_70_6C_69_65_73 } ; (in) => { $ crate :: ATOM_LOCALNAME__69_6E } ; (in2) => { $ crate :: ATOM_LOCALNAME__69_6E_32 } ; (index) => { $ crate :: ATOM_LOCALNAME__69_6E_64_65_78 } ; (inert) => { $ crate :: ATOM_LOCALNAME__69_6E_65_72_74 } ; (input) => { $ crate :: ATOM_LOCALNAME__69_6E_70_75_74 } ; (ins) => { $ crate :: ATOM_LOCALNAME__69_6E_73 } ; (int) => { $ crate :: ATOM_LOCALNAME__69_6E_74 } ; (inverse) => { $ crate :: ATOM_LOCALNAME__69_6E_76_65_72_73_65 } ; (isindex) => { $ crate :: ATOM_LOCALNAME__69_73_69_6E_64_65_78 } ; (ismap) => { $ crate :: ATOM_LOCALNAME__69_73_6D_61_70 } ; (itemid) => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_69_64 } ; (itemref) => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_72_65_66 } ; (k) => { $ crate :: ATOM_LOCALNAME__6B } ; (k1) => { $ crate :: ATOM_LOCALNAME__6B_31 } ; (k2) => { $ crate :: ATOM_LOCALNAME__6B_32 } ; (k3) => { $ crate :: ATOM_LOCALNAME__6B_33 } ; (k4) => { $ crate :: ATOM_LOCALNAME__6B_34 } ; (kbd) => { $ crate :: ATOM_LOCALNAME__6B_62_64 } ; (kerning) => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_69_6E_67 } ; (keygen) => { $ crate :: ATOM_LOCALNAME__6B_65_79_67_65_6E } ; (kind) => { $ crate :: ATOM_LOCALNAME__6B_69_6E_64 } ; (label) => { $ crate :: ATOM_LOCALNAME__6C_61_62_65_6C } ; (lambda) => { $ crate :: ATOM_LOCALNAME__6C_61_6D_62_64_61 } ; (lang) => { $ crate :: ATOM_LOCALNAME__6C_61_6E_67 } ; (largeop) => { $ crate :: ATOM_LOCALNAME__6C_61_72_67_65_6F_70 } ; (lcm) => { $ crate :: ATOM_LOCALNAME__6C_63_6D } ; (legend) => { $ crate :: ATOM_LOCALNAME__6C_65_67_65_6E_64 } ; (leq) => { $ crate :: ATOM_LOCALNAME__6C_65_71 } ; (li) => { $ crate :: ATOM_LOCALNAME__6C_69 } ; (limit) => { $ crate :: ATOM_LOCALNAME__6C_69_6D_69_74 } ; (line) => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65 } ; (link) => { $ crate :: ATOM_LOCALNAME__6C_69_6E_6B } ; (list) => { $ crate :: ATOM_LOCALNAME__6C_69_73_74 } ; (listing) => { $ crate :: ATOM_LOCALNAME__6C_69_73_74_69_6E_67 } ; (ln) => { $ crate :: ATOM_LOCALNAME__6C_6E } ; (loading) => { $ crate :: ATOM_LOCALNAME__6C_6F_61_64_69_6E_67 } ; (local) => { $ crate :: ATOM_LOCALNAME__6C_6F_63_61_6C } ; (log) => { $ crate :: ATOM_LOCALNAME__6C_6F_67 } ; (logbase) => { $ crate :: ATOM_LOCALNAME__6C_6F_67_62_61_73_65 } ; (loop) => { $ crate :: ATOM_LOCALNAME__6C_6F_6F_70 } ; (low) => { $ crate :: ATOM_LOCALNAME__6C_6F_77 } ; (lowsrc) => { $ crate :: ATOM_LOCALNAME__6C_6F_77_73_72_63 } ; (lquote) => { $ crate :: ATOM_LOCALNAME__6C_71_75_6F_74_65 } ; (lspace) => { $ crate :: ATOM_LOCALNAME__6C_73_70_61_63_65 } ; (lt) => { $ crate :: ATOM_LOCALNAME__6C_74 } ; (macros) => { $ crate :: ATOM_LOCALNAME__6D_61_63_72_6F_73 } ; (maction) => { $ crate :: ATOM_LOCALNAME__6D_61_63_74_69_6F_6E } ; (main) => { $ crate :: ATOM_LOCALNAME__6D_61_69_6E } ; (map) => { $ crate :: ATOM_LOCALNAME__6D_61_70 } ; (mark) => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B } ; (marker) => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72 } ; (marquee) => { $ crate :: ATOM_LOCALNAME__6D_61_72_71_75_65_65 } ; (mask) => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B } ; (math) => { $ crate :: ATOM_LOCALNAME__6D_61_74_68 } ; (matrix) => { $ crate :: ATOM_LOCALNAME__6D_61_74_72_69_78 } ; (max) => { $ crate :: ATOM_LOCALNAME__6D_61_78 } ; (maxsize) => { $ crate :: ATOM_LOCALNAME__6D_61_78_73_69_7A_65 } ; (mean) => { $ crate :: ATOM_LOCALNAME__6D_65_61_6E } ; (media) => { $ crate :: ATOM_LOCALNAME__6D_65_64_69_61 } ; (median) => { $ crate :: ATOM_LOCALNAME__6D_65_64_69_61_6E } ; (menu) => { $ crate :: ATOM_LOCALNAME__6D_65_6E_75 } ; (merror) => { $ crate :: ATOM_LOCALNAME__6D_65_72_72_6F_72 } ; (meta) => { $ crate :: ATOM_LOCALNAME__6D_65_74_61 } ; (meter) => { $ crate :: ATOM_LOCALNAME__6D_65_74_65_72 } ; (method) => { $ crate :: ATOM_LOCALNAME__6D_65_74_68_6F_64 } ; (mfenced) => { $ crate :: ATOM_LOCALNAME__6D_66_65_6E_63_65_64 } ; (mfrac) => { $ crate :: ATOM_LOCALNAME__6D_66_72_61_63 } ; (mglyph) => { $ crate :: ATOM_LOCALNAME__6D_67_6C_79_70_68 } ; (mi) => { $ crate :: ATOM_LOCALNAME__6D_69 } ; (min) => { $ crate :: ATOM_LOCALNAME__6D_69_6E } ; (minsize) => { $ crate :: ATOM_LOCALNAME__6D_69_6E_73_69_7A_65 } ; (minus) => { $ crate :: ATOM_LOCALNAME__6D_69_6E_75_73 } ; (mn) => { $ crate :: ATOM_LOCALNAME__6D_6E } ; (mo) => { $ crate :: ATOM_LOCALNAME__6D_6F } ; (mode) => { $ crate :: ATOM_LOCALNAME__6D_6F_64_65 } ; (moment) => { $ crate :: ATOM_LOCALNAME__6D_6F_6D_65_6E_74 } ; (mover) => { $ crate :: ATOM_LOCALNAME__6D_6F_76_65_72 } ; (mpadded) => { $ crate :: ATOM_LOCALNAME__6D_70_61_64_64_65_64 } ; (mpath) => { $ crate :: ATOM_LOCALNAME__6D_70_61_74_68 } ; (mroot) => { $ crate :: ATOM_LOCALNAME__6D_72_6F_6F_74 } ; (mrow) => { $ crate :: ATOM_LOCALNAME__6D_72_6F_77 } ; (ms) => { $ crate :: ATOM_LOCALNAME__6D_73 } ; (mspace) => { $ crate :: ATOM_LOCALNAME__6D_73_70_61_63_65 } ; (msqrt) => { $ crate :: ATOM_LOCALNAME__6D_73_71_72_74 } ; (mstyle) => { $ crate :: ATOM_LOCALNAME__6D_73_74_79_6C_65 } ; (msub) => { $ crate :: ATOM_LOCALNAME__6D_73_75_62 } ; (msubsup) => { $ crate :: ATOM_LOCALNAME__6D_73_75_62_73_75_70 } ; (msup) => { $ crate :: ATOM_LOCALNAME__6D_73_75_70 } ; (mtable) => { $ crate :: ATOM_LOCALNAME__6D_74_61_62_6C_65 } ; (mtd) => { $ crate :: ATOM_LOCALNAME__6D_74_64 } ; (mtext) => { $ crate :: ATOM_LOCALNAME__6D_74_65_78_74 } ; (mtr) => { $ crate :: ATOM_LOCALNAME__6D_74_72 } ; (munder) => { $ crate :: ATOM_LOCALNAME__6D_75_6E_64_65_72 } ; (muted) => { $ crate :: ATOM_LOCALNAME__6D_75_74_65_64 } ; (name) => { $ crate :: ATOM_LOCALNAME__6E_61_6D_65 } ; (nargs) => { $ crate :: ATOM_LOCALNAME__6E_61_72_67_73 } ; (nav) => { $ crate :: ATOM_LOCALNAME__6E_61_76 } ; (neq) => { $ crate :: ATOM_LOCALNAME__6E_65_71 } ; (nest) => { $ crate :: ATOM_LOCALNAME__6E_65_73_74 } ; (nextid) => { $ crate :: ATOM_LOCALNAME__6E_65_78_74_69_64 } ; (nobr) => { $ crate :: ATOM_LOCALNAME__6E_6F_62_72 } ; (noembed) => { $ crate :: ATOM_LOCALNAME__6E_6F_65_6D_62_65_64 } ; (nohref) => { $ crate :: ATOM_LOCALNAME__6E_6F_68_72_65_66 } ; (nonce) => { $ crate :: ATOM_LOCALNAME__6E_6F_6E_63_65 } ; (none) => { $ crate :: ATOM_LOCALNAME__6E_6F_6E_65 } ; (noshade) => { $ crate :: ATOM_LOCALNAME__6E_6F_73_68_61_64_65 } ; (not) => { $ crate :: ATOM_LOCALNAME__6E_6F_74 } ; (notin) => { $ crate :: ATOM_LOCALNAME__6E_6F_74_69_6E } ; (nowrap) => { $ crate :: ATOM_LOCALNAME__6E_6F_77_72_61_70 } ; (object) => { $ crate :: ATOM_LOCALNAME__6F_62_6A_65_63_74 } ; (offset) => { $ crate :: ATOM_LOCALNAME__6F_66_66_73_65_74 } ; (ol) => { $ crate :: ATOM_LOCALNAME__6F_6C } ; (onabort) => { $ crate :: ATOM_LOCALNAME__6F_6E_61_62_6F_72_74 } ; (onbegin) => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_67_69_6E } ; (onblur) => { $ crate :: ATOM_LOCALNAME__6F_6E_62_6C_75_72 } ; (onclick) => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6C_69_63_6B } ; (onclose) => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6C_6F_73_65 } ; (oncopy) => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6F_70_79 } ; (oncut) => { $ crate :: ATOM_LOCALNAME__6F_6E_63_75_74 } ; (ondrag) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67 } ; (ondrop) => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_6F_70 } ; (onend) => { $ crate :: ATOM_LOCALNAME__6F_6E_65_6E_64 } ; (onended) => { $ crate :: ATOM_LOCALNAME__6F_6E_65_6E_64_65_64 } ; (onerror) => { $ crate :: ATOM_LOCALNAME__6F_6E_65_72_72_6F_72 } ; (onfocus) => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_63_75_73 } ; (onhelp) => { $ crate :: ATOM_LOCALNAME__6F_6E_68_65_6C_70 } ; (oninput) => { $ crate :: ATOM_LOCALNAME__6F_6E_69_6E_70_75_74 } ; (onkeyup) => { $ crate :: ATOM_LOCALNAME__6F_6E_6B_65_79_75_70 } ; (onload) => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_6F_61_64 } ; (onmove) => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_76_65 } ; (onpaste) => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_73_74_65 } ; (onpause) => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_75_73_65 } ; (onplay) => { $ crate :: ATOM_LOCALNAME__6F_6E_70_6C_61_79 } ; (onreset) => { $ crate :: ATOM_LOCALNAME__6F_6E_72_65_73_65_74 } ; (onshow) => { $ crate :: ATOM_LOCALNAME__6F_6E_73_68_6F_77 } ; (onstart) => { $ crate :: ATOM_LOCALNAME__6F_6E_73_74_61_72_74 } ; (onstop) => { $ crate :: ATOM_LOCALNAME__6F_6E_73_74_6F_70 } ; (onwheel) => { $ crate :: ATOM_LOCALNAME__6F_6E_77_68_65_65_6C } ; (onzoom) => { $ crate :: ATOM_LOCALNAME__6F_6E_7A_6F_6F_6D } ; (opacity) => { $ crate :: ATOM_LOCALNAME__6F_70_61_63_69_74_79 } ; (open) => { $ crate :: ATOM_LOCALNAME__6F_70_65_6E } ; (optimum) => { $ crate :: ATOM_LOCALNAME__6F_70_74_69_6D_75_6D } ; (option) => { $ crate :: ATOM_LOCALNAME__6F_70_74_69_6F_6E } ; (or) => { $ crate :: ATOM_LOCALNAME__6F_72 } ; (order) => { $ crate :: ATOM_LOCALNAME__6F_72_64_65_72 } ; (orient) => { $ crate :: ATOM_LOCALNAME__6F_72_69_65_6E_74 } ; (origin) => { $ crate :: ATOM_LOCALNAME__6F_72_69_67_69_6E } ; (other) => { $ crate :: ATOM_LOCALNAME__6F_74_68_65_72 } ; (output) => { $ crate :: ATOM_LOCALNAME__6F_75_74_70_75_74 } ; (p) => { $ crate :: ATOM_LOCALNAME__70 } ; (param) => { $ crate :: ATOM_LOCALNAME__70_61_72_61_6D } ; (parse) => { $ crate :: ATOM_LOCALNAME__70_61_72_73_65 } ; (part) => { $ crate :: ATOM_LOCALNAME__70_61_72_74 } ; (path) => { $ crate :: ATOM_LOCALNAME__70_61_74_68 } ; (pattern) => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E } ; (pi) => { $ crate :: ATOM_LOCALNAME__70_69 } ; (picture) => { $ crate :: ATOM_LOCALNAME__70_69_63_74_75_72_65 } ; (piece) => { $ crate :: ATOM_LOCALNAME__70_69_65_63_65 } ; (ping) => { $ crate :: ATOM_LOCALNAME__70_69_6E_67 } ; (pitch) => { $ crate :: ATOM_LOCALNAME__70_69_74_63_68 } ; (plus) => { $ crate :: ATOM_LOCALNAME__70_6C_75_73 } ; (points) => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73 } ; (polygon) => { $ crate :: ATOM_LOCALNAME__70_6F_6C_79_67_6F_6E } ; (popover) => { $ crate :: ATOM_LOCALNAME__70_6F_70_6F_76_65_72 } ; (poster) => { $ crate :: ATOM_LOCALNAME__70_6F_73_74_65_72 } ; (power) => { $ crate :: ATOM_LOCALNAME__70_6F_77_65_72 } ; (pre) => { $ crate :: ATOM_LOCALNAME__70_72_65 } ; (preload) => { $ crate :: ATOM_LOCALNAME__70_72_65_6C_6F_61_64 } ; (primes) => { $ crate :: ATOM_LOCALNAME__70_72_69_6D_65_73 } ; (product) => { $ crate :: ATOM_LOCALNAME__70_72_6F_64_75_63_74 } ; (profile) => { $ crate :: ATOM_LOCALNAME__70_72_6F_66_69_6C_65 } ; (prompt) => { $ crate :: ATOM_LOCALNAME__70_72_6F_6D_70_74 } ; (q) => { $ crate :: ATOM_LOCALNAME__71 } ; (r) => { $ crate :: ATOM_LOCALNAME__72 } ; (radius) => { $ crate :: ATOM_LOCALNAME__72_61_64_69_75_73 } ; (rb) => { $ crate :: ATOM_LOCALNAME__72_62 } ; (real) => { $ crate :: ATOM_LOCALNAME__72_65_61_6C } ; (reals) => { $ crate :: ATOM_LOCALNAME__72_65_61_6C_73 } ; (rect) => { $ crate :: ATOM_LOCALNAME__72_65_63_74 } ; (refX) => { $ crate :: ATOM_LOCALNAME__72_65_66_58 } ; (refY) => { $ crate :: ATOM_LOCALNAME__72_65_66_59 } ; (refx) => { $ crate :: ATOM_LOCALNAME__72_65_66_78 } ; (refy) => { $ crate :: ATOM_LOCALNAME__72_65_66_79 } ; (rel) => { $ crate :: ATOM_LOCALNAME__72_65_6C } ; (reln) => { $ crate :: ATOM_LOCALNAME__72_65_6C_6E } ; (rem) => { $ crate :: ATOM_LOCALNAME__72_65_6D } ; (repeat) => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74 } ; (replace) => { $ crate :: ATOM_LOCALNAME__72_65_70_6C_61_63_65 } ; (restart) => { $ crate :: ATOM_LOCALNAME__72_65_73_74_61_72_74 } ; (result) => { $ crate :: ATOM_LOCALNAME__72_65_73_75_6C_74 } ; (rev) => { $ crate :: ATOM_LOCALNAME__72_65_76 } ; (role) => { $ crate :: ATOM_LOCALNAME__72_6F_6C_65 } ; (root) => { $ crate :: ATOM_LOCALNAME__72_6F_6F_74 } ; (rotate) => { $ crate :: ATOM_LOCALNAME__72_6F_74_61_74_65 } ; (rows) => { $ crate :: ATOM_LOCALNAME__72_6F_77_73 } ; (rowspan) => { $ crate :: ATOM_LOCALNAME__72_6F_77_73_70_61_6E } ; (rp) => { $ crate :: ATOM_LOCALNAME__72_70 } ; (rquote) => { $ crate :: ATOM_LOCALNAME__72_71_75_6F_74_65 } ; (rspace) => { $ crate :: ATOM_LOCALNAME__72_73_70_61_63_65 } ; (rt) => { $ crate :: ATOM_LOCALNAME__72_74 } ; (rtc) => { $ crate :: ATOM_LOCALNAME__72_74_63 } ; (ruby) => { $ crate :: ATOM_LOCALNAME__72_75_62_79 } ; (rule) => { $ crate :: ATOM_LOCALNAME__72_75_6C_65 } ; (rules) => { $ crate :: ATOM_LOCALNAME__72_75_6C_65_73 } ; (rx) => { $ crate :: ATOM_LOCALNAME__72_78 } ; (ry) => { $ crate :: ATOM_LOCALNAME__72_79 } ; (s) => { $ crate :: ATOM_LOCALNAME__73 } ; (samp) => { $ crate :: ATOM_LOCALNAME__73_61_6D_70 } ; (sandbox) => { $ crate :: ATOM_LOCALNAME__73_61_6E_64_62_6F_78 } ; (scale) => { $ crate :: ATOM_LOCALNAME__73_63_61_6C_65 } ; (scheme) => { $ crate :: ATOM_LOCALNAME__73_63_68_65_6D_65 } ; (scope) => { $ crate :: ATOM_LOCALNAME__73_63_6F_70_65 } ; (scoped) => { $ crate :: ATOM_LOCALNAME__73_63_6F_70_65_64 } ; (script) => { $ crate :: ATOM_LOCALNAME__73_63_72_69_70_74 } ; (sdev) => { $ crate :: ATOM_LOCALNAME__73_64_65_76 } ; (search) => { $ crate :: ATOM_LOCALNAME__73_65_61_72_63_68 } ; (sec) => { $ crate :: ATOM_LOCALNAME__73_65_63 } ; (sech) => { $ crate :: ATOM_LOCALNAME__73_65_63_68 } ; (section) => { $ crate :: ATOM_LOCALNAME__73_65_63_74_69_6F_6E } ; (seed) => { $ crate :: ATOM_LOCALNAME__73_65_65_64 } ; (select) => { $ crate :: ATOM_LOCALNAME__73_65_6C_65_63_74 } ; (sep) => { $ crate :: ATOM_LOCALNAME__73_65_70 } ; (set) => { $ crate :: ATOM_LOCALNAME__73_65_74 } ; (setdiff) => { $ crate :: ATOM_LOCALNAME__73_65_74_64_69_66_66 } ; (shape) => { $ crate :: ATOM_LOCALNAME__73_68_61_70_65 } ; (show) => { $ crate :: ATOM_LOCALNAME__73_68_6F_77 } ; (side) => { $ crate :: ATOM_LOCALNAME__73_69_64_65 } ; (sin) => { $ crate :: ATOM_LOCALNAME__73_69_6E } ; (sinh) => { $ crate :: ATOM_LOCALNAME__73_69_6E_68 } ; (size) => { $ crate :: ATOM_LOCALNAME__73_69_7A_65 } ; (sizes) => { $ crate :: ATOM_LOCALNAME__73_69_7A_65_73 } ; (slope) => { $ crate :: ATOM_LOCALNAME__73_6C_6F_70_65 } ; (slot) => { $ crate :: ATOM_LOCALNAME__73_6C_6F_74 } ; (small) => { $ crate :: ATOM_LOCALNAME__73_6D_61_6C_6C } ; (source) => { $ crate :: ATOM_LOCALNAME__73_6F_75_72_63_65 } ; (space) => { $ crate :: ATOM_LOCALNAME__73_70_61_63_65 } ; (spacer) => { $ crate :: ATOM_LOCALNAME__73_70_61_63_65_72 } ; (spacing) => { $ crate :: ATOM_LOCALNAME__73_70_61_63_69_6E_67 } ; (span) => { $ crate :: ATOM_LOCALNAME__73_70_61_6E } ; (speed) => { $ crate :: ATOM_LOCALNAME__73_70_65_65_64 } ; (src) => { $ crate :: ATOM_LOCALNAME__73_72_63 } ; (srcdoc) => { $ crate :: ATOM_LOCALNAME__73_72_63_64_6F_63 } ; (srclang) => { $ crate :: ATOM_LOCALNAME__73_72_63_6C_61_6E_67 } ; (srcset) => { $ crate :: ATOM_LOCALNAME__73_72_63_73_65_74 } ; (standby) => { $ crate :: ATOM_LOCALNAME__73_74_61_6E_64_62_79 } ; (start) => { $ crate :: ATOM_LOCALNAME__73_74_61_72_74 } ; (stemh) => { $ crate :: ATOM_LOCALNAME__73_74_65_6D_68 } ; (stemv) => { $ crate :: ATOM_LOCALNAME__73_74_65_6D_76 } ; (step) => { $ crate :: ATOM_LOCALNAME__73_74_65_70 } ; (stop) => { $ crate :: ATOM_LOCALNAME__73_74_6F_70 } ; (strike) => { $ crate :: ATOM_LOCALNAME__73_74_72_69_6B_65 } ; (string) => { $ crate :: ATOM_LOCALNAME__73_74_72_69_6E_67 } ; (stroke) => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65 } ; (strong) => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6E_67 } ; (style) => { $ crate :: ATOM_LOCALNAME__73_74_79_6C_65 } ; (sub) => { $ crate :: ATOM_LOCALNAME__73_75_62 } ; (subset) => { $ crate :: ATOM_LOCALNAME__73_75_62_73_65_74 } ; (sum) => { $ crate :: ATOM_LOCALNAME__73_75_6D } ; (summary) => { $ crate :: ATOM_LOCALNAME__73_75_6D_6D_61_72_79 } ; (sup) => { $ crate :: ATOM_LOCALNAME__73_75_70 } ; (svg) => { $ crate :: ATOM_LOCALNAME__73_76_67 } ; (switch) => { $ crate :: ATOM_LOCALNAME__73_77_69_74_63_68 } ; (symbol) => { $ crate :: ATOM_LOCALNAME__73_79_6D_62_6F_6C } ; (table) => { $ crate :: ATOM_LOCALNAME__74_61_62_6C_65 } ; (tan) => { $ crate :: ATOM_LOCALNAME__74_61_6E } ; (tanh) => { $ crate :: ATOM_LOCALNAME__74_61_6E_68 } ; (target) => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74 } ; (targetX) => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74_58 } ; (targetY) => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74_59 } ; (targetx) => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74_78 } ; (targety) => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74_79 } ; (tbody) => { $ crate :: ATOM_LOCALNAME__74_62_6F_64_79 } ; (tbreak) => { $ crate :: ATOM_LOCALNAME__74_62_72_65_61_6B } ; (td) => { $ crate :: ATOM_LOCALNAME__74_64 } ; (tendsto) => { $ crate :: ATOM_LOCALNAME__74_65_6E_64_73_74_6F } ; (text) => { $ crate :: ATOM_LOCALNAME__74_65_78_74 } ; (tfoot) => { $ crate :: ATOM_LOCALNAME__74_66_6F_6F_74 } ; (th) => { $ crate :: ATOM_LOCALNAME__74_68 } ; (thead) => { $ crate :: ATOM_LOCALNAME__74_68_65_61_64 } ; (time) => { $ crate :: ATOM_LOCALNAME__74_69_6D_65 } ; (times) => { $ crate :: ATOM_LOCALNAME__74_69_6D_65_73 } ; (title) => { $ crate :: ATOM_LOCALNAME__74_69_74_6C_65 } ; (to) => { $ crate :: ATOM_LOCALNAME__74_6F } ; (toggle) => { $ crate :: ATOM_LOCALNAME__74_6F_67_67_6C_65 } ; (tr) => { $ crate :: ATOM_LOCALNAME__74_72 } ; (track) => { $ crate :: ATOM_LOCALNAME__74_72_61_63_6B } ; (tref) => { $ crate :: ATOM_LOCALNAME__74_72_65_66 } ; (true) => { $ crate :: ATOM_LOCALNAME__74_72_75_65 } ; (tspan) => { $ crate :: ATOM_LOCALNAME__74_73_70_61_6E } ; (tt) => { $ crate :: ATOM_LOCALNAME__74_74 } ; (type) => { $ crate :: ATOM_LOCALNAME__74_79_70_65 } ; (u) => { $ crate :: ATOM_LOCALNAME__75 } ; (u1) => { $ crate :: ATOM_LOCALNAME__75_31 } ; (u2) => { $ crate :: ATOM_LOCALNAME__75_32 } ; (ul) => { $ crate :: ATOM_LOCALNAME__75_6C } ; (unicode) => { $ crate :: ATOM_LOCALNAME__75_6E_69_63_6F_64_65 } ; (union) => { $ crate :: ATOM_LOCALNAME__75_6E_69_6F_6E } ; (uplimit) => { $ crate :: ATOM_LOCALNAME__75_70_6C_69_6D_69_74 } ; (use) => { $ crate :: ATOM_LOCALNAME__75_73_65 } ; (usemap) => { $ crate :: ATOM_LOCALNAME__75_73_65_6D_61_70 } ; (valign) => { $ crate :: ATOM_LOCALNAME__76_61_6C_69_67_6E } ; (value) => { $ crate :: ATOM_LOCALNAME__76_61_6C_75_65 } ; (values) => { $ crate :: ATOM_LOCALNAME__76_61_6C_75_65_73 } ; (var) => { $ crate :: ATOM_LOCALNAME__76_61_72 } ; (vector) => { $ crate :: ATOM_LOCALNAME__76_65_63_74_6F_72 } ; (version) => { $ crate :: ATOM_LOCALNAME__76_65_72_73_69_6F_6E } ; (video) => { $ crate :: ATOM_LOCALNAME__76_69_64_65_6F } ; (view) => { $ crate :: ATOM_LOCALNAME__76_69_65_77 } ; (viewBox) => { $ crate :: ATOM_LOCALNAME__76_69_65_77_42_6F_78 } ; (viewbox) => { $ crate :: ATOM_LOCALNAME__76_69_65_77_62_6F_78 } ; (vkern) => { $ crate :: ATOM_LOCALNAME__76_6B_65_72_6E } ; (vlink) => { $ crate :: ATOM_LOCALNAME__76_6C_69_6E_6B } ; (vspace) => { $ crate :: ATOM_LOCALNAME__76_73_70_61_63_65 } ; (wbr) => { $ crate :: ATOM_LOCALNAME__77_62_72 } ; (when) => { $ crate :: ATOM_LOCALNAME__77_68_65_6E } ; (width) => { $ crate :: ATOM_LOCALNAME__77_69_64_74_68 } ; (widths) => { $ crate :: ATOM_LOCALNAME__77_69_64_74_68_73 } ; (wrap) => { $ crate :: ATOM_LOCALNAME__77_72_61_70 } ; (x) => { $ crate :: ATOM_LOCALNAME__78 } ; (x1) => { $ crate :: ATOM_LOCALNAME__78_31 } ; (x2) => { $ crate :: ATOM_LOCALNAME__78_32 } ; (xlink) => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B } ; (xmlns) => { $ crate :: ATOM_LOCALNAME__78_6D_6C_6E_73 } ; (xmp) => { $ crate :: ATOM_LOCALNAME__78_6D_70 } ; (xor) => { $ crate :: ATOM_LOCALNAME__78_6F_72 } ; (xref) => { $ crate :: ATOM_LOCALNAME__78_72_65_66 } ; (y) => { $ crate :: ATOM_LOCALNAME__79 } ; (y1) => { $ crate :: ATOM_LOCALNAME__79_31 } ; (y2) => { $ crate :: ATOM_LOCALNAME__79_32 } ; (z) => { $ crate :: ATOM_LOCALNAME__7A } ; }
+pub type Prefix = ::string_cache::Atom<PrefixStaticSet>;
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct PrefixStaticSet;
+impl ::string_cache::StaticAtomSet for PrefixStaticSet {
+    fn get() -> &'static ::string_cache::PhfStrSet {
+        static SET: ::string_cache::PhfStrSet = ::string_cache::PhfStrSet {
+            key: 16287231350648472473u64,
+            disps: &[(0u32, 0u32)],
+            atoms: &["        "],
+            hashes: &[9439000296423054886u64],
+        };
+        &SET
+    }
+}
+pub const ATOM_PREFIX__20_20_20_20_20_20_20_20: Prefix = Prefix::pack_static(0u32);
+pub const ATOM_PREFIX_: Prefix = Prefix::pack_inline(0u64, 0u8);
+pub const ATOM_PREFIX__2A: Prefix = Prefix::pack_inline(10752u64, 1u8);
+pub const ATOM_PREFIX__68_74_6D_6C: Prefix = Prefix::pack_inline(465692813312u64, 4u8);
+pub const ATOM_PREFIX__6D_61_74_68_6D_6C: Prefix = Prefix::pack_inline(30519592881319168u64, 6u8);
+pub const ATOM_PREFIX__73_76_67: Prefix = Prefix::pack_inline(1735815936u64, 3u8);
+pub const ATOM_PREFIX__78_6C_69_6E_6B: Prefix = Prefix::pack_inline(118121959290880u64, 5u8);
+pub const ATOM_PREFIX__78_6D_6C: Prefix = Prefix::pack_inline(1819113472u64, 3u8);
+pub const ATOM_PREFIX__78_6D_6C_6E_73: Prefix = Prefix::pack_inline(126918102710272u64, 5u8);
+#[doc = "Takes a namespace prefix string and returns its key in a string cache."]
+#[macro_export]
+macro_rules! namespace_prefix {
+    ("        ") => {
+        $crate::ATOM_PREFIX__20_20_20_20_20_20_20_20
+    };
+    ("") => {
+        $crate::ATOM_PREFIX_
+    };
+    ("*") => {
+        $crate::ATOM_PREFIX__2A
+    };
+    ("html") => {
+        $crate::ATOM_PREFIX__68_74_6D_6C
+    };
+    ("mathml") => {
+        $crate::ATOM_PREFIX__6D_61_74_68_6D_6C
+    };
+    ("svg") => {
+        $crate::ATOM_PREFIX__73_76_67
+    };
+    ("xlink") => {
+        $crate::ATOM_PREFIX__78_6C_69_6E_6B
+    };
+    ("xml") => {
+        $crate::ATOM_PREFIX__78_6D_6C
+    };
+    ("xmlns") => {
+        $crate::ATOM_PREFIX__78_6D_6C_6E_73
+    };
+    (html) => {
+        $crate::ATOM_PREFIX__68_74_6D_6C
+    };
+    (mathml) => {
+        $crate::ATOM_PREFIX__6D_61_74_68_6D_6C
+    };
+    (svg) => {
+        $crate::ATOM_PREFIX__73_76_67
+    };
+    (xlink) => {
+        $crate::ATOM_PREFIX__78_6C_69_6E_6B
+    };
+    (xml) => {
+        $crate::ATOM_PREFIX__78_6D_6C
+    };
+    (xmlns) => {
+        $crate::ATOM_PREFIX__78_6D_6C_6E_73
+    };
+}
+pub type Namespace = ::string_cache::Atom<NamespaceStaticSet>;
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct NamespaceStaticSet;
+impl ::string_cache::StaticAtomSet for NamespaceStaticSet {
+    fn get() -> &'static ::string_cache::PhfStrSet {
+        static SET: ::string_cache::PhfStrSet = ::string_cache::PhfStrSet {
+            key: 16287231350648472473u64,
+            disps: &[(0u32, 0u32), (3u32, 2u32)],
+            atoms: &[
+                "http://www.w3.org/XML/1998/namespace",
+                "http://www.w3.org/2000/xmlns/",
+                "http://www.w3.org/1998/Math/MathML",
+                "http://www.w3.org/1999/xhtml",
+                "http://www.w3.org/1999/xlink",
+                "http://www.w3.org/2000/svg",
+            ],
+            hashes: &[
+                4583176406360530153u64,
+                4745262417691772193u64,
+                4450797095258653163u64,
+                6944248246210969779u64,
+                15922398447564758006u64,
+                720297225963605210u64,
+            ],
+        };
+        &SET
+    }
+}
+pub const ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_58_4D_4C_2F_31_39_39_38_2F_6E_61_6D_65_73_70_61_63_65 : Namespace = Namespace :: pack_static (0u32) ;
+pub const ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_32_30_30_30_2F_78_6D_6C_6E_73_2F : Namespace = Namespace :: pack_static (1u32) ;
+pub const ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_31_39_39_38_2F_4D_61_74_68_2F_4D_61_74_68_4D_4C : Namespace = Namespace :: pack_static (2u32) ;
+pub const ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_31_39_39_39_2F_78_68_74_6D_6C : Namespace = Namespace :: pack_static (3u32) ;
+pub const ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_31_39_39_39_2F_78_6C_69_6E_6B : Namespace = Namespace :: pack_static (4u32) ;
+pub const ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_32_30_30_30_2F_73_76_67 : Namespace = Namespace :: pack_static (5u32) ;
+pub const ATOM_NAMESPACE_: Namespace = Namespace::pack_inline(0u64, 0u8);
+pub const ATOM_NAMESPACE__2A: Namespace = Namespace::pack_inline(10752u64, 1u8);
+#[doc = "Takes a namespace url string and returns its key in a string cache."]
+#[macro_export]
+macro_rules ! namespace_url { ("http://www.w3.org/XML/1998/namespace") => { $ crate :: ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_58_4D_4C_2F_31_39_39_38_2F_6E_61_6D_65_73_70_61_63_65 } ; ("http://www.w3.org/2000/xmlns/") => { $ crate :: ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_32_30_30_30_2F_78_6D_6C_6E_73_2F } ; ("http://www.w3.org/1998/Math/MathML") => { $ crate :: ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_31_39_39_38_2F_4D_61_74_68_2F_4D_61_74_68_4D_4C } ; ("http://www.w3.org/1999/xhtml") => { $ crate :: ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_31_39_39_39_2F_78_68_74_6D_6C } ; ("http://www.w3.org/1999/xlink") => { $ crate :: ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_31_39_39_39_2F_78_6C_69_6E_6B } ; ("http://www.w3.org/2000/svg") => { $ crate :: ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_32_30_30_30_2F_73_76_67 } ; ("") => { $ crate :: ATOM_NAMESPACE_ } ; ("*") => { $ crate :: ATOM_NAMESPACE__2A } ; }
+
+        /// Maps the input of [`namespace_prefix!`](macro.namespace_prefix.html) to
+        /// the output of [`namespace_url!`](macro.namespace_url.html).
+        ///
+        #[macro_export] macro_rules! ns {
+        
+() => { $crate::namespace_url!("") };
+(*) => { $crate::namespace_url!("*") };
+(html) => { $crate::namespace_url!("http://www.w3.org/1999/xhtml") };
+(xml) => { $crate::namespace_url!("http://www.w3.org/XML/1998/namespace") };
+(xmlns) => { $crate::namespace_url!("http://www.w3.org/2000/xmlns/") };
+(xlink) => { $crate::namespace_url!("http://www.w3.org/1999/xlink") };
+(svg) => { $crate::namespace_url!("http://www.w3.org/2000/svg") };
+(mathml) => { $crate::namespace_url!("http://www.w3.org/1998/Math/MathML") };
+}
@@ -0,0 +1,674 @@
+#![cfg(any(feature = "kv-mem", feature = "kv-rocksdb"))]
+
+use chrono::Local;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use std::{net::SocketAddr, num::NonZeroU16, time::Duration};
+use tokio::time::timeout;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_surreal::{
+    stop::{
+        surrealdb::{engine::local::Db, RecordId, Value},
+        MsgPage, Stop, StopBuilder, StopError, SCHEMA_VERSION,
+    },
+    tracing_msg::{
+        Callsite, CallsiteKind, ClientRole, CloseOk, GraceType, HelloMsg, Level, MsgBody,
+        MsgFormat, ObserveMsg, Parent, Payload, PushMsg, QueryHistory, Role, Sampling, SpanFrame,
+        SpanId, SpanTiming, Structured, TraceContext, TracingLayerDefault, TracingMsg,
+        Value as FieldValue,
+    },
+};
+
+// Every test gets its own database, since the session's tables are named after the second it started.
+#[cfg(feature = "kv-mem")]
+async fn builder(app: &str) -> StopBuilder<Db> {
+    Stop::builder_mem(app)
+        .await
+        .unwrap()
+        .disable_ctrlc_shutdown()
+}
+
+#[cfg(not(feature = "kv-mem"))]
+async fn builder(app: &str) -> StopBuilder<Db> {
+    let path = std::env::temp_dir().join(format!("tracing-surreal-{}", ulid::Ulid::new()));
+    Stop::builder_rocksdb(path, app)
+        .await
+        .unwrap()
+        .disable_ctrlc_shutdown()
+}
+
+fn hello(client_name: &str) -> HelloMsg {
+    HelloMsg {
+        client_name: client_name.into(),
+        proc_env: None,
+    }
+}
+
+fn addr() -> SocketAddr {
+    "127.0.0.1:8192".parse().unwrap()
+}
+
+async fn pusher_hello(stop: &Stop<Db>, client_name: &str) -> Stop<Db> {
+    stop.client_hello(
+        ClientRole::Pusher,
+        hello(client_name),
+        addr(),
+        MsgFormat::Msgpack,
+        None,
+        None,
+    )
+    .await
+    .unwrap()
+}
+
+// Pushes an event within a span through `MsgLayer`, and closes the transport on shutdown.
+async fn push_through_layer(stop: &Stop<Db>) {
+    let (layer, routine) = stop
+        .clone()
+        .tracing_layer_default()
+        .disable_ctrlc_shutdown()
+        .close_transport_on_shutdown()
+        .build();
+    let subscriber = tracing_subscriber::registry().with(layer);
+
+    // Kept until the shutdown, since the routine stops with `LayerDropped` once it's dropped.
+    let guard = tracing::subscriber::set_default(subscriber);
+    tracing::info_span!("work").in_scope(|| tracing::info!(answer = 42, "hello stop"));
+
+    routine.graceful_shutdown().await.unwrap().unwrap();
+    drop(guard);
+}
+
+#[derive(Deserialize)]
+struct SessionRow {
+    id: RecordId,
+    h_link_client: bool,
+    i_schema_version: u32,
+}
+
+#[derive(Deserialize)]
+struct ClientRow {
+    id: RecordId,
+    b_session_id: RecordId,
+    c_client_name: String,
+    d_client_role: Role,
+    e_msg_format: Option<MsgFormat>,
+    f_query_history: Option<QueryHistory>,
+    g_client_addr: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct MsgRow {
+    // Named, since `#[serde(flatten)]` can't buffer record links
+    #[serde(rename = "id")]
+    _id: RecordId,
+    session_id: RecordId,
+    client_id: RecordId,
+    callsite: Option<RecordId>,
+    #[serde(flatten)]
+    msg: TracingMsg,
+}
+
+#[derive(Deserialize)]
+struct DisconnectRow {
+    c_client_id: RecordId,
+    d_normal: bool,
+    e_ok_kind: Option<CloseOk>,
+}
+
+async fn clients(stop: &Stop<Db>) -> Vec<ClientRow> {
+    let table = format!("{}-clients", stop.formatted_timestamp());
+    stop.db().select(table).await.unwrap()
+}
+
+#[tokio::test]
+async fn init_registers_session_and_host() {
+    let (stop, _routine) = builder("init").await.init().await.unwrap();
+
+    let sessions: Vec<SessionRow> = stop.db().select(".sessions").await.unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(&sessions[0].id, stop.session_id());
+    assert!(sessions[0].h_link_client);
+    assert_eq!(sessions[0].i_schema_version, SCHEMA_VERSION);
+
+    let clients = clients(&stop).await;
+    assert_eq!(clients.len(), 1);
+    assert_eq!(&clients[0].id, stop.client_id());
+    assert_eq!(&clients[0].b_session_id, stop.session_id());
+    assert_eq!(clients[0].c_client_name, "host");
+    assert_eq!(clients[0].d_client_role, Role::host());
+    assert_eq!(clients[0].g_client_addr, None);
+}
+
+#[tokio::test]
+async fn client_hello_registers_clients() {
+    let (stop, _routine) = builder("hello").await.init().await.unwrap();
+
+    let pusher = pusher_hello(&stop, "pusher").await;
+    let observer = stop
+        .client_hello(
+            ClientRole::Observer,
+            hello("observer"),
+            addr(),
+            MsgFormat::Json,
+            Some(QueryHistory::Full),
+            None,
+        )
+        .await
+        .unwrap();
+    let res = stop
+        .client_hello(
+            ClientRole::Director,
+            hello("director"),
+            addr(),
+            MsgFormat::Json,
+            None,
+            None,
+        )
+        .await;
+    assert!(matches!(res, Err(StopError::MustFillQueryHistory)));
+
+    let clients = clients(&stop).await;
+    assert_eq!(clients.len(), 3);
+
+    let row = clients.iter().find(|row| &row.id == pusher.client_id());
+    let row = row.unwrap();
+    assert_eq!(row.c_client_name, "pusher");
+    assert_eq!(row.d_client_role, Role::pusher());
+    assert_eq!(row.e_msg_format, Some(MsgFormat::Msgpack));
+    assert_eq!(row.g_client_addr, Some(addr().to_string()));
+    assert_eq!(pusher.session_id(), stop.session_id());
+
+    let row = clients.iter().find(|row| &row.id == observer.client_id());
+    let row = row.unwrap();
+    assert_eq!(row.d_client_role, Role::observer());
+    assert_eq!(row.f_query_history, Some(QueryHistory::Full));
+
+    let res = observer.clone().bulk_push(Vec::new()).await;
+    assert!(matches!(res, Err(StopError::ObserverCannotPush)));
+}
+
+#[tokio::test]
+async fn bulk_push_and_close_transport() {
+    let (stop, _routine) = builder("push").await.init().await.unwrap();
+    let pusher = pusher_hello(&stop, "pusher").await;
+
+    pusher.clone().bulk_push(Vec::new()).await.unwrap();
+    push_through_layer(&pusher).await;
+
+    let ts = stop.formatted_timestamp();
+    let msgs: Vec<MsgRow> = stop.db().select(format!("{ts}-msg")).await.unwrap();
+    assert!(msgs.iter().all(|row| &row.client_id == pusher.client_id()));
+    assert!(msgs.iter().all(|row| &row.session_id == stop.session_id()));
+    assert!(msgs
+        .iter()
+        .all(|row| !matches!(row.msg.body, MsgBody::OnRegisterCallsite { .. })));
+
+    let event = msgs.iter().find(|row| match &row.msg.body {
+        MsgBody::OnEvent { message, .. } => message == "hello stop",
+        _ => false,
+    });
+    let event = event.unwrap();
+    assert!(event.callsite.is_some());
+    assert!(msgs
+        .iter()
+        .any(|row| matches!(row.msg.body, MsgBody::OnNewSpan { .. })));
+
+    let callsites: Vec<Callsite> = stop.db().select(format!("{ts}-callsites")).await.unwrap();
+    assert!(callsites.iter().any(|callsite| callsite.name == "work"));
+
+    let disconnects: Vec<DisconnectRow> =
+        stop.db().select(format!("{ts}-disconnects")).await.unwrap();
+    assert_eq!(disconnects.len(), 1);
+    assert_eq!(&disconnects[0].c_client_id, pusher.client_id());
+    assert!(disconnects[0].d_normal);
+    assert_eq!(
+        disconnects[0].e_ok_kind,
+        Some(CloseOk::Grace(GraceType::Explicit))
+    );
+}
+
+#[tokio::test]
+async fn live_query_routine_serves_observers() {
+    let (stop, routine) = builder("live").await.init().await.unwrap();
+    let mut first = stop.observe(Some(QueryHistory::None)).await.unwrap();
+    let mut second = stop.observe(Some(QueryHistory::None)).await.unwrap();
+    let pusher = pusher_hello(&stop, "pusher").await;
+
+    push_through_layer(&pusher).await;
+
+    for observer in [&mut first, &mut second] {
+        assert!(observer.history().is_empty());
+
+        let event = async {
+            loop {
+                if let ObserveMsg::OnMsg(_, info) = observer.next_live().await.unwrap() {
+                    if let MsgBody::OnEvent { message, .. } = info.tracing_msg.body {
+                        return (message, info.callsite);
+                    }
+                }
+            }
+        };
+        let (message, callsite) = timeout(Duration::from_secs(10), event)
+            .await
+            .expect("no live event");
+        assert_eq!(message, "hello stop");
+        assert_eq!(callsite.unwrap().fields, ["message", "answer"]);
+    }
+
+    let res = routine.graceful_shutdown().await.unwrap();
+    assert!(matches!(res, Ok(GraceType::Explicit)));
+    let res = stop.observe(None).await;
+    assert!(matches!(res, Err(StopError::RoutineStopped)));
+}
+
+#[tokio::test]
+async fn routine_stops_once_every_stop_is_dropped() {
+    let (stop, routine) = builder("drop").await.init().await.unwrap();
+    drop(stop);
+
+    let res = timeout(Duration::from_secs(10), routine)
+        .await
+        .expect("observe routine stuck")
+        .unwrap();
+    assert!(matches!(res, Err(StopError::RequesterDropped)));
+}
+
+#[tokio::test]
+async fn observe_backfills_history() {
+    let (stop, _routine) = builder("observe").await.init().await.unwrap();
+    let pusher = pusher_hello(&stop, "pusher").await;
+
+    push_through_layer(&pusher).await;
+
+    let res = pusher.observe(None).await;
+    assert!(matches!(res, Err(StopError::PusherCannotObserve)));
+    let res = pusher.observe(Some(QueryHistory::Full)).await;
+    assert!(matches!(res, Err(StopError::ClientCannotObserve)));
+
+    let mut observer = timeout(Duration::from_secs(10), stop.observe(None))
+        .await
+        .expect("observe request stuck")
+        .unwrap();
+    let history = observer.history();
+    let keys: Vec<_> = history.iter().map(ObserveMsg::get_msg_key).collect();
+    assert!(keys.windows(2).all(|w| w[0] < w[1]));
+    assert!(history.iter().any(|msg| match msg {
+        ObserveMsg::OnClientHello(id, _) => &RecordId::from(id.clone()) == pusher.client_id(),
+        _ => false,
+    }));
+    assert!(history.iter().any(|msg| match msg {
+        ObserveMsg::OnMsg(_, info) => matches!(
+            &info.tracing_msg.body,
+            MsgBody::OnEvent { message, .. } if message == "hello stop"
+        ),
+        _ => false,
+    }));
+}
+
+#[tokio::test]
+async fn observers_get_the_callsites_without_link_client() {
+    let (stop, _routine) = builder("callsites")
+        .await
+        .no_link_client()
+        .init()
+        .await
+        .unwrap();
+    let mut live = stop.observe(Some(QueryHistory::None)).await.unwrap();
+    let pusher = pusher_hello(&stop, "pusher").await;
+
+    push_through_layer(&pusher).await;
+
+    let span = async {
+        loop {
+            if let ObserveMsg::OnMsg(_, info) = live.next_live().await.unwrap() {
+                if let MsgBody::OnNewSpan { .. } = info.tracing_msg.body {
+                    return info.callsite;
+                }
+            }
+        }
+    };
+    let callsite = timeout(Duration::from_secs(10), span)
+        .await
+        .expect("no live span")
+        .unwrap();
+    assert_eq!(
+        (callsite.kind, callsite.name.as_str()),
+        (CallsiteKind::Span, "work")
+    );
+
+    let mut observer = stop.observe(Some(QueryHistory::Full)).await.unwrap();
+    let callsites: Vec<_> = observer
+        .history()
+        .into_iter()
+        .filter_map(|msg| match msg {
+            ObserveMsg::OnMsg(_, info) => match info.tracing_msg.body {
+                MsgBody::OnNewSpan { .. } | MsgBody::OnEvent { .. } => Some(info.callsite),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    // Along with the tracing of the embedded engine, which runs on the same thread.
+    assert!(callsites.iter().all(Option::is_some));
+    assert!(callsites
+        .iter()
+        .flatten()
+        .any(|callsite| callsite.name == "work"));
+}
+
+// Used to backfill the records missed while reconnecting.
+#[tokio::test]
+async fn all_after_key_asc_skips_the_last_key() {
+    let (stop, _routine) = builder("after").await.init().await.unwrap();
+    let mut pushers = Vec::new();
+
+    for name in ["first", "second"] {
+        let pusher = pusher_hello(&stop, name).await;
+        pushers.push(pusher);
+    }
+
+    let table = format!("{}-clients", stop.formatted_timestamp());
+    let rows: Vec<ClientRow> = stop
+        .db()
+        .query("fn::all_after_key_asc($table_name, $last_key)")
+        .bind(("table_name", table))
+        .bind(("last_key", Value::from(stop.client_id().key().clone())))
+        .await
+        .unwrap()
+        .take(0)
+        .unwrap();
+    let names: Vec<_> = rows.iter().map(|row| row.c_client_name.as_str()).collect();
+    assert_eq!(names, ["first", "second"]);
+    assert_eq!(&rows[0].id, pushers[0].client_id());
+}
+
+#[tokio::test]
+async fn query_filters_and_paginates() {
+    let (stop, _routine) = builder("query").await.init().await.unwrap();
+    let pusher = pusher_hello(&stop, "pusher").await;
+    let (layer, routine) = pusher
+        .tracing_layer_default()
+        .disable_ctrlc_shutdown()
+        .build();
+    let subscriber = tracing_subscriber::registry().with(layer);
+
+    let guard = tracing::subscriber::set_default(subscriber);
+    tracing::info_span!("work").in_scope(|| {
+        for i in 0..5 {
+            tracing::warn!(i, "retrying");
+        }
+    });
+    tracing::info!("done");
+
+    routine.graceful_shutdown().await.unwrap().unwrap();
+    drop(guard);
+
+    let messages = |page: &MsgPage| -> Vec<String> {
+        page.msgs
+            .iter()
+            .filter_map(|msg| match msg {
+                ObserveMsg::OnMsg(_, info) => match &info.tracing_msg.body {
+                    MsgBody::OnEvent { message, .. } => Some(message.clone()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect()
+    };
+
+    let page = stop.query().min_level(Level::Warn).fetch().await.unwrap();
+    assert_eq!(messages(&page), ["retrying"; 5]);
+    assert_eq!(page.cursor, None);
+
+    // Exactly full, with nothing older
+    let full = NonZeroU16::new(5).unwrap();
+    let query = stop.query().min_level(Level::Warn).limit(full);
+    assert_eq!(query.fetch().await.unwrap().cursor, None);
+
+    let query = stop
+        .query()
+        .message_contains("retry")
+        .client_name("pusher")
+        .target_prefix("stop")
+        .limit(NonZeroU16::new(2).unwrap());
+    let mut values = Vec::new();
+    let mut cursor = None;
+
+    loop {
+        let page = match cursor {
+            None => query.fetch().await.unwrap(),
+            Some(cursor) => query.clone().before(cursor).fetch().await.unwrap(),
+        };
+
+        // Pages go back in time, the msgs of each one are in ascending order.
+        let page_values = page.msgs.iter().filter_map(|msg| match msg {
+            ObserveMsg::OnMsg(_, info) => match &info.tracing_msg.body {
+                MsgBody::OnEvent { payload, .. } => Some(payload.get("i").unwrap()[0].clone()),
+                _ => None,
+            },
+            _ => None,
+        });
+        values.splice(0..0, page_values);
+
+        match page.cursor {
+            None => break,
+            Some(next) => cursor = Some(next),
+        }
+    }
+
+    assert_eq!(values, (0..5).map(FieldValue::I64).collect::<Vec<_>>());
+
+    let page = stop
+        .query()
+        .field_eq("i", FieldValue::I64(3))
+        .fetch()
+        .await
+        .unwrap();
+    assert_eq!(messages(&page), ["retrying"]);
+
+    let page = stop.query().message_contains("done").fetch().await.unwrap();
+    assert_eq!(messages(&page), ["done"]);
+    let page = stop
+        .query()
+        .until(Local::now() - chrono::Duration::hours(1))
+        .fetch()
+        .await;
+    assert!(page.unwrap().msgs.is_empty());
+    let page = stop
+        .query()
+        .since(Local::now() - chrono::Duration::hours(1))
+        .min_level(Level::Info)
+        .fetch()
+        .await
+        .unwrap();
+    assert_eq!(messages(&page).len(), 6);
+}
+
+#[tokio::test]
+async fn schema_rejects_corrupted_records() {
+    let (stop, _routine) = builder("schema").await.init().await.unwrap();
+    let table = format!("{}-clients", stop.formatted_timestamp());
+    let res: Result<Option<ClientRow>, _> = stop
+        .db()
+        .create((table, "corrupted"))
+        .content(serde_json::json!({ "c_client_name": 42 }))
+        .await;
+    assert!(res.is_err());
+}
+
+// Every `MsgBody` variant, with every optional field set, so that a field missing from (or
+// mistyped in) the SCHEMAFULL msg table shows up as a difference.
+fn every_msg_body() -> Vec<TracingMsg> {
+    let span_id = |id: u64| SpanId(id.try_into().unwrap());
+    let callsite = Callsite {
+        kind: CallsiteKind::Event,
+        level: Level::Warn,
+        name: "event".into(),
+        target: "stop::schema".into(),
+        module_path: Some("stop::schema".into()),
+        file: Some("tests/stop.rs".into()),
+        line: Some(42),
+        fields: vec!["message".into(), "answer".into()],
+    };
+    let callsite_id = callsite.id();
+    let mut payload = Payload::default();
+    payload.record("debug", FieldValue::Debug("Some(1)".into()));
+    payload.record("f64", FieldValue::F64(0.5));
+    payload.record("i64", FieldValue::I64(-42));
+    payload.record("u64", FieldValue::U64(u64::MAX));
+    payload.record("i128", FieldValue::I128(i128::MIN));
+    payload.record("u128", FieldValue::U128(u128::MAX));
+    payload.record("bytes", FieldValue::Bytes(vec![0, 1, 255]));
+    payload.record("bool", FieldValue::Bool(true));
+    payload.record("string", FieldValue::String("text".into()));
+    payload.record("string", FieldValue::String("twice".into()));
+    payload.record("error", FieldValue::Error("failed".into()));
+    payload.record(
+        "truncated",
+        FieldValue::Truncated {
+            partial: Box::new(FieldValue::String("tex".into())),
+            original_len: 4,
+        },
+    );
+    payload.record(
+        "list",
+        FieldValue::List(vec![Structured::I64(1), Structured::String("two".into())]),
+    );
+    payload.record(
+        "map",
+        FieldValue::Map(IndexMap::from([(
+            "user".into(),
+            Structured::Map(IndexMap::from([("id".into(), Structured::I64(42))])),
+        )])),
+    );
+    payload.record("null", FieldValue::Null);
+    payload.insert_empty("empty");
+    let stack = Some(vec![
+        SpanFrame {
+            span_id: span_id(1),
+            name: "outer".into(),
+        },
+        SpanFrame {
+            span_id: span_id(2),
+            name: "inner".into(),
+        },
+    ]);
+    let trace = Some(TraceContext::root(true).child(true));
+    let bodies = [
+        MsgBody::OnNewSpan {
+            span_id: span_id(3),
+            level: Level::Info,
+            callsite_id,
+            parent: Parent::Explicit(span_id(2)),
+            stack: stack.clone(),
+            trace,
+            payload: payload.clone(),
+        },
+        MsgBody::OnRecord {
+            span_id: span_id(3),
+            payload: payload.clone(),
+        },
+        MsgBody::OnFollowsFrom {
+            span_id: span_id(3),
+            follows: span_id(1),
+        },
+        MsgBody::OnEvent {
+            message: "every field".into(),
+            level: Level::Warn,
+            callsite_id,
+            parent: Parent::Current,
+            stack,
+            trace,
+            payload,
+        },
+        MsgBody::OnEnter {
+            span_id: span_id(3),
+        },
+        MsgBody::OnExit {
+            span_id: span_id(3),
+        },
+        MsgBody::OnClose {
+            span_id: span_id(3),
+            timing: Some(SpanTiming {
+                busy_ns: 1_000,
+                idle_ns: 2_000,
+                total_ns: 3_000,
+                enters: 2,
+            }),
+        },
+        MsgBody::OnIdChange {
+            old_span: span_id(3),
+            new_span: span_id(4),
+        },
+    ];
+    let register = MsgBody::OnRegisterCallsite {
+        callsite_id,
+        callsite,
+    };
+
+    std::iter::once(register)
+        .chain(bodies)
+        .map(|body| TracingMsg {
+            sampling: Some(Sampling {
+                ratio: 0.25,
+                rate_limited: 3,
+                forced: true,
+            }),
+            ..body.into()
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn schema_keeps_every_msg_body() {
+    let (stop, _routine) = builder("bodies").await.init().await.unwrap();
+    let pusher = pusher_hello(&stop, "pusher").await;
+    let msgs = every_msg_body();
+
+    pusher.clone().bulk_push(msgs.clone()).await.unwrap();
+
+    let ts = stop.formatted_timestamp();
+    let rows: Vec<MsgRow> = stop.db().select(format!("{ts}-msg")).await.unwrap();
+    let stored: Vec<_> = rows.into_iter().map(|row| row.msg).collect();
+    assert_eq!(stored, msgs[1..]);
+
+    // The payloads of the span, the record & the event
+    let mut res = stop
+        .db()
+        .query("SELECT VALUE id FROM type::table($table) WHERE payload.map.user.id = 42")
+        .bind(("table", format!("{ts}-msg")))
+        .await
+        .unwrap();
+    let ids: Vec<RecordId> = res.take(0).unwrap();
+    assert_eq!(ids.len(), 3);
+
+    let callsites: Vec<Callsite> = stop.db().select(format!("{ts}-callsites")).await.unwrap();
+    assert_eq!(callsites.len(), 1);
+    assert_eq!(callsites[0].id(), msgs[1].body.callsite_id().unwrap());
+}
+
+#[tokio::test]
+async fn init_refuses_a_newer_schema() {
+    let (stop, _routine) = builder("version").await.init().await.unwrap();
+    let db = stop.db().clone();
+
+    let (_again, _routine) = Stop::builder_default(db.clone(), "version")
+        .disable_ctrlc_shutdown()
+        .init()
+        .await
+        .unwrap();
+
+    db.query("UPDATE `.sessions` SET i_schema_version = $version")
+        .bind(("version", SCHEMA_VERSION + 1))
+        .await
+        .unwrap()
+        .check()
+        .unwrap();
+
+    let res = Stop::builder_default(db, "version")
+        .disable_ctrlc_shutdown()
+        .init()
+        .await;
+    assert!(matches!(res, Err(StopError::NewerSchema(v)) if v == SCHEMA_VERSION + 1));
+}